@@ -0,0 +1,11 @@
+-- Drop tables
+DROP TABLE IF EXISTS bates_counters;
+DROP TABLE IF EXISTS production_documents;
+
+-- Drop columns
+ALTER TABLE production_sets
+    DROP COLUMN IF EXISTS confidentiality,
+    DROP COLUMN IF EXISTS bates_padding,
+    DROP COLUMN IF EXISTS bates_end,
+    DROP COLUMN IF EXISTS bates_start,
+    DROP COLUMN IF EXISTS bates_prefix;
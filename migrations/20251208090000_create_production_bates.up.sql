@@ -0,0 +1,36 @@
+-- Bates numbering configuration on production sets
+ALTER TABLE production_sets
+    ADD COLUMN bates_prefix VARCHAR(50),
+    ADD COLUMN bates_start INTEGER,
+    ADD COLUMN bates_end INTEGER,
+    ADD COLUMN bates_padding INTEGER DEFAULT 8,
+    ADD COLUMN confidentiality VARCHAR(255);
+
+-- Create production documents table (per-document Bates ranges)
+CREATE TABLE production_documents (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    production_set_id UUID NOT NULL REFERENCES production_sets(id) ON DELETE CASCADE,
+    case_id UUID NOT NULL REFERENCES cases(id) ON DELETE CASCADE,
+    document_id UUID NOT NULL REFERENCES documents(id),
+    sort_order INTEGER NOT NULL,
+    bates_prefix VARCHAR(50) NOT NULL,
+    bates_begin INTEGER NOT NULL,
+    bates_end INTEGER NOT NULL,
+    page_count INTEGER NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(production_set_id, document_id),
+    CHECK (bates_end >= bates_begin)
+);
+
+CREATE INDEX idx_production_documents_production_set_id ON production_documents(production_set_id);
+CREATE INDEX idx_production_documents_document_id ON production_documents(document_id);
+CREATE INDEX idx_production_documents_case_prefix ON production_documents(case_id, bates_prefix);
+
+-- Create Bates counters table (next unused number per case and prefix)
+CREATE TABLE bates_counters (
+    case_id UUID NOT NULL REFERENCES cases(id) ON DELETE CASCADE,
+    prefix VARCHAR(50) NOT NULL,
+    next_number INTEGER NOT NULL DEFAULT 1,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (case_id, prefix)
+);
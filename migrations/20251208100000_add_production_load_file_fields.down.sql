@@ -0,0 +1,3 @@
+-- Drop columns
+ALTER TABLE production_sets
+    DROP COLUMN IF EXISTS load_file_fields;
@@ -0,0 +1,3 @@
+-- Per-production DAT field mapping for load file exports
+ALTER TABLE production_sets
+    ADD COLUMN load_file_fields JSONB;
@@ -0,0 +1,6 @@
+-- Drop tables
+DROP TABLE IF EXISTS production_withheld_items;
+DROP TABLE IF EXISTS privilege_designations;
+
+-- Drop enum types
+DROP TYPE IF EXISTS privilege_basis;
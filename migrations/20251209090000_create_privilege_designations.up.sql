@@ -0,0 +1,46 @@
+-- Create enum types
+CREATE TYPE privilege_basis AS ENUM (
+    'Attorney-Client',
+    'Work Product'
+);
+
+-- Create privilege designations table (one per withheld document or communication)
+CREATE TABLE privilege_designations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    case_id UUID NOT NULL REFERENCES cases(id) ON DELETE CASCADE,
+    document_id UUID REFERENCES documents(id) ON DELETE CASCADE,
+    communication_id UUID REFERENCES communications(id) ON DELETE CASCADE,
+    basis privilege_basis NOT NULL,
+    author VARCHAR(255) NOT NULL,
+    recipients TEXT[] NOT NULL DEFAULT '{}',
+    item_date TIMESTAMP WITH TIME ZONE,
+    description TEXT NOT NULL,
+    -- Whether the designation flagged its communication is_privileged, which
+    -- withdrawing it then undoes
+    flagged_communication BOOLEAN NOT NULL DEFAULT false,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    created_by UUID REFERENCES users(id),
+    updated_by UUID REFERENCES users(id),
+    version INTEGER DEFAULT 1,
+    deleted_at TIMESTAMP WITH TIME ZONE,
+    CHECK ((document_id IS NULL) <> (communication_id IS NULL))
+);
+
+CREATE INDEX idx_privilege_designations_case_id ON privilege_designations(case_id);
+CREATE UNIQUE INDEX idx_privilege_designations_document_id
+    ON privilege_designations(document_id) WHERE document_id IS NOT NULL AND deleted_at IS NULL;
+CREATE UNIQUE INDEX idx_privilege_designations_communication_id
+    ON privilege_designations(communication_id) WHERE communication_id IS NOT NULL AND deleted_at IS NULL;
+
+-- Create production withheld items table (privileged documents held back from a production)
+CREATE TABLE production_withheld_items (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    production_set_id UUID NOT NULL REFERENCES production_sets(id) ON DELETE CASCADE,
+    privilege_designation_id UUID NOT NULL REFERENCES privilege_designations(id) ON DELETE CASCADE,
+    document_id UUID NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(production_set_id, document_id)
+);
+
+CREATE INDEX idx_production_withheld_items_designation_id ON production_withheld_items(privilege_designation_id);
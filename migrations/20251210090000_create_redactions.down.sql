@@ -0,0 +1,11 @@
+-- Drop columns
+ALTER TABLE production_documents DROP COLUMN IF EXISTS redacted_version_id;
+ALTER TABLE documents DROP COLUMN IF EXISTS redacted_version_id;
+ALTER TABLE document_versions DROP COLUMN IF EXISTS is_redacted;
+
+-- Drop tables
+DROP TABLE IF EXISTS redactions;
+
+-- Drop enum types
+DROP TYPE IF EXISTS redaction_status;
+DROP TYPE IF EXISTS redaction_reason;
@@ -0,0 +1,55 @@
+-- Create enum types
+CREATE TYPE redaction_reason AS ENUM (
+    'PII',
+    'Privilege',
+    'Confidential'
+);
+
+CREATE TYPE redaction_status AS ENUM (
+    'Proposed',
+    'Approved',
+    'Rejected'
+);
+
+-- Create redactions table (text spans or page areas awaiting burn-in)
+CREATE TABLE redactions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    document_id UUID NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+    case_id UUID NOT NULL REFERENCES cases(id) ON DELETE CASCADE,
+    start_offset INTEGER,
+    end_offset INTEGER,
+    page_number INTEGER,
+    x DOUBLE PRECISION,
+    y DOUBLE PRECISION,
+    width DOUBLE PRECISION,
+    height DOUBLE PRECISION,
+    reason redaction_reason NOT NULL,
+    note TEXT,
+    status redaction_status NOT NULL DEFAULT 'Proposed',
+    reviewed_by UUID REFERENCES users(id),
+    reviewed_at TIMESTAMP WITH TIME ZONE,
+    review_note TEXT,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    created_by UUID REFERENCES users(id),
+    updated_by UUID REFERENCES users(id),
+    version INTEGER DEFAULT 1,
+    deleted_at TIMESTAMP WITH TIME ZONE,
+    CHECK (
+        (start_offset IS NOT NULL AND end_offset IS NOT NULL AND page_number IS NULL)
+        OR (start_offset IS NULL AND end_offset IS NULL AND page_number IS NOT NULL
+            AND x IS NOT NULL AND y IS NOT NULL AND width IS NOT NULL AND height IS NOT NULL)
+    )
+);
+
+CREATE INDEX idx_redactions_document_id ON redactions(document_id);
+CREATE INDEX idx_redactions_status ON redactions(status);
+
+-- Burned-in redacted output is stored as a derived document version
+ALTER TABLE document_versions ADD COLUMN is_redacted BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE documents
+    ADD COLUMN redacted_version_id UUID REFERENCES document_versions(id) ON DELETE SET NULL;
+
+-- Productions record the redacted version that was produced
+ALTER TABLE production_documents
+    ADD COLUMN redacted_version_id UUID REFERENCES document_versions(id);
@@ -0,0 +1,14 @@
+-- Drop triggers and functions
+DROP TRIGGER IF EXISTS documents_legal_hold_soft_delete ON documents;
+DROP TRIGGER IF EXISTS documents_legal_hold_delete ON documents;
+DROP FUNCTION IF EXISTS prevent_held_document_delete();
+DROP FUNCTION IF EXISTS document_on_hold(UUID, UUID);
+
+-- Drop tables
+DROP TABLE IF EXISTS retention_policies;
+DROP TABLE IF EXISTS legal_hold_documents;
+DROP TABLE IF EXISTS legal_hold_custodians;
+DROP TABLE IF EXISTS legal_holds;
+
+-- Drop enum types
+DROP TYPE IF EXISTS legal_hold_status;
@@ -0,0 +1,111 @@
+-- Create enum types
+CREATE TYPE legal_hold_status AS ENUM (
+    'Active',
+    'Released'
+);
+
+-- Create legal holds table
+CREATE TABLE legal_holds (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    case_id UUID NOT NULL REFERENCES cases(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    notice_text TEXT NOT NULL,
+    status legal_hold_status NOT NULL DEFAULT 'Active',
+    covers_entire_case BOOLEAN NOT NULL DEFAULT false,
+    reminder_interval_days INTEGER NOT NULL DEFAULT 30 CHECK (reminder_interval_days > 0),
+    issued_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    released_at TIMESTAMP WITH TIME ZONE,
+    released_by UUID REFERENCES users(id),
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    created_by UUID REFERENCES users(id),
+    updated_by UUID REFERENCES users(id),
+    version INTEGER DEFAULT 1,
+    deleted_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_legal_holds_case_id ON legal_holds(case_id);
+CREATE INDEX idx_legal_holds_status ON legal_holds(status);
+
+-- Create legal hold custodians table (custodian names match esi_sources.custodian)
+CREATE TABLE legal_hold_custodians (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    legal_hold_id UUID NOT NULL REFERENCES legal_holds(id) ON DELETE CASCADE,
+    custodian VARCHAR(255) NOT NULL,
+    user_id UUID REFERENCES users(id),
+    email VARCHAR(255),
+    notice_sent_at TIMESTAMP WITH TIME ZONE,
+    acknowledged_at TIMESTAMP WITH TIME ZONE,
+    last_reminder_at TIMESTAMP WITH TIME ZONE,
+    reminder_count INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(legal_hold_id, custodian)
+);
+
+CREATE INDEX idx_legal_hold_custodians_custodian ON legal_hold_custodians(custodian);
+
+-- Create legal hold documents table (explicit document set of a hold)
+CREATE TABLE legal_hold_documents (
+    legal_hold_id UUID NOT NULL REFERENCES legal_holds(id) ON DELETE CASCADE,
+    document_id UUID NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (legal_hold_id, document_id)
+);
+
+CREATE INDEX idx_legal_hold_documents_document_id ON legal_hold_documents(document_id);
+
+-- Create retention policies table
+CREATE TABLE retention_policies (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name VARCHAR(255) NOT NULL,
+    doc_type VARCHAR(100),
+    matter_type matter_type,
+    retention_days INTEGER NOT NULL CHECK (retention_days BETWEEN 1 AND 36525),
+    description TEXT,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    created_by UUID REFERENCES users(id),
+    updated_by UUID REFERENCES users(id),
+    version INTEGER DEFAULT 1,
+    deleted_at TIMESTAMP WITH TIME ZONE
+);
+
+-- Held documents can be neither soft-deleted nor purged, whatever the code path
+CREATE FUNCTION document_on_hold(doc_id UUID, doc_case_id UUID) RETURNS BOOLEAN AS $$
+    SELECT EXISTS (
+        SELECT 1 FROM legal_holds h
+        WHERE h.status = 'Active' AND h.deleted_at IS NULL
+          AND (
+              (h.covers_entire_case AND h.case_id = doc_case_id)
+              OR EXISTS (
+                  SELECT 1 FROM legal_hold_documents hd
+                  WHERE hd.legal_hold_id = h.id AND hd.document_id = doc_id
+              )
+          )
+    );
+$$ LANGUAGE SQL STABLE;
+
+CREATE FUNCTION prevent_held_document_delete() RETURNS TRIGGER AS $$
+BEGIN
+    IF document_on_hold(OLD.id, OLD.case_id) THEN
+        RAISE EXCEPTION 'document % is under an active legal hold', OLD.id
+            USING ERRCODE = 'check_violation';
+    END IF;
+    IF TG_OP = 'DELETE' THEN
+        RETURN OLD;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER documents_legal_hold_delete
+    BEFORE DELETE ON documents
+    FOR EACH ROW EXECUTE FUNCTION prevent_held_document_delete();
+
+CREATE TRIGGER documents_legal_hold_soft_delete
+    BEFORE UPDATE OF deleted_at ON documents
+    FOR EACH ROW
+    WHEN (OLD.deleted_at IS NULL AND NEW.deleted_at IS NOT NULL)
+    EXECUTE FUNCTION prevent_held_document_delete();
@@ -0,0 +1,16 @@
+-- Drop triggers and functions
+DROP TRIGGER IF EXISTS chain_of_custody_events_immutable ON chain_of_custody_events;
+DROP FUNCTION IF EXISTS prevent_custody_event_change();
+
+-- Drop indexes
+DROP INDEX IF EXISTS idx_chain_of_custody_events_sequence;
+
+-- Drop columns
+ALTER TABLE chain_of_custody_events
+    DROP COLUMN IF EXISTS hash,
+    DROP COLUMN IF EXISTS prev_hash,
+    DROP COLUMN IF EXISTS location,
+    DROP COLUMN IF EXISTS to_custodian,
+    DROP COLUMN IF EXISTS from_custodian,
+    DROP COLUMN IF EXISTS actor_id,
+    DROP COLUMN IF EXISTS sequence;
@@ -0,0 +1,86 @@
+-- Hash-link chain of custody events
+ALTER TABLE chain_of_custody_events
+    ADD COLUMN sequence INTEGER,
+    ADD COLUMN actor_id UUID REFERENCES users(id),
+    ADD COLUMN from_custodian VARCHAR(255),
+    ADD COLUMN to_custodian VARCHAR(255),
+    ADD COLUMN location VARCHAR(500),
+    ADD COLUMN prev_hash VARCHAR(64),
+    ADD COLUMN hash VARCHAR(64);
+
+UPDATE chain_of_custody_events e
+SET sequence = numbered.sequence
+FROM (
+    SELECT id, ROW_NUMBER() OVER (PARTITION BY evidence_id ORDER BY date, created_at) AS sequence
+    FROM chain_of_custody_events
+) numbered
+WHERE e.id = numbered.id;
+
+ALTER TABLE chain_of_custody_events ALTER COLUMN sequence SET NOT NULL;
+
+CREATE UNIQUE INDEX idx_chain_of_custody_events_sequence
+    ON chain_of_custody_events(evidence_id, sequence);
+
+-- Hash existing events the way the application does (see event_hash in
+-- src/api/evidence/custody.rs), so that chains recorded before this migration
+-- verify. This has to happen before the events become immutable below.
+DO $$
+DECLARE
+    event RECORD;
+    field TEXT;
+    digest_input BYTEA;
+    previous_evidence_id UUID;
+    previous_hash VARCHAR(64);
+    event_hash VARCHAR(64);
+BEGIN
+    FOR event IN
+        SELECT * FROM chain_of_custody_events ORDER BY evidence_id, sequence
+    LOOP
+        IF event.evidence_id IS DISTINCT FROM previous_evidence_id THEN
+            previous_hash := NULL;
+        END IF;
+
+        digest_input := ''::BYTEA;
+        FOREACH field IN ARRAY ARRAY[
+            COALESCE(previous_hash, ''),
+            event.id::TEXT,
+            event.evidence_id::TEXT,
+            event.sequence::TEXT,
+            to_char(event.date AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS.US"Z"'),
+            event.action,
+            event.actor,
+            '',
+            '',
+            '',
+            '',
+            COALESCE(event.notes, '')
+        ]
+        LOOP
+            -- Each field is prefixed with its length in bytes, big-endian
+            digest_input := digest_input
+                || int8send(octet_length(field)::BIGINT)
+                || convert_to(field, 'UTF8');
+        END LOOP;
+        event_hash := encode(sha256(digest_input), 'hex');
+
+        UPDATE chain_of_custody_events
+        SET prev_hash = previous_hash, hash = event_hash
+        WHERE id = event.id;
+
+        previous_evidence_id := event.evidence_id;
+        previous_hash := event_hash;
+    END LOOP;
+END;
+$$;
+
+-- Custody events are append-only
+CREATE FUNCTION prevent_custody_event_change() RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'chain of custody events are immutable'
+        USING ERRCODE = 'insufficient_privilege';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER chain_of_custody_events_immutable
+    BEFORE UPDATE OR DELETE ON chain_of_custody_events
+    FOR EACH ROW EXECUTE FUNCTION prevent_custody_event_change();
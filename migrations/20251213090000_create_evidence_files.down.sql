@@ -0,0 +1,9 @@
+-- Drop triggers and functions
+DROP TRIGGER IF EXISTS evidence_files_immutable ON evidence_files;
+DROP FUNCTION IF EXISTS prevent_evidence_file_change();
+
+-- Drop tables
+DROP TABLE IF EXISTS evidence_files;
+
+-- Drop enum types
+DROP TYPE IF EXISTS integrity_status;
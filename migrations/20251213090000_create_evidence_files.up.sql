@@ -0,0 +1,41 @@
+-- Create integrity status enum
+CREATE TYPE integrity_status AS ENUM ('Intact', 'Mismatch');
+
+-- Create evidence files table
+CREATE TABLE evidence_files (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    evidence_id UUID NOT NULL REFERENCES evidence_items(id),
+    tracking_uuid UUID NOT NULL,
+    file_name VARCHAR(500) NOT NULL,
+    content_type VARCHAR(255),
+    size_bytes BIGINT NOT NULL,
+    sha256 VARCHAR(64) NOT NULL,
+    md5 VARCHAR(32) NOT NULL,
+    content BYTEA NOT NULL,
+    integrity_status integrity_status NOT NULL DEFAULT 'Intact',
+    last_verified_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    uploaded_by UUID REFERENCES users(id),
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_evidence_files_evidence_id ON evidence_files(evidence_id);
+CREATE INDEX idx_evidence_files_tracking_uuid ON evidence_files(tracking_uuid);
+CREATE INDEX idx_evidence_files_last_verified_at ON evidence_files(last_verified_at);
+
+-- Acquired content and its intake digests may never be changed
+CREATE FUNCTION prevent_evidence_file_change() RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        RAISE EXCEPTION 'evidence files cannot be deleted'
+            USING ERRCODE = 'insufficient_privilege';
+    END IF;
+    RAISE EXCEPTION 'evidence file content and intake hashes are immutable'
+        USING ERRCODE = 'insufficient_privilege';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER evidence_files_immutable
+    BEFORE UPDATE OF evidence_id, tracking_uuid, content, size_bytes, sha256, md5 OR DELETE
+    ON evidence_files
+    FOR EACH ROW EXECUTE FUNCTION prevent_evidence_file_change();
@@ -0,0 +1,21 @@
+-- Drop indexes
+DROP INDEX IF EXISTS idx_trial_exhibits_document_id;
+DROP INDEX IF EXISTS idx_trial_exhibits_evidence_id;
+DROP INDEX IF EXISTS idx_trial_exhibits_party_sequence;
+
+-- Drop columns and restore free-text party and status
+ALTER TABLE trial_exhibits
+    DROP CONSTRAINT IF EXISTS trial_exhibits_single_source,
+    DROP COLUMN IF EXISTS ruled_at,
+    DROP COLUMN IF EXISTS offered_at,
+    DROP COLUMN IF EXISTS objection,
+    DROP COLUMN IF EXISTS document_id,
+    DROP COLUMN IF EXISTS evidence_id,
+    DROP COLUMN IF EXISTS exhibit_sequence,
+    ALTER COLUMN status DROP DEFAULT,
+    ALTER COLUMN status TYPE VARCHAR(50) USING status::text,
+    ALTER COLUMN party TYPE VARCHAR(100) USING party::text;
+
+-- Drop enum types
+DROP TYPE IF EXISTS exhibit_status;
+DROP TYPE IF EXISTS exhibit_party;
@@ -0,0 +1,69 @@
+-- Create exhibit enum types
+CREATE TYPE exhibit_party AS ENUM ('Plaintiff', 'Defendant', 'Joint');
+CREATE TYPE exhibit_status AS ENUM ('Marked', 'Offered', 'Admitted', 'Excluded');
+
+-- Type party and status, and track sources, numbering and rulings
+ALTER TABLE trial_exhibits
+    ALTER COLUMN party TYPE exhibit_party USING (
+        CASE
+            WHEN party ILIKE 'p%' THEN 'Plaintiff'
+            WHEN party ILIKE 'd%' THEN 'Defendant'
+            ELSE 'Joint'
+        END
+    )::exhibit_party,
+    ALTER COLUMN status DROP DEFAULT,
+    ALTER COLUMN status TYPE exhibit_status USING (
+        CASE
+            WHEN status IN ('Marked', 'Offered', 'Admitted', 'Excluded') THEN status
+            ELSE 'Marked'
+        END
+    )::exhibit_status,
+    ALTER COLUMN status SET DEFAULT 'Marked',
+    ADD COLUMN exhibit_sequence INTEGER,
+    ADD COLUMN evidence_id UUID REFERENCES evidence_items(id),
+    ADD COLUMN document_id UUID REFERENCES documents(id),
+    ADD COLUMN objection TEXT,
+    ADD COLUMN offered_at TIMESTAMP WITH TIME ZONE,
+    ADD COLUMN ruled_at TIMESTAMP WITH TIME ZONE,
+    ADD CONSTRAINT trial_exhibits_single_source CHECK (num_nonnulls(evidence_id, document_id) <= 1);
+
+-- Existing exhibits keep their numbers. Their sequence is the number's
+-- numeric suffix (P-12, PX-12 and 12 are all 12) where it parses and is not
+-- taken by an earlier exhibit of the party; the rest follow the highest one.
+WITH parsed AS (
+    SELECT id, case_id, party, date_marked, created_at,
+           NULLIF(substring(exhibit_number FROM '^\D*(\d{1,9})$')::INTEGER, 0) AS suffix
+    FROM trial_exhibits
+),
+ranked AS (
+    SELECT *,
+           suffix IS NOT NULL AND ROW_NUMBER() OVER (
+               PARTITION BY case_id, party, suffix ORDER BY date_marked, created_at
+           ) = 1 AS keeps_suffix
+    FROM parsed
+),
+highest AS (
+    SELECT *,
+           COALESCE(MAX(CASE WHEN keeps_suffix THEN suffix END) OVER (PARTITION BY case_id, party), 0)
+               AS highest_suffix
+    FROM ranked
+),
+numbered AS (
+    SELECT id, suffix AS sequence FROM highest WHERE keeps_suffix
+    UNION ALL
+    SELECT id, highest_suffix + ROW_NUMBER() OVER (
+        PARTITION BY case_id, party ORDER BY date_marked, created_at
+    )::INTEGER
+    FROM highest WHERE NOT keeps_suffix
+)
+UPDATE trial_exhibits t
+SET exhibit_sequence = numbered.sequence
+FROM numbered
+WHERE t.id = numbered.id;
+
+ALTER TABLE trial_exhibits ALTER COLUMN exhibit_sequence SET NOT NULL;
+
+CREATE UNIQUE INDEX idx_trial_exhibits_party_sequence
+    ON trial_exhibits(case_id, party, exhibit_sequence);
+CREATE INDEX idx_trial_exhibits_evidence_id ON trial_exhibits(evidence_id);
+CREATE INDEX idx_trial_exhibits_document_id ON trial_exhibits(document_id);
@@ -0,0 +1,10 @@
+-- Drop indexes
+DROP INDEX IF EXISTS idx_admissibility_challenges_legal_rule_id;
+DROP INDEX IF EXISTS idx_admissibility_challenges_motion_id;
+DROP INDEX IF EXISTS idx_admissibility_challenges_evidence_id;
+
+-- Drop tables
+DROP TABLE IF EXISTS admissibility_challenges;
+
+-- Drop enum types
+DROP TYPE IF EXISTS challenge_status;
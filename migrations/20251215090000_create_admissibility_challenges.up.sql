@@ -0,0 +1,24 @@
+-- Create challenge status enum
+CREATE TYPE challenge_status AS ENUM ('Pending', 'Sustained', 'Overruled', 'Withdrawn');
+
+-- Objections to the admissibility of evidence items, resolved by the ruling
+-- on the linked motion in limine
+CREATE TABLE admissibility_challenges (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    evidence_id UUID NOT NULL REFERENCES evidence_items(id),
+    objecting_party exhibit_party NOT NULL,
+    legal_rule_id UUID NOT NULL REFERENCES legal_rules(id),
+    motion_id UUID REFERENCES motions(id),
+    grounds TEXT,
+    status challenge_status NOT NULL DEFAULT 'Pending',
+    resolved_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    created_by UUID REFERENCES users(id),
+    updated_by UUID REFERENCES users(id),
+    deleted_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_admissibility_challenges_evidence_id ON admissibility_challenges(evidence_id);
+CREATE INDEX idx_admissibility_challenges_motion_id ON admissibility_challenges(motion_id);
+CREATE INDEX idx_admissibility_challenges_legal_rule_id ON admissibility_challenges(legal_rule_id);
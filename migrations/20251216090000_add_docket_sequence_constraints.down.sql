@@ -0,0 +1,6 @@
+-- Drop indexes
+DROP INDEX IF EXISTS idx_docket_entries_case_pacer_sequence;
+DROP INDEX IF EXISTS idx_docket_entries_case_sequence;
+
+-- Restore the original sequence index
+CREATE INDEX idx_docket_entries_sequence_number ON docket_entries(sequence_number);
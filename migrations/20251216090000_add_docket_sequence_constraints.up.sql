@@ -0,0 +1,29 @@
+-- Sequence numbers used to be entered by hand from the court's docket, so
+-- keep them as PACER numbers where none is recorded
+UPDATE docket_entries
+SET pacer_sequence_number = sequence_number
+WHERE pacer_sequence_number IS NULL AND sequence_number > 0;
+
+-- Renumber entries gap-free, keeping their relative order, only in cases
+-- whose numbers collide
+UPDATE docket_entries d
+SET sequence_number = numbered.sequence
+FROM (
+    SELECT id, ROW_NUMBER() OVER (
+        PARTITION BY case_id ORDER BY sequence_number, date, created_at
+    ) AS sequence
+    FROM docket_entries
+    WHERE case_id IN (
+        SELECT case_id FROM docket_entries
+        GROUP BY case_id, sequence_number
+        HAVING COUNT(*) > 1
+    )
+) numbered
+WHERE d.id = numbered.id;
+
+-- Sequence numbers are assigned by the server and never reused
+DROP INDEX IF EXISTS idx_docket_entries_sequence_number;
+CREATE UNIQUE INDEX idx_docket_entries_case_sequence ON docket_entries(case_id, sequence_number);
+CREATE INDEX idx_docket_entries_case_pacer_sequence
+    ON docket_entries(case_id, pacer_sequence_number)
+    WHERE pacer_sequence_number IS NOT NULL;
@@ -0,0 +1,4 @@
+-- One live case per PACER case, matched on court and case number by the docket importer
+CREATE UNIQUE INDEX idx_cases_pacer_case_number
+    ON cases ((pacer_data->>'court'), (pacer_data->>'case_number'))
+    WHERE deleted_at IS NULL AND pacer_data ? 'case_number';
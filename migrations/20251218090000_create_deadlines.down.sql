@@ -0,0 +1,21 @@
+-- Drop indexes
+DROP INDEX IF EXISTS idx_deadlines_deadline_rule_id;
+DROP INDEX IF EXISTS idx_deadlines_due_date;
+DROP INDEX IF EXISTS idx_deadlines_case_id;
+DROP INDEX IF EXISTS idx_deadline_rules_legal_rule_id;
+DROP INDEX IF EXISTS idx_deadline_rules_trigger_event;
+
+-- Drop tables
+DROP TABLE IF EXISTS deadlines;
+DROP TABLE IF EXISTS deadline_rules;
+
+-- Drop columns
+ALTER TABLE docket_entries
+    DROP COLUMN IF EXISTS service_method,
+    DROP COLUMN IF EXISTS trigger_event;
+
+-- Drop enum types
+DROP TYPE IF EXISTS deadline_status;
+DROP TYPE IF EXISTS day_count;
+DROP TYPE IF EXISTS service_method;
+DROP TYPE IF EXISTS deadline_trigger;
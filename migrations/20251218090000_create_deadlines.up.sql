@@ -0,0 +1,63 @@
+-- Create deadline enums
+CREATE TYPE deadline_trigger AS ENUM (
+    'ComplaintServed',
+    'AmendedPleadingServed',
+    'MotionFiled',
+    'DiscoveryServed',
+    'JudgmentEntered',
+    'OrderEntered'
+);
+CREATE TYPE service_method AS ENUM ('Personal', 'Electronic', 'Mail', 'LeftWithClerk', 'OtherConsented');
+CREATE TYPE day_count AS ENUM ('CalendarDays', 'CourtDays');
+CREATE TYPE deadline_status AS ENUM ('Open', 'Completed', 'Vacated');
+
+-- Docket events that start deadline periods running
+ALTER TABLE docket_entries
+    ADD COLUMN trigger_event deadline_trigger,
+    ADD COLUMN service_method service_method;
+
+-- Periods prescribed by legal rules, counted from a docket event
+CREATE TABLE deadline_rules (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    legal_rule_id UUID NOT NULL REFERENCES legal_rules(id),
+    trigger_event deadline_trigger NOT NULL,
+    title VARCHAR(500) NOT NULL,
+    days INTEGER NOT NULL CHECK (days BETWEEN -1098 AND 1098),
+    day_count day_count NOT NULL DEFAULT 'CalendarDays',
+    service_days_apply BOOLEAN NOT NULL DEFAULT FALSE,
+    court VARCHAR(200),
+    jurisdiction VARCHAR(200),
+    priority VARCHAR(50) NOT NULL DEFAULT 'High' CHECK (priority IN ('Low', 'Medium', 'High', 'Critical')),
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    created_by UUID REFERENCES users(id),
+    updated_by UUID REFERENCES users(id),
+    deleted_at TIMESTAMP WITH TIME ZONE,
+    CHECK (day_count = 'CalendarDays' OR days BETWEEN -549 AND 549)
+);
+
+-- Deadlines computed for docket entries; one per entry and rule
+CREATE TABLE deadlines (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    case_id UUID NOT NULL REFERENCES cases(id),
+    docket_entry_id UUID NOT NULL REFERENCES docket_entries(id),
+    deadline_rule_id UUID NOT NULL REFERENCES deadline_rules(id),
+    task_id UUID REFERENCES workflow_tasks(id),
+    title VARCHAR(500) NOT NULL,
+    trigger_date DATE NOT NULL,
+    due_date DATE NOT NULL,
+    computation TEXT NOT NULL,
+    status deadline_status NOT NULL DEFAULT 'Open',
+    completed_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    created_by UUID REFERENCES users(id),
+    updated_by UUID REFERENCES users(id),
+    UNIQUE (docket_entry_id, deadline_rule_id)
+);
+
+CREATE INDEX idx_deadline_rules_trigger_event ON deadline_rules(trigger_event);
+CREATE INDEX idx_deadline_rules_legal_rule_id ON deadline_rules(legal_rule_id);
+CREATE INDEX idx_deadlines_case_id ON deadlines(case_id);
+CREATE INDEX idx_deadlines_due_date ON deadlines(due_date);
+CREATE INDEX idx_deadlines_deadline_rule_id ON deadlines(deadline_rule_id);
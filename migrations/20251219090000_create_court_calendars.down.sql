@@ -0,0 +1,11 @@
+-- Drop indexes
+DROP INDEX IF EXISTS idx_court_closures_date;
+DROP INDEX IF EXISTS idx_court_closures_calendar_date;
+DROP INDEX IF EXISTS idx_court_calendars_court_jurisdiction;
+
+-- Drop tables
+DROP TABLE IF EXISTS court_closures;
+DROP TABLE IF EXISTS court_calendars;
+
+-- Drop enum types
+DROP TYPE IF EXISTS court_closure_kind;
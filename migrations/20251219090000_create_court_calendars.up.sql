@@ -0,0 +1,42 @@
+-- Create court closure kind enum
+CREATE TYPE court_closure_kind AS ENUM ('Holiday', 'Closure');
+
+-- Holiday and closure calendars, keyed by the court and/or jurisdiction of
+-- the cases they apply to
+CREATE TABLE court_calendars (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name VARCHAR(255) NOT NULL,
+    court VARCHAR(200),
+    jurisdiction VARCHAR(200),
+    observes_federal_holidays BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    created_by UUID REFERENCES users(id),
+    updated_by UUID REFERENCES users(id),
+    deleted_at TIMESTAMP WITH TIME ZONE,
+    CHECK (court IS NOT NULL OR jurisdiction IS NOT NULL)
+);
+
+-- Days a calendar's courts are closed, beyond weekends and federal holidays
+CREATE TABLE court_closures (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    calendar_id UUID NOT NULL REFERENCES court_calendars(id),
+    date DATE NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    kind court_closure_kind NOT NULL DEFAULT 'Closure',
+    -- Name of the imported file the holiday came from
+    source VARCHAR(255),
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    created_by UUID REFERENCES users(id),
+    updated_by UUID REFERENCES users(id),
+    deleted_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE UNIQUE INDEX idx_court_calendars_court_jurisdiction
+    ON court_calendars(COALESCE(court, ''), COALESCE(jurisdiction, ''))
+    WHERE deleted_at IS NULL;
+CREATE UNIQUE INDEX idx_court_closures_calendar_date
+    ON court_closures(calendar_id, date)
+    WHERE deleted_at IS NULL;
+CREATE INDEX idx_court_closures_date ON court_closures(date);
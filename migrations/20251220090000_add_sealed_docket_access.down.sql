@@ -0,0 +1,20 @@
+-- Drop indexes
+DROP INDEX IF EXISTS idx_sealed_entry_access_log_user_id;
+DROP INDEX IF EXISTS idx_sealed_entry_access_log_entry_id;
+DROP INDEX IF EXISTS idx_sealed_entry_grants_user_id;
+DROP INDEX IF EXISTS idx_sealed_entry_grants_entry_user;
+
+-- Drop tables
+DROP TABLE IF EXISTS sealed_entry_access_log;
+DROP TABLE IF EXISTS sealed_entry_grants;
+
+-- Drop columns
+ALTER TABLE docket_entries
+    DROP COLUMN IF EXISTS unsealed_at,
+    DROP COLUMN IF EXISTS unseal_order,
+    DROP COLUMN IF EXISTS sealed_by,
+    DROP COLUMN IF EXISTS sealed_at,
+    DROP COLUMN IF EXISTS seal_order;
+
+-- Drop enum types
+DROP TYPE IF EXISTS sealed_access_action;
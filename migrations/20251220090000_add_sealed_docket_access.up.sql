@@ -0,0 +1,62 @@
+-- Create sealed docket access action enum
+CREATE TYPE sealed_access_action AS ENUM (
+    'Sealed', 'Unsealed', 'Viewed', 'Modified', 'Deleted', 'Denied', 'Granted', 'Revoked'
+);
+
+-- Court orders under which entries are sealed and unsealed
+ALTER TABLE docket_entries
+    ADD COLUMN seal_order VARCHAR(255),
+    ADD COLUMN sealed_at TIMESTAMP WITH TIME ZONE,
+    ADD COLUMN sealed_by UUID REFERENCES users(id),
+    ADD COLUMN unseal_order VARCHAR(255),
+    ADD COLUMN unsealed_at TIMESTAMP WITH TIME ZONE;
+
+-- Users allowed to see the contents of a sealed docket entry
+CREATE TABLE sealed_entry_grants (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    docket_entry_id UUID NOT NULL REFERENCES docket_entries(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id),
+    reason TEXT,
+    granted_by UUID REFERENCES users(id),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    revoked_at TIMESTAMP WITH TIME ZONE,
+    revoked_by UUID REFERENCES users(id)
+);
+
+-- Append-only trail of every access to a sealed docket entry
+CREATE TABLE sealed_entry_access_log (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    docket_entry_id UUID NOT NULL REFERENCES docket_entries(id) ON DELETE CASCADE,
+    user_id UUID REFERENCES users(id),
+    action sealed_access_action NOT NULL,
+    court_order VARCHAR(255),
+    details TEXT,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX idx_sealed_entry_grants_entry_user
+    ON sealed_entry_grants(docket_entry_id, user_id)
+    WHERE revoked_at IS NULL;
+CREATE INDEX idx_sealed_entry_grants_user_id ON sealed_entry_grants(user_id);
+CREATE INDEX idx_sealed_entry_access_log_entry_id
+    ON sealed_entry_access_log(docket_entry_id, created_at);
+CREATE INDEX idx_sealed_entry_access_log_user_id ON sealed_entry_access_log(user_id);
+
+-- Entries sealed before access was restricted stay visible to whoever
+-- created them and to the owner of their case
+WITH legacy_grants AS (
+    SELECT DISTINCT d.id AS docket_entry_id, grantee.user_id
+    FROM docket_entries d
+    JOIN cases c ON c.id = d.case_id
+    CROSS JOIN LATERAL (VALUES (d.created_by), (c.owner_id)) AS grantee(user_id)
+    WHERE d.is_sealed = true AND grantee.user_id IS NOT NULL
+),
+inserted AS (
+    INSERT INTO sealed_entry_grants (docket_entry_id, user_id, reason)
+    SELECT docket_entry_id, user_id, 'Sealed before access was restricted'
+    FROM legacy_grants
+    RETURNING docket_entry_id, user_id
+)
+INSERT INTO sealed_entry_access_log (docket_entry_id, action, details)
+SELECT docket_entry_id, 'Granted', 'Access granted to user ' || user_id
+FROM inserted;
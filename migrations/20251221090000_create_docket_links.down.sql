@@ -0,0 +1,13 @@
+-- Drop indexes
+DROP INDEX IF EXISTS idx_motion_docket_entries_docket_entry_id;
+DROP INDEX IF EXISTS idx_motion_docket_entries_motion_entry;
+DROP INDEX IF EXISTS idx_docket_entry_documents_document_id;
+DROP INDEX IF EXISTS idx_docket_entry_documents_entry_document;
+DROP INDEX IF EXISTS idx_docket_entry_documents_entry_attachment;
+
+-- Drop tables
+DROP TABLE IF EXISTS motion_docket_entries;
+DROP TABLE IF EXISTS docket_entry_documents;
+
+-- Drop enum types
+DROP TYPE IF EXISTS motion_filing_role;
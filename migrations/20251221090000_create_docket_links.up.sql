@@ -0,0 +1,33 @@
+-- Create motion filing role enum
+CREATE TYPE motion_filing_role AS ENUM ('Filing', 'Opposition', 'Reply', 'Order');
+
+-- Documents filed with a docket entry. Attachment 0 is the main document;
+-- numbered attachments follow it, as on CM/ECF.
+CREATE TABLE docket_entry_documents (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    docket_entry_id UUID NOT NULL REFERENCES docket_entries(id) ON DELETE CASCADE,
+    document_id UUID NOT NULL REFERENCES documents(id),
+    attachment_number INTEGER NOT NULL CHECK (attachment_number >= 0),
+    description VARCHAR(500),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    created_by UUID REFERENCES users(id)
+);
+
+-- Docket entries of a motion's papers and the order deciding it
+CREATE TABLE motion_docket_entries (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    motion_id UUID NOT NULL REFERENCES motions(id) ON DELETE CASCADE,
+    docket_entry_id UUID NOT NULL REFERENCES docket_entries(id) ON DELETE CASCADE,
+    role motion_filing_role NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    created_by UUID REFERENCES users(id)
+);
+
+CREATE UNIQUE INDEX idx_docket_entry_documents_entry_attachment
+    ON docket_entry_documents(docket_entry_id, attachment_number);
+CREATE UNIQUE INDEX idx_docket_entry_documents_entry_document
+    ON docket_entry_documents(docket_entry_id, document_id);
+CREATE INDEX idx_docket_entry_documents_document_id ON docket_entry_documents(document_id);
+CREATE UNIQUE INDEX idx_motion_docket_entries_motion_entry
+    ON motion_docket_entries(motion_id, docket_entry_id);
+CREATE INDEX idx_motion_docket_entries_docket_entry_id ON motion_docket_entries(docket_entry_id);
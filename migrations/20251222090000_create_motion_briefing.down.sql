@@ -0,0 +1,14 @@
+-- Drop indexes
+DROP INDEX IF EXISTS idx_briefing_extensions_briefing_deadline_id;
+DROP INDEX IF EXISTS idx_briefing_deadlines_briefing_rule_id;
+DROP INDEX IF EXISTS idx_briefing_deadlines_due_date;
+DROP INDEX IF EXISTS idx_calendar_events_event_date;
+DROP INDEX IF EXISTS idx_calendar_events_case_id;
+DROP INDEX IF EXISTS idx_briefing_rules_legal_rule_id;
+DROP INDEX IF EXISTS idx_briefing_rules_role;
+
+-- Drop tables
+DROP TABLE IF EXISTS briefing_extensions;
+DROP TABLE IF EXISTS briefing_deadlines;
+DROP TABLE IF EXISTS calendar_events;
+DROP TABLE IF EXISTS briefing_rules;
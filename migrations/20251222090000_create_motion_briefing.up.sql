@@ -0,0 +1,80 @@
+-- Local rules setting when briefs on a motion are due, counted back from
+-- the hearing
+CREATE TABLE briefing_rules (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    legal_rule_id UUID NOT NULL REFERENCES legal_rules(id),
+    -- Motion type the rule is limited to; NULL applies to every motion
+    motion_type motion_type,
+    role motion_filing_role NOT NULL CHECK (role IN ('Opposition', 'Reply')),
+    days_before_hearing INTEGER NOT NULL CHECK (days_before_hearing BETWEEN 1 AND 365),
+    day_count day_count NOT NULL DEFAULT 'CalendarDays',
+    court VARCHAR(200),
+    jurisdiction VARCHAR(200),
+    priority VARCHAR(50) NOT NULL DEFAULT 'High' CHECK (priority IN ('Low', 'Medium', 'High', 'Critical')),
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    created_by UUID REFERENCES users(id),
+    updated_by UUID REFERENCES users(id),
+    deleted_at TIMESTAMP WITH TIME ZONE
+);
+
+-- Dated events shown on a case's calendar
+CREATE TABLE calendar_events (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    case_id UUID NOT NULL REFERENCES cases(id) ON DELETE CASCADE,
+    title VARCHAR(500) NOT NULL,
+    event_date DATE NOT NULL,
+    description TEXT,
+    related_module VARCHAR(100),
+    related_item_id VARCHAR(255),
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    created_by UUID REFERENCES users(id),
+    updated_by UUID REFERENCES users(id),
+    deleted_at TIMESTAMP WITH TIME ZONE
+);
+
+-- Opposition and reply due dates of a motion. The due date is the latest
+-- stipulated extension, else the manual override, else the computed date.
+CREATE TABLE briefing_deadlines (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    motion_id UUID NOT NULL REFERENCES motions(id) ON DELETE CASCADE,
+    role motion_filing_role NOT NULL CHECK (role IN ('Opposition', 'Reply')),
+    briefing_rule_id UUID REFERENCES briefing_rules(id),
+    computed_date DATE,
+    computation TEXT,
+    override_date DATE,
+    override_reason TEXT,
+    due_date DATE NOT NULL,
+    status deadline_status NOT NULL DEFAULT 'Open',
+    task_id UUID REFERENCES workflow_tasks(id),
+    calendar_event_id UUID REFERENCES calendar_events(id),
+    completed_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    created_by UUID REFERENCES users(id),
+    updated_by UUID REFERENCES users(id),
+    UNIQUE (motion_id, role)
+);
+
+-- Extensions of a briefing deadline agreed between the parties
+CREATE TABLE briefing_extensions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    briefing_deadline_id UUID NOT NULL REFERENCES briefing_deadlines(id) ON DELETE CASCADE,
+    previous_due_date DATE NOT NULL,
+    new_due_date DATE NOT NULL CHECK (new_due_date > previous_due_date),
+    stipulated_on DATE NOT NULL,
+    -- Docket entry of the stipulation or the order approving it
+    docket_entry_id UUID REFERENCES docket_entries(id),
+    notes TEXT,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    created_by UUID REFERENCES users(id)
+);
+
+CREATE INDEX idx_briefing_rules_role ON briefing_rules(role);
+CREATE INDEX idx_briefing_rules_legal_rule_id ON briefing_rules(legal_rule_id);
+CREATE INDEX idx_calendar_events_case_id ON calendar_events(case_id);
+CREATE INDEX idx_calendar_events_event_date ON calendar_events(event_date);
+CREATE INDEX idx_briefing_deadlines_due_date ON briefing_deadlines(due_date);
+CREATE INDEX idx_briefing_deadlines_briefing_rule_id ON briefing_deadlines(briefing_rule_id);
+CREATE INDEX idx_briefing_extensions_briefing_deadline_id ON briefing_extensions(briefing_deadline_id);
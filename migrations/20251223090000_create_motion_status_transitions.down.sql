@@ -0,0 +1,5 @@
+-- Drop indexes
+DROP INDEX IF EXISTS idx_motion_status_transitions_motion_id;
+
+-- Drop tables
+DROP TABLE IF EXISTS motion_status_transitions;
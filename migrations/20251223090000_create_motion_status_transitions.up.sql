@@ -0,0 +1,18 @@
+-- Status changes of a motion, oldest first. The first row records the
+-- status the motion was created in.
+CREATE TABLE motion_status_transitions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    motion_id UUID NOT NULL REFERENCES motions(id) ON DELETE CASCADE,
+    from_status motion_status,
+    to_status motion_status NOT NULL,
+    -- Docket entry whose docketing moved the motion
+    docket_entry_id UUID REFERENCES docket_entries(id),
+    transitioned_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    transitioned_by UUID REFERENCES users(id)
+);
+
+CREATE INDEX idx_motion_status_transitions_motion_id ON motion_status_transitions(motion_id);
+
+-- Existing motions start their history in their current status
+INSERT INTO motion_status_transitions (motion_id, to_status, transitioned_at, transitioned_by)
+SELECT id, status, COALESCE(created_at, CURRENT_TIMESTAMP), created_by FROM motions;
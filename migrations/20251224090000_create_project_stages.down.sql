@@ -0,0 +1,12 @@
+-- Drop indexes
+DROP INDEX IF EXISTS idx_workflow_tasks_project_id;
+DROP INDEX IF EXISTS idx_workflow_tasks_stage_id;
+DROP INDEX IF EXISTS idx_project_stages_project_id;
+
+-- Drop columns
+ALTER TABLE workflow_tasks DROP COLUMN IF EXISTS stage_id;
+ALTER TABLE projects DROP COLUMN IF EXISTS anchor_date;
+ALTER TABLE projects DROP COLUMN IF EXISTS template_id;
+
+-- Drop tables
+DROP TABLE IF EXISTS project_stages;
@@ -0,0 +1,25 @@
+-- Projects created from a workflow template, and the date the template's
+-- task offsets were counted from
+ALTER TABLE projects ADD COLUMN template_id UUID REFERENCES workflow_templates(id);
+ALTER TABLE projects ADD COLUMN anchor_date DATE;
+
+-- Stages of a project, in template order. Status rolls up from the
+-- stage's tasks.
+CREATE TABLE project_stages (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    status stage_status NOT NULL DEFAULT 'Pending',
+    completed_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (project_id, position)
+);
+
+CREATE INDEX idx_project_stages_project_id ON project_stages(project_id);
+
+ALTER TABLE workflow_tasks ADD COLUMN stage_id UUID REFERENCES project_stages(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_workflow_tasks_stage_id ON workflow_tasks(stage_id);
+CREATE INDEX idx_workflow_tasks_project_id ON workflow_tasks(project_id);
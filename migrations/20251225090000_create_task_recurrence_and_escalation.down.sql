@@ -0,0 +1,18 @@
+-- Drop indexes
+DROP INDEX IF EXISTS idx_task_escalations_task_id;
+DROP INDEX IF EXISTS idx_escalation_rules_case_id;
+
+-- Drop tables
+DROP TABLE IF EXISTS task_escalations;
+DROP TABLE IF EXISTS escalation_rules;
+
+-- Drop columns
+ALTER TABLE workflow_tasks DROP CONSTRAINT IF EXISTS workflow_tasks_series_occurrence_key;
+ALTER TABLE workflow_tasks DROP COLUMN IF EXISTS occurrence;
+ALTER TABLE workflow_tasks DROP COLUMN IF EXISTS series_id;
+ALTER TABLE workflow_tasks DROP COLUMN IF EXISTS recurrence_trigger;
+ALTER TABLE workflow_tasks DROP COLUMN IF EXISTS recurrence;
+
+-- Drop enum types
+DROP TYPE IF EXISTS escalation_action;
+DROP TYPE IF EXISTS recurrence_trigger;
@@ -0,0 +1,49 @@
+-- When the next task of a recurring series is created: once the current
+-- one is done, or once it falls due whether done or not
+CREATE TYPE recurrence_trigger AS ENUM ('OnCompletion', 'OnSchedule');
+
+-- What an escalation rule does with an overdue task
+CREATE TYPE escalation_action AS ENUM ('Reassign', 'Notify');
+
+-- Recurring tasks. Every task of a series shares the series ID (the ID of
+-- its first task) and is numbered from 1.
+ALTER TABLE workflow_tasks ADD COLUMN recurrence VARCHAR(255);
+ALTER TABLE workflow_tasks ADD COLUMN recurrence_trigger recurrence_trigger;
+ALTER TABLE workflow_tasks ADD COLUMN series_id UUID;
+ALTER TABLE workflow_tasks ADD COLUMN occurrence INTEGER;
+ALTER TABLE workflow_tasks ADD CONSTRAINT workflow_tasks_series_occurrence_key UNIQUE (series_id, occurrence);
+
+-- Escalate open tasks of at least a given priority once they are overdue
+-- by a number of hours
+CREATE TABLE escalation_rules (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name VARCHAR(255) NOT NULL,
+    min_priority VARCHAR(20) NOT NULL CHECK (min_priority IN ('High', 'Critical')),
+    overdue_hours INTEGER NOT NULL CHECK (overdue_hours > 0),
+    action escalation_action NOT NULL,
+    supervisor_id UUID NOT NULL REFERENCES users(id),
+    -- Only tasks of this case; all cases when NULL
+    case_id UUID REFERENCES cases(id) ON DELETE CASCADE,
+    is_active BOOLEAN NOT NULL DEFAULT true,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    created_by UUID REFERENCES users(id),
+    updated_by UUID REFERENCES users(id),
+    deleted_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_escalation_rules_case_id ON escalation_rules(case_id);
+
+-- Each rule escalates a task at most once
+CREATE TABLE task_escalations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    task_id UUID NOT NULL REFERENCES workflow_tasks(id) ON DELETE CASCADE,
+    rule_id UUID NOT NULL REFERENCES escalation_rules(id) ON DELETE CASCADE,
+    action escalation_action NOT NULL,
+    previous_assignee_id UUID REFERENCES users(id),
+    supervisor_id UUID NOT NULL REFERENCES users(id),
+    escalated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (task_id, rule_id)
+);
+
+CREATE INDEX idx_task_escalations_task_id ON task_escalations(task_id);
@@ -0,0 +1,13 @@
+-- Drop tables
+DROP TABLE IF EXISTS board_wip_limits;
+
+-- Drop triggers and functions
+DROP TRIGGER IF EXISTS workflow_tasks_clear_board_ranks ON workflow_tasks;
+DROP FUNCTION IF EXISTS clear_task_board_ranks();
+
+-- Drop columns
+ALTER TABLE workflow_tasks
+    DROP CONSTRAINT IF EXISTS workflow_tasks_project_board_rank_key,
+    DROP CONSTRAINT IF EXISTS workflow_tasks_case_board_rank_key,
+    DROP COLUMN IF EXISTS project_board_rank,
+    DROP COLUMN IF EXISTS case_board_rank;
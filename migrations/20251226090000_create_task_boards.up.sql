@@ -0,0 +1,48 @@
+-- Position of a task within its status column on Kanban boards. A task
+-- appears on its case's board and on its project's board, so it has a rank
+-- on each. Ranks are compared byte by byte; tasks without one come last,
+-- soonest due first. Respreading a column reassigns ranks one task at a
+-- time, so uniqueness is checked at commit.
+ALTER TABLE workflow_tasks
+    ADD COLUMN case_board_rank VARCHAR(255) COLLATE "C",
+    ADD COLUMN project_board_rank VARCHAR(255) COLLATE "C",
+    ADD CONSTRAINT workflow_tasks_case_board_rank_key
+        UNIQUE (case_id, status, case_board_rank) DEFERRABLE INITIALLY DEFERRED,
+    ADD CONSTRAINT workflow_tasks_project_board_rank_key
+        UNIQUE (project_id, status, project_board_rank) DEFERRABLE INITIALLY DEFERRED;
+
+-- A task entering a column other than by a move goes to the end of it, and
+-- a deleted task gives up its place
+CREATE FUNCTION clear_task_board_ranks() RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.status IS DISTINCT FROM OLD.status OR NEW.deleted_at IS NOT NULL
+        OR NEW.case_id IS DISTINCT FROM OLD.case_id THEN
+        NEW.case_board_rank := NULL;
+    END IF;
+    IF NEW.status IS DISTINCT FROM OLD.status OR NEW.deleted_at IS NOT NULL
+        OR NEW.project_id IS DISTINCT FROM OLD.project_id THEN
+        NEW.project_board_rank := NULL;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER workflow_tasks_clear_board_ranks
+    BEFORE UPDATE ON workflow_tasks
+    FOR EACH ROW EXECUTE FUNCTION clear_task_board_ranks();
+
+-- Work-in-progress limits of board columns, for a case's board or a
+-- project's board
+CREATE TABLE board_wip_limits (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    case_id UUID REFERENCES cases(id) ON DELETE CASCADE,
+    project_id UUID REFERENCES projects(id) ON DELETE CASCADE,
+    status task_status NOT NULL,
+    wip_limit INTEGER NOT NULL CHECK (wip_limit > 0),
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_by UUID REFERENCES users(id),
+    CHECK ((case_id IS NULL) <> (project_id IS NULL)),
+    UNIQUE (case_id, status),
+    UNIQUE (project_id, status)
+);
@@ -0,0 +1,18 @@
+-- Drop tables
+DROP TABLE IF EXISTS discovery_request_items;
+
+-- Drop indexes
+DROP INDEX IF EXISTS idx_discovery_requests_direction;
+
+-- Drop columns
+ALTER TABLE discovery_requests
+    DROP COLUMN IF EXISTS meet_and_confer_task_id,
+    DROP COLUMN IF EXISTS responded_at,
+    DROP COLUMN IF EXISTS computation,
+    DROP COLUMN IF EXISTS response_days,
+    DROP COLUMN IF EXISTS service_method,
+    DROP COLUMN IF EXISTS set_number,
+    DROP COLUMN IF EXISTS direction;
+
+-- Drop types
+DROP TYPE IF EXISTS discovery_direction;
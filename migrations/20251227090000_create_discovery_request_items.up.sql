@@ -0,0 +1,33 @@
+-- Whether a set of discovery requests was served by our side or on it
+CREATE TYPE discovery_direction AS ENUM ('Propounded', 'Received');
+
+ALTER TABLE discovery_requests
+    ADD COLUMN direction discovery_direction NOT NULL DEFAULT 'Propounded',
+    ADD COLUMN set_number INTEGER NOT NULL DEFAULT 1 CHECK (set_number > 0),
+    ADD COLUMN service_method service_method NOT NULL DEFAULT 'Electronic',
+    ADD COLUMN response_days INTEGER CHECK (response_days BETWEEN 1 AND 365),
+    ADD COLUMN computation TEXT,
+    ADD COLUMN responded_at TIMESTAMP WITH TIME ZONE,
+    ADD COLUMN meet_and_confer_task_id UUID REFERENCES workflow_tasks(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_discovery_requests_direction ON discovery_requests(direction);
+
+-- Individually numbered requests within a set (Interrogatory No. 1, ...).
+-- Numbers are closed up when a request is removed from a draft set, so the
+-- uniqueness check is deferred to the end of the transaction.
+CREATE TABLE discovery_request_items (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    discovery_request_id UUID NOT NULL REFERENCES discovery_requests(id) ON DELETE CASCADE,
+    number INTEGER NOT NULL CHECK (number > 0),
+    text TEXT NOT NULL,
+    response TEXT,
+    objections TEXT,
+    responded_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+    created_by UUID REFERENCES users(id),
+    updated_by UUID REFERENCES users(id),
+    UNIQUE (discovery_request_id, number) DEFERRABLE INITIALLY DEFERRED
+);
+
+CREATE INDEX idx_discovery_request_items_request_id ON discovery_request_items(discovery_request_id);
@@ -0,0 +1,112 @@
+use crate::api::analytics::outcomes::outcome_report_csv;
+use crate::api::analytics::service::{AnalyticsService, OutcomeFilters};
+use crate::error::AppError;
+use crate::models::{MotionOutcomeReport, MotionType, OutcomeDimension};
+use axum::{
+    extract::{Query, State},
+    http::header,
+    response::{IntoResponse, Json},
+};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MotionOutcomesQuery {
+    /// Judge (default), MotionType, Court or NatureOfSuit
+    pub group_by: Option<OutcomeDimension>,
+    /// Earliest decision date
+    pub from: Option<NaiveDate>,
+    /// Latest decision date
+    pub to: Option<NaiveDate>,
+    pub judge: Option<String>,
+    pub court: Option<String>,
+    pub motion_type: Option<MotionType>,
+    pub nature_of_suit: Option<String>,
+}
+
+impl MotionOutcomesQuery {
+    fn into_parts(self) -> (OutcomeDimension, OutcomeFilters) {
+        (
+            self.group_by.unwrap_or(OutcomeDimension::Judge),
+            OutcomeFilters {
+                from: self.from,
+                to: self.to,
+                judge: self.judge,
+                court: self.court,
+                motion_type: self.motion_type,
+                nature_of_suit: self.nature_of_suit,
+            },
+        )
+    }
+}
+
+/// Report motion grant and deny rates and decision times
+#[utoipa::path(
+    get,
+    path = "/api/analytics/motion-outcomes",
+    params(
+        ("group_by" = Option<OutcomeDimension>, Query, description = "Judge (default), MotionType, Court or NatureOfSuit"),
+        ("from" = Option<NaiveDate>, Query, description = "Earliest decision date"),
+        ("to" = Option<NaiveDate>, Query, description = "Latest decision date"),
+        ("judge" = Option<String>, Query, description = "Only cases before this judge"),
+        ("court" = Option<String>, Query, description = "Only cases in this court"),
+        ("motion_type" = Option<MotionType>, Query, description = "Only motions of this type"),
+        ("nature_of_suit" = Option<String>, Query, description = "Only cases of this nature of suit")
+    ),
+    responses(
+        (status = 200, description = "Outcome statistics by group and overall", body = MotionOutcomeReport),
+        (status = 400, description = "Invalid date range"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "analytics",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_motion_outcomes(
+    State(service): State<Arc<AnalyticsService>>,
+    Query(query): Query<MotionOutcomesQuery>,
+) -> Result<Json<MotionOutcomeReport>, AppError> {
+    let (group_by, filters) = query.into_parts();
+    let report = service.motion_outcomes(group_by, filters).await?;
+    Ok(Json(report))
+}
+
+/// Download the motion outcome report as CSV
+#[utoipa::path(
+    get,
+    path = "/api/analytics/motion-outcomes/export",
+    params(
+        ("group_by" = Option<OutcomeDimension>, Query, description = "Judge (default), MotionType, Court or NatureOfSuit"),
+        ("from" = Option<NaiveDate>, Query, description = "Earliest decision date"),
+        ("to" = Option<NaiveDate>, Query, description = "Latest decision date"),
+        ("judge" = Option<String>, Query, description = "Only cases before this judge"),
+        ("court" = Option<String>, Query, description = "Only cases in this court"),
+        ("motion_type" = Option<MotionType>, Query, description = "Only motions of this type"),
+        ("nature_of_suit" = Option<String>, Query, description = "Only cases of this nature of suit")
+    ),
+    responses(
+        (status = 200, description = "Outcome report, one row per group and a final overall row", content_type = "text/csv"),
+        (status = 400, description = "Invalid date range"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "analytics",
+    security(("bearer_auth" = []))
+)]
+pub async fn export_motion_outcomes(
+    State(service): State<Arc<AnalyticsService>>,
+    Query(query): Query<MotionOutcomesQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let (group_by, filters) = query.into_parts();
+    let report = service.motion_outcomes(group_by, filters).await?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"motion-outcomes.csv\"".to_string(),
+            ),
+        ],
+        outcome_report_csv(&report),
+    ))
+}
@@ -0,0 +1,192 @@
+//! Aggregation of decided motions into grant and deny rates and decision
+//! times
+
+use crate::export::write_csv;
+use crate::models::{
+    MotionOutcome, MotionOutcomeReport, MotionType, OutcomeDimension, OutcomeStats,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::BTreeMap;
+
+/// Columns of an exported outcome report after the group column
+pub const OUTCOME_REPORT_HEADERS: [&str; 8] = [
+    "Decided",
+    "Granted",
+    "Denied",
+    "Other",
+    "Grant Rate",
+    "Deny Rate",
+    "Median Days Filing to Decision",
+    "Median Days Hearing to Decision",
+];
+
+/// Decided motion with the case attributes it can be grouped by
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct DecidedMotion {
+    pub judge: Option<String>,
+    pub court: Option<String>,
+    pub nature_of_suit: Option<String>,
+    pub motion_type: MotionType,
+    pub outcome: MotionOutcome,
+    pub filing_date: Option<DateTime<Utc>>,
+    pub hearing_date: Option<DateTime<Utc>>,
+    /// Date of the deciding order, else when the motion was marked decided
+    pub decided_at: Option<DateTime<Utc>>,
+}
+
+/// Group decided motions and compute each group's outcome statistics
+pub fn outcome_report(
+    motions: &[DecidedMotion],
+    group_by: OutcomeDimension,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+) -> MotionOutcomeReport {
+    let mut grouped: BTreeMap<Option<String>, Vec<&DecidedMotion>> = BTreeMap::new();
+    for motion in motions {
+        grouped
+            .entry(group_key(motion, group_by))
+            .or_default()
+            .push(motion);
+    }
+
+    let mut groups: Vec<OutcomeStats> = grouped
+        .into_iter()
+        .map(|(key, motions)| outcome_stats(key, &motions))
+        .collect();
+    groups.sort_by(|a, b| b.decided.cmp(&a.decided).then_with(|| a.key.cmp(&b.key)));
+
+    MotionOutcomeReport {
+        group_by,
+        from,
+        to,
+        overall: outcome_stats(None, &motions.iter().collect::<Vec<_>>()),
+        groups,
+    }
+}
+
+/// Statistics of one group of decided motions
+pub fn outcome_stats(key: Option<String>, motions: &[&DecidedMotion]) -> OutcomeStats {
+    let decided = motions.len() as i64;
+    let count = |outcome: MotionOutcome| motions.iter().filter(|m| m.outcome == outcome).count();
+    let granted = count(MotionOutcome::Granted) as i64;
+    let denied = count(MotionOutcome::Denied) as i64;
+    let rate = |n: i64| {
+        if decided == 0 {
+            0.0
+        } else {
+            n as f64 / decided as f64
+        }
+    };
+
+    OutcomeStats {
+        key,
+        decided,
+        granted,
+        denied,
+        other: decided - granted - denied,
+        grant_rate: rate(granted),
+        deny_rate: rate(denied),
+        median_days_filing_to_decision: median(
+            motions
+                .iter()
+                .filter_map(|m| days_between(m.filing_date, m.decided_at))
+                .collect(),
+        ),
+        median_days_hearing_to_decision: median(
+            motions
+                .iter()
+                .filter_map(|m| days_between(m.hearing_date, m.decided_at))
+                .collect(),
+        ),
+    }
+}
+
+/// Median of whole-day durations; the mean of the middle two for an even
+/// count
+pub fn median(mut values: Vec<i64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_unstable();
+    let middle = values.len() / 2;
+    Some(if values.len().is_multiple_of(2) {
+        (values[middle - 1] + values[middle]) as f64 / 2.0
+    } else {
+        values[middle] as f64
+    })
+}
+
+/// Render a report as CSV, the overall row last
+pub fn outcome_report_csv(report: &MotionOutcomeReport) -> String {
+    let mut headers = vec![dimension_label(report.group_by)];
+    headers.extend(OUTCOME_REPORT_HEADERS);
+
+    let mut rows: Vec<Vec<String>> = report
+        .groups
+        .iter()
+        .map(|g| {
+            stats_row(
+                g.key
+                    .clone()
+                    .unwrap_or_else(|| "(not recorded)".to_string()),
+                g,
+            )
+        })
+        .collect();
+    rows.push(stats_row("All".to_string(), &report.overall));
+
+    write_csv(&headers, &rows)
+}
+
+pub fn dimension_label(dimension: OutcomeDimension) -> &'static str {
+    match dimension {
+        OutcomeDimension::Judge => "Judge",
+        OutcomeDimension::MotionType => "Motion Type",
+        OutcomeDimension::Court => "Court",
+        OutcomeDimension::NatureOfSuit => "Nature of Suit",
+    }
+}
+
+fn group_key(motion: &DecidedMotion, dimension: OutcomeDimension) -> Option<String> {
+    let value = match dimension {
+        OutcomeDimension::Judge => motion.judge.clone(),
+        OutcomeDimension::MotionType => Some(motion_type_label(motion.motion_type).to_string()),
+        OutcomeDimension::Court => motion.court.clone(),
+        OutcomeDimension::NatureOfSuit => motion.nature_of_suit.clone(),
+    };
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+fn motion_type_label(motion_type: MotionType) -> &'static str {
+    match motion_type {
+        MotionType::Dismiss => "Dismiss",
+        MotionType::SummaryJudgment => "Summary Judgment",
+        MotionType::CompelDiscovery => "Compel Discovery",
+        MotionType::InLimine => "In Limine",
+        MotionType::Continuance => "Continuance",
+        MotionType::Sanctions => "Sanctions",
+    }
+}
+
+fn days_between(start: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>) -> Option<i64> {
+    let days = (end?.date_naive() - start?.date_naive()).num_days();
+    // A decision recorded before the event it is measured from is bad data
+    (days >= 0).then_some(days)
+}
+
+fn stats_row(label: String, stats: &OutcomeStats) -> Vec<String> {
+    let days = |d: Option<f64>| d.map(|d| format!("{:.1}", d)).unwrap_or_default();
+    vec![
+        label,
+        stats.decided.to_string(),
+        stats.granted.to_string(),
+        stats.denied.to_string(),
+        stats.other.to_string(),
+        format!("{:.1}%", stats.grant_rate * 100.0),
+        format!("{:.1}%", stats.deny_rate * 100.0),
+        days(stats.median_days_filing_to_decision),
+        days(stats.median_days_hearing_to_decision),
+    ]
+}
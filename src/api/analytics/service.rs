@@ -0,0 +1,84 @@
+use crate::api::analytics::outcomes::{outcome_report, DecidedMotion};
+use crate::error::AppError;
+use crate::models::{MotionOutcomeReport, MotionType, OutcomeDimension};
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+/// Filters narrowing the decided motions an outcome report covers
+pub struct OutcomeFilters {
+    /// Earliest decision date (optional)
+    pub from: Option<NaiveDate>,
+    /// Latest decision date (optional)
+    pub to: Option<NaiveDate>,
+    pub judge: Option<String>,
+    pub court: Option<String>,
+    pub motion_type: Option<MotionType>,
+    pub nature_of_suit: Option<String>,
+}
+
+pub struct AnalyticsService {
+    pool: PgPool,
+}
+
+impl AnalyticsService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Grant and deny rates and median decision times of decided motions
+    /// across all matters, grouped by one case or motion attribute.
+    ///
+    /// A motion is decided on the date of its linked order, or else when it
+    /// was moved to `Decided`. With a date range, motions whose decision
+    /// date is unknown are left out.
+    pub async fn motion_outcomes(
+        &self,
+        group_by: OutcomeDimension,
+        filters: OutcomeFilters,
+    ) -> Result<MotionOutcomeReport, AppError> {
+        if let (Some(from), Some(to)) = (filters.from, filters.to) {
+            if to < from {
+                return Err(AppError::Validation(
+                    "End date must not be before start date".to_string(),
+                ));
+            }
+        }
+
+        let motions = sqlx::query_as::<_, DecidedMotion>(
+            r#"
+            SELECT * FROM (
+                SELECT c.judge, c.court, c.nature_of_suit, m.type AS motion_type, m.outcome,
+                       m.filing_date, m.hearing_date,
+                       COALESCE(
+                           (SELECT MIN(d.date) FROM motion_docket_entries l
+                            JOIN docket_entries d ON d.id = l.docket_entry_id
+                            WHERE l.motion_id = m.id AND l.role = 'Order' AND d.deleted_at IS NULL),
+                           (SELECT MAX(t.transitioned_at) FROM motion_status_transitions t
+                            WHERE t.motion_id = m.id AND t.to_status = 'Decided'
+                              AND t.from_status IS NOT NULL)
+                       ) AS decided_at
+                FROM motions m
+                JOIN cases c ON c.id = m.case_id
+                WHERE m.deleted_at IS NULL AND c.deleted_at IS NULL
+                  AND m.status = 'Decided' AND m.outcome IS NOT NULL
+                  AND ($1::VARCHAR IS NULL OR c.judge = $1)
+                  AND ($2::VARCHAR IS NULL OR c.court = $2)
+                  AND ($3::motion_type IS NULL OR m.type = $3)
+                  AND ($4::VARCHAR IS NULL OR c.nature_of_suit = $4)
+            ) decided
+            WHERE ($5::DATE IS NULL OR (decided_at AT TIME ZONE 'UTC')::DATE >= $5)
+              AND ($6::DATE IS NULL OR (decided_at AT TIME ZONE 'UTC')::DATE <= $6)
+            "#,
+        )
+        .bind(&filters.judge)
+        .bind(&filters.court)
+        .bind(filters.motion_type)
+        .bind(&filters.nature_of_suit)
+        .bind(filters.from)
+        .bind(filters.to)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(outcome_report(&motions, group_by, filters.from, filters.to))
+    }
+}
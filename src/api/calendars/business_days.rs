@@ -0,0 +1,153 @@
+//! Business-day arithmetic over a court's weekends, holidays and closures
+
+use crate::api::calendars::holidays::{federal_holiday, is_weekend};
+use crate::models::ClosedDay;
+use chrono::{Datelike, NaiveDate, Weekday};
+use std::collections::BTreeMap;
+
+/// First year of the dates calendars count over
+pub const MIN_CALENDAR_YEAR: i32 = 1900;
+/// Last year of the dates calendars count over
+pub const MAX_CALENDAR_YEAR: i32 = 9999;
+
+/// Reject a date outside the years calendars support
+pub fn check_calendar_date(date: NaiveDate) -> Result<(), String> {
+    if (MIN_CALENDAR_YEAR..=MAX_CALENDAR_YEAR).contains(&date.year()) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Date {} is outside the supported years {}-{}",
+            date, MIN_CALENDAR_YEAR, MAX_CALENDAR_YEAR
+        ))
+    }
+}
+
+/// Days a court is open, resolved from its holiday and closure calendars
+#[derive(Debug, Clone, PartialEq)]
+pub struct BusinessCalendar {
+    observes_federal_holidays: bool,
+    closures: BTreeMap<NaiveDate, String>,
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        Self::federal()
+    }
+}
+
+impl BusinessCalendar {
+    /// Calendar of a federal court: weekends and federal holidays
+    pub fn federal() -> Self {
+        Self::new(true)
+    }
+
+    pub fn new(observes_federal_holidays: bool) -> Self {
+        Self {
+            observes_federal_holidays,
+            closures: BTreeMap::new(),
+        }
+    }
+
+    /// Close the court on a date; the first name given for a date is kept
+    pub fn add_closure(&mut self, date: NaiveDate, name: impl Into<String>) {
+        self.closures.entry(date).or_insert_with(|| name.into());
+    }
+
+    /// Why the court is closed on a date, or `None` when it is open
+    pub fn closed_reason(&self, date: NaiveDate) -> Option<String> {
+        if is_weekend(date) {
+            return Some(
+                if date.weekday() == Weekday::Sat {
+                    "Saturday"
+                } else {
+                    "Sunday"
+                }
+                .to_string(),
+            );
+        }
+        if let Some(name) = self.closures.get(&date) {
+            return Some(name.clone());
+        }
+        if self.observes_federal_holidays {
+            return federal_holiday(date).map(String::from);
+        }
+        None
+    }
+
+    pub fn is_closed(&self, date: NaiveDate) -> bool {
+        self.closed_reason(date).is_some()
+    }
+
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.is_closed(date)
+    }
+
+    /// The date itself if the court is open, otherwise the next open day;
+    /// `None` past the last date chrono can represent
+    pub fn next_business_day(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let mut date = date;
+        while self.is_closed(date) {
+            date = date.succ_opt()?;
+        }
+        Some(date)
+    }
+
+    /// The date itself if the court is open, otherwise the last open day
+    /// before it; `None` before the first date chrono can represent
+    pub fn previous_business_day(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let mut date = date;
+        while self.is_closed(date) {
+            date = date.pred_opt()?;
+        }
+        Some(date)
+    }
+
+    /// Move a number of business days from a date, backward when negative.
+    ///
+    /// The starting date is not counted, so adding one business day on a
+    /// Friday gives the following Monday. `None` when the count runs past
+    /// the dates chrono can represent.
+    pub fn add_business_days(&self, date: NaiveDate, days: i32) -> Option<NaiveDate> {
+        let mut date = date;
+        let mut remaining = days.unsigned_abs();
+        while remaining > 0 {
+            date = if days < 0 {
+                date.pred_opt()?
+            } else {
+                date.succ_opt()?
+            };
+            if self.is_business_day(date) {
+                remaining -= 1;
+            }
+        }
+        Some(date)
+    }
+
+    /// Business days after `start` up to and including `end`; negative when
+    /// `end` is before `start`
+    pub fn business_days_between(&self, start: NaiveDate, end: NaiveDate) -> i32 {
+        let (from, to, sign) = if end >= start {
+            (start, end, 1)
+        } else {
+            (end, start, -1)
+        };
+        let count = from
+            .iter_days()
+            .skip(1)
+            .take_while(|d| *d <= to)
+            .filter(|d| self.is_business_day(*d))
+            .count() as i32;
+        count * sign
+    }
+
+    /// Closed days from `from` through `to`, in date order
+    pub fn closed_days(&self, from: NaiveDate, to: NaiveDate) -> Vec<ClosedDay> {
+        from.iter_days()
+            .take_while(|d| *d <= to)
+            .filter_map(|date| {
+                self.closed_reason(date)
+                    .map(|reason| ClosedDay { date, reason })
+            })
+            .collect()
+    }
+}
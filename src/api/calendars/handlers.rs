@@ -0,0 +1,470 @@
+use crate::api::calendars::service::{
+    CalendarScope, CourtCalendarService, CreateClosureParams, CreateCourtCalendarParams,
+    UpdateCourtCalendarParams,
+};
+use crate::error::AppError;
+use crate::models::{
+    BusinessDayResult, CalendarEvent, Claims, ClosedDay, CourtCalendar, CourtClosure,
+    CourtClosureKind, HolidayImportResult,
+};
+use axum::{
+    extract::{Multipart, Path, Query, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateCourtCalendarRequest {
+    pub name: String,
+    /// Court the calendar applies to, as in `cases.court`
+    pub court: Option<String>,
+    /// Jurisdiction the calendar applies to, as in `cases.jurisdiction`
+    pub jurisdiction: Option<String>,
+    /// Whether the courts close on federal holidays (default true)
+    pub observes_federal_holidays: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateCourtCalendarRequest {
+    pub name: Option<String>,
+    pub observes_federal_holidays: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ListClosuresQuery {
+    pub year: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateClosureRequest {
+    pub date: NaiveDate,
+    /// Holiday or reason for the closure
+    pub name: String,
+    /// Defaults to `Closure`
+    pub kind: Option<CourtClosureKind>,
+}
+
+/// Multipart upload of a holiday list
+#[derive(Debug, ToSchema)]
+pub struct ImportHolidaysRequest {
+    /// iCalendar (.ics) file, or CSV with `date,name` rows
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ClosedDaysQuery {
+    /// Use the court and jurisdiction of this case
+    pub case_id: Option<Uuid>,
+    pub court: Option<String>,
+    pub jurisdiction: Option<String>,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BusinessDaysQuery {
+    /// Use the court and jurisdiction of this case
+    pub case_id: Option<Uuid>,
+    pub court: Option<String>,
+    pub jurisdiction: Option<String>,
+    pub date: NaiveDate,
+    /// Business days to move; negative moves backward
+    pub days: i32,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ListCalendarEventsQuery {
+    pub case_id: Uuid,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+/// List court calendars
+#[utoipa::path(
+    get,
+    path = "/api/court-calendars",
+    responses(
+        (status = 200, description = "Court calendars", body = Vec<CourtCalendar>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "court-calendars",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_court_calendars(
+    State(service): State<Arc<CourtCalendarService>>,
+) -> Result<Json<Vec<CourtCalendar>>, AppError> {
+    let calendars = service.list_calendars().await?;
+    Ok(Json(calendars))
+}
+
+/// Create a court calendar
+#[utoipa::path(
+    post,
+    path = "/api/court-calendars",
+    request_body = CreateCourtCalendarRequest,
+    responses(
+        (status = 201, description = "Court calendar created", body = CourtCalendar),
+        (status = 400, description = "Neither a court nor a jurisdiction given"),
+        (status = 409, description = "A calendar already exists for the court and jurisdiction"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "court-calendars",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_court_calendar(
+    State(service): State<Arc<CourtCalendarService>>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<CreateCourtCalendarRequest>,
+) -> Result<(StatusCode, Json<CourtCalendar>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let calendar = service
+        .create_calendar(CreateCourtCalendarParams {
+            name: req.name,
+            court: req.court,
+            jurisdiction: req.jurisdiction,
+            observes_federal_holidays: req.observes_federal_holidays,
+            user_id,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(calendar)))
+}
+
+/// Get a court calendar
+#[utoipa::path(
+    get,
+    path = "/api/court-calendars/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Court calendar ID")
+    ),
+    responses(
+        (status = 200, description = "Court calendar details", body = CourtCalendar),
+        (status = 404, description = "Court calendar not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "court-calendars",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_court_calendar(
+    State(service): State<Arc<CourtCalendarService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<CourtCalendar>, AppError> {
+    let calendar = service.get_calendar(id).await?;
+    Ok(Json(calendar))
+}
+
+/// Update a court calendar
+#[utoipa::path(
+    put,
+    path = "/api/court-calendars/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Court calendar ID")
+    ),
+    request_body = UpdateCourtCalendarRequest,
+    responses(
+        (status = 200, description = "Court calendar updated", body = CourtCalendar),
+        (status = 404, description = "Court calendar not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "court-calendars",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_court_calendar(
+    State(service): State<Arc<CourtCalendarService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateCourtCalendarRequest>,
+) -> Result<Json<CourtCalendar>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let calendar = service
+        .update_calendar(
+            id,
+            UpdateCourtCalendarParams {
+                name: req.name,
+                observes_federal_holidays: req.observes_federal_holidays,
+                user_id,
+            },
+        )
+        .await?;
+    Ok(Json(calendar))
+}
+
+/// Delete a court calendar and its closures
+#[utoipa::path(
+    delete,
+    path = "/api/court-calendars/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Court calendar ID")
+    ),
+    responses(
+        (status = 204, description = "Court calendar deleted"),
+        (status = 404, description = "Court calendar not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "court-calendars",
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_court_calendar(
+    State(service): State<Arc<CourtCalendarService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    service.delete_calendar(id, user_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// List a court calendar's holidays and closures
+#[utoipa::path(
+    get,
+    path = "/api/court-calendars/{id}/closures",
+    params(
+        ("id" = Uuid, Path, description = "Court calendar ID"),
+        ("year" = Option<i32>, Query, description = "Only closures in this year")
+    ),
+    responses(
+        (status = 200, description = "Closures in date order", body = Vec<CourtClosure>),
+        (status = 404, description = "Court calendar not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "court-calendars",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_court_closures(
+    State(service): State<Arc<CourtCalendarService>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ListClosuresQuery>,
+) -> Result<Json<Vec<CourtClosure>>, AppError> {
+    let closures = service.list_closures(id, query.year).await?;
+    Ok(Json(closures))
+}
+
+/// Close the courts of a calendar on a date
+#[utoipa::path(
+    post,
+    path = "/api/court-calendars/{id}/closures",
+    params(
+        ("id" = Uuid, Path, description = "Court calendar ID")
+    ),
+    request_body = CreateClosureRequest,
+    responses(
+        (status = 201, description = "Closure recorded; affected deadlines are recounted", body = CourtClosure),
+        (status = 404, description = "Court calendar not found"),
+        (status = 409, description = "The court is already closed on this date"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "court-calendars",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_court_closure(
+    State(service): State<Arc<CourtCalendarService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<CreateClosureRequest>,
+) -> Result<(StatusCode, Json<CourtClosure>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let closure = service
+        .add_closure(
+            id,
+            CreateClosureParams {
+                date: req.date,
+                name: req.name,
+                kind: req.kind,
+                user_id,
+            },
+        )
+        .await?;
+    Ok((StatusCode::CREATED, Json(closure)))
+}
+
+/// Remove a holiday or closure from a court calendar
+#[utoipa::path(
+    delete,
+    path = "/api/court-calendars/{id}/closures/{closure_id}",
+    params(
+        ("id" = Uuid, Path, description = "Court calendar ID"),
+        ("closure_id" = Uuid, Path, description = "Closure ID")
+    ),
+    responses(
+        (status = 204, description = "Closure removed; affected deadlines are recounted"),
+        (status = 404, description = "Court calendar or closure not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "court-calendars",
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_court_closure(
+    State(service): State<Arc<CourtCalendarService>>,
+    Extension(claims): Extension<Claims>,
+    Path((id, closure_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    service.delete_closure(id, closure_id, user_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Import state or local holidays from an iCalendar or CSV file
+#[utoipa::path(
+    post,
+    path = "/api/court-calendars/{id}/holidays/import",
+    params(
+        ("id" = Uuid, Path, description = "Court calendar ID")
+    ),
+    request_body(content = ImportHolidaysRequest, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Holidays imported; affected deadlines are recounted", body = HolidayImportResult),
+        (status = 400, description = "Unreadable holiday file"),
+        (status = 404, description = "Court calendar not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "court-calendars",
+    security(("bearer_auth" = []))
+)]
+pub async fn import_court_holidays(
+    State(service): State<Arc<CourtCalendarService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    mut multipart: Multipart,
+) -> Result<Json<HolidayImportResult>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let mut upload = None;
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| AppError::BadRequest(format!("Invalid multipart body: {}", e)))?
+    {
+        if field.name() != Some("file") {
+            continue;
+        }
+        let file_name = field.file_name().map(String::from);
+        let value = field
+            .bytes()
+            .await
+            .map_err(|e| AppError::BadRequest(format!("Failed to read upload: {}", e)))?;
+        upload = Some((String::from_utf8_lossy(&value).into_owned(), file_name));
+    }
+    let (content, file_name) = upload.ok_or(AppError::BadRequest(
+        "Multipart body must contain a \"file\" field".to_string(),
+    ))?;
+
+    let result = service
+        .import_holidays(id, &content, file_name, user_id)
+        .await?;
+    Ok(Json(result))
+}
+
+/// List the days a court is closed in a date range
+#[utoipa::path(
+    get,
+    path = "/api/court-calendars/closed-days",
+    params(
+        ("case_id" = Option<Uuid>, Query, description = "Use the court and jurisdiction of this case"),
+        ("court" = Option<String>, Query, description = "Court, as in cases.court"),
+        ("jurisdiction" = Option<String>, Query, description = "Jurisdiction, as in cases.jurisdiction"),
+        ("from" = NaiveDate, Query, description = "First day of the range"),
+        ("to" = NaiveDate, Query, description = "Last day of the range")
+    ),
+    responses(
+        (status = 200, description = "Weekends, holidays and closures in the range", body = Vec<ClosedDay>),
+        (status = 400, description = "Invalid date range"),
+        (status = 404, description = "Case not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "court-calendars",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_closed_days(
+    State(service): State<Arc<CourtCalendarService>>,
+    Query(query): Query<ClosedDaysQuery>,
+) -> Result<Json<Vec<ClosedDay>>, AppError> {
+    let scope = match query.case_id {
+        Some(case_id) => service.case_scope(case_id).await?,
+        None => CalendarScope {
+            court: query.court,
+            jurisdiction: query.jurisdiction,
+        },
+    };
+    let days = service.closed_days(&scope, query.from, query.to).await?;
+    Ok(Json(days))
+}
+
+/// Add or subtract business days on a court's calendar
+#[utoipa::path(
+    get,
+    path = "/api/court-calendars/business-days",
+    params(
+        ("case_id" = Option<Uuid>, Query, description = "Use the court and jurisdiction of this case"),
+        ("court" = Option<String>, Query, description = "Court, as in cases.court"),
+        ("jurisdiction" = Option<String>, Query, description = "Jurisdiction, as in cases.jurisdiction"),
+        ("date" = NaiveDate, Query, description = "Starting date (not counted)"),
+        ("days" = i32, Query, description = "Business days to move; negative moves backward")
+    ),
+    responses(
+        (status = 200, description = "Resulting date and the closed days skipped", body = BusinessDayResult),
+        (status = 400, description = "Too many days"),
+        (status = 404, description = "Case not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "court-calendars",
+    security(("bearer_auth" = []))
+)]
+pub async fn add_business_days(
+    State(service): State<Arc<CourtCalendarService>>,
+    Query(query): Query<BusinessDaysQuery>,
+) -> Result<Json<BusinessDayResult>, AppError> {
+    let scope = match query.case_id {
+        Some(case_id) => service.case_scope(case_id).await?,
+        None => CalendarScope {
+            court: query.court,
+            jurisdiction: query.jurisdiction,
+        },
+    };
+    let result = service
+        .add_business_days(&scope, query.date, query.days)
+        .await?;
+    Ok(Json(result))
+}
+
+/// List a case's calendar events
+#[utoipa::path(
+    get,
+    path = "/api/calendar-events",
+    params(
+        ("case_id" = Uuid, Query, description = "Case ID"),
+        ("from" = Option<NaiveDate>, Query, description = "Earliest event date"),
+        ("to" = Option<NaiveDate>, Query, description = "Latest event date")
+    ),
+    responses(
+        (status = 200, description = "Calendar events, soonest first", body = Vec<CalendarEvent>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "court-calendars",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_calendar_events(
+    State(service): State<Arc<CourtCalendarService>>,
+    Query(query): Query<ListCalendarEventsQuery>,
+) -> Result<Json<Vec<CalendarEvent>>, AppError> {
+    let events = service
+        .list_events(query.case_id, query.from, query.to)
+        .await?;
+    Ok(Json(events))
+}
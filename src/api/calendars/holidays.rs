@@ -0,0 +1,84 @@
+//! Federal legal holidays (5 U.S.C. § 6103), the days FRCP 6(a)(6) treats
+//! as court closures
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+/// Holidays observed in a year, in date order.
+///
+/// A fixed-date holiday falling on a Saturday is observed the Friday before
+/// and one falling on a Sunday the Monday after, so New Year's Day of the
+/// following year can be observed on December 31.
+pub fn federal_holidays(year: i32) -> Vec<(NaiveDate, &'static str)> {
+    let mut holidays = Vec::new();
+
+    for (observed, name) in [
+        (observed(year, 1, 1), "New Year's Day"),
+        (
+            year.checked_add(1).and_then(|next| observed(next, 1, 1)),
+            "New Year's Day",
+        ),
+    ] {
+        if let Some(observed) = observed.filter(|date| date.year() == year) {
+            holidays.push((observed, name));
+        }
+    }
+    for (date, name) in [
+        (
+            nth_weekday(year, 1, Weekday::Mon, 3),
+            "Birthday of Martin Luther King, Jr.",
+        ),
+        (
+            nth_weekday(year, 2, Weekday::Mon, 3),
+            "Washington's Birthday",
+        ),
+        (last_weekday(year, 5, Weekday::Mon), "Memorial Day"),
+        (
+            observed(year, 6, 19).filter(|_| year >= 2021),
+            "Juneteenth National Independence Day",
+        ),
+        (observed(year, 7, 4), "Independence Day"),
+        (nth_weekday(year, 9, Weekday::Mon, 1), "Labor Day"),
+        (nth_weekday(year, 10, Weekday::Mon, 2), "Columbus Day"),
+        (observed(year, 11, 11), "Veterans Day"),
+        (nth_weekday(year, 11, Weekday::Thu, 4), "Thanksgiving Day"),
+        (observed(year, 12, 25), "Christmas Day"),
+    ] {
+        // Holidays falling outside the dates chrono can represent are left out
+        if let Some(date) = date {
+            holidays.push((date, name));
+        }
+    }
+
+    holidays.sort();
+    holidays
+}
+
+pub fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// Name of the federal holiday observed on a date, if any
+pub fn federal_holiday(date: NaiveDate) -> Option<&'static str> {
+    federal_holidays(date.year())
+        .into_iter()
+        .find(|(holiday, _)| *holiday == date)
+        .map(|(_, name)| name)
+}
+
+fn observed(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    let date = NaiveDate::from_ymd_opt(year, month, day)?;
+    match date.weekday() {
+        Weekday::Sat => date.pred_opt(),
+        Weekday::Sun => date.succ_opt(),
+        _ => Some(date),
+    }
+}
+
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u8) -> Option<NaiveDate> {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n)
+}
+
+fn last_weekday(year: i32, month: u32, weekday: Weekday) -> Option<NaiveDate> {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, 5)
+        .or_else(|| nth_weekday(year, month, weekday, 4))
+}
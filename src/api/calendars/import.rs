@@ -0,0 +1,172 @@
+//! Parsing of holiday lists published by state and local courts, as
+//! iCalendar files or CSV
+
+use crate::api::calendars::business_days::check_calendar_date;
+use crate::error::AppError;
+use chrono::NaiveDate;
+
+/// Holiday read from an imported file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedHoliday {
+    pub date: NaiveDate,
+    pub name: String,
+}
+
+/// Longest all-day event expanded into holidays, e.g. a winter recess
+const MAX_EVENT_DAYS: i64 = 31;
+
+/// Parse an iCalendar (.ics) file or a CSV file of `date,name` rows.
+///
+/// iCalendar events cover every day from DTSTART up to, but not including,
+/// DTEND. Recurring events are rejected; export them with their occurrences
+/// expanded. CSV dates may be `YYYY-MM-DD` or `MM/DD/YYYY` and a header row
+/// is skipped.
+pub fn parse_holidays(content: &str) -> Result<Vec<ImportedHoliday>, AppError> {
+    let content = content.trim_start_matches('\u{feff}');
+    let holidays = if content.trim_start().starts_with("BEGIN:VCALENDAR") {
+        parse_icalendar(content)?
+    } else {
+        parse_csv(content)?
+    };
+
+    if holidays.is_empty() {
+        return Err(AppError::Validation(
+            "No holidays found in the imported file".to_string(),
+        ));
+    }
+    Ok(holidays)
+}
+
+fn parse_icalendar(content: &str) -> Result<Vec<ImportedHoliday>, AppError> {
+    // Unfold continuation lines, which start with a space or tab
+    let mut lines: Vec<String> = Vec::new();
+    for line in content.lines() {
+        let line = line.trim_end_matches('\r');
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+
+    let mut holidays = Vec::new();
+    let mut event: Option<(Option<NaiveDate>, Option<NaiveDate>, Option<String>)> = None;
+    for line in &lines {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let property = key.split(';').next().unwrap_or_default().to_uppercase();
+        match (property.as_str(), &mut event) {
+            ("BEGIN", _) if value.eq_ignore_ascii_case("VEVENT") => {
+                event = Some((None, None, None));
+            }
+            ("END", Some((start, end, summary))) if value.eq_ignore_ascii_case("VEVENT") => {
+                let start = start.ok_or(AppError::Validation(
+                    "Calendar event has no DTSTART".to_string(),
+                ))?;
+                let name = summary
+                    .take()
+                    .unwrap_or_else(|| "Court holiday".to_string());
+                // DTSTART is within the supported years, so the day after
+                // it exists
+                let day_after = start.succ_opt().unwrap_or(start);
+                let end = end.unwrap_or(day_after).max(day_after);
+                if (end - start).num_days() > MAX_EVENT_DAYS {
+                    return Err(AppError::Validation(format!(
+                        "Calendar event \"{}\" spans more than {} days",
+                        name, MAX_EVENT_DAYS
+                    )));
+                }
+                for date in start.iter_days().take_while(|d| *d < end) {
+                    holidays.push(ImportedHoliday {
+                        date,
+                        name: name.clone(),
+                    });
+                }
+                event = None;
+            }
+            ("DTSTART", Some((start, _, _))) => *start = Some(parse_ical_date(value)?),
+            ("DTEND", Some((_, end, _))) => *end = Some(parse_ical_date(value)?),
+            ("SUMMARY", Some((_, _, summary))) => *summary = Some(unescape_text(value)),
+            ("RRULE" | "RDATE", Some(_)) => {
+                return Err(AppError::Validation(
+                    "Recurring calendar events are not supported; export the calendar with occurrences expanded".to_string(),
+                ));
+            }
+            _ => {}
+        }
+    }
+    Ok(holidays)
+}
+
+/// "20260101" or "20260101T000000Z"; only the date is kept
+fn parse_ical_date(value: &str) -> Result<NaiveDate, AppError> {
+    let date = value
+        .get(..8)
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y%m%d").ok())
+        .ok_or(AppError::Validation(format!(
+            "Invalid calendar date: {}",
+            value
+        )))?;
+    check_calendar_date(date).map_err(AppError::Validation)?;
+    Ok(date)
+}
+
+fn unescape_text(value: &str) -> String {
+    value
+        .replace("\\n", " ")
+        .replace("\\N", " ")
+        .replace("\\,", ",")
+        .replace("\\;", ";")
+        .replace("\\\\", "\\")
+        .trim()
+        .to_string()
+}
+
+fn parse_csv(content: &str) -> Result<Vec<ImportedHoliday>, AppError> {
+    let mut holidays = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let (date, name) = line.split_once(',').unwrap_or((line, ""));
+        let date = unquote(date);
+        let Some(date) = parse_csv_date(&date) else {
+            if index == 0 {
+                continue;
+            }
+            return Err(AppError::Validation(format!(
+                "Line {}: invalid date \"{}\"",
+                index + 1,
+                date
+            )));
+        };
+        check_calendar_date(date)
+            .map_err(|e| AppError::Validation(format!("Line {}: {}", index + 1, e)))?;
+        let name = unquote(name);
+        holidays.push(ImportedHoliday {
+            date,
+            name: if name.is_empty() {
+                "Court holiday".to_string()
+            } else {
+                name
+            },
+        });
+    }
+    Ok(holidays)
+}
+
+fn parse_csv_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(value, "%m/%d/%Y"))
+        .ok()
+}
+
+/// Strip surrounding quotes from a CSV field, undoing doubled quotes
+fn unquote(field: &str) -> String {
+    let field = field.trim();
+    match field.strip_prefix('"').and_then(|f| f.strip_suffix('"')) {
+        Some(inner) => inner.replace("\"\"", "\""),
+        None => field.to_string(),
+    }
+}
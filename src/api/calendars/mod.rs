@@ -0,0 +1,8 @@
+pub mod business_days;
+pub mod handlers;
+pub mod holidays;
+pub mod import;
+pub mod service;
+
+pub use handlers::*;
+pub use service::*;
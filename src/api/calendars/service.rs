@@ -0,0 +1,611 @@
+use crate::api::calendars::business_days::{check_calendar_date, BusinessCalendar};
+use crate::api::calendars::import::parse_holidays;
+use crate::api::deadlines::resync_calendar_deadlines;
+use crate::error::AppError;
+use crate::models::{
+    BusinessDayResult, CalendarEvent, ClosedDay, CourtCalendar, CourtClosure, CourtClosureKind,
+    HolidayImportResult,
+};
+use chrono::{NaiveDate, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Longest span, in days, that the closed-day listing and business-day
+/// arithmetic will cover in one request
+pub const MAX_CALENDAR_SPAN_DAYS: i64 = 3 * 366;
+
+/// Parameters for creating a court calendar
+pub struct CreateCourtCalendarParams {
+    /// Display name, e.g. "New York State Unified Court System"
+    pub name: String,
+    /// Court the calendar applies to (matches `cases.court`)
+    pub court: Option<String>,
+    /// Jurisdiction the calendar applies to (matches `cases.jurisdiction`)
+    pub jurisdiction: Option<String>,
+    /// Whether the courts close on federal holidays (defaults to true)
+    pub observes_federal_holidays: Option<bool>,
+    /// ID of the user creating the calendar
+    pub user_id: Uuid,
+}
+
+/// Parameters for updating a court calendar; omitted fields are unchanged
+pub struct UpdateCourtCalendarParams {
+    pub name: Option<String>,
+    pub observes_federal_holidays: Option<bool>,
+    /// ID of the user updating the calendar
+    pub user_id: Uuid,
+}
+
+/// Parameters for closing a court on a date
+pub struct CreateClosureParams {
+    pub date: NaiveDate,
+    /// Holiday or reason for the closure
+    pub name: String,
+    /// Defaults to an ad-hoc closure
+    pub kind: Option<CourtClosureKind>,
+    /// ID of the user recording the closure
+    pub user_id: Uuid,
+}
+
+/// Court and jurisdiction whose calendars apply
+pub struct CalendarScope {
+    pub court: Option<String>,
+    pub jurisdiction: Option<String>,
+}
+
+/// Calendar flags and closures of the calendars matching a court
+#[derive(sqlx::FromRow)]
+struct CalendarClosureRow {
+    observes_federal_holidays: bool,
+    date: Option<NaiveDate>,
+    name: Option<String>,
+}
+
+pub struct CourtCalendarService {
+    pool: PgPool,
+}
+
+impl CourtCalendarService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// List court calendars
+    pub async fn list_calendars(&self) -> Result<Vec<CourtCalendar>, AppError> {
+        let calendars = sqlx::query_as::<_, CourtCalendar>(
+            "SELECT * FROM court_calendars WHERE deleted_at IS NULL ORDER BY jurisdiction NULLS FIRST, court NULLS FIRST, name",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(calendars)
+    }
+
+    /// Get a specific court calendar
+    pub async fn get_calendar(&self, id: Uuid) -> Result<CourtCalendar, AppError> {
+        let calendar = sqlx::query_as::<_, CourtCalendar>(
+            "SELECT * FROM court_calendars WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound("Court calendar not found".to_string()))?;
+
+        Ok(calendar)
+    }
+
+    /// Create a court calendar; open deadlines in its courts are recounted
+    pub async fn create_calendar(
+        &self,
+        params: CreateCourtCalendarParams,
+    ) -> Result<CourtCalendar, AppError> {
+        let court = non_empty(params.court);
+        let jurisdiction = non_empty(params.jurisdiction);
+        if court.is_none() && jurisdiction.is_none() {
+            return Err(AppError::Validation(
+                "A court calendar needs a court, a jurisdiction, or both".to_string(),
+            ));
+        }
+        if params.name.trim().is_empty() {
+            return Err(AppError::Validation(
+                "Court calendar name is required".to_string(),
+            ));
+        }
+
+        let mut tx = self.pool.begin().await?;
+
+        let duplicate = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT id FROM court_calendars
+            WHERE COALESCE(court, '') = COALESCE($1, '')
+              AND COALESCE(jurisdiction, '') = COALESCE($2, '')
+              AND deleted_at IS NULL
+            "#,
+        )
+        .bind(&court)
+        .bind(&jurisdiction)
+        .fetch_optional(&mut *tx)
+        .await?;
+        if duplicate.is_some() {
+            return Err(AppError::Conflict(
+                "A calendar already exists for this court and jurisdiction".to_string(),
+            ));
+        }
+
+        let now = Utc::now();
+        let calendar = sqlx::query_as::<_, CourtCalendar>(
+            r#"
+            INSERT INTO court_calendars (
+                id, name, court, jurisdiction, observes_federal_holidays,
+                created_at, updated_at, created_by, updated_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $6, $7, $7)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(params.name.trim())
+        .bind(&court)
+        .bind(&jurisdiction)
+        .bind(params.observes_federal_holidays.unwrap_or(true))
+        .bind(now)
+        .bind(params.user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        resync_calendar_deadlines(&mut tx, &calendar, None, Some(params.user_id)).await?;
+        tx.commit().await?;
+
+        Ok(calendar)
+    }
+
+    /// Update a court calendar
+    pub async fn update_calendar(
+        &self,
+        id: Uuid,
+        params: UpdateCourtCalendarParams,
+    ) -> Result<CourtCalendar, AppError> {
+        let mut tx = self.pool.begin().await?;
+        let existing = lock_calendar(&mut tx, id).await?;
+
+        let name = params.name.unwrap_or(existing.name);
+        if name.trim().is_empty() {
+            return Err(AppError::Validation(
+                "Court calendar name is required".to_string(),
+            ));
+        }
+
+        let calendar = sqlx::query_as::<_, CourtCalendar>(
+            r#"
+            UPDATE court_calendars
+            SET name = $1, observes_federal_holidays = $2, updated_at = $3, updated_by = $4
+            WHERE id = $5
+            RETURNING *
+            "#,
+        )
+        .bind(name.trim())
+        .bind(
+            params
+                .observes_federal_holidays
+                .unwrap_or(existing.observes_federal_holidays),
+        )
+        .bind(Utc::now())
+        .bind(params.user_id)
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if calendar.observes_federal_holidays != existing.observes_federal_holidays {
+            resync_calendar_deadlines(&mut tx, &calendar, None, Some(params.user_id)).await?;
+        }
+        tx.commit().await?;
+
+        Ok(calendar)
+    }
+
+    /// Delete a court calendar and its closures
+    pub async fn delete_calendar(&self, id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+        let calendar = lock_calendar(&mut tx, id).await?;
+
+        sqlx::query(
+            "UPDATE court_closures SET deleted_at = NOW(), updated_by = $2 WHERE calendar_id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query("UPDATE court_calendars SET deleted_at = NOW(), updated_by = $2 WHERE id = $1")
+            .bind(id)
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        resync_calendar_deadlines(&mut tx, &calendar, None, Some(user_id)).await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// List a calendar's closures, optionally for one year
+    pub async fn list_closures(
+        &self,
+        calendar_id: Uuid,
+        year: Option<i32>,
+    ) -> Result<Vec<CourtClosure>, AppError> {
+        self.get_calendar(calendar_id).await?;
+
+        let closures = sqlx::query_as::<_, CourtClosure>(
+            r#"
+            SELECT * FROM court_closures
+            WHERE calendar_id = $1 AND deleted_at IS NULL
+              AND ($2::INTEGER IS NULL OR EXTRACT(YEAR FROM date)::INTEGER = $2)
+            ORDER BY date
+            "#,
+        )
+        .bind(calendar_id)
+        .bind(year)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(closures)
+    }
+
+    /// Close a calendar's courts on a date, recounting affected deadlines
+    pub async fn add_closure(
+        &self,
+        calendar_id: Uuid,
+        params: CreateClosureParams,
+    ) -> Result<CourtClosure, AppError> {
+        if params.name.trim().is_empty() {
+            return Err(AppError::Validation("Closure name is required".to_string()));
+        }
+        check_calendar_date(params.date).map_err(AppError::Validation)?;
+
+        let mut tx = self.pool.begin().await?;
+        let calendar = lock_calendar(&mut tx, calendar_id).await?;
+
+        let existing = sqlx::query_scalar::<_, Uuid>(
+            "SELECT id FROM court_closures WHERE calendar_id = $1 AND date = $2 AND deleted_at IS NULL",
+        )
+        .bind(calendar_id)
+        .bind(params.date)
+        .fetch_optional(&mut *tx)
+        .await?;
+        if existing.is_some() {
+            return Err(AppError::Conflict(
+                "The court is already closed on this date".to_string(),
+            ));
+        }
+
+        let now = Utc::now();
+        let closure = sqlx::query_as::<_, CourtClosure>(
+            r#"
+            INSERT INTO court_closures (
+                id, calendar_id, date, name, kind, created_at, updated_at, created_by, updated_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $6, $7, $7)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(calendar_id)
+        .bind(params.date)
+        .bind(params.name.trim())
+        .bind(params.kind.unwrap_or(CourtClosureKind::Closure))
+        .bind(now)
+        .bind(params.user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        resync_calendar_deadlines(
+            &mut tx,
+            &calendar,
+            Some((params.date, params.date)),
+            Some(params.user_id),
+        )
+        .await?;
+        tx.commit().await?;
+
+        Ok(closure)
+    }
+
+    /// Remove a closure, recounting affected deadlines
+    pub async fn delete_closure(
+        &self,
+        calendar_id: Uuid,
+        closure_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+        let calendar = lock_calendar(&mut tx, calendar_id).await?;
+
+        let date = sqlx::query_scalar::<_, NaiveDate>(
+            r#"
+            UPDATE court_closures SET deleted_at = NOW(), updated_by = $3
+            WHERE id = $1 AND calendar_id = $2 AND deleted_at IS NULL
+            RETURNING date
+            "#,
+        )
+        .bind(closure_id)
+        .bind(calendar_id)
+        .bind(user_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(AppError::NotFound("Court closure not found".to_string()))?;
+
+        resync_calendar_deadlines(&mut tx, &calendar, Some((date, date)), Some(user_id)).await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Import state or local holidays from an iCalendar or CSV file.
+    ///
+    /// Holidays on dates already in the calendar replace the name and kind
+    /// on file.
+    pub async fn import_holidays(
+        &self,
+        calendar_id: Uuid,
+        content: &str,
+        source: Option<String>,
+        user_id: Uuid,
+    ) -> Result<HolidayImportResult, AppError> {
+        let holidays = parse_holidays(content)?;
+
+        let mut tx = self.pool.begin().await?;
+        let calendar = lock_calendar(&mut tx, calendar_id).await?;
+
+        let now = Utc::now();
+        let mut result = HolidayImportResult {
+            added: 0,
+            updated: 0,
+            closures: Vec::with_capacity(holidays.len()),
+        };
+        for holiday in &holidays {
+            let updated = sqlx::query_as::<_, CourtClosure>(
+                r#"
+                UPDATE court_closures
+                SET name = $1, kind = 'Holiday', source = $2, updated_at = $3, updated_by = $4
+                WHERE calendar_id = $5 AND date = $6 AND deleted_at IS NULL
+                RETURNING *
+                "#,
+            )
+            .bind(&holiday.name)
+            .bind(&source)
+            .bind(now)
+            .bind(user_id)
+            .bind(calendar_id)
+            .bind(holiday.date)
+            .fetch_optional(&mut *tx)
+            .await?;
+
+            let closure = match updated {
+                Some(closure) => {
+                    result.updated += 1;
+                    closure
+                }
+                None => {
+                    result.added += 1;
+                    sqlx::query_as::<_, CourtClosure>(
+                        r#"
+                        INSERT INTO court_closures (
+                            id, calendar_id, date, name, kind, source,
+                            created_at, updated_at, created_by, updated_by
+                        )
+                        VALUES ($1, $2, $3, $4, 'Holiday', $5, $6, $6, $7, $7)
+                        RETURNING *
+                        "#,
+                    )
+                    .bind(Uuid::new_v4())
+                    .bind(calendar_id)
+                    .bind(holiday.date)
+                    .bind(&holiday.name)
+                    .bind(&source)
+                    .bind(now)
+                    .bind(user_id)
+                    .fetch_one(&mut *tx)
+                    .await?
+                }
+            };
+            result.closures.push(closure);
+        }
+
+        let first = holidays.iter().map(|h| h.date).min();
+        let last = holidays.iter().map(|h| h.date).max();
+        if let (Some(first), Some(last)) = (first, last) {
+            resync_calendar_deadlines(&mut tx, &calendar, Some((first, last)), Some(user_id))
+                .await?;
+        }
+        tx.commit().await?;
+
+        result.closures.sort_by_key(|c| c.date);
+        Ok(result)
+    }
+
+    /// Court and jurisdiction of a case
+    pub async fn case_scope(&self, case_id: Uuid) -> Result<CalendarScope, AppError> {
+        let (court, jurisdiction) = sqlx::query_as::<_, (Option<String>, Option<String>)>(
+            "SELECT court, jurisdiction FROM cases WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(case_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound("Case not found".to_string()))?;
+
+        Ok(CalendarScope {
+            court,
+            jurisdiction,
+        })
+    }
+
+    /// Days a court is closed between two dates, inclusive
+    pub async fn closed_days(
+        &self,
+        scope: &CalendarScope,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<ClosedDay>, AppError> {
+        check_calendar_date(from).map_err(AppError::Validation)?;
+        check_calendar_date(to).map_err(AppError::Validation)?;
+        if to < from {
+            return Err(AppError::Validation(
+                "End date must not be before start date".to_string(),
+            ));
+        }
+        if (to - from).num_days() > MAX_CALENDAR_SPAN_DAYS {
+            return Err(AppError::Validation(format!(
+                "Date range may span at most {} days",
+                MAX_CALENDAR_SPAN_DAYS
+            )));
+        }
+
+        let calendar = business_calendar(
+            &self.pool,
+            scope.court.as_deref(),
+            scope.jurisdiction.as_deref(),
+        )
+        .await?;
+        Ok(calendar.closed_days(from, to))
+    }
+
+    /// Move a number of business days from a date in a court
+    pub async fn add_business_days(
+        &self,
+        scope: &CalendarScope,
+        date: NaiveDate,
+        days: i32,
+    ) -> Result<BusinessDayResult, AppError> {
+        // Courts are open well over half of all days, so this bounds the span
+        if i64::from(days.unsigned_abs()) * 2 > MAX_CALENDAR_SPAN_DAYS {
+            return Err(AppError::Validation(format!(
+                "At most {} business days may be added",
+                MAX_CALENDAR_SPAN_DAYS / 2
+            )));
+        }
+        check_calendar_date(date).map_err(AppError::Validation)?;
+
+        let calendar = business_calendar(
+            &self.pool,
+            scope.court.as_deref(),
+            scope.jurisdiction.as_deref(),
+        )
+        .await?;
+        let result = calendar
+            .add_business_days(date, days)
+            .filter(|result| check_calendar_date(*result).is_ok())
+            .ok_or_else(|| {
+                AppError::Validation(format!(
+                    "{} business days from {} is outside the supported years",
+                    days, date
+                ))
+            })?;
+        let (from, to) = if result < date {
+            (result, date)
+        } else {
+            (date, result)
+        };
+        let skipped = calendar
+            .closed_days(from, to)
+            .into_iter()
+            .filter(|d| d.date != date)
+            .collect();
+
+        Ok(BusinessDayResult {
+            date,
+            days,
+            result,
+            skipped,
+        })
+    }
+
+    /// Calendar events of a case, optionally between two dates, soonest first
+    pub async fn list_events(
+        &self,
+        case_id: Uuid,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> Result<Vec<CalendarEvent>, AppError> {
+        let events = sqlx::query_as::<_, CalendarEvent>(
+            r#"
+            SELECT * FROM calendar_events
+            WHERE case_id = $1 AND deleted_at IS NULL
+              AND ($2::DATE IS NULL OR event_date >= $2)
+              AND ($3::DATE IS NULL OR event_date <= $3)
+            ORDER BY event_date, title
+            "#,
+        )
+        .bind(case_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(events)
+    }
+}
+
+/// Business calendar of a court, usable with a pool or inside a transaction.
+///
+/// Every calendar matching the court, the jurisdiction, or both contributes
+/// its closures. Whether federal holidays are observed is taken from the
+/// most specific match; with no match the court follows the federal
+/// calendar.
+pub async fn business_calendar<'e, E>(
+    executor: E,
+    court: Option<&str>,
+    jurisdiction: Option<&str>,
+) -> Result<BusinessCalendar, AppError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let rows = sqlx::query_as::<_, CalendarClosureRow>(
+        r#"
+        SELECT (CASE WHEN c.court IS NOT NULL THEN 2 ELSE 0 END
+                + CASE WHEN c.jurisdiction IS NOT NULL THEN 1 ELSE 0 END) AS specificity,
+               c.observes_federal_holidays, cl.date, cl.name
+        FROM court_calendars c
+        LEFT JOIN court_closures cl ON cl.calendar_id = c.id AND cl.deleted_at IS NULL
+        WHERE c.deleted_at IS NULL
+          AND (c.court IS NULL OR c.court = $1)
+          AND (c.jurisdiction IS NULL OR c.jurisdiction = $2)
+        ORDER BY specificity DESC, cl.date
+        "#,
+    )
+    .bind(court)
+    .bind(jurisdiction)
+    .fetch_all(executor)
+    .await?;
+
+    let mut calendar = BusinessCalendar::new(
+        rows.first()
+            .map(|r| r.observes_federal_holidays)
+            .unwrap_or(true),
+    );
+    for row in rows {
+        if let (Some(date), Some(name)) = (row.date, row.name) {
+            calendar.add_closure(date, name);
+        }
+    }
+
+    Ok(calendar)
+}
+
+async fn lock_calendar(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    id: Uuid,
+) -> Result<CourtCalendar, AppError> {
+    sqlx::query_as::<_, CourtCalendar>(
+        "SELECT * FROM court_calendars WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+    )
+    .bind(id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(AppError::NotFound("Court calendar not found".to_string()))
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
@@ -141,6 +141,7 @@ pub async fn update_case(
     responses(
         (status = 204, description = "Case deleted successfully"),
         (status = 404, description = "Case not found"),
+        (status = 409, description = "Case is under an active legal hold"),
         (status = 500, description = "Internal server error")
     ),
     security(
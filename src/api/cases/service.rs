@@ -2,6 +2,7 @@ use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::{
+    api::holds::service::{active_holds_for_case, held_error},
     error::AppError,
     models::{Case, CaseResponse, CreateCaseRequest, Party, UpdateCaseRequest},
 };
@@ -160,6 +161,11 @@ impl CaseService {
     }
 
     pub async fn delete_case(&self, id: Uuid) -> Result<(), AppError> {
+        let holds = active_holds_for_case(&self.db, id).await?;
+        if !holds.is_empty() {
+            return Err(held_error("Case", &holds));
+        }
+
         // Soft delete
         let result =
             sqlx::query("UPDATE cases SET deleted_at = NOW() WHERE id = $1 AND deleted_at IS NULL")
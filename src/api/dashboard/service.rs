@@ -1,6 +1,8 @@
 use sqlx::PgPool;
 
+use crate::api::motions::workflow::{is_pending, STATUS_FLOW};
 use crate::error::AppError;
+use crate::models::MotionStatus;
 
 use super::models::*;
 
@@ -21,10 +23,13 @@ impl DashboardService {
         .fetch_one(&self.db)
         .await?;
 
-        // Get pending motions count
+        // Get pending motions count, as the motion workflow defines them
+        let pending_statuses: Vec<MotionStatus> =
+            STATUS_FLOW.into_iter().filter(|s| is_pending(*s)).collect();
         let pending_motions: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM motions WHERE status IN ('Draft', 'Filed') AND deleted_at IS NULL"
+            "SELECT COUNT(*) FROM motions WHERE status = ANY($1) AND deleted_at IS NULL",
         )
+        .bind(pending_statuses)
         .fetch_one(&self.db)
         .await?;
 
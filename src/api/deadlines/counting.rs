@@ -0,0 +1,151 @@
+//! Counting of time periods under FRCP 6(a), with the days FRCP 6(d) adds
+//! for service by mail and similar means
+
+use crate::api::calendars::business_days::check_calendar_date;
+use crate::api::calendars::MAX_CALENDAR_SPAN_DAYS;
+use crate::models::{DayCount, ServiceMethod};
+use chrono::{Duration, NaiveDate};
+
+/// Due date of a period and how it was reached
+#[derive(Debug, Clone, PartialEq)]
+pub struct DueDate {
+    pub due_date: NaiveDate,
+    /// Human-readable account of each counting step
+    pub computation: String,
+}
+
+/// Days added to a period after service by the given method.
+///
+/// Electronic service earns no extra days since the 2016 amendments.
+pub fn added_service_days(method: ServiceMethod) -> i64 {
+    match method {
+        ServiceMethod::Mail | ServiceMethod::LeftWithClerk | ServiceMethod::OtherConsented => 3,
+        ServiceMethod::Personal | ServiceMethod::Electronic => 0,
+    }
+}
+
+/// Longest period, forward or backward, that rules may prescribe and the
+/// calculator counts: the span the court calendar covers, halved for court
+/// days since courts are open well over half of all days
+pub fn max_period_days(day_count: DayCount) -> i32 {
+    let span = MAX_CALENDAR_SPAN_DAYS as i32;
+    match day_count {
+        DayCount::CalendarDays => span,
+        DayCount::CourtDays => span / 2,
+    }
+}
+
+/// Reject a period longer than [`max_period_days`]
+pub fn check_period(days: i32, day_count: DayCount) -> Result<(), String> {
+    let max = max_period_days(day_count);
+    if days.unsigned_abs() > max as u32 {
+        let unit = match day_count {
+            DayCount::CalendarDays => "calendar days",
+            DayCount::CourtDays => "court days",
+        };
+        return Err(format!(
+            "Periods may be at most {} {} either way",
+            max, unit
+        ));
+    }
+    Ok(())
+}
+
+/// Count a period from its triggering date.
+///
+/// The day of the event is excluded. Calendar-day periods count every day;
+/// court-day periods skip days the court is closed. A period ending on a
+/// closed day runs until the next open day (the previous one when counting
+/// backward). Service days are added after the period would otherwise
+/// expire, and the result is rolled forward again.
+///
+/// Fails for periods longer than [`max_period_days`] and for trigger or
+/// counted dates outside the years calendars support.
+pub fn compute_due_date(
+    trigger_date: NaiveDate,
+    days: i32,
+    day_count: DayCount,
+    service_method: Option<ServiceMethod>,
+    service_days_apply: bool,
+    is_closed: impl Fn(NaiveDate) -> bool,
+) -> Result<DueDate, String> {
+    check_period(days, day_count)?;
+    check_calendar_date(trigger_date)?;
+    let step = if days < 0 { -1 } else { 1 };
+    let direction = if days < 0 { "before" } else { "after" };
+    let mut steps = Vec::new();
+
+    let mut date = match day_count {
+        DayCount::CalendarDays => {
+            let date = add_days(trigger_date, days.into())?;
+            steps.push(format!(
+                "{} calendar days {} {} is {}",
+                days.abs(),
+                direction,
+                trigger_date,
+                date
+            ));
+            date
+        }
+        DayCount::CourtDays => {
+            let mut date = trigger_date;
+            let mut remaining = days.abs();
+            while remaining > 0 {
+                date = add_days(date, step)?;
+                if !is_closed(date) {
+                    remaining -= 1;
+                }
+            }
+            steps.push(format!(
+                "{} court days {} {} is {}",
+                days.abs(),
+                direction,
+                trigger_date,
+                date
+            ));
+            date
+        }
+    };
+    date = roll_to_open_day(date, step, &is_closed, &mut steps)?;
+
+    if let Some(method) = service_method.filter(|_| service_days_apply && days > 0) {
+        let added = added_service_days(method);
+        if added > 0 {
+            date = add_days(date, added)?;
+            steps.push(format!(
+                "{} days added for {:?} service is {}",
+                added, method, date
+            ));
+            date = roll_to_open_day(date, 1, &is_closed, &mut steps)?;
+        }
+    }
+
+    Ok(DueDate {
+        due_date: date,
+        computation: steps.join("; "),
+    })
+}
+
+fn add_days(date: NaiveDate, days: i64) -> Result<NaiveDate, String> {
+    let counted = date
+        .checked_add_signed(Duration::days(days))
+        .ok_or_else(|| format!("{} days from {} is out of range", days, date))?;
+    check_calendar_date(counted)?;
+    Ok(counted)
+}
+
+fn roll_to_open_day(
+    date: NaiveDate,
+    step: i64,
+    is_closed: &impl Fn(NaiveDate) -> bool,
+    steps: &mut Vec<String>,
+) -> Result<NaiveDate, String> {
+    let mut rolled = date;
+    while is_closed(rolled) {
+        rolled = add_days(rolled, step)?;
+    }
+    if rolled != date {
+        steps.push(format!("court closed on {}, rolled to {}", date, rolled));
+    }
+    Ok(rolled)
+}
@@ -0,0 +1,338 @@
+use crate::api::deadlines::service::{
+    CalculateDeadlineParams, CreateDeadlineRuleParams, DeadlineService, UpdateDeadlineRuleParams,
+};
+use crate::error::AppError;
+use crate::models::{
+    Claims, DayCount, Deadline, DeadlineCalculation, DeadlineRule, DeadlineStatus, DeadlineTrigger,
+    ServiceMethod,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ListDeadlineRulesQuery {
+    pub trigger_event: Option<DeadlineTrigger>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateDeadlineRuleRequest {
+    /// Legal rule that prescribes the period
+    pub legal_rule_id: Uuid,
+    pub trigger_event: DeadlineTrigger,
+    /// Title given to computed deadlines, e.g. "Answer due"
+    pub title: String,
+    /// Length of the period; negative periods count back from the event
+    pub days: i32,
+    pub day_count: Option<DayCount>,
+    /// Add days for service by mail and similar means (FRCP 6(d))
+    pub service_days_apply: Option<bool>,
+    /// Limit the rule to cases in this court
+    pub court: Option<String>,
+    /// Limit the rule to cases in this jurisdiction
+    pub jurisdiction: Option<String>,
+    pub priority: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateDeadlineRuleRequest {
+    pub title: Option<String>,
+    pub days: Option<i32>,
+    pub day_count: Option<DayCount>,
+    pub service_days_apply: Option<bool>,
+    pub court: Option<String>,
+    pub jurisdiction: Option<String>,
+    pub priority: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ListDeadlinesQuery {
+    pub case_id: Uuid,
+    pub status: Option<DeadlineStatus>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RecomputeDeadlinesQuery {
+    pub case_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CalculateDeadlineRequest {
+    /// Count on this case's court calendar; otherwise the federal calendar
+    pub case_id: Option<Uuid>,
+    pub trigger_date: NaiveDate,
+    pub days: i32,
+    pub day_count: Option<DayCount>,
+    pub service_method: Option<ServiceMethod>,
+    pub service_days_apply: Option<bool>,
+}
+
+/// List deadline rules
+#[utoipa::path(
+    get,
+    path = "/api/deadline-rules",
+    params(
+        ("trigger_event" = Option<DeadlineTrigger>, Query, description = "Only rules for this docket event")
+    ),
+    responses(
+        (status = 200, description = "Deadline rules", body = Vec<DeadlineRule>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "deadlines",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_deadline_rules(
+    State(service): State<Arc<DeadlineService>>,
+    Query(query): Query<ListDeadlineRulesQuery>,
+) -> Result<Json<Vec<DeadlineRule>>, AppError> {
+    let rules = service.list_rules(query.trigger_event).await?;
+    Ok(Json(rules))
+}
+
+/// Create a deadline rule
+#[utoipa::path(
+    post,
+    path = "/api/deadline-rules",
+    request_body = CreateDeadlineRuleRequest,
+    responses(
+        (status = 201, description = "Deadline rule created", body = DeadlineRule),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "Legal rule not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "deadlines",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_deadline_rule(
+    State(service): State<Arc<DeadlineService>>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<CreateDeadlineRuleRequest>,
+) -> Result<(StatusCode, Json<DeadlineRule>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let rule = service
+        .create_rule(CreateDeadlineRuleParams {
+            legal_rule_id: req.legal_rule_id,
+            trigger_event: req.trigger_event,
+            title: req.title,
+            days: req.days,
+            day_count: req.day_count,
+            service_days_apply: req.service_days_apply,
+            court: req.court,
+            jurisdiction: req.jurisdiction,
+            priority: req.priority,
+            user_id,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(rule)))
+}
+
+/// Update a deadline rule, recomputing the deadlines it produced
+#[utoipa::path(
+    put,
+    path = "/api/deadline-rules/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Deadline rule ID")
+    ),
+    request_body = UpdateDeadlineRuleRequest,
+    responses(
+        (status = 200, description = "Deadline rule updated", body = DeadlineRule),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "Deadline rule not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "deadlines",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_deadline_rule(
+    State(service): State<Arc<DeadlineService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateDeadlineRuleRequest>,
+) -> Result<Json<DeadlineRule>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let rule = service
+        .update_rule(
+            id,
+            UpdateDeadlineRuleParams {
+                title: req.title,
+                days: req.days,
+                day_count: req.day_count,
+                service_days_apply: req.service_days_apply,
+                court: req.court,
+                jurisdiction: req.jurisdiction,
+                priority: req.priority,
+                user_id,
+            },
+        )
+        .await?;
+    Ok(Json(rule))
+}
+
+/// Delete a deadline rule, vacating its open deadlines
+#[utoipa::path(
+    delete,
+    path = "/api/deadline-rules/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Deadline rule ID")
+    ),
+    responses(
+        (status = 204, description = "Deadline rule deleted"),
+        (status = 404, description = "Deadline rule not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "deadlines",
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_deadline_rule(
+    State(service): State<Arc<DeadlineService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    service.delete_rule(id, user_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// List deadlines for a case
+#[utoipa::path(
+    get,
+    path = "/api/deadlines",
+    params(
+        ("case_id" = Uuid, Query, description = "Case ID to filter deadlines"),
+        ("status" = Option<DeadlineStatus>, Query, description = "Only deadlines with this status")
+    ),
+    responses(
+        (status = 200, description = "Deadlines, soonest first", body = Vec<Deadline>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "deadlines",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_deadlines(
+    State(service): State<Arc<DeadlineService>>,
+    Query(query): Query<ListDeadlinesQuery>,
+) -> Result<Json<Vec<Deadline>>, AppError> {
+    let deadlines = service.list_deadlines(query.case_id, query.status).await?;
+    Ok(Json(deadlines))
+}
+
+/// Get a specific deadline
+#[utoipa::path(
+    get,
+    path = "/api/deadlines/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Deadline ID")
+    ),
+    responses(
+        (status = 200, description = "Deadline details", body = Deadline),
+        (status = 404, description = "Deadline not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "deadlines",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_deadline(
+    State(service): State<Arc<DeadlineService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Deadline>, AppError> {
+    let deadline = service.get_deadline(id).await?;
+    Ok(Json(deadline))
+}
+
+/// Mark a deadline met, completing its task
+#[utoipa::path(
+    post,
+    path = "/api/deadlines/{id}/complete",
+    params(
+        ("id" = Uuid, Path, description = "Deadline ID")
+    ),
+    responses(
+        (status = 200, description = "Deadline completed", body = Deadline),
+        (status = 404, description = "Deadline not found"),
+        (status = 409, description = "Deadline is not open"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "deadlines",
+    security(("bearer_auth" = []))
+)]
+pub async fn complete_deadline(
+    State(service): State<Arc<DeadlineService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Deadline>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let deadline = service.complete_deadline(id, user_id).await?;
+    Ok(Json(deadline))
+}
+
+/// Recompute all deadlines on a case's docket against the current rules
+#[utoipa::path(
+    post,
+    path = "/api/deadlines/recompute",
+    params(
+        ("case_id" = Uuid, Query, description = "Case ID to recompute")
+    ),
+    responses(
+        (status = 200, description = "Deadlines after recomputation", body = Vec<Deadline>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "deadlines",
+    security(("bearer_auth" = []))
+)]
+pub async fn recompute_deadlines(
+    State(service): State<Arc<DeadlineService>>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<RecomputeDeadlinesQuery>,
+) -> Result<Json<Vec<Deadline>>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let deadlines = service.recompute_case(query.case_id, user_id).await?;
+    Ok(Json(deadlines))
+}
+
+/// Count a period without recording a deadline
+#[utoipa::path(
+    post,
+    path = "/api/deadlines/calculate",
+    request_body = CalculateDeadlineRequest,
+    responses(
+        (status = 200, description = "Computed due date", body = DeadlineCalculation),
+        (status = 404, description = "Case not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "deadlines",
+    security(("bearer_auth" = []))
+)]
+pub async fn calculate_deadline(
+    State(service): State<Arc<DeadlineService>>,
+    Json(req): Json<CalculateDeadlineRequest>,
+) -> Result<Json<DeadlineCalculation>, AppError> {
+    let calculation = service
+        .calculate(CalculateDeadlineParams {
+            case_id: req.case_id,
+            trigger_date: req.trigger_date,
+            days: req.days,
+            day_count: req.day_count.unwrap_or(DayCount::CalendarDays),
+            service_method: req.service_method,
+            service_days_apply: req.service_days_apply.unwrap_or(false),
+        })
+        .await?;
+    Ok(Json(calculation))
+}
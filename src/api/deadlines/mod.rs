@@ -0,0 +1,6 @@
+pub mod counting;
+pub mod handlers;
+pub mod service;
+
+pub use handlers::*;
+pub use service::*;
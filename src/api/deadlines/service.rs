@@ -0,0 +1,680 @@
+use crate::api::calendars::business_calendar;
+use crate::api::calendars::business_days::BusinessCalendar;
+use crate::api::deadlines::counting::{check_period, compute_due_date};
+use crate::api::motions::resync_calendar_briefing;
+use crate::error::AppError;
+use crate::models::{
+    CourtCalendar, DayCount, Deadline, DeadlineCalculation, DeadlineRule, DeadlineStatus,
+    DeadlineTrigger, ServiceMethod,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Parameters for creating a deadline rule
+pub struct CreateDeadlineRuleParams {
+    /// Legal rule that prescribes the period
+    pub legal_rule_id: Uuid,
+    /// Docket event the period runs from
+    pub trigger_event: DeadlineTrigger,
+    /// Title given to computed deadlines
+    pub title: String,
+    /// Length of the period; negative periods count back from the event
+    pub days: i32,
+    /// Calendar or court days (defaults to calendar days)
+    pub day_count: Option<DayCount>,
+    /// Whether service days are added (defaults to false)
+    pub service_days_apply: Option<bool>,
+    /// Court the rule is limited to (optional)
+    pub court: Option<String>,
+    /// Jurisdiction the rule is limited to (optional)
+    pub jurisdiction: Option<String>,
+    /// Priority of the deadline tasks (defaults to "High")
+    pub priority: Option<String>,
+    /// ID of the user creating the rule
+    pub user_id: Uuid,
+}
+
+/// Parameters for updating a deadline rule; omitted fields are unchanged
+pub struct UpdateDeadlineRuleParams {
+    pub title: Option<String>,
+    pub days: Option<i32>,
+    pub day_count: Option<DayCount>,
+    pub service_days_apply: Option<bool>,
+    pub court: Option<String>,
+    pub jurisdiction: Option<String>,
+    pub priority: Option<String>,
+    /// ID of the user updating the rule
+    pub user_id: Uuid,
+}
+
+/// Parameters for counting a period without recording a deadline
+pub struct CalculateDeadlineParams {
+    /// Case whose court calendar applies; the federal calendar otherwise
+    pub case_id: Option<Uuid>,
+    pub trigger_date: NaiveDate,
+    pub days: i32,
+    pub day_count: DayCount,
+    pub service_method: Option<ServiceMethod>,
+    pub service_days_apply: bool,
+}
+
+/// Docket entry with the case fields that decide which rules apply
+#[derive(sqlx::FromRow)]
+struct TriggerEntry {
+    case_id: Uuid,
+    title: String,
+    date: DateTime<Utc>,
+    trigger_event: Option<DeadlineTrigger>,
+    service_method: Option<ServiceMethod>,
+    deleted: bool,
+    court: Option<String>,
+    jurisdiction: Option<String>,
+    owner_id: Option<Uuid>,
+    owner_name: Option<String>,
+}
+
+pub struct DeadlineService {
+    pool: PgPool,
+}
+
+impl DeadlineService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// List deadline rules, optionally for one trigger event
+    pub async fn list_rules(
+        &self,
+        trigger_event: Option<DeadlineTrigger>,
+    ) -> Result<Vec<DeadlineRule>, AppError> {
+        let rules = sqlx::query_as::<_, DeadlineRule>(
+            r#"
+            SELECT * FROM deadline_rules
+            WHERE deleted_at IS NULL AND ($1::deadline_trigger IS NULL OR trigger_event = $1)
+            ORDER BY trigger_event, days, title
+            "#,
+        )
+        .bind(trigger_event)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rules)
+    }
+
+    /// Create a deadline rule.
+    ///
+    /// The rule applies to docket events recorded or changed from now on;
+    /// recompute a case to apply it to events already on its docket.
+    pub async fn create_rule(
+        &self,
+        params: CreateDeadlineRuleParams,
+    ) -> Result<DeadlineRule, AppError> {
+        let priority = params.priority.unwrap_or_else(|| "High".to_string());
+        let day_count = params.day_count.unwrap_or(DayCount::CalendarDays);
+        validate_rule(&params.title, params.days, day_count, &priority)?;
+
+        sqlx::query_scalar::<_, Uuid>(
+            "SELECT id FROM legal_rules WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(params.legal_rule_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound("Legal rule not found".to_string()))?;
+
+        let rule = sqlx::query_as::<_, DeadlineRule>(
+            r#"
+            INSERT INTO deadline_rules (
+                id, legal_rule_id, trigger_event, title, days, day_count, service_days_apply,
+                court, jurisdiction, priority, created_at, updated_at, created_by, updated_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $11, $12, $12)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(params.legal_rule_id)
+        .bind(params.trigger_event)
+        .bind(params.title.trim())
+        .bind(params.days)
+        .bind(day_count)
+        .bind(params.service_days_apply.unwrap_or(false))
+        .bind(&params.court)
+        .bind(&params.jurisdiction)
+        .bind(&priority)
+        .bind(Utc::now())
+        .bind(params.user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(rule)
+    }
+
+    /// Update a deadline rule and recompute the deadlines it produced
+    pub async fn update_rule(
+        &self,
+        id: Uuid,
+        params: UpdateDeadlineRuleParams,
+    ) -> Result<DeadlineRule, AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        let existing = sqlx::query_as::<_, DeadlineRule>(
+            "SELECT * FROM deadline_rules WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(AppError::NotFound("Deadline rule not found".to_string()))?;
+
+        let title = params.title.unwrap_or(existing.title);
+        let priority = params.priority.unwrap_or(existing.priority);
+        let days = params.days.unwrap_or(existing.days);
+        let day_count = params.day_count.unwrap_or(existing.day_count);
+        validate_rule(&title, days, day_count, &priority)?;
+
+        let rule = sqlx::query_as::<_, DeadlineRule>(
+            r#"
+            UPDATE deadline_rules
+            SET title = $1, days = $2, day_count = $3, service_days_apply = $4, court = $5,
+                jurisdiction = $6, priority = $7, updated_at = $8, updated_by = $9
+            WHERE id = $10
+            RETURNING *
+            "#,
+        )
+        .bind(title.trim())
+        .bind(days)
+        .bind(day_count)
+        .bind(
+            params
+                .service_days_apply
+                .unwrap_or(existing.service_days_apply),
+        )
+        .bind(params.court.or(existing.court))
+        .bind(params.jurisdiction.or(existing.jurisdiction))
+        .bind(&priority)
+        .bind(Utc::now())
+        .bind(params.user_id)
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sync_rule_deadlines(&mut tx, id, Some(params.user_id)).await?;
+        tx.commit().await?;
+
+        Ok(rule)
+    }
+
+    /// Delete a deadline rule, vacating the open deadlines it produced
+    pub async fn delete_rule(&self, id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        let result = sqlx::query(
+            "UPDATE deadline_rules SET deleted_at = NOW(), updated_by = $2 WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound("Deadline rule not found".to_string()));
+        }
+
+        sync_rule_deadlines(&mut tx, id, Some(user_id)).await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// List deadlines for a case, soonest first
+    pub async fn list_deadlines(
+        &self,
+        case_id: Uuid,
+        status: Option<DeadlineStatus>,
+    ) -> Result<Vec<Deadline>, AppError> {
+        let deadlines = sqlx::query_as::<_, Deadline>(
+            r#"
+            SELECT * FROM deadlines
+            WHERE case_id = $1 AND ($2::deadline_status IS NULL OR status = $2)
+            ORDER BY due_date, title
+            "#,
+        )
+        .bind(case_id)
+        .bind(status)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(deadlines)
+    }
+
+    /// Get a specific deadline
+    pub async fn get_deadline(&self, id: Uuid) -> Result<Deadline, AppError> {
+        let deadline = sqlx::query_as::<_, Deadline>("SELECT * FROM deadlines WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or(AppError::NotFound("Deadline not found".to_string()))?;
+
+        Ok(deadline)
+    }
+
+    /// Mark an open deadline met, completing its task
+    pub async fn complete_deadline(&self, id: Uuid, user_id: Uuid) -> Result<Deadline, AppError> {
+        let mut tx = self.pool.begin().await?;
+        let now = Utc::now();
+
+        let deadline = sqlx::query_as::<_, Deadline>(
+            r#"
+            UPDATE deadlines
+            SET status = 'Completed', completed_at = $1, updated_at = $1, updated_by = $2
+            WHERE id = $3 AND status = 'Open'
+            RETURNING *
+            "#,
+        )
+        .bind(now)
+        .bind(user_id)
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(deadline) = deadline else {
+            self.get_deadline(id).await?;
+            return Err(AppError::Conflict("Deadline is not open".to_string()));
+        };
+
+        if let Some(task_id) = deadline.task_id {
+            sqlx::query(
+                "UPDATE workflow_tasks SET status = 'Completed', completion = 100, updated_at = $1, updated_by = $2 WHERE id = $3",
+            )
+            .bind(now)
+            .bind(user_id)
+            .bind(task_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(deadline)
+    }
+
+    /// Count a period on a case's court calendar
+    pub async fn calculate(
+        &self,
+        params: CalculateDeadlineParams,
+    ) -> Result<DeadlineCalculation, AppError> {
+        check_period(params.days, params.day_count).map_err(AppError::Validation)?;
+
+        let calendar = match params.case_id {
+            Some(case_id) => {
+                let (court, jurisdiction) = sqlx::query_as::<_, (Option<String>, Option<String>)>(
+                    "SELECT court, jurisdiction FROM cases WHERE id = $1 AND deleted_at IS NULL",
+                )
+                .bind(case_id)
+                .fetch_optional(&self.pool)
+                .await?
+                .ok_or(AppError::NotFound("Case not found".to_string()))?;
+                business_calendar(&self.pool, court.as_deref(), jurisdiction.as_deref()).await?
+            }
+            None => BusinessCalendar::federal(),
+        };
+
+        let computed = compute_due_date(
+            params.trigger_date,
+            params.days,
+            params.day_count,
+            params.service_method,
+            params.service_days_apply,
+            |date| calendar.is_closed(date),
+        )
+        .map_err(AppError::Validation)?;
+        Ok(DeadlineCalculation {
+            trigger_date: params.trigger_date,
+            due_date: computed.due_date,
+            computation: computed.computation,
+        })
+    }
+
+    /// Recompute every deadline on a case's docket, e.g. after adding rules
+    pub async fn recompute_case(
+        &self,
+        case_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<Deadline>, AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        let entry_ids = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT id FROM docket_entries
+            WHERE case_id = $1 AND (trigger_event IS NOT NULL OR id IN (SELECT docket_entry_id FROM deadlines))
+            ORDER BY sequence_number
+            "#,
+        )
+        .bind(case_id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for entry_id in entry_ids {
+            sync_entry_deadlines(&mut tx, entry_id, Some(user_id)).await?;
+        }
+
+        tx.commit().await?;
+
+        self.list_deadlines(case_id, None).await
+    }
+}
+
+/// Bring a docket entry's deadlines in line with its triggering event.
+///
+/// Deadlines are created for every rule matching the entry's event, court
+/// and jurisdiction, each with a task due on the deadline. Existing
+/// deadlines are recounted from the entry's current date and service
+/// method; those whose rule no longer applies, or whose entry was deleted,
+/// are vacated and their tasks removed. Rules that cannot be counted from
+/// the entry's date are logged and skipped.
+pub async fn sync_entry_deadlines(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    docket_entry_id: Uuid,
+    user_id: Option<Uuid>,
+) -> Result<Vec<Deadline>, AppError> {
+    let entry = sqlx::query_as::<_, TriggerEntry>(
+        r#"
+        SELECT d.case_id, d.title, d.date, d.trigger_event, d.service_method,
+               d.deleted_at IS NOT NULL AS deleted, c.court, c.jurisdiction, c.owner_id,
+               u.username AS owner_name
+        FROM docket_entries d
+        JOIN cases c ON c.id = d.case_id
+        LEFT JOIN users u ON u.id = c.owner_id
+        WHERE d.id = $1
+        FOR UPDATE OF d
+        "#,
+    )
+    .bind(docket_entry_id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(AppError::NotFound("Docket entry not found".to_string()))?;
+
+    let rules = match entry.trigger_event {
+        Some(trigger_event) if !entry.deleted => {
+            sqlx::query_as::<_, DeadlineRule>(
+                r#"
+                SELECT * FROM deadline_rules
+                WHERE trigger_event = $1 AND deleted_at IS NULL
+                  AND (court IS NULL OR court = $2)
+                  AND (jurisdiction IS NULL OR jurisdiction = $3)
+                "#,
+            )
+            .bind(trigger_event)
+            .bind(&entry.court)
+            .bind(&entry.jurisdiction)
+            .fetch_all(&mut **tx)
+            .await?
+        }
+        _ => Vec::new(),
+    };
+
+    let existing = sqlx::query_as::<_, Deadline>(
+        "SELECT * FROM deadlines WHERE docket_entry_id = $1 FOR UPDATE",
+    )
+    .bind(docket_entry_id)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    let calendar = business_calendar(
+        &mut **tx,
+        entry.court.as_deref(),
+        entry.jurisdiction.as_deref(),
+    )
+    .await?;
+    let now = Utc::now();
+    let trigger_date = entry.date.date_naive();
+    let mut deadlines = Vec::with_capacity(rules.len());
+
+    for rule in &rules {
+        // A rule that cannot be counted from this entry leaves its deadline
+        // as it was rather than blocking changes to the docket
+        let computed = match compute_due_date(
+            trigger_date,
+            rule.days,
+            rule.day_count,
+            entry.service_method,
+            rule.service_days_apply,
+            |date| calendar.is_closed(date),
+        ) {
+            Ok(computed) => computed,
+            Err(e) => {
+                tracing::warn!(
+                    "Deadline rule {} not counted for docket entry {}: {}",
+                    rule.id,
+                    docket_entry_id,
+                    e
+                );
+                continue;
+            }
+        };
+        let linked_rule: String = sqlx::query_scalar("SELECT code FROM legal_rules WHERE id = $1")
+            .bind(rule.legal_rule_id)
+            .fetch_one(&mut **tx)
+            .await?;
+        let description = format!(
+            "{} ({}), triggered by docket entry \"{}\". {}",
+            rule.title, linked_rule, entry.title, computed.computation
+        );
+
+        match existing.iter().find(|d| d.deadline_rule_id == rule.id) {
+            Some(current) => {
+                let deadline = sqlx::query_as::<_, Deadline>(
+                    r#"
+                    UPDATE deadlines
+                    SET title = $1, trigger_date = $2, due_date = $3, computation = $4,
+                        status = CASE WHEN status = 'Vacated' THEN 'Open'::deadline_status ELSE status END,
+                        updated_at = $5, updated_by = $6
+                    WHERE id = $7
+                    RETURNING *
+                    "#,
+                )
+                .bind(&rule.title)
+                .bind(trigger_date)
+                .bind(computed.due_date)
+                .bind(&computed.computation)
+                .bind(now)
+                .bind(user_id)
+                .bind(current.id)
+                .fetch_one(&mut **tx)
+                .await?;
+
+                if let Some(task_id) = deadline.task_id {
+                    sqlx::query(
+                        r#"
+                        UPDATE workflow_tasks
+                        SET title = $1, due_date = $2, priority = $3, description = $4,
+                            deleted_at = NULL, updated_at = $5, updated_by = $6
+                        WHERE id = $7
+                        "#,
+                    )
+                    .bind(&rule.title)
+                    .bind(end_of_day(computed.due_date))
+                    .bind(&rule.priority)
+                    .bind(&description)
+                    .bind(now)
+                    .bind(user_id)
+                    .bind(task_id)
+                    .execute(&mut **tx)
+                    .await?;
+                }
+                deadlines.push(deadline);
+            }
+            None => {
+                let deadline_id = Uuid::new_v4();
+                let task_id = Uuid::new_v4();
+
+                sqlx::query(
+                    r#"
+                    INSERT INTO workflow_tasks (
+                        id, title, status, assignee, assignee_id, due_date, priority, description,
+                        case_id, related_module, related_item_id, related_item_title,
+                        automated_trigger, linked_rules, created_at, updated_at, created_by, updated_by
+                    )
+                    VALUES ($1, $2, 'Pending', $3, $4, $5, $6, $7, $8, 'Deadlines', $9, $10, $11, $12, $13, $13, $14, $14)
+                    "#,
+                )
+                .bind(task_id)
+                .bind(&rule.title)
+                .bind(entry.owner_name.as_deref().unwrap_or("Unassigned"))
+                .bind(entry.owner_id)
+                .bind(end_of_day(computed.due_date))
+                .bind(&rule.priority)
+                .bind(&description)
+                .bind(entry.case_id)
+                .bind(deadline_id.to_string())
+                .bind(&entry.title)
+                .bind(format!("{:?}", rule.trigger_event))
+                .bind(vec![linked_rule])
+                .bind(now)
+                .bind(user_id)
+                .execute(&mut **tx)
+                .await?;
+
+                let deadline = sqlx::query_as::<_, Deadline>(
+                    r#"
+                    INSERT INTO deadlines (
+                        id, case_id, docket_entry_id, deadline_rule_id, task_id, title,
+                        trigger_date, due_date, computation, status, created_at, updated_at,
+                        created_by, updated_by
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, 'Open', $10, $10, $11, $11)
+                    RETURNING *
+                    "#,
+                )
+                .bind(deadline_id)
+                .bind(entry.case_id)
+                .bind(docket_entry_id)
+                .bind(rule.id)
+                .bind(task_id)
+                .bind(&rule.title)
+                .bind(trigger_date)
+                .bind(computed.due_date)
+                .bind(&computed.computation)
+                .bind(now)
+                .bind(user_id)
+                .fetch_one(&mut **tx)
+                .await?;
+                deadlines.push(deadline);
+            }
+        }
+    }
+
+    for stale in existing
+        .iter()
+        .filter(|d| d.status == DeadlineStatus::Open)
+        .filter(|d| !rules.iter().any(|r| r.id == d.deadline_rule_id))
+    {
+        sqlx::query(
+            "UPDATE deadlines SET status = 'Vacated', updated_at = $1, updated_by = $2 WHERE id = $3",
+        )
+        .bind(now)
+        .bind(user_id)
+        .bind(stale.id)
+        .execute(&mut **tx)
+        .await?;
+
+        if let Some(task_id) = stale.task_id {
+            sqlx::query(
+                "UPDATE workflow_tasks SET deleted_at = $1, updated_by = $2 WHERE id = $3 AND deleted_at IS NULL",
+            )
+            .bind(now)
+            .bind(user_id)
+            .bind(task_id)
+            .execute(&mut **tx)
+            .await?;
+        }
+    }
+
+    Ok(deadlines)
+}
+
+/// Recompute the deadlines a rule produced, after it changed or was deleted
+async fn sync_rule_deadlines(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    deadline_rule_id: Uuid,
+    user_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    let entry_ids = sqlx::query_scalar::<_, Uuid>(
+        "SELECT docket_entry_id FROM deadlines WHERE deadline_rule_id = $1 ORDER BY docket_entry_id",
+    )
+    .bind(deadline_rule_id)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    for entry_id in entry_ids {
+        sync_entry_deadlines(tx, entry_id, user_id).await?;
+    }
+
+    Ok(())
+}
+
+/// Recount open deadlines in the courts a calendar covers after its
+/// holidays or closures change.
+///
+/// With a window, only deadlines whose counting period overlaps it are
+/// recounted. Motion briefing schedules in those courts are recounted too.
+pub async fn resync_calendar_deadlines(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    calendar: &CourtCalendar,
+    window: Option<(NaiveDate, NaiveDate)>,
+    user_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    let (from, to) = window.unzip();
+    let entry_ids = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        SELECT DISTINCT d.docket_entry_id
+        FROM deadlines d
+        JOIN cases c ON c.id = d.case_id
+        WHERE d.status = 'Open'
+          AND ($1::VARCHAR IS NULL OR c.court = $1)
+          AND ($2::VARCHAR IS NULL OR c.jurisdiction = $2)
+          AND ($3::DATE IS NULL OR GREATEST(d.trigger_date, d.due_date) >= $3)
+          AND ($4::DATE IS NULL OR LEAST(d.trigger_date, d.due_date) <= $4)
+        ORDER BY d.docket_entry_id
+        "#,
+    )
+    .bind(&calendar.court)
+    .bind(&calendar.jurisdiction)
+    .bind(from)
+    .bind(to)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    for entry_id in entry_ids {
+        sync_entry_deadlines(tx, entry_id, user_id).await?;
+    }
+
+    resync_calendar_briefing(tx, calendar, window, user_id).await
+}
+
+/// Tasks fall due at the end of the deadline day
+pub fn end_of_day(date: NaiveDate) -> DateTime<Utc> {
+    date.and_hms_opt(23, 59, 59)
+        .expect("valid time of day")
+        .and_utc()
+}
+
+fn validate_rule(
+    title: &str,
+    days: i32,
+    day_count: DayCount,
+    priority: &str,
+) -> Result<(), AppError> {
+    if title.trim().is_empty() {
+        return Err(AppError::Validation(
+            "Deadline rule title is required".to_string(),
+        ));
+    }
+    check_period(days, day_count).map_err(AppError::Validation)?;
+    if !matches!(priority, "Low" | "Medium" | "High" | "Critical") {
+        return Err(AppError::Validation(
+            "Priority must be Low, Medium, High or Critical".to_string(),
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,344 @@
+use crate::api::discovery_requests::service::{
+    CreateDiscoveryRequestParams, DiscoveryFilters, DiscoveryService, UpdateDiscoveryItemParams,
+    UpdateDiscoveryRequestParams,
+};
+use crate::error::AppError;
+use crate::models::{
+    Claims, DiscoveryDirection, DiscoveryRequest, DiscoveryRequestDetail, DiscoveryRequestItem,
+    DiscoveryStatus, DiscoveryType, ServiceMethod,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct ListDiscoveryQuery {
+    pub case_id: Option<Uuid>,
+    pub direction: Option<DiscoveryDirection>,
+    pub status: Option<DiscoveryStatus>,
+    #[serde(rename = "type")]
+    pub discovery_type: Option<DiscoveryType>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateDiscoveryRequestRequest {
+    pub case_id: Uuid,
+    #[serde(rename = "type")]
+    pub discovery_type: DiscoveryType,
+    /// Propounded by us or received from another party
+    pub direction: DiscoveryDirection,
+    pub propounding_party: String,
+    pub responding_party: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub service_date: DateTime<Utc>,
+    pub service_method: ServiceMethod,
+    /// Defaults to the next set of this type in the case
+    pub set_number: Option<i32>,
+    /// Days allowed to respond when extended by stipulation or order
+    /// (defaults to 30)
+    pub response_days: Option<i32>,
+    /// Date of a noticed deposition; required for depositions only
+    pub due_date: Option<DateTime<Utc>>,
+    /// Text of each numbered request, in order
+    pub items: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateDiscoveryRequestRequest {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub propounding_party: Option<String>,
+    pub responding_party: Option<String>,
+    pub status: Option<DiscoveryStatus>,
+    pub service_date: Option<DateTime<Utc>>,
+    pub service_method: Option<ServiceMethod>,
+    pub response_days: Option<i32>,
+    /// New deposition date
+    pub due_date: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateDiscoveryItemRequest {
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateDiscoveryItemRequest {
+    pub text: Option<String>,
+    /// Answer or response; an empty string clears it
+    pub response: Option<String>,
+    /// Objections; an empty string clears them
+    pub objections: Option<String>,
+}
+
+fn user_id(claims: &Claims) -> Result<Uuid, AppError> {
+    Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))
+}
+
+/// List discovery request sets
+#[utoipa::path(
+    get,
+    path = "/api/discovery",
+    params(
+        ("case_id" = Option<Uuid>, Query, description = "Filter by case"),
+        ("direction" = Option<DiscoveryDirection>, Query, description = "Propounded or Received"),
+        ("status" = Option<DiscoveryStatus>, Query, description = "Filter by status"),
+        ("type" = Option<DiscoveryType>, Query, description = "Filter by discovery type")
+    ),
+    responses(
+        (status = 200, description = "Discovery request sets, soonest due first", body = Vec<DiscoveryRequest>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "discovery",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_discovery_requests(
+    State(service): State<Arc<DiscoveryService>>,
+    Query(query): Query<ListDiscoveryQuery>,
+) -> Result<Json<Vec<DiscoveryRequest>>, AppError> {
+    let requests = service
+        .list_requests(DiscoveryFilters {
+            case_id: query.case_id,
+            direction: query.direction,
+            status: query.status,
+            discovery_type: query.discovery_type,
+        })
+        .await?;
+    Ok(Json(requests))
+}
+
+/// Get a discovery request set with its numbered requests
+#[utoipa::path(
+    get,
+    path = "/api/discovery/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Discovery request ID")
+    ),
+    responses(
+        (status = 200, description = "Discovery request set", body = DiscoveryRequestDetail),
+        (status = 404, description = "Discovery request not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "discovery",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_discovery_request(
+    State(service): State<Arc<DiscoveryService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<DiscoveryRequestDetail>, AppError> {
+    let request = service.get_request(id).await?;
+    Ok(Json(request))
+}
+
+/// Create a discovery request set; the response due date is counted from
+/// the service date and method
+#[utoipa::path(
+    post,
+    path = "/api/discovery",
+    request_body = CreateDiscoveryRequestRequest,
+    responses(
+        (status = 201, description = "Discovery request set created", body = DiscoveryRequestDetail),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "Case not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "discovery",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_discovery_request(
+    State(service): State<Arc<DiscoveryService>>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<CreateDiscoveryRequestRequest>,
+) -> Result<(StatusCode, Json<DiscoveryRequestDetail>), AppError> {
+    let request = service
+        .create_request(CreateDiscoveryRequestParams {
+            case_id: req.case_id,
+            discovery_type: req.discovery_type,
+            direction: req.direction,
+            propounding_party: req.propounding_party,
+            responding_party: req.responding_party,
+            title: req.title,
+            description: req.description,
+            service_date: req.service_date,
+            service_method: req.service_method,
+            set_number: req.set_number,
+            response_days: req.response_days,
+            due_date: req.due_date,
+            items: req.items.unwrap_or_default(),
+            user_id: user_id(&claims)?,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(request)))
+}
+
+/// Update a discovery request set, recounting its due date when service
+/// changes
+#[utoipa::path(
+    put,
+    path = "/api/discovery/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Discovery request ID")
+    ),
+    request_body = UpdateDiscoveryRequestRequest,
+    responses(
+        (status = 200, description = "Discovery request set updated", body = DiscoveryRequestDetail),
+        (status = 400, description = "Invalid request or status transition"),
+        (status = 404, description = "Discovery request not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "discovery",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_discovery_request(
+    State(service): State<Arc<DiscoveryService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateDiscoveryRequestRequest>,
+) -> Result<Json<DiscoveryRequestDetail>, AppError> {
+    let request = service
+        .update_request(
+            id,
+            UpdateDiscoveryRequestParams {
+                title: req.title,
+                description: req.description,
+                propounding_party: req.propounding_party,
+                responding_party: req.responding_party,
+                status: req.status,
+                service_date: req.service_date,
+                service_method: req.service_method,
+                response_days: req.response_days,
+                due_date: req.due_date,
+                user_id: user_id(&claims)?,
+            },
+        )
+        .await?;
+    Ok(Json(request))
+}
+
+/// Delete a discovery request set
+#[utoipa::path(
+    delete,
+    path = "/api/discovery/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Discovery request ID")
+    ),
+    responses(
+        (status = 204, description = "Discovery request set deleted"),
+        (status = 404, description = "Discovery request not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "discovery",
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_discovery_request(
+    State(service): State<Arc<DiscoveryService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    service.delete_request(id, user_id(&claims)?).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Add a numbered request to the end of a set
+#[utoipa::path(
+    post,
+    path = "/api/discovery/{id}/items",
+    params(
+        ("id" = Uuid, Path, description = "Discovery request ID")
+    ),
+    request_body = CreateDiscoveryItemRequest,
+    responses(
+        (status = 201, description = "Request added", body = DiscoveryRequestItem),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "Discovery request not found"),
+        (status = 409, description = "The set has already been served"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "discovery",
+    security(("bearer_auth" = []))
+)]
+pub async fn add_discovery_item(
+    State(service): State<Arc<DiscoveryService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<CreateDiscoveryItemRequest>,
+) -> Result<(StatusCode, Json<DiscoveryRequestItem>), AppError> {
+    let item = service.add_item(id, req.text, user_id(&claims)?).await?;
+    Ok((StatusCode::CREATED, Json(item)))
+}
+
+/// Update a numbered request or record its response
+#[utoipa::path(
+    put,
+    path = "/api/discovery/{id}/items/{item_id}",
+    params(
+        ("id" = Uuid, Path, description = "Discovery request ID"),
+        ("item_id" = Uuid, Path, description = "Discovery request item ID")
+    ),
+    request_body = UpdateDiscoveryItemRequest,
+    responses(
+        (status = 200, description = "Request updated", body = DiscoveryRequestItem),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "Discovery request item not found"),
+        (status = 409, description = "The set has already been served"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "discovery",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_discovery_item(
+    State(service): State<Arc<DiscoveryService>>,
+    Extension(claims): Extension<Claims>,
+    Path((id, item_id)): Path<(Uuid, Uuid)>,
+    Json(req): Json<UpdateDiscoveryItemRequest>,
+) -> Result<Json<DiscoveryRequestItem>, AppError> {
+    let item = service
+        .update_item(
+            id,
+            item_id,
+            UpdateDiscoveryItemParams {
+                text: req.text,
+                response: req.response,
+                objections: req.objections,
+                user_id: user_id(&claims)?,
+            },
+        )
+        .await?;
+    Ok(Json(item))
+}
+
+/// Remove a numbered request from a draft set, renumbering those after it
+#[utoipa::path(
+    delete,
+    path = "/api/discovery/{id}/items/{item_id}",
+    params(
+        ("id" = Uuid, Path, description = "Discovery request ID"),
+        ("item_id" = Uuid, Path, description = "Discovery request item ID")
+    ),
+    responses(
+        (status = 204, description = "Request removed"),
+        (status = 404, description = "Discovery request item not found"),
+        (status = 409, description = "The set has already been served"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "discovery",
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_discovery_item(
+    State(service): State<Arc<DiscoveryService>>,
+    Extension(claims): Extension<Claims>,
+    Path((id, item_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    service.delete_item(id, item_id, user_id(&claims)?).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
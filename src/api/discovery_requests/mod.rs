@@ -0,0 +1,6 @@
+pub mod handlers;
+pub mod responses;
+pub mod service;
+
+pub use handlers::*;
+pub use service::*;
@@ -0,0 +1,78 @@
+//! Response periods and status rules for discovery requests
+
+use crate::api::deadlines::counting::{compute_due_date, DueDate};
+use crate::models::{DayCount, DiscoveryStatus, DiscoveryType, ServiceMethod};
+use chrono::NaiveDate;
+
+/// Days to respond to interrogatories, requests for production and requests
+/// for admission (FRCP 33(b)(2), 34(b)(2)(A), 36(a)(3))
+pub const RESPONSE_DAYS: i32 = 30;
+
+/// Longest response period a set may be extended to
+pub const MAX_RESPONSE_DAYS: i32 = 365;
+
+/// Days after responses become overdue that the meet-and-confer task is due
+pub const MEET_AND_CONFER_DAYS: i64 = 7;
+
+/// Default response period for a type of discovery.
+///
+/// Depositions have no response period; they take place on the date in the
+/// notice.
+pub fn response_period(discovery_type: DiscoveryType) -> Option<i32> {
+    match discovery_type {
+        DiscoveryType::Production | DiscoveryType::Interrogatory | DiscoveryType::Admission => {
+            Some(RESPONSE_DAYS)
+        }
+        DiscoveryType::Deposition => None,
+    }
+}
+
+/// Count the response period from the date of service.
+///
+/// Periods are in calendar days, with three days added after service by
+/// mail and similar means (FRCP 6(d)).
+pub fn response_due_date(
+    service_date: NaiveDate,
+    days: i32,
+    service_method: ServiceMethod,
+    is_closed: impl Fn(NaiveDate) -> bool,
+) -> Result<DueDate, String> {
+    compute_due_date(
+        service_date,
+        days,
+        DayCount::CalendarDays,
+        Some(service_method),
+        true,
+        is_closed,
+    )
+}
+
+/// How a numbered request is cited, e.g. "Interrogatory No. 3"
+pub fn item_label(discovery_type: DiscoveryType, number: i32) -> String {
+    let kind = match discovery_type {
+        DiscoveryType::Production => "Request for Production",
+        DiscoveryType::Interrogatory => "Interrogatory",
+        DiscoveryType::Admission => "Request for Admission",
+        DiscoveryType::Deposition => "Deposition Topic",
+    };
+    format!("{} No. {}", kind, number)
+}
+
+/// Whether a discovery set may move from one status to another
+pub fn can_transition(from: DiscoveryStatus, to: DiscoveryStatus) -> bool {
+    use DiscoveryStatus::*;
+
+    matches!(
+        (from, to),
+        (Draft, Served)
+            | (Served, Responded | Overdue | MotionFiled | Closed)
+            | (Overdue, Responded | MotionFiled | Closed)
+            | (Responded, MotionFiled | Closed)
+            | (MotionFiled, Closed)
+    )
+}
+
+/// Whether served requests have gone unanswered past their due date
+pub fn is_overdue(status: DiscoveryStatus, due_date: NaiveDate, today: NaiveDate) -> bool {
+    status == DiscoveryStatus::Served && due_date < today
+}
@@ -0,0 +1,807 @@
+use crate::api::calendars::business_calendar;
+use crate::api::deadlines::end_of_day;
+use crate::api::discovery_requests::responses::{
+    can_transition, is_overdue, item_label, response_due_date, response_period, MAX_RESPONSE_DAYS,
+    MEET_AND_CONFER_DAYS,
+};
+use crate::error::AppError;
+use crate::models::{
+    DiscoveryDirection, DiscoveryRequest, DiscoveryRequestDetail, DiscoveryRequestItem,
+    DiscoveryStatus, DiscoveryType, ServiceMethod,
+};
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{FromRow, PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+/// How often served discovery is checked for overdue responses
+pub const DISCOVERY_SWEEP_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+/// Filters for listing discovery sets; all are optional and combine with AND
+#[derive(Default)]
+pub struct DiscoveryFilters {
+    pub case_id: Option<Uuid>,
+    pub direction: Option<DiscoveryDirection>,
+    pub status: Option<DiscoveryStatus>,
+    pub discovery_type: Option<DiscoveryType>,
+}
+
+/// Parameters for creating a set of discovery requests
+pub struct CreateDiscoveryRequestParams {
+    pub case_id: Uuid,
+    pub discovery_type: DiscoveryType,
+    pub direction: DiscoveryDirection,
+    pub propounding_party: String,
+    pub responding_party: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub service_date: DateTime<Utc>,
+    pub service_method: ServiceMethod,
+    /// Defaults to the next set of this type and direction in the case
+    pub set_number: Option<i32>,
+    /// Days allowed to respond, overriding the rule's 30 days
+    pub response_days: Option<i32>,
+    /// Date of a noticed deposition; written discovery is counted from service
+    pub due_date: Option<DateTime<Utc>>,
+    /// Text of each numbered request, in order
+    pub items: Vec<String>,
+    /// ID of the user creating the set
+    pub user_id: Uuid,
+}
+
+/// Parameters for updating a discovery set; omitted fields are unchanged
+pub struct UpdateDiscoveryRequestParams {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub propounding_party: Option<String>,
+    pub responding_party: Option<String>,
+    pub status: Option<DiscoveryStatus>,
+    pub service_date: Option<DateTime<Utc>>,
+    pub service_method: Option<ServiceMethod>,
+    pub response_days: Option<i32>,
+    pub due_date: Option<DateTime<Utc>>,
+    /// ID of the user updating the set
+    pub user_id: Uuid,
+}
+
+/// Parameters for updating a numbered request; omitted fields are unchanged
+pub struct UpdateDiscoveryItemParams {
+    pub text: Option<String>,
+    /// Answer or response; an empty string clears it
+    pub response: Option<String>,
+    /// Objections; an empty string clears them
+    pub objections: Option<String>,
+    /// ID of the user updating the request
+    pub user_id: Uuid,
+}
+
+#[derive(FromRow)]
+struct OverdueRequest {
+    #[sqlx(flatten)]
+    request: DiscoveryRequest,
+    owner_id: Option<Uuid>,
+    owner_name: Option<String>,
+}
+
+/// Service for propounded and received discovery
+#[derive(Clone)]
+pub struct DiscoveryService {
+    pool: PgPool,
+}
+
+impl DiscoveryService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// List discovery sets, soonest due first
+    pub async fn list_requests(
+        &self,
+        filters: DiscoveryFilters,
+    ) -> Result<Vec<DiscoveryRequest>, AppError> {
+        let requests = sqlx::query_as::<_, DiscoveryRequest>(
+            r#"
+            SELECT * FROM discovery_requests
+            WHERE deleted_at IS NULL
+              AND ($1::UUID IS NULL OR case_id = $1)
+              AND ($2::discovery_direction IS NULL OR direction = $2)
+              AND ($3::discovery_status IS NULL OR status = $3)
+              AND ($4::discovery_type IS NULL OR type = $4)
+            ORDER BY due_date ASC, set_number ASC
+            "#,
+        )
+        .bind(filters.case_id)
+        .bind(filters.direction)
+        .bind(filters.status)
+        .bind(filters.discovery_type)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(requests)
+    }
+
+    /// Get a discovery set with its numbered requests
+    pub async fn get_request(&self, id: Uuid) -> Result<DiscoveryRequestDetail, AppError> {
+        let request = sqlx::query_as::<_, DiscoveryRequest>(
+            "SELECT * FROM discovery_requests WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound(
+            "Discovery request not found".to_string(),
+        ))?;
+
+        let items = list_items(&self.pool, &request).await?;
+        Ok(DiscoveryRequestDetail { request, items })
+    }
+
+    /// Create a discovery set and its numbered requests, counting the
+    /// response due date from service
+    pub async fn create_request(
+        &self,
+        params: CreateDiscoveryRequestParams,
+    ) -> Result<DiscoveryRequestDetail, AppError> {
+        validate_request(
+            &params.title,
+            &params.propounding_party,
+            &params.responding_party,
+        )?;
+        if params.items.iter().any(|text| text.trim().is_empty()) {
+            return Err(AppError::Validation(
+                "Discovery request text is required".to_string(),
+            ));
+        }
+        if params.set_number.is_some_and(|n| n < 1) {
+            return Err(AppError::Validation(
+                "Set number must be at least 1".to_string(),
+            ));
+        }
+
+        let mut tx = self.pool.begin().await?;
+
+        let (due_date, computation) = response_deadline(
+            &mut tx,
+            params.case_id,
+            params.discovery_type,
+            params.service_date,
+            params.service_method,
+            params.response_days,
+            params.due_date,
+        )
+        .await?;
+
+        let set_number = match params.set_number {
+            Some(n) => n,
+            None => {
+                sqlx::query_scalar::<_, i32>(
+                    r#"
+                    SELECT COALESCE(MAX(set_number), 0) + 1 FROM discovery_requests
+                    WHERE case_id = $1 AND type = $2 AND direction = $3 AND deleted_at IS NULL
+                    "#,
+                )
+                .bind(params.case_id)
+                .bind(params.discovery_type)
+                .bind(params.direction)
+                .fetch_one(&mut *tx)
+                .await?
+            }
+        };
+
+        // Requests we receive have already been served on us
+        let status = match params.direction {
+            DiscoveryDirection::Propounded => DiscoveryStatus::Draft,
+            DiscoveryDirection::Received => DiscoveryStatus::Served,
+        };
+
+        let now = Utc::now();
+        let request = sqlx::query_as::<_, DiscoveryRequest>(
+            r#"
+            INSERT INTO discovery_requests (
+                id, case_id, type, direction, set_number, propounding_party, responding_party,
+                service_date, service_method, response_days, due_date, computation, status,
+                title, description, created_at, updated_at, created_by, updated_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $16, $17, $17)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(params.case_id)
+        .bind(params.discovery_type)
+        .bind(params.direction)
+        .bind(set_number)
+        .bind(params.propounding_party.trim())
+        .bind(params.responding_party.trim())
+        .bind(params.service_date)
+        .bind(params.service_method)
+        .bind(params.response_days)
+        .bind(due_date)
+        .bind(computation)
+        .bind(status)
+        .bind(params.title.trim())
+        .bind(params.description.unwrap_or_default())
+        .bind(now)
+        .bind(params.user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        for (index, text) in params.items.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO discovery_request_items (
+                    id, discovery_request_id, number, text, created_at, updated_at,
+                    created_by, updated_by
+                )
+                VALUES ($1, $2, $3, $4, $5, $5, $6, $6)
+                "#,
+            )
+            .bind(Uuid::new_v4())
+            .bind(request.id)
+            .bind(index as i32 + 1)
+            .bind(text.trim())
+            .bind(now)
+            .bind(params.user_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        let items = list_items(&mut *tx, &request).await?;
+        tx.commit().await?;
+
+        Ok(DiscoveryRequestDetail { request, items })
+    }
+
+    /// Update a discovery set, recounting its due date when service changes.
+    ///
+    /// An overdue set whose response period is extended past today goes back
+    /// to Served.
+    pub async fn update_request(
+        &self,
+        id: Uuid,
+        params: UpdateDiscoveryRequestParams,
+    ) -> Result<DiscoveryRequestDetail, AppError> {
+        let mut tx = self.pool.begin().await?;
+        let existing = lock_request(&mut tx, id).await?;
+
+        let title = params.title.unwrap_or(existing.title);
+        let propounding_party = params
+            .propounding_party
+            .unwrap_or(existing.propounding_party);
+        let responding_party = params.responding_party.unwrap_or(existing.responding_party);
+        validate_request(&title, &propounding_party, &responding_party)?;
+
+        let mut status = params.status.unwrap_or(existing.status);
+        if status != existing.status && !can_transition(existing.status, status) {
+            return Err(AppError::Validation(format!(
+                "A {:?} discovery request cannot move to {:?}",
+                existing.status, status
+            )));
+        }
+
+        let service_date = params.service_date.unwrap_or(existing.service_date);
+        let service_method = params.service_method.unwrap_or(existing.service_method);
+        let response_days = params.response_days.or(existing.response_days);
+        let recount = params.service_date.is_some()
+            || params.service_method.is_some()
+            || params.response_days.is_some()
+            || params.due_date.is_some();
+        let (due_date, computation) = if recount {
+            // A deposition keeps its noticed date unless a new one is given
+            let noticed_date = params.due_date.or_else(|| {
+                response_period(existing.discovery_type)
+                    .is_none()
+                    .then_some(existing.due_date)
+            });
+            let (due_date, computation) = response_deadline(
+                &mut tx,
+                existing.case_id,
+                existing.discovery_type,
+                service_date,
+                service_method,
+                response_days,
+                noticed_date,
+            )
+            .await?;
+            let today = Utc::now().date_naive();
+            if status == DiscoveryStatus::Overdue
+                && !is_overdue(DiscoveryStatus::Served, due_date.date_naive(), today)
+            {
+                status = DiscoveryStatus::Served;
+            }
+            (due_date, computation)
+        } else {
+            (existing.due_date, existing.computation)
+        };
+
+        let now = Utc::now();
+        let responded_at = match status {
+            DiscoveryStatus::Responded => existing.responded_at.or(Some(now)),
+            _ => existing.responded_at,
+        };
+
+        let request = sqlx::query_as::<_, DiscoveryRequest>(
+            r#"
+            UPDATE discovery_requests
+            SET title = $1, description = $2, propounding_party = $3, responding_party = $4,
+                status = $5, service_date = $6, service_method = $7, response_days = $8,
+                due_date = $9, computation = $10, responded_at = $11, updated_at = $12,
+                updated_by = $13, version = COALESCE(version, 1) + 1
+            WHERE id = $14
+            RETURNING *
+            "#,
+        )
+        .bind(title.trim())
+        .bind(params.description.unwrap_or(existing.description))
+        .bind(propounding_party.trim())
+        .bind(responding_party.trim())
+        .bind(status)
+        .bind(service_date)
+        .bind(service_method)
+        .bind(response_days)
+        .bind(due_date)
+        .bind(computation)
+        .bind(responded_at)
+        .bind(now)
+        .bind(params.user_id)
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let items = list_items(&mut *tx, &request).await?;
+        tx.commit().await?;
+
+        Ok(DiscoveryRequestDetail { request, items })
+    }
+
+    /// Soft delete a discovery set
+    pub async fn delete_request(&self, id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+        let result = sqlx::query(
+            "UPDATE discovery_requests SET deleted_at = NOW(), updated_by = $2 WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(
+                "Discovery request not found".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Add a request to the end of a set
+    pub async fn add_item(
+        &self,
+        request_id: Uuid,
+        text: String,
+        user_id: Uuid,
+    ) -> Result<DiscoveryRequestItem, AppError> {
+        if text.trim().is_empty() {
+            return Err(AppError::Validation(
+                "Discovery request text is required".to_string(),
+            ));
+        }
+
+        let mut tx = self.pool.begin().await?;
+        let request = lock_request(&mut tx, request_id).await?;
+        check_editable(&request)?;
+
+        let now = Utc::now();
+        let mut item = sqlx::query_as::<_, DiscoveryRequestItem>(
+            r#"
+            INSERT INTO discovery_request_items (
+                id, discovery_request_id, number, text, created_at, updated_at, created_by, updated_by
+            )
+            SELECT $1, $2, COALESCE(MAX(number), 0) + 1, $3, $4, $4, $5, $5
+            FROM discovery_request_items WHERE discovery_request_id = $2
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(request_id)
+        .bind(text.trim())
+        .bind(now)
+        .bind(user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        item.label = item_label(request.discovery_type, item.number);
+        Ok(item)
+    }
+
+    /// Update a request's text or record its response.
+    ///
+    /// Once every request in a served set has a response or objection, the
+    /// set is marked Responded.
+    pub async fn update_item(
+        &self,
+        request_id: Uuid,
+        item_id: Uuid,
+        params: UpdateDiscoveryItemParams,
+    ) -> Result<DiscoveryRequestItem, AppError> {
+        let mut tx = self.pool.begin().await?;
+        let request = lock_request(&mut tx, request_id).await?;
+        let existing = get_item(&mut tx, request_id, item_id).await?;
+
+        let text = match params.text {
+            Some(text) => {
+                check_editable(&request)?;
+                if text.trim().is_empty() {
+                    return Err(AppError::Validation(
+                        "Discovery request text is required".to_string(),
+                    ));
+                }
+                text.trim().to_string()
+            }
+            None => existing.text,
+        };
+        let response = match params.response {
+            Some(response) => non_empty(response),
+            None => existing.response,
+        };
+        let objections = match params.objections {
+            Some(objections) => non_empty(objections),
+            None => existing.objections,
+        };
+
+        let now = Utc::now();
+        let responded_at = if response.is_some() || objections.is_some() {
+            existing.responded_at.or(Some(now))
+        } else {
+            None
+        };
+
+        let mut item = sqlx::query_as::<_, DiscoveryRequestItem>(
+            r#"
+            UPDATE discovery_request_items
+            SET text = $1, response = $2, objections = $3, responded_at = $4, updated_at = $5,
+                updated_by = $6
+            WHERE id = $7
+            RETURNING *
+            "#,
+        )
+        .bind(&text)
+        .bind(response)
+        .bind(objections)
+        .bind(responded_at)
+        .bind(now)
+        .bind(params.user_id)
+        .bind(item_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        roll_up_responses(&mut tx, request_id).await?;
+        tx.commit().await?;
+
+        item.label = item_label(request.discovery_type, item.number);
+        Ok(item)
+    }
+
+    /// Remove a request from a set, closing up the numbering
+    pub async fn delete_item(
+        &self,
+        request_id: Uuid,
+        item_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+        let request = lock_request(&mut tx, request_id).await?;
+        check_editable(&request)?;
+        let item = get_item(&mut tx, request_id, item_id).await?;
+
+        sqlx::query("DELETE FROM discovery_request_items WHERE id = $1")
+            .bind(item_id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            r#"
+            UPDATE discovery_request_items
+            SET number = number - 1, updated_at = NOW(), updated_by = $3
+            WHERE discovery_request_id = $1 AND number > $2
+            "#,
+        )
+        .bind(request_id)
+        .bind(item.number)
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        roll_up_responses(&mut tx, request_id).await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Mark served written discovery whose responses are past due as
+    /// Overdue, opening a meet-and-confer task for each set.
+    ///
+    /// Depositions are left alone; they are closed out by hand.
+    pub async fn mark_overdue_requests(&self) -> Result<usize, AppError> {
+        let now = Utc::now();
+        let overdue = sqlx::query_as::<_, OverdueRequest>(
+            r#"
+            SELECT r.*, u.id AS owner_id, u.username AS owner_name
+            FROM discovery_requests r
+            LEFT JOIN users u ON u.id = r.created_by AND u.is_active
+            WHERE r.deleted_at IS NULL AND r.status = 'Served'
+              AND r.type <> 'Deposition' AND r.due_date < $1
+            "#,
+        )
+        .bind(now)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut marked = 0;
+        for OverdueRequest {
+            request,
+            owner_id,
+            owner_name,
+        } in overdue
+        {
+            let mut tx = self.pool.begin().await?;
+            let updated = sqlx::query(
+                r#"
+                UPDATE discovery_requests SET status = 'Overdue', updated_at = $1
+                WHERE id = $2 AND status = 'Served' AND deleted_at IS NULL
+                "#,
+            )
+            .bind(now)
+            .bind(request.id)
+            .execute(&mut *tx)
+            .await?;
+            if updated.rows_affected() == 0 {
+                continue;
+            }
+
+            let (title, description, priority) = meet_and_confer_task(&request);
+            let task_id = Uuid::new_v4();
+            sqlx::query(
+                r#"
+                INSERT INTO workflow_tasks (
+                    id, title, status, assignee, assignee_id, due_date, priority, description,
+                    case_id, related_module, related_item_id, related_item_title,
+                    automated_trigger, created_at, updated_at
+                )
+                VALUES ($1, $2, 'Pending', $3, $4, $5, $6, $7, $8, 'Discovery', $9, $10, 'DiscoveryOverdue', $11, $11)
+                "#,
+            )
+            .bind(task_id)
+            .bind(&title)
+            .bind(owner_name.as_deref().unwrap_or("Unassigned"))
+            .bind(owner_id)
+            .bind(end_of_day(
+                now.date_naive() + Duration::days(MEET_AND_CONFER_DAYS),
+            ))
+            .bind(priority)
+            .bind(&description)
+            .bind(request.case_id)
+            .bind(request.id.to_string())
+            .bind(&request.title)
+            .bind(now)
+            .execute(&mut *tx)
+            .await?;
+
+            sqlx::query("UPDATE discovery_requests SET meet_and_confer_task_id = $1 WHERE id = $2")
+                .bind(task_id)
+                .bind(request.id)
+                .execute(&mut *tx)
+                .await?;
+
+            tx.commit().await?;
+            marked += 1;
+        }
+
+        Ok(marked)
+    }
+}
+
+/// Numbered requests of a set, in order
+async fn list_items<'e, E>(
+    executor: E,
+    request: &DiscoveryRequest,
+) -> Result<Vec<DiscoveryRequestItem>, AppError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let mut items = sqlx::query_as::<_, DiscoveryRequestItem>(
+        "SELECT * FROM discovery_request_items WHERE discovery_request_id = $1 ORDER BY number",
+    )
+    .bind(request.id)
+    .fetch_all(executor)
+    .await?;
+
+    for item in &mut items {
+        item.label = item_label(request.discovery_type, item.number);
+    }
+    Ok(items)
+}
+
+async fn lock_request(
+    tx: &mut Transaction<'_, Postgres>,
+    id: Uuid,
+) -> Result<DiscoveryRequest, AppError> {
+    sqlx::query_as::<_, DiscoveryRequest>(
+        "SELECT * FROM discovery_requests WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+    )
+    .bind(id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(AppError::NotFound(
+        "Discovery request not found".to_string(),
+    ))
+}
+
+async fn get_item(
+    tx: &mut Transaction<'_, Postgres>,
+    request_id: Uuid,
+    item_id: Uuid,
+) -> Result<DiscoveryRequestItem, AppError> {
+    sqlx::query_as::<_, DiscoveryRequestItem>(
+        "SELECT * FROM discovery_request_items WHERE id = $1 AND discovery_request_id = $2",
+    )
+    .bind(item_id)
+    .bind(request_id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(AppError::NotFound(
+        "Discovery request item not found".to_string(),
+    ))
+}
+
+/// Due date of a set's responses and how it was counted.
+///
+/// Written discovery is counted from service on the case's court calendar;
+/// a deposition is due on its noticed date.
+async fn response_deadline(
+    tx: &mut Transaction<'_, Postgres>,
+    case_id: Uuid,
+    discovery_type: DiscoveryType,
+    service_date: DateTime<Utc>,
+    service_method: ServiceMethod,
+    response_days: Option<i32>,
+    due_date: Option<DateTime<Utc>>,
+) -> Result<(DateTime<Utc>, Option<String>), AppError> {
+    let (court, jurisdiction) = sqlx::query_as::<_, (Option<String>, Option<String>)>(
+        "SELECT court, jurisdiction FROM cases WHERE id = $1 AND deleted_at IS NULL",
+    )
+    .bind(case_id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(AppError::NotFound("Case not found".to_string()))?;
+
+    let Some(default_days) = response_period(discovery_type) else {
+        if response_days.is_some() {
+            return Err(AppError::Validation(
+                "Depositions have no response period; set the deposition date instead".to_string(),
+            ));
+        }
+        let due_date = due_date.ok_or(AppError::Validation(
+            "Deposition date is required".to_string(),
+        ))?;
+        if due_date < service_date {
+            return Err(AppError::Validation(
+                "Deposition date must not be before the notice is served".to_string(),
+            ));
+        }
+        return Ok((due_date, None));
+    };
+
+    if due_date.is_some() {
+        return Err(AppError::Validation(
+            "Response due dates are counted from service; set response_days to extend them"
+                .to_string(),
+        ));
+    }
+    let days = response_days.unwrap_or(default_days);
+    if !(1..=MAX_RESPONSE_DAYS).contains(&days) {
+        return Err(AppError::Validation(format!(
+            "Response days must be between 1 and {}",
+            MAX_RESPONSE_DAYS
+        )));
+    }
+
+    let calendar = business_calendar(&mut **tx, court.as_deref(), jurisdiction.as_deref()).await?;
+    let computed = response_due_date(service_date.date_naive(), days, service_method, |date| {
+        calendar.is_closed(date)
+    })
+    .map_err(AppError::Validation)?;
+    Ok((end_of_day(computed.due_date), Some(computed.computation)))
+}
+
+/// Mark a served set Responded once every request in it is answered
+async fn roll_up_responses(
+    tx: &mut Transaction<'_, Postgres>,
+    request_id: Uuid,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        UPDATE discovery_requests r
+        SET status = 'Responded', responded_at = NOW(), updated_at = NOW()
+        WHERE r.id = $1 AND r.status IN ('Served', 'Overdue')
+          AND EXISTS (SELECT 1 FROM discovery_request_items i WHERE i.discovery_request_id = r.id)
+          AND NOT EXISTS (
+              SELECT 1 FROM discovery_request_items i
+              WHERE i.discovery_request_id = r.id AND i.responded_at IS NULL
+          )
+        "#,
+    )
+    .bind(request_id)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+/// Title, description and priority of the task opened when responses to a
+/// set become overdue
+fn meet_and_confer_task(request: &DiscoveryRequest) -> (String, String, &'static str) {
+    let due = request.due_date.date_naive();
+    match request.direction {
+        DiscoveryDirection::Propounded => (
+            format!("Meet and confer: overdue responses to {}", request.title),
+            format!(
+                "{}'s responses to {} were due {}. Meet and confer before moving to compel (FRCP 37(a)(1)).",
+                request.responding_party, request.title, due
+            ),
+            "High",
+        ),
+        DiscoveryDirection::Received => {
+            let mut description = format!(
+                "Our responses to {}'s {} were due {}. Serve responses or agree an extension with opposing counsel.",
+                request.propounding_party, request.title, due
+            );
+            let mut priority = "High";
+            if request.discovery_type == DiscoveryType::Admission {
+                description.push_str(
+                    " Unanswered requests for admission are deemed admitted (FRCP 36(a)(3)).",
+                );
+                priority = "Critical";
+            }
+            (
+                format!("Meet and confer: our overdue responses to {}", request.title),
+                description,
+                priority,
+            )
+        }
+    }
+}
+
+/// Requests in a set we propounded are fixed once it has been served
+fn check_editable(request: &DiscoveryRequest) -> Result<(), AppError> {
+    if request.direction == DiscoveryDirection::Propounded
+        && request.status != DiscoveryStatus::Draft
+    {
+        return Err(AppError::Conflict(
+            "Requests in a served set cannot be changed; serve a new set instead".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn non_empty(value: String) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+fn validate_request(
+    title: &str,
+    propounding_party: &str,
+    responding_party: &str,
+) -> Result<(), AppError> {
+    if title.trim().is_empty() {
+        return Err(AppError::Validation(
+            "Discovery request title is required".to_string(),
+        ));
+    }
+    if propounding_party.trim().is_empty() || responding_party.trim().is_empty() {
+        return Err(AppError::Validation(
+            "Propounding and responding parties are required".to_string(),
+        ));
+    }
+    Ok(())
+}
@@ -1,10 +1,18 @@
-use crate::api::docket::service::DocketService;
+use crate::api::docket::service::{
+    AttachDocumentParams, DocketService, GrantSealedAccessParams, SealEntryParams,
+    UpdateDocketEntryParams,
+};
+use crate::api::docket::sheet::DocketSheetFormat;
 use crate::error::AppError;
-use crate::models::DocketEntry;
+use crate::models::{
+    Claims, DeadlineTrigger, DocketConsistencyReport, DocketEntry, DocketEntryDocument,
+    MotionFilingRole, SealedEntryAccess, SealedEntryGrant, ServiceMethod,
+};
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
-    response::Json,
+    http::{header, StatusCode},
+    response::{IntoResponse, Json},
+    Extension,
 };
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
@@ -20,21 +28,63 @@ pub struct ListDocketEntriesQuery {
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateDocketEntryRequest {
     pub case_id: Uuid,
-    pub sequence_number: i32,
+    /// Docket number assigned by PACER/CM-ECF; the local number is assigned by the server
+    pub pacer_sequence_number: Option<i32>,
     pub entry_type: String,
     pub title: String,
     pub description: Option<String>,
     pub date: Option<DateTime<Utc>>,
     pub filed_by: Option<String>,
+    /// Event that starts deadline periods running, e.g. "ComplaintServed"
+    pub trigger_event: Option<DeadlineTrigger>,
+    /// How the filing was served, for added service days
+    pub service_method: Option<ServiceMethod>,
+    /// Motion whose paper this entry records; requires `motion_role`
+    pub motion_id: Option<Uuid>,
+    /// Filing, Opposition, Reply or Order; advances the motion's status
+    pub motion_role: Option<MotionFilingRole>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateDocketEntryRequest {
     pub title: Option<String>,
     pub description: Option<String>,
+    pub pacer_sequence_number: Option<i32>,
+    pub date: Option<DateTime<Utc>>,
+    pub trigger_event: Option<DeadlineTrigger>,
+    pub service_method: Option<ServiceMethod>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AttachDocumentRequest {
+    pub document_id: Uuid,
+    /// File as the main document; otherwise the next numbered attachment
+    pub main_document: Option<bool>,
+    pub description: Option<String>,
 }
 
-/// List docket entries for a case
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SealDocketEntryRequest {
+    /// Court order directing the change, e.g. "ECF No. 42"
+    pub court_order: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct GrantSealedAccessRequest {
+    /// User to be given access
+    pub user_id: Uuid,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ExportDocketQuery {
+    pub case_id: Uuid,
+    /// "pdf" (default) or "csv"
+    pub format: Option<String>,
+}
+
+/// List docket entries for a case; sealed entries are redacted unless the
+/// caller holds a grant
 #[utoipa::path(
     get,
     path = "/api/docket",
@@ -50,9 +100,13 @@ pub struct UpdateDocketEntryRequest {
 )]
 pub async fn list_docket_entries(
     State(service): State<Arc<DocketService>>,
+    Extension(claims): Extension<Claims>,
     Query(query): Query<ListDocketEntriesQuery>,
 ) -> Result<Json<Vec<DocketEntry>>, AppError> {
-    let entries = service.list_entries(query.case_id).await?;
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let entries = service.list_entries(query.case_id, user_id).await?;
     Ok(Json(entries))
 }
 
@@ -65,6 +119,7 @@ pub async fn list_docket_entries(
     ),
     responses(
         (status = 200, description = "Docket entry details", body = DocketEntry),
+        (status = 403, description = "Docket entry is sealed"),
         (status = 404, description = "Docket entry not found"),
         (status = 401, description = "Unauthorized")
     ),
@@ -73,9 +128,13 @@ pub async fn list_docket_entries(
 )]
 pub async fn get_docket_entry(
     State(service): State<Arc<DocketService>>,
+    Extension(claims): Extension<Claims>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<DocketEntry>, AppError> {
-    let entry = service.get_entry(id).await?;
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let entry = service.get_entry(id, user_id).await?;
     Ok(Json(entry))
 }
 
@@ -99,18 +158,22 @@ pub async fn create_docket_entry(
     let entry = service
         .create_entry(crate::api::docket::service::CreateDocketEntryParams {
             case_id: req.case_id,
-            sequence_number: req.sequence_number,
+            pacer_sequence_number: req.pacer_sequence_number,
             entry_type: req.entry_type,
             title: req.title,
             description: req.description,
             date: req.date,
             filed_by: req.filed_by,
+            trigger_event: req.trigger_event,
+            service_method: req.service_method,
+            motion_id: req.motion_id,
+            motion_role: req.motion_role,
         })
         .await?;
     Ok((StatusCode::CREATED, Json(entry)))
 }
 
-/// Update a docket entry
+/// Update a docket entry, recomputing the deadlines it triggers
 #[utoipa::path(
     put,
     path = "/api/docket/{id}",
@@ -120,6 +183,7 @@ pub async fn create_docket_entry(
     request_body = UpdateDocketEntryRequest,
     responses(
         (status = 200, description = "Docket entry updated", body = DocketEntry),
+        (status = 403, description = "Docket entry is sealed"),
         (status = 404, description = "Docket entry not found"),
         (status = 401, description = "Unauthorized")
     ),
@@ -128,10 +192,27 @@ pub async fn create_docket_entry(
 )]
 pub async fn update_docket_entry(
     State(service): State<Arc<DocketService>>,
+    Extension(claims): Extension<Claims>,
     Path(id): Path<Uuid>,
     Json(req): Json<UpdateDocketEntryRequest>,
 ) -> Result<Json<DocketEntry>, AppError> {
-    let entry = service.update_entry(id, req.title, req.description).await?;
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let entry = service
+        .update_entry(
+            id,
+            UpdateDocketEntryParams {
+                title: req.title,
+                description: req.description,
+                pacer_sequence_number: req.pacer_sequence_number,
+                date: req.date,
+                trigger_event: req.trigger_event,
+                service_method: req.service_method,
+            },
+            user_id,
+        )
+        .await?;
     Ok(Json(entry))
 }
 
@@ -144,6 +225,7 @@ pub async fn update_docket_entry(
     ),
     responses(
         (status = 204, description = "Docket entry deleted"),
+        (status = 403, description = "Docket entry is sealed"),
         (status = 404, description = "Docket entry not found"),
         (status = 401, description = "Unauthorized")
     ),
@@ -152,8 +234,377 @@ pub async fn update_docket_entry(
 )]
 pub async fn delete_docket_entry(
     State(service): State<Arc<DocketService>>,
+    Extension(claims): Extension<Claims>,
     Path(id): Path<Uuid>,
 ) -> Result<StatusCode, AppError> {
-    service.delete_entry(id).await?;
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    service.delete_entry(id, user_id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+/// Check a case's docket numbering for gaps and duplicates, locally and
+/// against PACER
+#[utoipa::path(
+    get,
+    path = "/api/docket/consistency",
+    params(
+        ("case_id" = Uuid, Query, description = "Case ID to check")
+    ),
+    responses(
+        (status = 200, description = "Docket numbering consistency report", body = DocketConsistencyReport),
+        (status = 404, description = "Case not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "docket",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_docket_consistency(
+    State(service): State<Arc<DocketService>>,
+    Query(query): Query<ListDocketEntriesQuery>,
+) -> Result<Json<DocketConsistencyReport>, AppError> {
+    let report = service.consistency_report(query.case_id).await?;
+    Ok(Json(report))
+}
+
+/// List the documents filed with a docket entry
+#[utoipa::path(
+    get,
+    path = "/api/docket/{id}/documents",
+    params(
+        ("id" = Uuid, Path, description = "Docket entry ID")
+    ),
+    responses(
+        (status = 200, description = "Main document, then attachments in number order", body = Vec<DocketEntryDocument>),
+        (status = 403, description = "Docket entry is sealed"),
+        (status = 404, description = "Docket entry not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "docket",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_docket_entry_documents(
+    State(service): State<Arc<DocketService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<DocketEntryDocument>>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let documents = service.list_documents(id, user_id).await?;
+    Ok(Json(documents))
+}
+
+/// File a document with a docket entry
+#[utoipa::path(
+    post,
+    path = "/api/docket/{id}/documents",
+    params(
+        ("id" = Uuid, Path, description = "Docket entry ID")
+    ),
+    request_body = AttachDocumentRequest,
+    responses(
+        (status = 201, description = "Document attached", body = DocketEntryDocument),
+        (status = 400, description = "Document belongs to another case"),
+        (status = 403, description = "Docket entry is sealed"),
+        (status = 404, description = "Docket entry or document not found"),
+        (status = 409, description = "Document already attached, or the entry already has a main document"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "docket",
+    security(("bearer_auth" = []))
+)]
+pub async fn attach_docket_entry_document(
+    State(service): State<Arc<DocketService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<AttachDocumentRequest>,
+) -> Result<(StatusCode, Json<DocketEntryDocument>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let document = service
+        .attach_document(
+            id,
+            AttachDocumentParams {
+                document_id: req.document_id,
+                main_document: req.main_document.unwrap_or(false),
+                description: req.description,
+                user_id,
+            },
+        )
+        .await?;
+    Ok((StatusCode::CREATED, Json(document)))
+}
+
+/// Remove a document from a docket entry
+#[utoipa::path(
+    delete,
+    path = "/api/docket/{id}/documents/{attachment_id}",
+    params(
+        ("id" = Uuid, Path, description = "Docket entry ID"),
+        ("attachment_id" = Uuid, Path, description = "Docket entry document ID")
+    ),
+    responses(
+        (status = 204, description = "Document removed"),
+        (status = 403, description = "Docket entry is sealed"),
+        (status = 404, description = "Docket entry or document not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "docket",
+    security(("bearer_auth" = []))
+)]
+pub async fn detach_docket_entry_document(
+    State(service): State<Arc<DocketService>>,
+    Extension(claims): Extension<Claims>,
+    Path((id, attachment_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    service.detach_document(id, attachment_id, user_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Seal a docket entry under a court order
+#[utoipa::path(
+    post,
+    path = "/api/docket/{id}/seal",
+    params(
+        ("id" = Uuid, Path, description = "Docket entry ID")
+    ),
+    request_body = SealDocketEntryRequest,
+    responses(
+        (status = 200, description = "Docket entry sealed; the caller is granted access", body = DocketEntry),
+        (status = 400, description = "Missing court order reference"),
+        (status = 404, description = "Docket entry not found"),
+        (status = 409, description = "Docket entry is already sealed"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "docket",
+    security(("bearer_auth" = []))
+)]
+pub async fn seal_docket_entry(
+    State(service): State<Arc<DocketService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<SealDocketEntryRequest>,
+) -> Result<Json<DocketEntry>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let entry = service
+        .seal_entry(
+            id,
+            SealEntryParams {
+                court_order: req.court_order,
+                user_id,
+            },
+        )
+        .await?;
+    Ok(Json(entry))
+}
+
+/// Unseal a docket entry under a court order
+#[utoipa::path(
+    post,
+    path = "/api/docket/{id}/unseal",
+    params(
+        ("id" = Uuid, Path, description = "Docket entry ID")
+    ),
+    request_body = SealDocketEntryRequest,
+    responses(
+        (status = 200, description = "Docket entry unsealed; its grants are revoked", body = DocketEntry),
+        (status = 400, description = "Missing court order reference"),
+        (status = 403, description = "Caller holds no grant on the entry"),
+        (status = 404, description = "Docket entry not found"),
+        (status = 409, description = "Docket entry is not sealed"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "docket",
+    security(("bearer_auth" = []))
+)]
+pub async fn unseal_docket_entry(
+    State(service): State<Arc<DocketService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<SealDocketEntryRequest>,
+) -> Result<Json<DocketEntry>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let entry = service
+        .unseal_entry(
+            id,
+            SealEntryParams {
+                court_order: req.court_order,
+                user_id,
+            },
+        )
+        .await?;
+    Ok(Json(entry))
+}
+
+/// List the users granted access to a sealed docket entry
+#[utoipa::path(
+    get,
+    path = "/api/docket/{id}/access-grants",
+    params(
+        ("id" = Uuid, Path, description = "Docket entry ID")
+    ),
+    responses(
+        (status = 200, description = "Grants, revoked ones included", body = Vec<SealedEntryGrant>),
+        (status = 403, description = "Docket entry is sealed"),
+        (status = 404, description = "Docket entry not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "docket",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_sealed_access_grants(
+    State(service): State<Arc<DocketService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<SealedEntryGrant>>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let grants = service.list_grants(id, user_id).await?;
+    Ok(Json(grants))
+}
+
+/// Let a user see a sealed docket entry
+#[utoipa::path(
+    post,
+    path = "/api/docket/{id}/access-grants",
+    params(
+        ("id" = Uuid, Path, description = "Docket entry ID")
+    ),
+    request_body = GrantSealedAccessRequest,
+    responses(
+        (status = 201, description = "Access granted", body = SealedEntryGrant),
+        (status = 400, description = "Docket entry is not sealed"),
+        (status = 403, description = "Caller holds no grant on the entry"),
+        (status = 404, description = "Docket entry or user not found"),
+        (status = 409, description = "User already has access"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "docket",
+    security(("bearer_auth" = []))
+)]
+pub async fn grant_sealed_access(
+    State(service): State<Arc<DocketService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<GrantSealedAccessRequest>,
+) -> Result<(StatusCode, Json<SealedEntryGrant>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let grant = service
+        .grant_access(
+            id,
+            GrantSealedAccessParams {
+                grantee_id: req.user_id,
+                reason: req.reason,
+                user_id,
+            },
+        )
+        .await?;
+    Ok((StatusCode::CREATED, Json(grant)))
+}
+
+/// Revoke a user's access to a sealed docket entry
+#[utoipa::path(
+    delete,
+    path = "/api/docket/{id}/access-grants/{grant_id}",
+    params(
+        ("id" = Uuid, Path, description = "Docket entry ID"),
+        ("grant_id" = Uuid, Path, description = "Access grant ID")
+    ),
+    responses(
+        (status = 204, description = "Access revoked"),
+        (status = 400, description = "The last grant on a sealed entry cannot be revoked"),
+        (status = 403, description = "Caller holds no grant on the entry"),
+        (status = 404, description = "Docket entry or grant not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "docket",
+    security(("bearer_auth" = []))
+)]
+pub async fn revoke_sealed_access(
+    State(service): State<Arc<DocketService>>,
+    Extension(claims): Extension<Claims>,
+    Path((id, grant_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    service.revoke_access(id, grant_id, user_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Audit trail of a docket entry's sealing and of every access to it
+#[utoipa::path(
+    get,
+    path = "/api/docket/{id}/access-log",
+    params(
+        ("id" = Uuid, Path, description = "Docket entry ID")
+    ),
+    responses(
+        (status = 200, description = "Access events, oldest first", body = Vec<SealedEntryAccess>),
+        (status = 403, description = "Docket entry is sealed"),
+        (status = 404, description = "Docket entry not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "docket",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_sealed_access_log(
+    State(service): State<Arc<DocketService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<SealedEntryAccess>>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let events = service.access_log(id, user_id).await?;
+    Ok(Json(events))
+}
+
+/// Download a case's docket sheet; sealed entries are left out
+#[utoipa::path(
+    get,
+    path = "/api/docket/export",
+    params(
+        ("case_id" = Uuid, Query, description = "Case ID"),
+        ("format" = Option<String>, Query, description = "pdf (default) or csv")
+    ),
+    responses(
+        (status = 200, description = "Docket sheet", content_type = "application/pdf"),
+        (status = 200, description = "Docket sheet", content_type = "text/csv"),
+        (status = 400, description = "Unsupported format"),
+        (status = 404, description = "Case not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "docket",
+    security(("bearer_auth" = []))
+)]
+pub async fn export_docket_sheet(
+    State(service): State<Arc<DocketService>>,
+    Query(query): Query<ExportDocketQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let format = DocketSheetFormat::parse(query.format.as_deref().unwrap_or("pdf"))?;
+    let export = service.export_docket(query.case_id, format).await?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, export.content_type.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", export.file_name),
+            ),
+        ],
+        export.content,
+    ))
+}
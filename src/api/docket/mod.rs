@@ -1,5 +1,8 @@
 pub mod handlers;
+pub mod sealing;
+pub mod sequence;
 pub mod service;
+pub mod sheet;
 
 pub use handlers::*;
 pub use service::*;
@@ -0,0 +1,47 @@
+//! Redaction of sealed docket entries for users without a grant
+
+use crate::error::AppError;
+use crate::models::DocketEntry;
+
+/// Docket text shown in place of a sealed entry's title
+pub const SEALED_TITLE: &str = "SEALED";
+
+/// Longest court order reference accepted, matching the column width
+const MAX_COURT_ORDER_LEN: usize = 255;
+
+/// Whether an entry is currently sealed
+pub fn is_sealed(entry: &DocketEntry) -> bool {
+    entry.is_sealed.unwrap_or(false)
+}
+
+/// Withhold a sealed entry's contents, keeping its numbers, date and type so
+/// that the docket still reads in sequence
+pub fn redact_entry(mut entry: DocketEntry) -> DocketEntry {
+    if !is_sealed(&entry) {
+        return entry;
+    }
+    entry.title = SEALED_TITLE.to_string();
+    entry.description = None;
+    entry.filed_by = None;
+    entry.trigger_event = None;
+    entry.service_method = None;
+    entry.redacted = true;
+    entry
+}
+
+/// Trimmed court order reference, e.g. "ECF No. 42" or "Order of 3/2/2026"
+pub fn validate_court_order(court_order: &str) -> Result<String, AppError> {
+    let court_order = court_order.trim();
+    if court_order.is_empty() {
+        return Err(AppError::Validation(
+            "A court order reference is required".to_string(),
+        ));
+    }
+    if court_order.chars().count() > MAX_COURT_ORDER_LEN {
+        return Err(AppError::Validation(format!(
+            "Court order reference may be at most {} characters",
+            MAX_COURT_ORDER_LEN
+        )));
+    }
+    Ok(court_order.to_string())
+}
@@ -0,0 +1,91 @@
+//! Docket numbering consistency checks
+
+use crate::models::{DocketConsistencyReport, PacerDuplicate, SequenceGap};
+use sqlx::FromRow;
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+/// Numbering of a single docket entry, including deleted ones
+#[derive(Debug, Clone, FromRow)]
+pub struct DocketNumbering {
+    pub id: Uuid,
+    pub sequence_number: i32,
+    pub pacer_sequence_number: Option<i32>,
+    pub deleted: bool,
+}
+
+/// Ranges of numbers between 1 and the highest number that are not used
+pub fn missing_ranges(numbers: &[i32]) -> Vec<SequenceGap> {
+    let mut sorted: Vec<i32> = numbers.iter().copied().filter(|n| *n > 0).collect();
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    let mut gaps = Vec::new();
+    let mut expected = 1;
+    for number in sorted {
+        if number > expected {
+            gaps.push(SequenceGap {
+                from: expected,
+                to: number - 1,
+            });
+        }
+        expected = number + 1;
+    }
+    gaps
+}
+
+/// Check a case's docket numbering.
+///
+/// Local numbers are checked across all entries, since deleted entries keep
+/// their numbers; PACER numbers are checked across live entries only.
+pub fn consistency_report(case_id: Uuid, entries: &[DocketNumbering]) -> DocketConsistencyReport {
+    let sequence_numbers: Vec<i32> = entries.iter().map(|e| e.sequence_number).collect();
+    let mut sequence_counts: BTreeMap<i32, usize> = BTreeMap::new();
+    for number in &sequence_numbers {
+        *sequence_counts.entry(*number).or_default() += 1;
+    }
+    let duplicate_sequence_numbers: Vec<i32> = sequence_counts
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .map(|(number, _)| number)
+        .collect();
+
+    let live: Vec<&DocketNumbering> = entries.iter().filter(|e| !e.deleted).collect();
+    let mut by_pacer_number: BTreeMap<i32, Vec<Uuid>> = BTreeMap::new();
+    for entry in &live {
+        if let Some(pacer) = entry.pacer_sequence_number {
+            by_pacer_number.entry(pacer).or_default().push(entry.id);
+        }
+    }
+    let pacer_numbers: Vec<i32> = by_pacer_number.keys().copied().collect();
+    let pacer_duplicates: Vec<PacerDuplicate> = by_pacer_number
+        .into_iter()
+        .filter(|(_, ids)| ids.len() > 1)
+        .map(|(pacer_sequence_number, entry_ids)| PacerDuplicate {
+            pacer_sequence_number,
+            entry_ids,
+        })
+        .collect();
+
+    let sequence_gaps = missing_ranges(&sequence_numbers);
+    let pacer_gaps = missing_ranges(&pacer_numbers);
+    let consistent = sequence_gaps.is_empty()
+        && duplicate_sequence_numbers.is_empty()
+        && pacer_gaps.is_empty()
+        && pacer_duplicates.is_empty();
+
+    DocketConsistencyReport {
+        case_id,
+        entry_count: live.len(),
+        last_sequence_number: sequence_numbers.iter().copied().max().unwrap_or(0),
+        sequence_gaps,
+        duplicate_sequence_numbers,
+        pacer_gaps,
+        pacer_duplicates,
+        entries_without_pacer_number: live
+            .iter()
+            .filter(|e| e.pacer_sequence_number.is_none())
+            .count(),
+        consistent,
+    }
+}
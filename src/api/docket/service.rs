@@ -1,15 +1,24 @@
+use crate::api::deadlines::sync_entry_deadlines;
+use crate::api::docket::sealing::{is_sealed, redact_entry, validate_court_order};
+use crate::api::docket::sequence::{consistency_report, DocketNumbering};
+use crate::api::docket::sheet::{render_docket_sheet, DocketSheetFormat};
+use crate::api::motions::link_motion_entry;
 use crate::error::AppError;
-use crate::models::DocketEntry;
+use crate::models::{
+    DeadlineTrigger, DocketConsistencyReport, DocketEntry, DocketEntryDocument, MotionFilingRole,
+    SealedAccessAction, SealedEntryAccess, SealedEntryGrant, ServiceMethod,
+};
 use chrono::{DateTime, Utc};
 use sqlx::PgPool;
+use std::collections::HashSet;
 use uuid::Uuid;
 
 /// Parameters for creating a new docket entry
 pub struct CreateDocketEntryParams {
     /// ID of the case this docket entry belongs to
     pub case_id: Uuid,
-    /// Sequential number of the entry in the docket
-    pub sequence_number: i32,
+    /// Docket number assigned by PACER/CM-ECF (optional)
+    pub pacer_sequence_number: Option<i32>,
     /// Type of docket entry (e.g., "Motion", "Order", "Filing")
     pub entry_type: String,
     /// Title or brief description of the entry
@@ -20,6 +29,61 @@ pub struct CreateDocketEntryParams {
     pub date: Option<DateTime<Utc>>,
     /// Name of the person or party filing the entry (optional)
     pub filed_by: Option<String>,
+    /// Event that starts deadline periods running (optional)
+    pub trigger_event: Option<DeadlineTrigger>,
+    /// How the filing was served (optional)
+    pub service_method: Option<ServiceMethod>,
+    /// Motion whose paper the entry records (optional, with `motion_role`)
+    pub motion_id: Option<Uuid>,
+    /// Paper the entry records in the motion's briefing
+    pub motion_role: Option<MotionFilingRole>,
+}
+
+/// Parameters for updating a docket entry; omitted fields are unchanged
+pub struct UpdateDocketEntryParams {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub pacer_sequence_number: Option<i32>,
+    pub date: Option<DateTime<Utc>>,
+    pub trigger_event: Option<DeadlineTrigger>,
+    pub service_method: Option<ServiceMethod>,
+}
+
+/// Parameters for filing a document with a docket entry
+pub struct AttachDocumentParams {
+    pub document_id: Uuid,
+    /// File as the main document rather than the next numbered attachment
+    pub main_document: bool,
+    pub description: Option<String>,
+    /// ID of the user attaching the document
+    pub user_id: Uuid,
+}
+
+/// Parameters for sealing or unsealing a docket entry
+pub struct SealEntryParams {
+    /// Court order directing the change, e.g. "ECF No. 42"
+    pub court_order: String,
+    /// ID of the user recording the order
+    pub user_id: Uuid,
+}
+
+/// Parameters for letting a user see a sealed docket entry
+pub struct GrantSealedAccessParams {
+    /// User to be given access
+    pub grantee_id: Uuid,
+    pub reason: Option<String>,
+    /// ID of the user granting access, who must hold a grant
+    pub user_id: Uuid,
+}
+
+/// Rendered docket sheet
+pub struct DocketSheetExport {
+    /// Suggested file name of the sheet
+    pub file_name: String,
+    /// MIME type of the rendered sheet
+    pub content_type: &'static str,
+    /// Rendered sheet bytes
+    pub content: Vec<u8>,
 }
 
 pub struct DocketService {
@@ -31,103 +95,944 @@ impl DocketService {
         Self { pool }
     }
 
-    /// List docket entries for a case
-    pub async fn list_entries(&self, case_id: Uuid) -> Result<Vec<DocketEntry>, AppError> {
+    /// List docket entries for a case.
+    ///
+    /// Sealed entries are redacted unless the user holds a grant; showing a
+    /// sealed entry's contents is recorded in its access log.
+    pub async fn list_entries(
+        &self,
+        case_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<DocketEntry>, AppError> {
         let entries = sqlx::query_as::<_, DocketEntry>(
-            "SELECT * FROM docket_entries WHERE case_id = $1 ORDER BY date DESC, sequence_number DESC"
+            "SELECT * FROM docket_entries WHERE case_id = $1 AND deleted_at IS NULL ORDER BY date DESC, sequence_number DESC"
         )
         .bind(case_id)
         .fetch_all(&self.pool)
         .await?;
 
+        if !entries.iter().any(is_sealed) {
+            return Ok(entries);
+        }
+
+        let granted: HashSet<Uuid> = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT g.docket_entry_id
+            FROM sealed_entry_grants g
+            JOIN docket_entries d ON d.id = g.docket_entry_id
+            WHERE d.case_id = $1 AND g.user_id = $2 AND g.revoked_at IS NULL
+            "#,
+        )
+        .bind(case_id)
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .collect();
+
+        let mut viewed = Vec::new();
+        let entries = entries
+            .into_iter()
+            .map(|entry| {
+                if !is_sealed(&entry) {
+                    entry
+                } else if granted.contains(&entry.id) {
+                    viewed.push(entry.id);
+                    entry
+                } else {
+                    redact_entry(entry)
+                }
+            })
+            .collect();
+
+        if !viewed.is_empty() {
+            sqlx::query(
+                r#"
+                INSERT INTO sealed_entry_access_log (id, docket_entry_id, user_id, action, details)
+                SELECT gen_random_uuid(), entry_id, $2, 'Viewed', 'Docket listing'
+                FROM UNNEST($1::UUID[]) AS entry_id
+                "#,
+            )
+            .bind(&viewed)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+        }
+
         Ok(entries)
     }
 
-    /// Get a specific docket entry
-    pub async fn get_entry(&self, id: Uuid) -> Result<DocketEntry, AppError> {
-        let entry = sqlx::query_as::<_, DocketEntry>("SELECT * FROM docket_entries WHERE id = $1")
-            .bind(id)
-            .fetch_optional(&self.pool)
-            .await?
-            .ok_or(AppError::NotFound("Docket entry not found".to_string()))?;
+    /// Get a specific docket entry.
+    ///
+    /// Sealed entries are only shown to users holding a grant; every
+    /// request for one is recorded in its access log.
+    pub async fn get_entry(&self, id: Uuid, user_id: Uuid) -> Result<DocketEntry, AppError> {
+        let entry = sqlx::query_as::<_, DocketEntry>(
+            "SELECT * FROM docket_entries WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound("Docket entry not found".to_string()))?;
+
+        if is_sealed(&entry) {
+            self.require_grant(id, user_id, "View").await?;
+            log_access(
+                &self.pool,
+                id,
+                user_id,
+                SealedAccessAction::Viewed,
+                None,
+                None,
+            )
+            .await?;
+        }
 
         Ok(entry)
     }
 
-    /// Create a new docket entry
-    pub async fn create_entry(&self, params: CreateDocketEntryParams) -> Result<DocketEntry, AppError> {
+    /// Create a new docket entry.
+    ///
+    /// The entry gets the case's next docket number; numbers are allocated
+    /// one at a time per case and never reused. Deadlines triggered by the
+    /// entry are computed with it.
+    pub async fn create_entry(
+        &self,
+        params: CreateDocketEntryParams,
+    ) -> Result<DocketEntry, AppError> {
+        validate_pacer_sequence_number(params.pacer_sequence_number)?;
+        let motion_link = match (params.motion_id, params.motion_role) {
+            (Some(motion_id), Some(role)) => Some((motion_id, role)),
+            (None, None) => None,
+            _ => {
+                return Err(AppError::Validation(
+                    "motion_id and motion_role must be given together".to_string(),
+                ))
+            }
+        };
+
         let id = Uuid::new_v4();
         let now = Utc::now();
         let entry_date = params.date.unwrap_or(now);
+        let mut tx = self.pool.begin().await?;
+
+        lock_docket(&mut tx, params.case_id).await?;
+        let sequence_number = next_sequence_number(&mut tx, params.case_id).await?;
 
         let entry = sqlx::query_as::<_, DocketEntry>(
             r#"
             INSERT INTO docket_entries (
-                id, case_id, sequence_number, type, title, description, date, filed_by, created_at, updated_at
+                id, case_id, sequence_number, pacer_sequence_number, type, title, description,
+                date, filed_by, trigger_event, service_method, created_at, updated_at
             )
-            VALUES ($1, $2, $3, $4::docket_entry_type, $5, $6, $7, $8, $9, $10)
+            VALUES ($1, $2, $3, $4, $5::docket_entry_type, $6, $7, $8, $9, $10, $11, $12, $13)
             RETURNING *
             "#,
         )
         .bind(id)
         .bind(params.case_id)
-        .bind(params.sequence_number)
+        .bind(sequence_number)
+        .bind(params.pacer_sequence_number)
         .bind(&params.entry_type)
         .bind(&params.title)
         .bind(&params.description)
         .bind(entry_date)
         .bind(&params.filed_by)
+        .bind(params.trigger_event)
+        .bind(params.service_method)
         .bind(now)
         .bind(now)
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await?;
 
+        if let Some((motion_id, role)) = motion_link {
+            link_motion_entry(&mut tx, motion_id, id, role, None).await?;
+        }
+        sync_entry_deadlines(&mut tx, id, None).await?;
+        tx.commit().await?;
+
         Ok(entry)
     }
 
-    /// Update a docket entry
+    /// Update a docket entry.
+    ///
+    /// Deadlines triggered by the entry are recomputed when its date, event
+    /// or service method changes.
     pub async fn update_entry(
         &self,
         id: Uuid,
-        title: Option<String>,
-        description: Option<String>,
+        params: UpdateDocketEntryParams,
+        user_id: Uuid,
     ) -> Result<DocketEntry, AppError> {
+        validate_pacer_sequence_number(params.pacer_sequence_number)?;
+
         let now = Utc::now();
-        let existing = self.get_entry(id).await?;
+        let mut tx = self.pool.begin().await?;
+
+        let existing = lock_entry(&mut tx, id).await?;
+        if is_sealed(&existing) {
+            self.require_grant(id, user_id, "Update").await?;
+            log_access(
+                &mut *tx,
+                id,
+                user_id,
+                SealedAccessAction::Modified,
+                None,
+                None,
+            )
+            .await?;
+        }
 
-        let updated_title = title.unwrap_or(existing.title);
-        let updated_description = description.or(existing.description);
+        let updated_title = params.title.unwrap_or(existing.title);
+        let updated_description = params.description.or(existing.description);
+        let updated_pacer_sequence_number = params
+            .pacer_sequence_number
+            .or(existing.pacer_sequence_number);
+        let updated_date = params.date.unwrap_or(existing.date);
+        let updated_trigger_event = params.trigger_event.or(existing.trigger_event);
+        let updated_service_method = params.service_method.or(existing.service_method);
 
         let entry = sqlx::query_as::<_, DocketEntry>(
             r#"
             UPDATE docket_entries
-            SET title = $1, description = $2, updated_at = $3
-            WHERE id = $4
+            SET title = $1, description = $2, pacer_sequence_number = $3, date = $4,
+                trigger_event = $5, service_method = $6, updated_at = $7
+            WHERE id = $8
             RETURNING *
             "#,
         )
         .bind(&updated_title)
         .bind(&updated_description)
+        .bind(updated_pacer_sequence_number)
+        .bind(updated_date)
+        .bind(updated_trigger_event)
+        .bind(updated_service_method)
         .bind(now)
         .bind(id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if entry.date != existing.date
+            || entry.trigger_event != existing.trigger_event
+            || entry.service_method != existing.service_method
+        {
+            sync_entry_deadlines(&mut tx, id, None).await?;
+        }
+        tx.commit().await?;
+
+        Ok(entry)
+    }
+
+    /// Delete a docket entry.
+    ///
+    /// Entries are soft-deleted so that their docket numbers are not reused.
+    /// Open deadlines the entry triggered are vacated.
+    pub async fn delete_entry(&self, id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        let existing = lock_entry(&mut tx, id).await?;
+        if is_sealed(&existing) {
+            self.require_grant(id, user_id, "Delete").await?;
+            log_access(
+                &mut *tx,
+                id,
+                user_id,
+                SealedAccessAction::Deleted,
+                None,
+                None,
+            )
+            .await?;
+        }
+
+        sqlx::query("UPDATE docket_entries SET deleted_at = NOW() WHERE id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        sync_entry_deadlines(&mut tx, id, None).await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Documents filed with a docket entry, main document first
+    pub async fn list_documents(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<DocketEntryDocument>, AppError> {
+        let entry = sqlx::query_as::<_, DocketEntry>(
+            "SELECT * FROM docket_entries WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
         .fetch_optional(&self.pool)
         .await?
         .ok_or(AppError::NotFound("Docket entry not found".to_string()))?;
 
-        Ok(entry)
+        if is_sealed(&entry) {
+            self.require_grant(id, user_id, "Document listing").await?;
+            log_access(
+                &self.pool,
+                id,
+                user_id,
+                SealedAccessAction::Viewed,
+                None,
+                Some("Document listing"),
+            )
+            .await?;
+        }
+
+        entry_documents(&self.pool, id).await
     }
 
-    /// Delete a docket entry
-    pub async fn delete_entry(&self, id: Uuid) -> Result<(), AppError> {
-        let result = sqlx::query("DELETE FROM docket_entries WHERE id = $1")
-            .bind(id)
-            .execute(&self.pool)
+    /// File a document with a docket entry, as its main document or as the
+    /// next numbered attachment
+    pub async fn attach_document(
+        &self,
+        id: Uuid,
+        params: AttachDocumentParams,
+    ) -> Result<DocketEntryDocument, AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        let entry = lock_entry(&mut tx, id).await?;
+        if is_sealed(&entry) {
+            self.require_grant(id, params.user_id, "Attach document")
+                .await?;
+            log_access(
+                &mut *tx,
+                id,
+                params.user_id,
+                SealedAccessAction::Modified,
+                None,
+                Some(&format!("Attached document {}", params.document_id)),
+            )
             .await?;
+        }
+
+        let document_case_id = sqlx::query_scalar::<_, Uuid>(
+            "SELECT case_id FROM documents WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(params.document_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(AppError::NotFound("Document not found".to_string()))?;
+        if document_case_id != entry.case_id {
+            return Err(AppError::Validation(
+                "Document belongs to a different case than the docket entry".to_string(),
+            ));
+        }
+
+        let attached: Vec<(Uuid, i32)> = sqlx::query_as(
+            "SELECT document_id, attachment_number FROM docket_entry_documents WHERE docket_entry_id = $1",
+        )
+        .bind(id)
+        .fetch_all(&mut *tx)
+        .await?;
+        if attached.iter().any(|(doc, _)| *doc == params.document_id) {
+            return Err(AppError::Conflict(
+                "Document is already filed with this docket entry".to_string(),
+            ));
+        }
+        let attachment_number = if params.main_document {
+            if attached.iter().any(|(_, number)| *number == 0) {
+                return Err(AppError::Conflict(
+                    "Docket entry already has a main document".to_string(),
+                ));
+            }
+            0
+        } else {
+            attached
+                .iter()
+                .map(|(_, number)| *number)
+                .max()
+                .unwrap_or(0)
+                + 1
+        };
+
+        let attachment_id = Uuid::new_v4();
+        sqlx::query(
+            r#"
+            INSERT INTO docket_entry_documents (
+                id, docket_entry_id, document_id, attachment_number, description, created_at, created_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+        )
+        .bind(attachment_id)
+        .bind(id)
+        .bind(params.document_id)
+        .bind(attachment_number)
+        .bind(&params.description)
+        .bind(Utc::now())
+        .bind(params.user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        let document = entry_documents(&mut *tx, id)
+            .await?
+            .into_iter()
+            .find(|d| d.id == attachment_id)
+            .ok_or(AppError::InternalServerError(
+                "Attached document not found".to_string(),
+            ))?;
+        tx.commit().await?;
+
+        Ok(document)
+    }
+
+    /// Remove a document from a docket entry; other attachments keep their
+    /// numbers
+    pub async fn detach_document(
+        &self,
+        id: Uuid,
+        attachment_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        let entry = lock_entry(&mut tx, id).await?;
+        if is_sealed(&entry) {
+            self.require_grant(id, user_id, "Detach document").await?;
+            log_access(
+                &mut *tx,
+                id,
+                user_id,
+                SealedAccessAction::Modified,
+                None,
+                Some(&format!("Removed attachment {}", attachment_id)),
+            )
+            .await?;
+        }
 
+        let result = sqlx::query(
+            "DELETE FROM docket_entry_documents WHERE id = $1 AND docket_entry_id = $2",
+        )
+        .bind(attachment_id)
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
         if result.rows_affected() == 0 {
-            return Err(AppError::NotFound("Docket entry not found".to_string()));
+            return Err(AppError::NotFound(
+                "Docket entry document not found".to_string(),
+            ));
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Seal a docket entry under a court order.
+    ///
+    /// The user recording the order is granted access; everyone else sees
+    /// the entry redacted until they are granted access or it is unsealed.
+    pub async fn seal_entry(
+        &self,
+        id: Uuid,
+        params: SealEntryParams,
+    ) -> Result<DocketEntry, AppError> {
+        let court_order = validate_court_order(&params.court_order)?;
+
+        let now = Utc::now();
+        let mut tx = self.pool.begin().await?;
+
+        let existing = lock_entry(&mut tx, id).await?;
+        if is_sealed(&existing) {
+            return Err(AppError::Conflict(
+                "Docket entry is already sealed".to_string(),
+            ));
+        }
+
+        let entry = sqlx::query_as::<_, DocketEntry>(
+            r#"
+            UPDATE docket_entries
+            SET is_sealed = true, seal_order = $1, sealed_at = $2, sealed_by = $3,
+                unseal_order = NULL, unsealed_at = NULL, updated_at = $2, updated_by = $3
+            WHERE id = $4
+            RETURNING *
+            "#,
+        )
+        .bind(&court_order)
+        .bind(now)
+        .bind(params.user_id)
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO sealed_entry_grants (id, docket_entry_id, user_id, reason, granted_by, created_at)
+            VALUES ($1, $2, $3, $4, $3, $5)
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(id)
+        .bind(params.user_id)
+        .bind(format!("Sealed under {}", court_order))
+        .bind(now)
+        .execute(&mut *tx)
+        .await?;
+
+        log_access(
+            &mut *tx,
+            id,
+            params.user_id,
+            SealedAccessAction::Sealed,
+            Some(&court_order),
+            None,
+        )
+        .await?;
+        tx.commit().await?;
+
+        Ok(entry)
+    }
+
+    /// Unseal a docket entry under a court order.
+    ///
+    /// Only a user holding a grant may record the order. The entry's grants
+    /// are revoked, so resealing it starts from a fresh grant list.
+    pub async fn unseal_entry(
+        &self,
+        id: Uuid,
+        params: SealEntryParams,
+    ) -> Result<DocketEntry, AppError> {
+        let court_order = validate_court_order(&params.court_order)?;
+
+        let now = Utc::now();
+        let mut tx = self.pool.begin().await?;
+
+        let existing = lock_entry(&mut tx, id).await?;
+        if !is_sealed(&existing) {
+            return Err(AppError::Conflict("Docket entry is not sealed".to_string()));
+        }
+        self.require_grant(id, params.user_id, "Unseal").await?;
+
+        let entry = sqlx::query_as::<_, DocketEntry>(
+            r#"
+            UPDATE docket_entries
+            SET is_sealed = false, unseal_order = $1, unsealed_at = $2, updated_at = $2, updated_by = $3
+            WHERE id = $4
+            RETURNING *
+            "#,
+        )
+        .bind(&court_order)
+        .bind(now)
+        .bind(params.user_id)
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r#"
+            UPDATE sealed_entry_grants SET revoked_at = $1, revoked_by = $2
+            WHERE docket_entry_id = $3 AND revoked_at IS NULL
+            "#,
+        )
+        .bind(now)
+        .bind(params.user_id)
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+        log_access(
+            &mut *tx,
+            id,
+            params.user_id,
+            SealedAccessAction::Unsealed,
+            Some(&court_order),
+            None,
+        )
+        .await?;
+        tx.commit().await?;
+
+        Ok(entry)
+    }
+
+    /// List the grants on a docket entry, revoked ones included
+    pub async fn list_grants(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<SealedEntryGrant>, AppError> {
+        self.authorize_history(id, user_id).await?;
+
+        let grants = sqlx::query_as::<_, SealedEntryGrant>(
+            "SELECT * FROM sealed_entry_grants WHERE docket_entry_id = $1 ORDER BY created_at",
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(grants)
+    }
+
+    /// Let a user see a sealed docket entry
+    pub async fn grant_access(
+        &self,
+        id: Uuid,
+        params: GrantSealedAccessParams,
+    ) -> Result<SealedEntryGrant, AppError> {
+        let now = Utc::now();
+        let mut tx = self.pool.begin().await?;
+
+        let entry = lock_entry(&mut tx, id).await?;
+        if !is_sealed(&entry) {
+            return Err(AppError::Validation(
+                "Only sealed docket entries take access grants".to_string(),
+            ));
+        }
+        self.require_grant(id, params.user_id, "Grant").await?;
+
+        sqlx::query_scalar::<_, Uuid>("SELECT id FROM users WHERE id = $1 AND is_active = true")
+            .bind(params.grantee_id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or(AppError::NotFound("User not found".to_string()))?;
+
+        if has_grant(&mut *tx, id, params.grantee_id).await? {
+            return Err(AppError::Conflict(
+                "User already has access to this docket entry".to_string(),
+            ));
+        }
+
+        let grant = sqlx::query_as::<_, SealedEntryGrant>(
+            r#"
+            INSERT INTO sealed_entry_grants (id, docket_entry_id, user_id, reason, granted_by, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(id)
+        .bind(params.grantee_id)
+        .bind(&params.reason)
+        .bind(params.user_id)
+        .bind(now)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        log_access(
+            &mut *tx,
+            id,
+            params.user_id,
+            SealedAccessAction::Granted,
+            None,
+            Some(&format!("Access granted to user {}", params.grantee_id)),
+        )
+        .await?;
+        tx.commit().await?;
+
+        Ok(grant)
+    }
+
+    /// Revoke a user's access to a sealed docket entry.
+    ///
+    /// The last remaining grant cannot be revoked, so that someone can
+    /// always act on the entry.
+    pub async fn revoke_access(
+        &self,
+        id: Uuid,
+        grant_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        let entry = lock_entry(&mut tx, id).await?;
+        if is_sealed(&entry) {
+            self.require_grant(id, user_id, "Revoke").await?;
+        }
+
+        let grantee_id = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            UPDATE sealed_entry_grants SET revoked_at = NOW(), revoked_by = $3
+            WHERE id = $1 AND docket_entry_id = $2 AND revoked_at IS NULL
+            RETURNING user_id
+            "#,
+        )
+        .bind(grant_id)
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(AppError::NotFound("Access grant not found".to_string()))?;
+
+        let remaining: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM sealed_entry_grants WHERE docket_entry_id = $1 AND revoked_at IS NULL",
+        )
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await?;
+        if remaining == 0 {
+            return Err(AppError::Validation(
+                "The last access grant on a sealed docket entry cannot be revoked".to_string(),
+            ));
+        }
+
+        log_access(
+            &mut *tx,
+            id,
+            user_id,
+            SealedAccessAction::Revoked,
+            None,
+            Some(&format!("Access revoked from user {}", grantee_id)),
+        )
+        .await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Audit trail of a docket entry's sealing and of access to it, oldest
+    /// first
+    pub async fn access_log(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<SealedEntryAccess>, AppError> {
+        self.authorize_history(id, user_id).await?;
+
+        let events = sqlx::query_as::<_, SealedEntryAccess>(
+            "SELECT * FROM sealed_entry_access_log WHERE docket_entry_id = $1 ORDER BY created_at, id",
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(events)
+    }
+
+    /// Render a case's docket sheet; sealed entries are left out
+    pub async fn export_docket(
+        &self,
+        case_id: Uuid,
+        format: DocketSheetFormat,
+    ) -> Result<DocketSheetExport, AppError> {
+        let (title, court): (String, Option<String>) =
+            sqlx::query_as("SELECT title, court FROM cases WHERE id = $1 AND deleted_at IS NULL")
+                .bind(case_id)
+                .fetch_optional(&self.pool)
+                .await?
+                .ok_or(AppError::NotFound("Case not found".to_string()))?;
+
+        let entries = sqlx::query_as::<_, DocketEntry>(
+            "SELECT * FROM docket_entries WHERE case_id = $1 AND deleted_at IS NULL AND is_sealed IS NOT TRUE ORDER BY sequence_number",
+        )
+        .bind(case_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut heading = Vec::new();
+        if let Some(court) = court.filter(|c| !c.trim().is_empty()) {
+            heading.push(court.to_uppercase());
+        }
+        heading.push(title);
+
+        Ok(DocketSheetExport {
+            file_name: format!("docket-sheet.{}", format.extension()),
+            content_type: format.content_type(),
+            content: render_docket_sheet(format, &heading, &entries),
+        })
+    }
+
+    /// Check a case's docket for gaps and duplicates in its own and in
+    /// PACER's numbering
+    pub async fn consistency_report(
+        &self,
+        case_id: Uuid,
+    ) -> Result<DocketConsistencyReport, AppError> {
+        sqlx::query_scalar::<_, Uuid>("SELECT id FROM cases WHERE id = $1 AND deleted_at IS NULL")
+            .bind(case_id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or(AppError::NotFound("Case not found".to_string()))?;
+
+        let entries = sqlx::query_as::<_, DocketNumbering>(
+            r#"
+            SELECT id, sequence_number, pacer_sequence_number, deleted_at IS NOT NULL AS deleted
+            FROM docket_entries
+            WHERE case_id = $1
+            ORDER BY sequence_number
+            "#,
+        )
+        .bind(case_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(consistency_report(case_id, &entries))
+    }
+
+    /// Check that a user holds a grant on a sealed entry.
+    ///
+    /// Refusals are logged through the pool rather than the caller's
+    /// transaction so that they survive its rollback.
+    async fn require_grant(
+        &self,
+        entry_id: Uuid,
+        user_id: Uuid,
+        attempted: &str,
+    ) -> Result<(), AppError> {
+        if has_grant(&self.pool, entry_id, user_id).await? {
+            return Ok(());
+        }
+
+        log_access(
+            &self.pool,
+            entry_id,
+            user_id,
+            SealedAccessAction::Denied,
+            None,
+            Some(&format!("{} refused without a grant", attempted)),
+        )
+        .await?;
+        Err(AppError::Authorization(
+            "Docket entry is sealed".to_string(),
+        ))
+    }
+
+    /// Grants and the access log of a sealed entry are limited to its
+    /// grantees; those of unsealed entries are open
+    async fn authorize_history(&self, id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+        let entry = sqlx::query_as::<_, DocketEntry>(
+            "SELECT * FROM docket_entries WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound("Docket entry not found".to_string()))?;
+
+        if is_sealed(&entry) {
+            self.require_grant(id, user_id, "Access history").await?;
         }
 
         Ok(())
     }
 }
+
+/// Lock a docket entry for a change.
+///
+/// `NO KEY UPDATE` still lets access log rows referencing the entry be
+/// written from outside the transaction.
+async fn lock_entry(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    id: Uuid,
+) -> Result<DocketEntry, AppError> {
+    sqlx::query_as::<_, DocketEntry>(
+        "SELECT * FROM docket_entries WHERE id = $1 AND deleted_at IS NULL FOR NO KEY UPDATE",
+    )
+    .bind(id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(AppError::NotFound("Docket entry not found".to_string()))
+}
+
+async fn entry_documents<'e, E>(
+    executor: E,
+    entry_id: Uuid,
+) -> Result<Vec<DocketEntryDocument>, AppError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let documents = sqlx::query_as::<_, DocketEntryDocument>(
+        r#"
+        SELECT a.id, a.docket_entry_id, a.document_id, d.title AS document_title,
+               a.attachment_number, a.description, a.created_at, a.created_by
+        FROM docket_entry_documents a
+        JOIN documents d ON d.id = a.document_id
+        WHERE a.docket_entry_id = $1
+        ORDER BY a.attachment_number
+        "#,
+    )
+    .bind(entry_id)
+    .fetch_all(executor)
+    .await?;
+
+    Ok(documents)
+}
+
+/// Whether a user holds an unrevoked grant to a sealed entry
+pub async fn has_grant<'e, E>(executor: E, entry_id: Uuid, user_id: Uuid) -> Result<bool, AppError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let granted = sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS (SELECT 1 FROM sealed_entry_grants WHERE docket_entry_id = $1 AND user_id = $2 AND revoked_at IS NULL)",
+    )
+    .bind(entry_id)
+    .bind(user_id)
+    .fetch_one(executor)
+    .await?;
+
+    Ok(granted)
+}
+
+/// Record an access to a sealed entry in its access log
+pub async fn log_access<'e, E>(
+    executor: E,
+    entry_id: Uuid,
+    user_id: Uuid,
+    action: SealedAccessAction,
+    court_order: Option<&str>,
+    details: Option<&str>,
+) -> Result<(), AppError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query(
+        r#"
+        INSERT INTO sealed_entry_access_log (id, docket_entry_id, user_id, action, court_order, details)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        "#,
+    )
+    .bind(Uuid::new_v4())
+    .bind(entry_id)
+    .bind(user_id)
+    .bind(action)
+    .bind(court_order)
+    .bind(details)
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
+/// Lock a case's docket so that entries are numbered one at a time
+pub async fn lock_docket(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    case_id: Uuid,
+) -> Result<(), AppError> {
+    sqlx::query_scalar::<_, Uuid>(
+        "SELECT id FROM cases WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+    )
+    .bind(case_id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(AppError::NotFound("Case not found".to_string()))?;
+
+    Ok(())
+}
+
+/// Next docket number of a case.
+///
+/// Deleted entries keep their numbers. The caller must hold the docket lock
+/// (see `lock_docket`).
+pub async fn next_sequence_number(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    case_id: Uuid,
+) -> Result<i32, AppError> {
+    let sequence_number = sqlx::query_scalar(
+        "SELECT COALESCE(MAX(sequence_number), 0) + 1 FROM docket_entries WHERE case_id = $1",
+    )
+    .bind(case_id)
+    .fetch_one(&mut **tx)
+    .await?;
+
+    Ok(sequence_number)
+}
+
+fn validate_pacer_sequence_number(number: Option<i32>) -> Result<(), AppError> {
+    match number {
+        Some(n) if n < 1 => Err(AppError::Validation(
+            "PACER sequence number must be positive".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,106 @@
+//! Docket sheet rendering; sealed entries are never exported
+
+use crate::api::docket::sealing::is_sealed;
+use crate::error::AppError;
+use crate::export::{render_table_report, write_csv, PdfColumn};
+use crate::models::{DocketEntry, DocketEntryType};
+
+/// Columns of an exported docket sheet, following the CM/ECF docket report
+pub const DOCKET_SHEET_HEADERS: [&str; 5] =
+    ["Date Filed", "#", "PACER #", "Docket Text", "Filed By"];
+
+const COLUMN_WEIGHTS: [f32; 5] = [0.9, 0.5, 0.7, 5.0, 1.5];
+
+/// Output format of a docket sheet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocketSheetFormat {
+    Csv,
+    Pdf,
+}
+
+impl DocketSheetFormat {
+    pub fn parse(value: &str) -> Result<Self, AppError> {
+        match value.to_lowercase().as_str() {
+            "csv" => Ok(DocketSheetFormat::Csv),
+            "pdf" => Ok(DocketSheetFormat::Pdf),
+            other => Err(AppError::Validation(format!(
+                "Unsupported docket sheet format: {}",
+                other
+            ))),
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            DocketSheetFormat::Csv => "csv",
+            DocketSheetFormat::Pdf => "pdf",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            DocketSheetFormat::Csv => "text/csv; charset=utf-8",
+            DocketSheetFormat::Pdf => "application/pdf",
+        }
+    }
+}
+
+/// Flatten unsealed entries into rows matching `DOCKET_SHEET_HEADERS`, in
+/// docket number order
+pub fn docket_sheet_rows(entries: &[DocketEntry]) -> Vec<Vec<String>> {
+    let mut unsealed: Vec<&DocketEntry> = entries.iter().filter(|e| !is_sealed(e)).collect();
+    unsealed.sort_by_key(|e| e.sequence_number);
+
+    unsealed
+        .into_iter()
+        .map(|e| {
+            let text = match &e.description {
+                Some(description) if !description.trim().is_empty() => {
+                    format!("{}: {}. {}", type_label(e.entry_type), e.title, description)
+                }
+                _ => format!("{}: {}", type_label(e.entry_type), e.title),
+            };
+            vec![
+                e.date.format("%m/%d/%Y").to_string(),
+                e.sequence_number.to_string(),
+                e.pacer_sequence_number
+                    .map(|n| n.to_string())
+                    .unwrap_or_default(),
+                text,
+                e.filed_by.clone().unwrap_or_default(),
+            ]
+        })
+        .collect()
+}
+
+/// Render a docket sheet; `heading` carries the court and caption
+pub fn render_docket_sheet(
+    format: DocketSheetFormat,
+    heading: &[String],
+    entries: &[DocketEntry],
+) -> Vec<u8> {
+    let rows = docket_sheet_rows(entries);
+
+    match format {
+        DocketSheetFormat::Csv => write_csv(&DOCKET_SHEET_HEADERS, &rows).into_bytes(),
+        DocketSheetFormat::Pdf => {
+            let columns: Vec<PdfColumn> = DOCKET_SHEET_HEADERS
+                .iter()
+                .zip(COLUMN_WEIGHTS)
+                .map(|(header, weight)| PdfColumn { header, weight })
+                .collect();
+            render_table_report("Docket Sheet", heading, &columns, &rows)
+        }
+    }
+}
+
+fn type_label(entry_type: DocketEntryType) -> &'static str {
+    match entry_type {
+        DocketEntryType::Filing => "Filing",
+        DocketEntryType::Order => "Order",
+        DocketEntryType::Notice => "Notice",
+        DocketEntryType::MinuteEntry => "Minute Entry",
+        DocketEntryType::Exhibit => "Exhibit",
+        DocketEntryType::Hearing => "Hearing",
+    }
+}
@@ -131,6 +131,7 @@ pub async fn update_document(
     responses(
         (status = 204, description = "Document deleted"),
         (status = 404, description = "Document not found"),
+        (status = 409, description = "Document is under an active legal hold"),
         (status = 401, description = "Unauthorized")
     ),
     tag = "documents",
@@ -1,3 +1,4 @@
+use crate::api::holds::service::{active_holds_for_document, held_error};
 use crate::error::AppError;
 use crate::models::{CreateDocumentRequest, Document};
 use chrono::Utc;
@@ -84,7 +85,10 @@ impl DocumentService {
         Ok(doc)
     }
 
-    /// Update document
+    /// Update document.
+    ///
+    /// Changing the content detaches any burned-in redacted version and sends
+    /// approved redactions back for review, since their offsets may have moved.
     pub async fn update_document(
         &self,
         id: Uuid,
@@ -97,15 +101,19 @@ impl DocumentService {
         // Get existing document
         let existing = self.get_document(id).await?;
 
+        let content_changed = content.is_some() && content != existing.content;
         let updated_title = title.unwrap_or(existing.title);
         let updated_content = content.or(existing.content);
         let updated_tags = tags.unwrap_or(existing.tags);
 
+        let mut tx = self.pool.begin().await?;
+
         let doc = sqlx::query_as::<_, Document>(
             r#"
             UPDATE documents
-            SET title = $1, content = $2, tags = $3, last_modified = $4, updated_at = $5
-            WHERE id = $6 AND deleted_at IS NULL
+            SET title = $1, content = $2, tags = $3, last_modified = $4, updated_at = $5,
+                redacted_version_id = CASE WHEN $6 THEN NULL ELSE redacted_version_id END
+            WHERE id = $7 AND deleted_at IS NULL
             RETURNING *
             "#,
         )
@@ -114,16 +122,38 @@ impl DocumentService {
         .bind(&updated_tags)
         .bind(now)
         .bind(now)
+        .bind(content_changed)
         .bind(id)
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *tx)
         .await?
         .ok_or(AppError::NotFound("Document not found".to_string()))?;
 
+        if content_changed {
+            sqlx::query(
+                r#"
+                UPDATE redactions
+                SET status = 'Proposed', reviewed_by = NULL, reviewed_at = NULL, updated_at = $1
+                WHERE document_id = $2 AND status = 'Approved' AND deleted_at IS NULL
+                "#,
+            )
+            .bind(now)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
         Ok(doc)
     }
 
-    /// Soft delete document
+    /// Soft delete document, unless it is under an active legal hold
     pub async fn delete_document(&self, id: Uuid) -> Result<(), AppError> {
+        let holds = active_holds_for_document(&self.pool, id).await?;
+        if !holds.is_empty() {
+            return Err(held_error("Document", &holds));
+        }
+
         let now = Utc::now();
 
         let result = sqlx::query(
@@ -0,0 +1,35 @@
+//! Resolution of admissibility challenges from motion in limine rulings
+
+use crate::models::{AdmissibilityStatus, ChallengeStatus, MotionOutcome};
+
+/// How a ruling on a motion in limine resolves the challenges it raised.
+///
+/// Granting the motion sustains the objection and denying it overrules the
+/// objection; a withdrawn or moot motion leaves nothing to rule on.
+pub fn challenge_status_for_outcome(outcome: MotionOutcome) -> ChallengeStatus {
+    match outcome {
+        MotionOutcome::Granted => ChallengeStatus::Sustained,
+        MotionOutcome::Denied => ChallengeStatus::Overruled,
+        MotionOutcome::Withdrawn | MotionOutcome::Moot => ChallengeStatus::Withdrawn,
+    }
+}
+
+/// Admissibility of an evidence item given the status of its challenges.
+///
+/// Any open challenge keeps the item challenged and any sustained one
+/// excludes it. An item whose challenges were all overruled is admissible;
+/// one whose challenges were all withdrawn returns to pending. Returns
+/// `None` when the item has never been challenged.
+pub fn admissibility_after_challenges(statuses: &[ChallengeStatus]) -> Option<AdmissibilityStatus> {
+    if statuses.is_empty() {
+        None
+    } else if statuses.contains(&ChallengeStatus::Pending) {
+        Some(AdmissibilityStatus::Challenged)
+    } else if statuses.contains(&ChallengeStatus::Sustained) {
+        Some(AdmissibilityStatus::Inadmissible)
+    } else if statuses.contains(&ChallengeStatus::Overruled) {
+        Some(AdmissibilityStatus::Admissible)
+    } else {
+        Some(AdmissibilityStatus::Pending)
+    }
+}
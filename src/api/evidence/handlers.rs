@@ -1,10 +1,18 @@
-use crate::api::evidence::service::EvidenceService;
+use crate::api::evidence::labels::LabelFormat;
+use crate::api::evidence::service::{
+    AttachEvidenceFileParams, CreateChallengeParams, EvidenceService, RecordCustodyParams,
+    ScanLabelParams, UpdateChallengeParams,
+};
 use crate::error::AppError;
-use crate::models::EvidenceItem;
+use crate::models::{
+    AdmissibilityChallenge, ChainOfCustodyEvent, Claims, CustodyAction, CustodyChain, EvidenceFile,
+    EvidenceFileVerification, EvidenceItem, EvidenceScanResponse, ExhibitParty,
+};
 use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
-    response::Json,
+    extract::{Multipart, Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Json},
+    Extension,
 };
 use serde::Deserialize;
 use std::sync::Arc;
@@ -38,6 +46,52 @@ pub struct UpdateEvidenceRequest {
     pub tags: Option<Vec<String>>,
 }
 
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RenderLabelsRequest {
+    pub evidence_ids: Vec<Uuid>,
+    /// `pdf` (default) or `svg`
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ScanLabelRequest {
+    pub tracking_uuid: Uuid,
+    pub location: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Multipart upload of an acquired file or forensic image
+#[derive(Debug, ToSchema)]
+pub struct UploadEvidenceFileRequest {
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RecordCustodyEventRequest {
+    pub action: CustodyAction,
+    pub to_custodian: Option<String>,
+    pub location: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateChallengeRequest {
+    pub objecting_party: ExhibitParty,
+    /// Federal Rule of Evidence the objection rests on
+    pub legal_rule_id: Uuid,
+    /// Motion in limine raising the objection
+    pub motion_id: Option<Uuid>,
+    pub grounds: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateChallengeRequest {
+    pub legal_rule_id: Option<Uuid>,
+    pub motion_id: Option<Uuid>,
+    pub grounds: Option<String>,
+}
+
 /// List evidence items for a case
 #[utoipa::path(
     get,
@@ -98,8 +152,12 @@ pub async fn get_evidence(
 )]
 pub async fn create_evidence(
     State(service): State<Arc<EvidenceService>>,
+    Extension(claims): Extension<Claims>,
     Json(req): Json<CreateEvidenceRequest>,
 ) -> Result<(StatusCode, Json<EvidenceItem>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
     let tags = req.tags.unwrap_or_default();
     let item = service
         .create_evidence(crate::api::evidence::service::CreateEvidenceParams {
@@ -111,6 +169,8 @@ pub async fn create_evidence(
             custodian: req.custodian,
             location: req.location,
             tags,
+            actor: claims.email,
+            user_id,
         })
         .await?;
     Ok((StatusCode::CREATED, Json(item)))
@@ -127,6 +187,7 @@ pub async fn create_evidence(
     responses(
         (status = 200, description = "Evidence item updated", body = EvidenceItem),
         (status = 404, description = "Evidence item not found"),
+        (status = 409, description = "Custodian changed while the item is checked out"),
         (status = 401, description = "Unauthorized")
     ),
     tag = "evidence",
@@ -134,9 +195,13 @@ pub async fn create_evidence(
 )]
 pub async fn update_evidence(
     State(service): State<Arc<EvidenceService>>,
+    Extension(claims): Extension<Claims>,
     Path(id): Path<Uuid>,
     Json(req): Json<UpdateEvidenceRequest>,
 ) -> Result<Json<EvidenceItem>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
     let item = service
         .update_evidence(
             id,
@@ -147,6 +212,8 @@ pub async fn update_evidence(
                 location: req.location,
                 admissibility: req.admissibility,
                 tags: req.tags,
+                actor: claims.email,
+                user_id,
             },
         )
         .await?;
@@ -175,3 +242,409 @@ pub async fn delete_evidence(
     service.delete_evidence(id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+/// Record a transfer, check-out, analysis or return of an evidence item
+#[utoipa::path(
+    post,
+    path = "/api/evidence/{id}/custody",
+    params(
+        ("id" = Uuid, Path, description = "Evidence item ID")
+    ),
+    request_body = RecordCustodyEventRequest,
+    responses(
+        (status = 201, description = "Custody event recorded", body = ChainOfCustodyEvent),
+        (status = 400, description = "Invalid custody event"),
+        (status = 404, description = "Evidence item not found"),
+        (status = 409, description = "Item is already checked out or is not checked out"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "evidence",
+    security(("bearer_auth" = []))
+)]
+pub async fn record_custody_event(
+    State(service): State<Arc<EvidenceService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<RecordCustodyEventRequest>,
+) -> Result<(StatusCode, Json<ChainOfCustodyEvent>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let event = service
+        .record_custody_event(RecordCustodyParams {
+            evidence_id: id,
+            action: req.action,
+            to_custodian: req.to_custodian,
+            location: req.location,
+            notes: req.notes,
+            actor: claims.email,
+            user_id,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(event)))
+}
+
+/// Get the verified chain of custody of an evidence item
+#[utoipa::path(
+    get,
+    path = "/api/evidence/{id}/custody",
+    params(
+        ("id" = Uuid, Path, description = "Evidence item ID")
+    ),
+    responses(
+        (status = 200, description = "Chain of custody", body = CustodyChain),
+        (status = 404, description = "Evidence item not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "evidence",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_custody_chain(
+    State(service): State<Arc<EvidenceService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<CustodyChain>, AppError> {
+    let chain = service.custody_chain(id).await?;
+    Ok(Json(chain))
+}
+
+/// Download the chain of custody report as a PDF
+#[utoipa::path(
+    get,
+    path = "/api/evidence/{id}/custody/report",
+    params(
+        ("id" = Uuid, Path, description = "Evidence item ID")
+    ),
+    responses(
+        (status = 200, description = "Chain of custody report", content_type = "application/pdf"),
+        (status = 404, description = "Evidence item not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "evidence",
+    security(("bearer_auth" = []))
+)]
+pub async fn export_custody_report(
+    State(service): State<Arc<EvidenceService>>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let report = service.custody_report(id).await?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/pdf".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", report.file_name),
+            ),
+        ],
+        report.content,
+    ))
+}
+
+/// List files and images attached to an evidence item
+#[utoipa::path(
+    get,
+    path = "/api/evidence/{id}/files",
+    params(
+        ("id" = Uuid, Path, description = "Evidence item ID")
+    ),
+    responses(
+        (status = 200, description = "Attached files with their intake digests", body = Vec<EvidenceFile>),
+        (status = 404, description = "Evidence item not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "evidence",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_evidence_files(
+    State(service): State<Arc<EvidenceService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<EvidenceFile>>, AppError> {
+    let files = service.list_files(id).await?;
+    Ok(Json(files))
+}
+
+/// Attach an acquired file or image to a digital or forensic evidence item
+#[utoipa::path(
+    post,
+    path = "/api/evidence/{id}/files",
+    params(
+        ("id" = Uuid, Path, description = "Evidence item ID")
+    ),
+    request_body(content = UploadEvidenceFileRequest, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "File attached and hashed", body = EvidenceFile),
+        (status = 400, description = "Missing file or evidence is not digital or forensic"),
+        (status = 404, description = "Evidence item not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "evidence",
+    security(("bearer_auth" = []))
+)]
+pub async fn upload_evidence_file(
+    State(service): State<Arc<EvidenceService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, Json<EvidenceFile>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| AppError::BadRequest(format!("Invalid multipart body: {}", e)))?
+    {
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let file_name = field.file_name().unwrap_or_default().to_string();
+        let content_type = field.content_type().map(|c| c.to_string());
+        let content = field
+            .bytes()
+            .await
+            .map_err(|e| AppError::BadRequest(format!("Failed to read upload: {}", e)))?;
+
+        let file = service
+            .attach_file(AttachEvidenceFileParams {
+                evidence_id: id,
+                file_name,
+                content_type,
+                content: content.to_vec(),
+                actor: claims.email,
+                user_id,
+            })
+            .await?;
+        return Ok((StatusCode::CREATED, Json(file)));
+    }
+
+    Err(AppError::BadRequest(
+        "Multipart body must contain a \"file\" field".to_string(),
+    ))
+}
+
+/// Re-verify the files of an evidence item against their intake hashes
+#[utoipa::path(
+    post,
+    path = "/api/evidence/{id}/files/verify",
+    params(
+        ("id" = Uuid, Path, description = "Evidence item ID")
+    ),
+    responses(
+        (status = 200, description = "Verification results", body = Vec<EvidenceFileVerification>),
+        (status = 400, description = "Evidence item has no files"),
+        (status = 404, description = "Evidence item not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "evidence",
+    security(("bearer_auth" = []))
+)]
+pub async fn verify_evidence_files(
+    State(service): State<Arc<EvidenceService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<EvidenceFileVerification>>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let results = service.verify_files(id, &claims.email, user_id).await?;
+    Ok(Json(results))
+}
+
+/// Render a sheet of QR-coded labels for one or more evidence items
+#[utoipa::path(
+    post,
+    path = "/api/evidence/labels",
+    request_body = RenderLabelsRequest,
+    responses(
+        (status = 200, description = "Label sheet", content_type = "application/pdf"),
+        (status = 200, description = "Label sheet", content_type = "image/svg+xml"),
+        (status = 400, description = "No items selected or unsupported format"),
+        (status = 404, description = "Evidence item not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "evidence",
+    security(("bearer_auth" = []))
+)]
+pub async fn render_evidence_labels(
+    State(service): State<Arc<EvidenceService>>,
+    Json(req): Json<RenderLabelsRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let format = LabelFormat::parse(req.format.as_deref().unwrap_or("pdf"))?;
+    let sheet = service.label_sheet(&req.evidence_ids, format).await?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, sheet.content_type.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", sheet.file_name),
+            ),
+        ],
+        sheet.content,
+    ))
+}
+
+/// Resolve a scanned label to its evidence item and log the scan
+#[utoipa::path(
+    post,
+    path = "/api/evidence/scan",
+    request_body = ScanLabelRequest,
+    responses(
+        (status = 200, description = "Scanned evidence item", body = EvidenceScanResponse),
+        (status = 404, description = "No evidence item has this tracking ID"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "evidence",
+    security(("bearer_auth" = []))
+)]
+pub async fn scan_evidence_label(
+    State(service): State<Arc<EvidenceService>>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<ScanLabelRequest>,
+) -> Result<Json<EvidenceScanResponse>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let scan = service
+        .resolve_scan(ScanLabelParams {
+            tracking_uuid: req.tracking_uuid,
+            location: req.location,
+            notes: req.notes,
+            actor: claims.email,
+            user_id,
+        })
+        .await?;
+    Ok(Json(scan))
+}
+
+/// List admissibility challenges raised against an evidence item
+#[utoipa::path(
+    get,
+    path = "/api/evidence/{id}/challenges",
+    params(
+        ("id" = Uuid, Path, description = "Evidence item ID")
+    ),
+    responses(
+        (status = 200, description = "Admissibility challenges", body = Vec<AdmissibilityChallenge>),
+        (status = 404, description = "Evidence item not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "evidence",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_challenges(
+    State(service): State<Arc<EvidenceService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<AdmissibilityChallenge>>, AppError> {
+    let challenges = service.list_challenges(id).await?;
+    Ok(Json(challenges))
+}
+
+/// Challenge the admissibility of an evidence item
+#[utoipa::path(
+    post,
+    path = "/api/evidence/{id}/challenges",
+    params(
+        ("id" = Uuid, Path, description = "Evidence item ID")
+    ),
+    request_body = CreateChallengeRequest,
+    responses(
+        (status = 201, description = "Challenge recorded", body = AdmissibilityChallenge),
+        (status = 400, description = "Rule is not a rule of evidence, or motion is not a motion in limine for this case"),
+        (status = 404, description = "Evidence item, rule or motion not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "evidence",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_challenge(
+    State(service): State<Arc<EvidenceService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<CreateChallengeRequest>,
+) -> Result<(StatusCode, Json<AdmissibilityChallenge>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let challenge = service
+        .create_challenge(CreateChallengeParams {
+            evidence_id: id,
+            objecting_party: req.objecting_party,
+            legal_rule_id: req.legal_rule_id,
+            motion_id: req.motion_id,
+            grounds: req.grounds,
+            user_id,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(challenge)))
+}
+
+/// Amend a pending admissibility challenge, e.g. to link its motion in limine
+#[utoipa::path(
+    put,
+    path = "/api/evidence/{id}/challenges/{challenge_id}",
+    params(
+        ("id" = Uuid, Path, description = "Evidence item ID"),
+        ("challenge_id" = Uuid, Path, description = "Challenge ID")
+    ),
+    request_body = UpdateChallengeRequest,
+    responses(
+        (status = 200, description = "Challenge updated", body = AdmissibilityChallenge),
+        (status = 400, description = "Rule is not a rule of evidence, or motion is not a motion in limine for this case"),
+        (status = 404, description = "Challenge, rule or motion not found"),
+        (status = 409, description = "Challenge has already been resolved"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "evidence",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_challenge(
+    State(service): State<Arc<EvidenceService>>,
+    Extension(claims): Extension<Claims>,
+    Path((id, challenge_id)): Path<(Uuid, Uuid)>,
+    Json(req): Json<UpdateChallengeRequest>,
+) -> Result<Json<AdmissibilityChallenge>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let challenge = service
+        .update_challenge(
+            id,
+            challenge_id,
+            UpdateChallengeParams {
+                legal_rule_id: req.legal_rule_id,
+                motion_id: req.motion_id,
+                grounds: req.grounds,
+                user_id,
+            },
+        )
+        .await?;
+    Ok(Json(challenge))
+}
+
+/// Delete an admissibility challenge recorded in error
+#[utoipa::path(
+    delete,
+    path = "/api/evidence/{id}/challenges/{challenge_id}",
+    params(
+        ("id" = Uuid, Path, description = "Evidence item ID"),
+        ("challenge_id" = Uuid, Path, description = "Challenge ID")
+    ),
+    responses(
+        (status = 204, description = "Challenge deleted"),
+        (status = 404, description = "Challenge not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "evidence",
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_challenge(
+    State(service): State<Arc<EvidenceService>>,
+    Extension(claims): Extension<Claims>,
+    Path((id, challenge_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    service.delete_challenge(id, challenge_id, user_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,41 @@
+//! Intake digests and re-verification of acquired evidence files
+
+use crate::models::EvidenceFile;
+use md5::Md5;
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+
+/// Largest file accepted for a single evidence upload
+pub const MAX_EVIDENCE_FILE_BYTES: usize = 256 * 1024 * 1024;
+
+/// How often the scheduled sweep looks for files due for re-verification
+pub const VERIFICATION_SWEEP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Files are re-verified once their last check is older than this
+pub const REVERIFY_AFTER_DAYS: i32 = 7;
+
+/// Most files re-hashed in a single sweep
+pub const VERIFICATION_BATCH_SIZE: i64 = 50;
+
+/// Digests of a file's content
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileDigests {
+    pub sha256: String,
+    pub md5: String,
+    pub size_bytes: i64,
+}
+
+impl FileDigests {
+    pub fn compute(content: &[u8]) -> Self {
+        Self {
+            sha256: hex::encode(Sha256::digest(content)),
+            md5: hex::encode(Md5::digest(content)),
+            size_bytes: content.len() as i64,
+        }
+    }
+
+    /// Whether these digests match the ones recorded at intake
+    pub fn matches(&self, file: &EvidenceFile) -> bool {
+        self.sha256 == file.sha256 && self.md5 == file.md5 && self.size_bytes == file.size_bytes
+    }
+}
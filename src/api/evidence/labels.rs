@@ -0,0 +1,356 @@
+//! Printable evidence label sheets with QR codes
+
+use crate::error::AppError;
+use crate::export::{
+    escape_xml, wrap_text, PdfDocument, PdfFont, PdfPage, PAGE_HEIGHT, PAGE_WIDTH,
+};
+use crate::models::EvidenceType;
+use chrono::{DateTime, Utc};
+use qrcode::{Color, EcLevel, QrCode};
+use sqlx::FromRow;
+use std::fmt::Write;
+use uuid::Uuid;
+
+// Sheet layout in points: 2 x 5 labels of 4" x 2" on US Letter
+pub const LABEL_WIDTH: f32 = 288.0;
+pub const LABEL_HEIGHT: f32 = 144.0;
+pub const LABEL_COLUMNS: usize = 2;
+pub const LABEL_ROWS: usize = 5;
+pub const LABELS_PER_SHEET: usize = LABEL_COLUMNS * LABEL_ROWS;
+const SHEET_LEFT: f32 = 11.25;
+const SHEET_TOP: f32 = 36.0;
+const COLUMN_GAP: f32 = 13.5;
+const PADDING: f32 = 8.0;
+/// Blank modules around the code; ISO/IEC 18004 requires four for QR codes
+const QUIET_ZONE: usize = 4;
+
+/// What a label shows about an evidence item
+#[derive(Debug, Clone, FromRow)]
+pub struct LabelData {
+    pub tracking_uuid: Uuid,
+    pub case_caption: String,
+    pub title: String,
+    pub collection_date: DateTime<Utc>,
+    pub evidence_type: EvidenceType,
+}
+
+/// Output format of a label sheet
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LabelFormat {
+    Pdf,
+    Svg,
+}
+
+impl LabelFormat {
+    pub fn parse(value: &str) -> Result<Self, AppError> {
+        match value.to_lowercase().as_str() {
+            "pdf" => Ok(LabelFormat::Pdf),
+            "svg" => Ok(LabelFormat::Svg),
+            other => Err(AppError::Validation(format!(
+                "Unsupported label format: {}",
+                other
+            ))),
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            LabelFormat::Pdf => "pdf",
+            LabelFormat::Svg => "svg",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            LabelFormat::Pdf => "application/pdf",
+            LabelFormat::Svg => "image/svg+xml",
+        }
+    }
+}
+
+/// Drawing operation in sheet coordinates (origin top-left, y downwards)
+#[derive(Debug, Clone, PartialEq)]
+enum LabelOp {
+    /// Black filled rectangle
+    Fill {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+    },
+    /// Cut guide around a label
+    Outline {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+    },
+    /// Text with its baseline at y
+    Text {
+        font: PdfFont,
+        size: f32,
+        x: f32,
+        y: f32,
+        text: String,
+    },
+}
+
+/// Modules of the QR code for a tracking UUID, row by row (true = dark)
+pub fn qr_modules(tracking_uuid: Uuid) -> Result<(usize, Vec<bool>), AppError> {
+    let code = QrCode::with_error_correction_level(tracking_uuid.to_string(), EcLevel::M)
+        .map_err(|e| AppError::InternalServerError(format!("Failed to encode QR code: {}", e)))?;
+    let modules = code
+        .to_colors()
+        .into_iter()
+        .map(|c| c == Color::Dark)
+        .collect();
+    Ok((code.width(), modules))
+}
+
+/// Render labels in the requested format
+pub fn render_labels(format: LabelFormat, labels: &[LabelData]) -> Result<Vec<u8>, AppError> {
+    match format {
+        LabelFormat::Pdf => render_labels_pdf(labels),
+        LabelFormat::Svg => render_labels_svg(labels).map(String::into_bytes),
+    }
+}
+
+/// Render labels as Letter sheets, ten labels per page
+pub fn render_labels_pdf(labels: &[LabelData]) -> Result<Vec<u8>, AppError> {
+    let mut pdf = PdfDocument::new().with_title("Evidence Labels");
+
+    for sheet in labels.chunks(LABELS_PER_SHEET) {
+        let mut page = PdfPage::new();
+        for (index, label) in sheet.iter().enumerate() {
+            let (x, y) = label_origin(index % LABEL_COLUMNS, index / LABEL_COLUMNS);
+            for op in label_ops(label, x, y)? {
+                match op {
+                    LabelOp::Fill {
+                        x,
+                        y,
+                        width,
+                        height,
+                    } => page.fill_rect(x, PAGE_HEIGHT - y - height, width, height, 0.0),
+                    LabelOp::Outline {
+                        x,
+                        y,
+                        width,
+                        height,
+                    } => page.stroke_rect(x, PAGE_HEIGHT - y - height, width, height),
+                    LabelOp::Text {
+                        font,
+                        size,
+                        x,
+                        y,
+                        text,
+                    } => page.text(font, size, x, PAGE_HEIGHT - y, &text),
+                }
+            }
+        }
+        pdf.add_page(page);
+    }
+
+    Ok(pdf.render())
+}
+
+/// Render labels as a single SVG sheet, two labels per row
+pub fn render_labels_svg(labels: &[LabelData]) -> Result<String, AppError> {
+    let rows = labels.len().div_ceil(LABEL_COLUMNS).max(1);
+    let height = SHEET_TOP * 2.0 + rows as f32 * LABEL_HEIGHT;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}pt" height="{h}pt" viewBox="0 0 {w} {h}">"#,
+        w = PAGE_WIDTH,
+        h = height
+    );
+    svg.push_str("<rect width=\"100%\" height=\"100%\" fill=\"#fff\"/>\n");
+
+    for (index, label) in labels.iter().enumerate() {
+        let (x, y) = label_origin(index % LABEL_COLUMNS, index / LABEL_COLUMNS);
+        for op in label_ops(label, x, y)? {
+            match op {
+                LabelOp::Fill {
+                    x,
+                    y,
+                    width,
+                    height,
+                } => {
+                    let _ = writeln!(
+                        svg,
+                        r##"<rect x="{}" y="{}" width="{}" height="{}" fill="#000"/>"##,
+                        x, y, width, height
+                    );
+                }
+                LabelOp::Outline {
+                    x,
+                    y,
+                    width,
+                    height,
+                } => {
+                    let _ = writeln!(
+                        svg,
+                        r##"<rect x="{}" y="{}" width="{}" height="{}" fill="none" stroke="#999" stroke-width="0.5"/>"##,
+                        x, y, width, height
+                    );
+                }
+                LabelOp::Text {
+                    font,
+                    size,
+                    x,
+                    y,
+                    text,
+                } => {
+                    let (family, weight) = match font {
+                        PdfFont::Helvetica => ("Helvetica, Arial, sans-serif", "normal"),
+                        PdfFont::HelveticaBold => ("Helvetica, Arial, sans-serif", "bold"),
+                        PdfFont::Courier => ("Courier New, monospace", "normal"),
+                    };
+                    let _ = writeln!(
+                        svg,
+                        r#"<text x="{}" y="{}" font-family="{}" font-weight="{}" font-size="{}">{}</text>"#,
+                        x,
+                        y,
+                        family,
+                        weight,
+                        size,
+                        escape_xml(&text)
+                    );
+                }
+            }
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    Ok(svg)
+}
+
+/// Top-left corner of the label in the given sheet column and row
+fn label_origin(column: usize, row: usize) -> (f32, f32) {
+    (
+        SHEET_LEFT + column as f32 * (LABEL_WIDTH + COLUMN_GAP),
+        SHEET_TOP + row as f32 * LABEL_HEIGHT,
+    )
+}
+
+/// Lay out one label: QR code on the left, item details on the right
+fn label_ops(label: &LabelData, x: f32, y: f32) -> Result<Vec<LabelOp>, AppError> {
+    let mut ops = vec![LabelOp::Outline {
+        x,
+        y,
+        width: LABEL_WIDTH,
+        height: LABEL_HEIGHT,
+    }];
+
+    // QR code, with horizontal runs of dark modules merged into one rectangle
+    let (width, modules) = qr_modules(label.tracking_uuid)?;
+    let code_size = LABEL_HEIGHT - PADDING * 2.0;
+    let module = code_size / (width + QUIET_ZONE * 2) as f32;
+    let code_x = x + PADDING + module * QUIET_ZONE as f32;
+    let code_y = y + PADDING + module * QUIET_ZONE as f32;
+    for (row, cells) in modules.chunks(width).enumerate() {
+        let mut column = 0;
+        while column < width {
+            if !cells[column] {
+                column += 1;
+                continue;
+            }
+            let start = column;
+            while column < width && cells[column] {
+                column += 1;
+            }
+            ops.push(LabelOp::Fill {
+                x: code_x + start as f32 * module,
+                y: code_y + row as f32 * module,
+                width: (column - start) as f32 * module,
+                height: module,
+            });
+        }
+    }
+
+    // Item details
+    let text_x = x + PADDING * 2.0 + code_size;
+    let text_width = x + LABEL_WIDTH - PADDING - text_x;
+    let mut text = TextBlock {
+        x: text_x,
+        width: text_width,
+        baseline: y + PADDING + 9.0,
+    };
+    text.push(
+        &mut ops,
+        PdfFont::HelveticaBold,
+        9.0,
+        &label.case_caption,
+        2,
+    );
+    text.baseline += 3.0;
+    text.push(&mut ops, PdfFont::Helvetica, 8.0, &label.title, 3);
+    text.baseline += 3.0;
+    text.push(
+        &mut ops,
+        PdfFont::Helvetica,
+        8.0,
+        &format!("Collected: {}", label.collection_date.format("%Y-%m-%d")),
+        1,
+    );
+    text.push(
+        &mut ops,
+        PdfFont::Helvetica,
+        8.0,
+        &format!("Type: {:?}", label.evidence_type),
+        1,
+    );
+
+    ops.push(LabelOp::Text {
+        font: PdfFont::Courier,
+        size: 6.0,
+        x: text_x,
+        y: y + LABEL_HEIGHT - PADDING,
+        text: label.tracking_uuid.to_string(),
+    });
+
+    Ok(ops)
+}
+
+/// Column of wrapped text lines flowing down a label
+struct TextBlock {
+    x: f32,
+    width: f32,
+    baseline: f32,
+}
+
+impl TextBlock {
+    fn push(
+        &mut self,
+        ops: &mut Vec<LabelOp>,
+        font: PdfFont,
+        size: f32,
+        text: &str,
+        max_lines: usize,
+    ) {
+        let chars = (self.width / font.char_width(size)) as usize;
+        for line in clip_lines(wrap_text(text, chars), max_lines, chars) {
+            ops.push(LabelOp::Text {
+                font,
+                size,
+                x: self.x,
+                y: self.baseline,
+                text: line,
+            });
+            self.baseline += size + 2.0;
+        }
+    }
+}
+
+/// Keep at most `max_lines`, marking truncation on the last one kept
+fn clip_lines(mut lines: Vec<String>, max_lines: usize, width: usize) -> Vec<String> {
+    if lines.len() > max_lines {
+        lines.truncate(max_lines);
+        if let Some(last) = lines.last_mut() {
+            let keep = width.saturating_sub(3).min(last.chars().count());
+            *last = format!("{}...", last.chars().take(keep).collect::<String>());
+        }
+    }
+    lines
+}
@@ -1,4 +1,8 @@
+pub mod challenges;
+pub mod custody;
 pub mod handlers;
+pub mod integrity;
+pub mod labels;
 pub mod service;
 
 pub use handlers::*;
@@ -1,5 +1,16 @@
+use crate::api::evidence::challenges::{
+    admissibility_after_challenges, challenge_status_for_outcome,
+};
+use crate::api::evidence::custody::{custody_timestamp, event_hash, verify_chain};
+use crate::api::evidence::integrity::{FileDigests, REVERIFY_AFTER_DAYS, VERIFICATION_BATCH_SIZE};
+use crate::api::evidence::labels::{render_labels, LabelData, LabelFormat};
 use crate::error::AppError;
-use crate::models::EvidenceItem;
+use crate::export::{render_table_report, PdfColumn};
+use crate::models::{
+    AdmissibilityChallenge, ChainOfCustodyEvent, ChallengeStatus, CustodyAction, CustodyChain,
+    EvidenceFile, EvidenceFileVerification, EvidenceItem, EvidenceScanResponse, EvidenceType,
+    ExhibitParty, IntegrityStatus, LegalRuleType, Motion, MotionOutcome, MotionType,
+};
 use chrono::Utc;
 use sqlx::PgPool;
 use uuid::Uuid;
@@ -22,6 +33,10 @@ pub struct CreateEvidenceParams {
     pub location: String,
     /// Tags for categorizing the evidence
     pub tags: Vec<String>,
+    /// Email of the user recording the collection
+    pub actor: String,
+    /// ID of the user recording the collection
+    pub user_id: Uuid,
 }
 
 /// Parameters for updating an existing evidence item
@@ -38,6 +53,122 @@ pub struct UpdateEvidenceParams {
     pub admissibility: Option<String>,
     /// Optional new tags
     pub tags: Option<Vec<String>>,
+    /// Email of the user making the change, recorded on custodian transfers
+    pub actor: String,
+    /// ID of the user making the change
+    pub user_id: Uuid,
+}
+
+/// Parameters for recording a custody event against an evidence item
+pub struct RecordCustodyParams {
+    /// Evidence item the event applies to
+    pub evidence_id: Uuid,
+    /// Transfer, check-out, analysis or return
+    pub action: CustodyAction,
+    /// Receiving custodian (transfers, check-outs and returns)
+    pub to_custodian: Option<String>,
+    /// New location of the item (optional)
+    pub location: Option<String>,
+    /// Free-form notes (optional)
+    pub notes: Option<String>,
+    /// Email of the user recording the event
+    pub actor: String,
+    /// ID of the user recording the event
+    pub user_id: Uuid,
+}
+
+/// Parameters for attaching an acquired file or image to an evidence item
+pub struct AttachEvidenceFileParams {
+    /// Digital or forensic evidence item the file belongs to
+    pub evidence_id: Uuid,
+    /// Original file name
+    pub file_name: String,
+    /// MIME type reported by the uploader (optional)
+    pub content_type: Option<String>,
+    /// File content exactly as acquired
+    pub content: Vec<u8>,
+    /// Email of the user attaching the file
+    pub actor: String,
+    /// ID of the user attaching the file
+    pub user_id: Uuid,
+}
+
+/// Parameters for resolving a scanned evidence label
+pub struct ScanLabelParams {
+    /// Tracking UUID read from the label's QR code
+    pub tracking_uuid: Uuid,
+    /// Where the label was scanned (optional; the item's location is not changed)
+    pub location: Option<String>,
+    /// Free-form notes (optional)
+    pub notes: Option<String>,
+    /// Email of the user who scanned the label
+    pub actor: String,
+    /// ID of the user who scanned the label
+    pub user_id: Uuid,
+}
+
+/// Parameters for challenging the admissibility of an evidence item
+pub struct CreateChallengeParams {
+    /// Evidence item being challenged
+    pub evidence_id: Uuid,
+    /// Party raising the objection (plaintiff or defendant)
+    pub objecting_party: ExhibitParty,
+    /// Federal Rule of Evidence the objection rests on
+    pub legal_rule_id: Uuid,
+    /// Motion in limine raising the objection (optional; may be linked later)
+    pub motion_id: Option<Uuid>,
+    /// Argument for exclusion (optional)
+    pub grounds: Option<String>,
+    /// ID of the user recording the challenge
+    pub user_id: Uuid,
+}
+
+/// Parameters for amending a pending admissibility challenge
+pub struct UpdateChallengeParams {
+    /// Optional new rule basis
+    pub legal_rule_id: Option<Uuid>,
+    /// Optional motion in limine to link
+    pub motion_id: Option<Uuid>,
+    /// Optional new grounds
+    pub grounds: Option<String>,
+    /// ID of the user making the change
+    pub user_id: Uuid,
+}
+
+/// Rendered evidence label sheet
+pub struct LabelSheet {
+    /// Suggested file name of the sheet
+    pub file_name: String,
+    /// MIME type of the rendered sheet
+    pub content_type: &'static str,
+    /// Rendered sheet bytes
+    pub content: Vec<u8>,
+}
+
+/// Rendered court-ready custody report
+pub struct CustodyReport {
+    /// Suggested file name of the report
+    pub file_name: String,
+    /// Rendered PDF bytes
+    pub content: Vec<u8>,
+}
+
+/// Evidence file columns, without the stored content
+const FILE_COLUMNS: &str = "id, evidence_id, tracking_uuid, file_name, content_type, size_bytes, \
+    sha256, md5, integrity_status, last_verified_at, uploaded_by, created_at, updated_at";
+
+/// Actor recorded on custody events written by the scheduled verification sweep
+const SCHEDULED_VERIFICATION_ACTOR: &str = "Scheduled integrity verification";
+
+/// Custody event before it is sequenced and hashed
+struct NewCustodyEvent {
+    action: CustodyAction,
+    actor: String,
+    actor_id: Option<Uuid>,
+    from_custodian: Option<String>,
+    to_custodian: Option<String>,
+    location: Option<String>,
+    notes: Option<String>,
 }
 
 pub struct EvidenceService {
@@ -52,7 +183,7 @@ impl EvidenceService {
     /// List evidence items for a case
     pub async fn list_evidence(&self, case_id: Uuid) -> Result<Vec<EvidenceItem>, AppError> {
         let items = sqlx::query_as::<_, EvidenceItem>(
-            "SELECT * FROM evidence_items WHERE case_id = $1 ORDER BY created_at DESC",
+            "SELECT * FROM evidence_items WHERE case_id = $1 AND deleted_at IS NULL ORDER BY created_at DESC",
         )
         .bind(case_id)
         .fetch_all(&self.pool)
@@ -63,29 +194,36 @@ impl EvidenceService {
 
     /// Get a specific evidence item
     pub async fn get_evidence(&self, id: Uuid) -> Result<EvidenceItem, AppError> {
-        let item = sqlx::query_as::<_, EvidenceItem>("SELECT * FROM evidence_items WHERE id = $1")
-            .bind(id)
-            .fetch_optional(&self.pool)
-            .await?
-            .ok_or(AppError::NotFound("Evidence item not found".to_string()))?;
+        let item = sqlx::query_as::<_, EvidenceItem>(
+            "SELECT * FROM evidence_items WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound("Evidence item not found".to_string()))?;
 
         Ok(item)
     }
 
-    /// Create a new evidence item
-    pub async fn create_evidence(&self, params: CreateEvidenceParams) -> Result<EvidenceItem, AppError> {
+    /// Create a new evidence item and open its chain of custody with a
+    /// collection event
+    pub async fn create_evidence(
+        &self,
+        params: CreateEvidenceParams,
+    ) -> Result<EvidenceItem, AppError> {
         let id = Uuid::new_v4();
         let tracking_uuid = Uuid::new_v4();
         let now = Utc::now();
+        let mut tx = self.pool.begin().await?;
 
         let item = sqlx::query_as::<_, EvidenceItem>(
             r#"
             INSERT INTO evidence_items (
                 id, case_id, title, type, description, collection_date,
                 collected_by, custodian, location, admissibility, tags,
-                tracking_uuid, created_at, updated_at
+                tracking_uuid, created_at, updated_at, created_by
             )
-            VALUES ($1, $2, $3, $4::evidence_type, $5, $6, $7, $8, $9, $10::admissibility_status, $11, $12, $13, $14)
+            VALUES ($1, $2, $3, $4::evidence_type, $5, $6, $7, $8, $9, $10::admissibility_status, $11, $12, $13, $14, $15)
             RETURNING *
             "#,
         )
@@ -103,17 +241,55 @@ impl EvidenceService {
         .bind(tracking_uuid)
         .bind(now)
         .bind(now)
-        .fetch_one(&self.pool)
+        .bind(params.user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        append_custody_event(
+            &mut tx,
+            item.id,
+            NewCustodyEvent {
+                action: CustodyAction::Collection,
+                actor: params.actor,
+                actor_id: Some(params.user_id),
+                from_custodian: None,
+                to_custodian: Some(item.custodian.clone()),
+                location: Some(item.location.clone()),
+                notes: Some(format!("Collected by {}", item.collected_by)),
+            },
+        )
         .await?;
 
+        tx.commit().await?;
+
         Ok(item)
     }
 
-    /// Update an evidence item
-    pub async fn update_evidence(&self, id: Uuid, params: UpdateEvidenceParams) -> Result<EvidenceItem, AppError> {
+    /// Update an evidence item.
+    ///
+    /// A change of custodian is recorded as a transfer in the chain of custody
+    /// and is refused while the item is checked out.
+    pub async fn update_evidence(
+        &self,
+        id: Uuid,
+        params: UpdateEvidenceParams,
+    ) -> Result<EvidenceItem, AppError> {
         let now = Utc::now();
-        let existing = self.get_evidence(id).await?;
+        let mut tx = self.pool.begin().await?;
+        let existing = lock_evidence(&mut tx, id).await?;
+
+        if params
+            .custodian
+            .as_ref()
+            .is_some_and(|c| *c != existing.custodian)
+            && is_checked_out(&mut tx, id).await?
+        {
+            return Err(AppError::Conflict(
+                "Evidence item is checked out; return it before changing the custodian".to_string(),
+            ));
+        }
 
+        let previous_custodian = existing.custodian.clone();
         let updated_title = params.title.unwrap_or(existing.title);
         let updated_description = params.description.unwrap_or(existing.description);
         let updated_custodian = params.custodian.unwrap_or(existing.custodian);
@@ -125,8 +301,9 @@ impl EvidenceService {
                 r#"
                 UPDATE evidence_items
                 SET title = $1, description = $2, custodian = $3, location = $4,
-                    admissibility = $5::admissibility_status, tags = $6, updated_at = $7
-                WHERE id = $8
+                    admissibility = $5::admissibility_status, tags = $6, updated_at = $7,
+                    updated_by = $8
+                WHERE id = $9
                 RETURNING *
                 "#,
             )
@@ -137,16 +314,17 @@ impl EvidenceService {
             .bind(&adm)
             .bind(&updated_tags)
             .bind(now)
+            .bind(params.user_id)
             .bind(id)
-            .fetch_optional(&self.pool)
+            .fetch_one(&mut *tx)
             .await?
         } else {
             sqlx::query_as::<_, EvidenceItem>(
                 r#"
                 UPDATE evidence_items
                 SET title = $1, description = $2, custodian = $3, location = $4,
-                    tags = $5, updated_at = $6
-                WHERE id = $7
+                    tags = $5, updated_at = $6, updated_by = $7
+                WHERE id = $8
                 RETURNING *
                 "#,
             )
@@ -156,20 +334,44 @@ impl EvidenceService {
             .bind(&updated_location)
             .bind(&updated_tags)
             .bind(now)
+            .bind(params.user_id)
             .bind(id)
-            .fetch_optional(&self.pool)
+            .fetch_one(&mut *tx)
             .await?
         };
 
-        item.ok_or(AppError::NotFound("Evidence item not found".to_string()))
+        if item.custodian != previous_custodian {
+            append_custody_event(
+                &mut tx,
+                id,
+                NewCustodyEvent {
+                    action: CustodyAction::Transfer,
+                    actor: params.actor,
+                    actor_id: Some(params.user_id),
+                    from_custodian: Some(previous_custodian),
+                    to_custodian: Some(item.custodian.clone()),
+                    location: Some(item.location.clone()),
+                    notes: None,
+                },
+            )
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(item)
     }
 
-    /// Delete an evidence item
+    /// Delete an evidence item.
+    ///
+    /// Evidence is soft-deleted so that its chain of custody is preserved.
     pub async fn delete_evidence(&self, id: Uuid) -> Result<(), AppError> {
-        let result = sqlx::query("DELETE FROM evidence_items WHERE id = $1")
-            .bind(id)
-            .execute(&self.pool)
-            .await?;
+        let result = sqlx::query(
+            "UPDATE evidence_items SET deleted_at = NOW() WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
 
         if result.rows_affected() == 0 {
             return Err(AppError::NotFound("Evidence item not found".to_string()));
@@ -177,4 +379,1042 @@ impl EvidenceService {
 
         Ok(())
     }
+
+    /// Record a transfer, check-out, analysis or return.
+    ///
+    /// Transfers, check-outs and returns move the item to the receiving
+    /// custodian; a return is only accepted while the item is checked out.
+    pub async fn record_custody_event(
+        &self,
+        params: RecordCustodyParams,
+    ) -> Result<ChainOfCustodyEvent, AppError> {
+        let to_custodian = params
+            .to_custodian
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty());
+        let location = params
+            .location
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty());
+
+        let mut tx = self.pool.begin().await?;
+        let item = lock_evidence(&mut tx, params.evidence_id).await?;
+        let checked_out = is_checked_out(&mut tx, item.id).await?;
+
+        match params.action {
+            CustodyAction::Collection
+            | CustodyAction::Acquisition
+            | CustodyAction::Verification
+            | CustodyAction::Scanned => {
+                return Err(AppError::Validation(format!(
+                    "{} events are recorded automatically",
+                    params.action.as_str()
+                )))
+            }
+            CustodyAction::Analysis => {
+                if to_custodian.is_some() {
+                    return Err(AppError::Validation(
+                        "Analysis does not change the custodian; record a transfer instead"
+                            .to_string(),
+                    ));
+                }
+            }
+            CustodyAction::Transfer | CustodyAction::CheckOut | CustodyAction::Return => {
+                if to_custodian.is_none() {
+                    return Err(AppError::Validation(format!(
+                        "{} requires the receiving custodian",
+                        params.action.as_str()
+                    )));
+                }
+                if params.action == CustodyAction::CheckOut && checked_out {
+                    return Err(AppError::Conflict(
+                        "Evidence item is already checked out".to_string(),
+                    ));
+                }
+                if params.action == CustodyAction::Return && !checked_out {
+                    return Err(AppError::Conflict(
+                        "Evidence item is not checked out".to_string(),
+                    ));
+                }
+            }
+        }
+
+        let moves = to_custodian.is_some();
+        let new_location = location.clone().unwrap_or_else(|| item.location.clone());
+
+        if moves || location.is_some() {
+            sqlx::query(
+                r#"
+                UPDATE evidence_items
+                SET custodian = $1, location = $2, updated_at = $3, updated_by = $4
+                WHERE id = $5
+                "#,
+            )
+            .bind(to_custodian.as_deref().unwrap_or(&item.custodian))
+            .bind(&new_location)
+            .bind(Utc::now())
+            .bind(params.user_id)
+            .bind(item.id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        let event = append_custody_event(
+            &mut tx,
+            item.id,
+            NewCustodyEvent {
+                action: params.action,
+                actor: params.actor,
+                actor_id: Some(params.user_id),
+                from_custodian: moves.then(|| item.custodian.clone()),
+                to_custodian,
+                location: Some(new_location),
+                notes: params.notes,
+            },
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(event)
+    }
+
+    /// Full chain of custody of an evidence item, verified against its hashes
+    pub async fn custody_chain(&self, id: Uuid) -> Result<CustodyChain, AppError> {
+        let item = self.get_evidence(id).await?;
+
+        let events = sqlx::query_as::<_, ChainOfCustodyEvent>(
+            "SELECT * FROM chain_of_custody_events WHERE evidence_id = $1 ORDER BY sequence",
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let broken_at_sequence = verify_chain(&events);
+
+        Ok(CustodyChain {
+            evidence_id: item.id,
+            tracking_uuid: item.tracking_uuid,
+            events,
+            verified: broken_at_sequence.is_none(),
+            broken_at_sequence,
+        })
+    }
+
+    /// Render the chain of custody as a PDF report suitable for filing
+    pub async fn custody_report(&self, id: Uuid) -> Result<CustodyReport, AppError> {
+        let item = self.get_evidence(id).await?;
+        let chain = self.custody_chain(id).await?;
+
+        let case_title: String = sqlx::query_scalar("SELECT title FROM cases WHERE id = $1")
+            .bind(item.case_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        let integrity = match chain.broken_at_sequence {
+            None => format!(
+                "Chain integrity: verified ({} event(s), SHA-256 hash-linked)",
+                chain.events.len()
+            ),
+            Some(sequence) => format!("Chain integrity: FAILED verification at event {}", sequence),
+        };
+        let subtitle = vec![
+            format!("Case: {}", case_title),
+            format!("Evidence: {} ({:?})", item.title, item.evidence_type),
+            format!("Tracking ID: {}", item.tracking_uuid),
+            format!(
+                "Current custodian: {} | Location: {}",
+                item.custodian, item.location
+            ),
+            integrity,
+            format!("Generated: {}", Utc::now().format("%Y-%m-%d %H:%M:%S UTC")),
+        ];
+
+        let headers = [
+            "#",
+            "Date/Time (UTC)",
+            "Action",
+            "Actor",
+            "From",
+            "To",
+            "Location",
+            "Notes",
+            "Hash",
+        ];
+        let weights = [0.4, 1.4, 0.9, 1.6, 1.2, 1.2, 1.3, 2.2, 2.0];
+        let columns: Vec<PdfColumn> = headers
+            .iter()
+            .zip(weights)
+            .map(|(header, weight)| PdfColumn { header, weight })
+            .collect();
+        let rows: Vec<Vec<String>> = chain
+            .events
+            .iter()
+            .map(|e| {
+                vec![
+                    e.sequence.to_string(),
+                    e.date.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    e.action.clone(),
+                    e.actor.clone(),
+                    e.from_custodian.clone().unwrap_or_default(),
+                    e.to_custodian.clone().unwrap_or_default(),
+                    e.location.clone().unwrap_or_default(),
+                    e.notes.clone().unwrap_or_default(),
+                    e.hash.clone().unwrap_or_default(),
+                ]
+            })
+            .collect();
+
+        Ok(CustodyReport {
+            file_name: format!("chain-of-custody-{}.pdf", item.tracking_uuid),
+            content: render_table_report("Chain of Custody Report", &subtitle, &columns, &rows),
+        })
+    }
+
+    /// Render a label sheet for the given items, in the order requested
+    pub async fn label_sheet(
+        &self,
+        evidence_ids: &[Uuid],
+        format: LabelFormat,
+    ) -> Result<LabelSheet, AppError> {
+        if evidence_ids.is_empty() {
+            return Err(AppError::Validation(
+                "Select at least one evidence item to label".to_string(),
+            ));
+        }
+
+        let rows: Vec<(Uuid, LabelData)> = sqlx::query_as::<_, LabelRow>(
+            r#"
+            SELECT e.id, e.tracking_uuid, c.title AS case_caption, e.title,
+                   e.collection_date, e.type AS evidence_type
+            FROM evidence_items e
+            JOIN cases c ON c.id = e.case_id
+            WHERE e.id = ANY($1) AND e.deleted_at IS NULL
+            "#,
+        )
+        .bind(evidence_ids)
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(|row| (row.id, row.label))
+        .collect();
+
+        let labels = evidence_ids
+            .iter()
+            .map(|id| {
+                rows.iter()
+                    .find(|(row_id, _)| row_id == id)
+                    .map(|(_, label)| label.clone())
+                    .ok_or(AppError::NotFound(format!(
+                        "Evidence item {} not found",
+                        id
+                    )))
+            })
+            .collect::<Result<Vec<LabelData>, AppError>>()?;
+
+        Ok(LabelSheet {
+            file_name: format!("evidence-labels.{}", format.extension()),
+            content_type: format.content_type(),
+            content: render_labels(format, &labels)?,
+        })
+    }
+
+    /// Look an item up by the tracking UUID on its label and record the scan
+    /// in its chain of custody
+    pub async fn resolve_scan(
+        &self,
+        params: ScanLabelParams,
+    ) -> Result<EvidenceScanResponse, AppError> {
+        let id: Uuid = sqlx::query_scalar(
+            "SELECT id FROM evidence_items WHERE tracking_uuid = $1 AND deleted_at IS NULL",
+        )
+        .bind(params.tracking_uuid)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound(
+            "No evidence item has this tracking ID".to_string(),
+        ))?;
+
+        let mut tx = self.pool.begin().await?;
+        let item = lock_evidence(&mut tx, id).await?;
+
+        let location = params
+            .location
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .unwrap_or_else(|| item.location.clone());
+
+        let custody_event = append_custody_event(
+            &mut tx,
+            item.id,
+            NewCustodyEvent {
+                action: CustodyAction::Scanned,
+                actor: params.actor,
+                actor_id: Some(params.user_id),
+                from_custodian: None,
+                to_custodian: None,
+                location: Some(location),
+                notes: params.notes,
+            },
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(EvidenceScanResponse {
+            item,
+            custody_event,
+        })
+    }
+
+    /// Admissibility challenges raised against an evidence item
+    pub async fn list_challenges(
+        &self,
+        evidence_id: Uuid,
+    ) -> Result<Vec<AdmissibilityChallenge>, AppError> {
+        self.get_evidence(evidence_id).await?;
+
+        let challenges = sqlx::query_as::<_, AdmissibilityChallenge>(
+            r#"
+            SELECT * FROM admissibility_challenges
+            WHERE evidence_id = $1 AND deleted_at IS NULL
+            ORDER BY created_at
+            "#,
+        )
+        .bind(evidence_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(challenges)
+    }
+
+    /// Challenge the admissibility of an evidence item.
+    ///
+    /// The item becomes challenged until the linked motion in limine is
+    /// decided; a challenge linked to an already decided motion is resolved
+    /// straight away.
+    pub async fn create_challenge(
+        &self,
+        params: CreateChallengeParams,
+    ) -> Result<AdmissibilityChallenge, AppError> {
+        if params.objecting_party == ExhibitParty::Joint {
+            return Err(AppError::Validation(
+                "An admissibility challenge is raised by the plaintiff or the defendant"
+                    .to_string(),
+            ));
+        }
+
+        let now = Utc::now();
+        let mut tx = self.pool.begin().await?;
+
+        // The motion is locked before the item, in the same order as when a
+        // ruling is applied, so the ruling cannot miss this challenge
+        let motion = match params.motion_id {
+            Some(motion_id) => Some(lock_limine_motion(&mut tx, motion_id).await?),
+            None => None,
+        };
+        let item = lock_evidence(&mut tx, params.evidence_id).await?;
+        check_rule_of_evidence(&mut tx, params.legal_rule_id).await?;
+        if let Some(motion) = &motion {
+            check_motion_case(motion, &item)?;
+        }
+
+        let (status, resolved_at) = match motion.as_ref().and_then(|m| m.outcome) {
+            Some(outcome) => (challenge_status_for_outcome(outcome), Some(now)),
+            None => (ChallengeStatus::Pending, None),
+        };
+
+        let challenge = sqlx::query_as::<_, AdmissibilityChallenge>(
+            r#"
+            INSERT INTO admissibility_challenges (
+                id, evidence_id, objecting_party, legal_rule_id, motion_id, grounds,
+                status, resolved_at, created_at, updated_at, created_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(item.id)
+        .bind(params.objecting_party)
+        .bind(params.legal_rule_id)
+        .bind(params.motion_id)
+        .bind(&params.grounds)
+        .bind(status)
+        .bind(resolved_at)
+        .bind(now)
+        .bind(now)
+        .bind(params.user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        refresh_admissibility(&mut tx, item.id, Some(params.user_id)).await?;
+        tx.commit().await?;
+
+        Ok(challenge)
+    }
+
+    /// Amend the rule basis, grounds or linked motion of a pending challenge
+    pub async fn update_challenge(
+        &self,
+        evidence_id: Uuid,
+        challenge_id: Uuid,
+        params: UpdateChallengeParams,
+    ) -> Result<AdmissibilityChallenge, AppError> {
+        let now = Utc::now();
+        let mut tx = self.pool.begin().await?;
+
+        let motion = match params.motion_id {
+            Some(motion_id) => Some(lock_limine_motion(&mut tx, motion_id).await?),
+            None => None,
+        };
+        let item = lock_evidence(&mut tx, evidence_id).await?;
+        let existing = sqlx::query_as::<_, AdmissibilityChallenge>(
+            r#"
+            SELECT * FROM admissibility_challenges
+            WHERE id = $1 AND evidence_id = $2 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(challenge_id)
+        .bind(evidence_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(AppError::NotFound("Challenge not found".to_string()))?;
+
+        if existing.status != ChallengeStatus::Pending {
+            return Err(AppError::Conflict(format!(
+                "Challenge has already been resolved as {:?}",
+                existing.status
+            )));
+        }
+        if let Some(legal_rule_id) = params.legal_rule_id {
+            check_rule_of_evidence(&mut tx, legal_rule_id).await?;
+        }
+        if let Some(motion) = &motion {
+            check_motion_case(motion, &item)?;
+        }
+
+        let (status, resolved_at) = match motion.as_ref().and_then(|m| m.outcome) {
+            Some(outcome) => (challenge_status_for_outcome(outcome), Some(now)),
+            None => (existing.status, existing.resolved_at),
+        };
+
+        let challenge = sqlx::query_as::<_, AdmissibilityChallenge>(
+            r#"
+            UPDATE admissibility_challenges
+            SET legal_rule_id = $1, motion_id = $2, grounds = $3, status = $4,
+                resolved_at = $5, updated_at = $6, updated_by = $7
+            WHERE id = $8
+            RETURNING *
+            "#,
+        )
+        .bind(params.legal_rule_id.unwrap_or(existing.legal_rule_id))
+        .bind(params.motion_id.or(existing.motion_id))
+        .bind(params.grounds.or(existing.grounds))
+        .bind(status)
+        .bind(resolved_at)
+        .bind(now)
+        .bind(params.user_id)
+        .bind(challenge_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        refresh_admissibility(&mut tx, evidence_id, Some(params.user_id)).await?;
+        tx.commit().await?;
+
+        Ok(challenge)
+    }
+
+    /// Soft delete a challenge recorded in error and recompute the item's
+    /// admissibility from the remaining ones
+    pub async fn delete_challenge(
+        &self,
+        evidence_id: Uuid,
+        challenge_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+        lock_evidence(&mut tx, evidence_id).await?;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE admissibility_challenges
+            SET deleted_at = NOW(), updated_by = $1
+            WHERE id = $2 AND evidence_id = $3 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(user_id)
+        .bind(challenge_id)
+        .bind(evidence_id)
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound("Challenge not found".to_string()));
+        }
+
+        refresh_admissibility(&mut tx, evidence_id, Some(user_id)).await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Files and images attached to an evidence item
+    pub async fn list_files(&self, evidence_id: Uuid) -> Result<Vec<EvidenceFile>, AppError> {
+        self.get_evidence(evidence_id).await?;
+
+        let files = sqlx::query_as::<_, EvidenceFile>(&format!(
+            "SELECT {} FROM evidence_files WHERE evidence_id = $1 ORDER BY created_at",
+            FILE_COLUMNS
+        ))
+        .bind(evidence_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(files)
+    }
+
+    /// Attach an acquired file to a digital or forensic evidence item.
+    ///
+    /// SHA-256 and MD5 digests are computed at intake, stored against the
+    /// item's tracking UUID and recorded in the chain of custody.
+    pub async fn attach_file(
+        &self,
+        params: AttachEvidenceFileParams,
+    ) -> Result<EvidenceFile, AppError> {
+        let file_name = params.file_name.trim().to_string();
+        if file_name.is_empty() {
+            return Err(AppError::Validation("File name is required".to_string()));
+        }
+        if params.content.is_empty() {
+            return Err(AppError::Validation("File is empty".to_string()));
+        }
+
+        let mut tx = self.pool.begin().await?;
+        let item = lock_evidence(&mut tx, params.evidence_id).await?;
+
+        if !matches!(
+            item.evidence_type,
+            EvidenceType::Digital | EvidenceType::Forensic
+        ) {
+            return Err(AppError::Validation(
+                "Files can only be attached to digital or forensic evidence".to_string(),
+            ));
+        }
+
+        let digests = FileDigests::compute(&params.content);
+        let now = Utc::now();
+
+        let file = sqlx::query_as::<_, EvidenceFile>(&format!(
+            r#"
+            INSERT INTO evidence_files (
+                id, evidence_id, tracking_uuid, file_name, content_type, size_bytes,
+                sha256, md5, content, integrity_status, last_verified_at, uploaded_by,
+                created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $11, $11)
+            RETURNING {}
+            "#,
+            FILE_COLUMNS
+        ))
+        .bind(Uuid::new_v4())
+        .bind(item.id)
+        .bind(item.tracking_uuid)
+        .bind(&file_name)
+        .bind(&params.content_type)
+        .bind(digests.size_bytes)
+        .bind(&digests.sha256)
+        .bind(&digests.md5)
+        .bind(&params.content)
+        .bind(IntegrityStatus::Intact)
+        .bind(now)
+        .bind(params.user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        append_custody_event(
+            &mut tx,
+            item.id,
+            NewCustodyEvent {
+                action: CustodyAction::Acquisition,
+                actor: params.actor,
+                actor_id: Some(params.user_id),
+                from_custodian: None,
+                to_custodian: None,
+                location: Some(item.location.clone()),
+                notes: Some(format!(
+                    "Acquired {} ({} bytes); SHA-256 {}; MD5 {}",
+                    file.file_name, file.size_bytes, file.sha256, file.md5
+                )),
+            },
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(file)
+    }
+
+    /// Re-hash every file of an evidence item against its intake digests
+    pub async fn verify_files(
+        &self,
+        evidence_id: Uuid,
+        actor: &str,
+        user_id: Uuid,
+    ) -> Result<Vec<EvidenceFileVerification>, AppError> {
+        let files = self.list_files(evidence_id).await?;
+        if files.is_empty() {
+            return Err(AppError::Validation(
+                "Evidence item has no files to verify".to_string(),
+            ));
+        }
+
+        let mut results = Vec::with_capacity(files.len());
+        for file in files {
+            results.push(self.verify_file(file.id, actor, Some(user_id)).await?);
+        }
+
+        Ok(results)
+    }
+
+    /// Re-verify files whose last check is older than the re-verification
+    /// period. Returns how many files were checked.
+    ///
+    /// A file that cannot be checked is logged and skipped, so that it does
+    /// not hold up the rest of the batch; it stays due for the next sweep.
+    pub async fn verify_due_files(&self) -> Result<usize, AppError> {
+        let due: Vec<Uuid> = sqlx::query_scalar(
+            r#"
+            SELECT f.id FROM evidence_files f
+            JOIN evidence_items e ON e.id = f.evidence_id
+            WHERE e.deleted_at IS NULL
+              AND f.last_verified_at < NOW() - make_interval(days => $1)
+            ORDER BY f.last_verified_at
+            LIMIT $2
+            "#,
+        )
+        .bind(REVERIFY_AFTER_DAYS)
+        .bind(VERIFICATION_BATCH_SIZE)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut failed = 0;
+        for id in &due {
+            if let Err(e) = self
+                .verify_file(*id, SCHEDULED_VERIFICATION_ACTOR, None)
+                .await
+            {
+                tracing::error!(
+                    "Scheduled verification of evidence file {} failed: {}",
+                    id,
+                    e
+                );
+                failed += 1;
+            }
+        }
+        if failed > 0 {
+            tracing::warn!(
+                "{} of {} evidence file(s) could not be verified",
+                failed,
+                due.len()
+            );
+        }
+
+        Ok(due.len() - failed)
+    }
+
+    /// Re-hash one file, record the check in the chain of custody and alert
+    /// the case owner and the people who handled the evidence on mismatch
+    async fn verify_file(
+        &self,
+        file_id: Uuid,
+        actor: &str,
+        actor_id: Option<Uuid>,
+    ) -> Result<EvidenceFileVerification, AppError> {
+        let evidence_id: Uuid =
+            sqlx::query_scalar("SELECT evidence_id FROM evidence_files WHERE id = $1")
+                .bind(file_id)
+                .fetch_optional(&self.pool)
+                .await?
+                .ok_or(AppError::NotFound("Evidence file not found".to_string()))?;
+
+        let mut tx = self.pool.begin().await?;
+        let item = lock_evidence(&mut tx, evidence_id).await?;
+
+        let file = sqlx::query_as::<_, EvidenceFile>(&format!(
+            "SELECT {} FROM evidence_files WHERE id = $1",
+            FILE_COLUMNS
+        ))
+        .bind(file_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        let content: Vec<u8> =
+            sqlx::query_scalar("SELECT content FROM evidence_files WHERE id = $1")
+                .bind(file_id)
+                .fetch_one(&mut *tx)
+                .await?;
+
+        let digests = FileDigests::compute(&content);
+        let intact = digests.matches(&file);
+        let status = if intact {
+            IntegrityStatus::Intact
+        } else {
+            IntegrityStatus::Mismatch
+        };
+        let now = Utc::now();
+
+        let file = sqlx::query_as::<_, EvidenceFile>(&format!(
+            r#"
+            UPDATE evidence_files
+            SET integrity_status = $1, last_verified_at = $2, updated_at = $2
+            WHERE id = $3
+            RETURNING {}
+            "#,
+            FILE_COLUMNS
+        ))
+        .bind(&status)
+        .bind(now)
+        .bind(file_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let notes = if intact {
+            format!(
+                "Verified {}: SHA-256 and MD5 match intake digests",
+                file.file_name
+            )
+        } else {
+            format!(
+                "INTEGRITY MISMATCH for {}: expected SHA-256 {} MD5 {} ({} bytes), computed SHA-256 {} MD5 {} ({} bytes)",
+                file.file_name,
+                file.sha256,
+                file.md5,
+                file.size_bytes,
+                digests.sha256,
+                digests.md5,
+                digests.size_bytes
+            )
+        };
+
+        let event = append_custody_event(
+            &mut tx,
+            item.id,
+            NewCustodyEvent {
+                action: CustodyAction::Verification,
+                actor: actor.to_string(),
+                actor_id,
+                from_custodian: None,
+                to_custodian: None,
+                location: Some(item.location.clone()),
+                notes: Some(notes),
+            },
+        )
+        .await?;
+
+        if !intact {
+            tracing::error!(
+                "Integrity mismatch on evidence file {} of evidence item {}",
+                file.id,
+                item.id
+            );
+            raise_integrity_alert(&mut tx, &item, &file).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(EvidenceFileVerification {
+            file,
+            computed_sha256: digests.sha256,
+            computed_md5: digests.md5,
+            intact,
+            custody_event_id: event.id,
+        })
+    }
+}
+
+/// Label details of an evidence item keyed by its ID
+#[derive(sqlx::FromRow)]
+struct LabelRow {
+    id: Uuid,
+    #[sqlx(flatten)]
+    label: LabelData,
+}
+
+/// Lock an evidence item for the rest of the transaction so that custody
+/// events are sequenced one at a time
+async fn lock_evidence(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    id: Uuid,
+) -> Result<EvidenceItem, AppError> {
+    sqlx::query_as::<_, EvidenceItem>(
+        "SELECT * FROM evidence_items WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+    )
+    .bind(id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(AppError::NotFound("Evidence item not found".to_string()))
+}
+
+/// Whether the latest check-out of an item has not been returned yet
+async fn is_checked_out(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    evidence_id: Uuid,
+) -> Result<bool, AppError> {
+    let last: Option<String> = sqlx::query_scalar(
+        r#"
+        SELECT action FROM chain_of_custody_events
+        WHERE evidence_id = $1 AND action IN ('Check-Out', 'Return')
+        ORDER BY sequence DESC
+        LIMIT 1
+        "#,
+    )
+    .bind(evidence_id)
+    .fetch_optional(&mut **tx)
+    .await?;
+
+    Ok(last.as_deref() == Some(CustodyAction::CheckOut.as_str()))
+}
+
+/// Append an event to an item's chain, linking it to the latest event.
+///
+/// The caller must hold the item's row lock (see `lock_evidence`).
+async fn append_custody_event(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    evidence_id: Uuid,
+    new: NewCustodyEvent,
+) -> Result<ChainOfCustodyEvent, AppError> {
+    let last: Option<(i32, Option<String>)> = sqlx::query_as(
+        r#"
+        SELECT sequence, hash FROM chain_of_custody_events
+        WHERE evidence_id = $1
+        ORDER BY sequence DESC
+        LIMIT 1
+        "#,
+    )
+    .bind(evidence_id)
+    .fetch_optional(&mut **tx)
+    .await?;
+
+    let (last_sequence, prev_hash) = last.unwrap_or((0, None));
+    let mut event = ChainOfCustodyEvent {
+        id: Uuid::new_v4(),
+        evidence_id,
+        sequence: last_sequence + 1,
+        date: custody_timestamp(),
+        action: new.action.as_str().to_string(),
+        actor: new.actor,
+        actor_id: new.actor_id,
+        from_custodian: new.from_custodian,
+        to_custodian: new.to_custodian,
+        location: new.location,
+        notes: new.notes,
+        prev_hash,
+        hash: None,
+        created_at: None,
+    };
+    event.hash = Some(event_hash(&event));
+
+    let event = sqlx::query_as::<_, ChainOfCustodyEvent>(
+        r#"
+        INSERT INTO chain_of_custody_events (
+            id, evidence_id, sequence, date, action, actor, actor_id,
+            from_custodian, to_custodian, location, notes, prev_hash, hash
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+        RETURNING *
+        "#,
+    )
+    .bind(event.id)
+    .bind(event.evidence_id)
+    .bind(event.sequence)
+    .bind(event.date)
+    .bind(&event.action)
+    .bind(&event.actor)
+    .bind(event.actor_id)
+    .bind(&event.from_custodian)
+    .bind(&event.to_custodian)
+    .bind(&event.location)
+    .bind(&event.notes)
+    .bind(&event.prev_hash)
+    .bind(&event.hash)
+    .fetch_one(&mut **tx)
+    .await?;
+
+    Ok(event)
+}
+
+/// Resolve the admissibility challenges raised by a decided motion in limine
+/// and update the admissibility of the challenged items.
+///
+/// The caller must hold the motion's row lock. Called again after a
+/// corrected ruling, it re-resolves the challenges accordingly.
+pub async fn apply_motion_outcome(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    motion_id: Uuid,
+    outcome: MotionOutcome,
+    user_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    let evidence_ids: Vec<Uuid> = sqlx::query_scalar(
+        r#"
+        SELECT DISTINCT evidence_id FROM admissibility_challenges
+        WHERE motion_id = $1 AND deleted_at IS NULL
+        ORDER BY evidence_id
+        "#,
+    )
+    .bind(motion_id)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    for evidence_id in evidence_ids {
+        lock_evidence(tx, evidence_id).await?;
+
+        sqlx::query(
+            r#"
+            UPDATE admissibility_challenges
+            SET status = $1, resolved_at = NOW(), updated_at = NOW(), updated_by = $2
+            WHERE motion_id = $3 AND evidence_id = $4 AND deleted_at IS NULL
+              AND status <> $1
+            "#,
+        )
+        .bind(challenge_status_for_outcome(outcome))
+        .bind(user_id)
+        .bind(motion_id)
+        .bind(evidence_id)
+        .execute(&mut **tx)
+        .await?;
+
+        refresh_admissibility(tx, evidence_id, user_id).await?;
+    }
+
+    Ok(())
+}
+
+/// Set an item's admissibility from its challenges; items that were never
+/// challenged keep their current status.
+///
+/// The caller must hold the item's row lock (see `lock_evidence`).
+async fn refresh_admissibility(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    evidence_id: Uuid,
+    user_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    let statuses: Vec<ChallengeStatus> = sqlx::query_scalar(
+        "SELECT status FROM admissibility_challenges WHERE evidence_id = $1 AND deleted_at IS NULL",
+    )
+    .bind(evidence_id)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    if let Some(admissibility) = admissibility_after_challenges(&statuses) {
+        sqlx::query(
+            r#"
+            UPDATE evidence_items
+            SET admissibility = $1, updated_at = NOW(), updated_by = COALESCE($2, updated_by)
+            WHERE id = $3 AND admissibility <> $1
+            "#,
+        )
+        .bind(admissibility)
+        .bind(user_id)
+        .bind(evidence_id)
+        .execute(&mut **tx)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Lock a motion in limine against concurrent rulings for the rest of the
+/// transaction
+async fn lock_limine_motion(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    motion_id: Uuid,
+) -> Result<Motion, AppError> {
+    let motion = sqlx::query_as::<_, Motion>(
+        "SELECT * FROM motions WHERE id = $1 AND deleted_at IS NULL FOR SHARE",
+    )
+    .bind(motion_id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(AppError::NotFound("Motion not found".to_string()))?;
+
+    if !matches!(motion.motion_type, MotionType::InLimine) {
+        return Err(AppError::Validation(
+            "Admissibility challenges must be linked to a motion in limine".to_string(),
+        ));
+    }
+
+    Ok(motion)
+}
+
+/// Reject a motion filed in a different case than the challenged item
+fn check_motion_case(motion: &Motion, item: &EvidenceItem) -> Result<(), AppError> {
+    if motion.case_id != item.case_id {
+        return Err(AppError::Validation(
+            "Motion belongs to a different case than the evidence item".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Reject a challenge basis that is not a Federal Rule of Evidence
+async fn check_rule_of_evidence(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    legal_rule_id: Uuid,
+) -> Result<(), AppError> {
+    let rule_type: LegalRuleType =
+        sqlx::query_scalar("SELECT type FROM legal_rules WHERE id = $1 AND deleted_at IS NULL")
+            .bind(legal_rule_id)
+            .fetch_optional(&mut **tx)
+            .await?
+            .ok_or(AppError::NotFound("Legal rule not found".to_string()))?;
+
+    if !matches!(rule_type, LegalRuleType::FRE) {
+        return Err(AppError::Validation(
+            "Admissibility challenges must cite a Federal Rule of Evidence".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Notify the case owner, the user who recorded the evidence and the user
+/// who attached the file of a failed integrity check
+async fn raise_integrity_alert(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    item: &EvidenceItem,
+    file: &EvidenceFile,
+) -> Result<(), AppError> {
+    let recipients: Vec<Uuid> = sqlx::query_scalar(
+        r#"
+        SELECT DISTINCT recipient
+        FROM evidence_items e
+        JOIN cases c ON c.id = e.case_id,
+        unnest(ARRAY[c.owner_id, e.created_by, $2::uuid]) AS recipient
+        WHERE e.id = $1 AND recipient IS NOT NULL
+        "#,
+    )
+    .bind(item.id)
+    .bind(file.uploaded_by)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    let text = format!(
+        "Integrity check failed for \"{}\" on evidence \"{}\" (tracking ID {}); the file no longer matches its intake hashes",
+        file.file_name, item.title, item.tracking_uuid
+    );
+
+    for user_id in recipients {
+        sqlx::query(
+            "INSERT INTO notifications (id, user_id, text, time, read, type) VALUES ($1, $2, $3, $4, false, $5)",
+        )
+        .bind(Uuid::new_v4())
+        .bind(user_id)
+        .bind(&text)
+        .bind(Utc::now())
+        .bind("evidence_integrity")
+        .execute(&mut **tx)
+        .await?;
+    }
+
+    Ok(())
 }
@@ -0,0 +1,327 @@
+use crate::api::exhibits::list::ExhibitListFormat;
+use crate::api::exhibits::service::{
+    CreateExhibitParams, ExhibitService, PromoteExhibitParams, UpdateExhibitParams,
+};
+use crate::error::AppError;
+use crate::models::{Claims, ExhibitParty, ExhibitStatus, TrialExhibit};
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Json},
+    Extension,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ListExhibitsQuery {
+    pub case_id: Uuid,
+    pub party: Option<ExhibitParty>,
+    pub status: Option<ExhibitStatus>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateExhibitRequest {
+    pub case_id: Uuid,
+    pub party: ExhibitParty,
+    pub title: String,
+    pub file_type: String,
+    pub description: Option<String>,
+    pub witness: Option<String>,
+    pub date_marked: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PromoteExhibitRequest {
+    pub evidence_id: Option<Uuid>,
+    pub document_id: Option<Uuid>,
+    pub party: ExhibitParty,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub witness: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateExhibitRequest {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub witness: Option<String>,
+    pub file_type: Option<String>,
+    pub objection: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateExhibitStatusRequest {
+    pub status: ExhibitStatus,
+    pub objection: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ExportExhibitListQuery {
+    pub case_id: Uuid,
+    pub party: Option<ExhibitParty>,
+    /// `pdf` (default) or `docx`
+    pub format: Option<String>,
+}
+
+/// List trial exhibits for a case
+#[utoipa::path(
+    get,
+    path = "/api/exhibits",
+    params(
+        ("case_id" = Uuid, Query, description = "Case ID"),
+        ("party" = Option<ExhibitParty>, Query, description = "Filter by offering party"),
+        ("status" = Option<ExhibitStatus>, Query, description = "Filter by status")
+    ),
+    responses(
+        (status = 200, description = "List of exhibits", body = Vec<TrialExhibit>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "exhibits",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_exhibits(
+    State(service): State<Arc<ExhibitService>>,
+    Query(query): Query<ListExhibitsQuery>,
+) -> Result<Json<Vec<TrialExhibit>>, AppError> {
+    let exhibits = service
+        .list_exhibits(query.case_id, query.party, query.status)
+        .await?;
+    Ok(Json(exhibits))
+}
+
+/// Get a trial exhibit
+#[utoipa::path(
+    get,
+    path = "/api/exhibits/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Exhibit ID")
+    ),
+    responses(
+        (status = 200, description = "Exhibit details", body = TrialExhibit),
+        (status = 404, description = "Exhibit not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "exhibits",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_exhibit(
+    State(service): State<Arc<ExhibitService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<TrialExhibit>, AppError> {
+    let exhibit = service.get_exhibit(id).await?;
+    Ok(Json(exhibit))
+}
+
+/// Mark a new exhibit with the party's next exhibit number
+#[utoipa::path(
+    post,
+    path = "/api/exhibits",
+    request_body = CreateExhibitRequest,
+    responses(
+        (status = 201, description = "Exhibit marked", body = TrialExhibit),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "Case not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "exhibits",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_exhibit(
+    State(service): State<Arc<ExhibitService>>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<CreateExhibitRequest>,
+) -> Result<(StatusCode, Json<TrialExhibit>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let exhibit = service
+        .create_exhibit(CreateExhibitParams {
+            case_id: req.case_id,
+            party: req.party,
+            title: req.title,
+            file_type: req.file_type,
+            description: req.description,
+            witness: req.witness,
+            date_marked: req.date_marked,
+            created_by: user_id,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(exhibit)))
+}
+
+/// Promote an evidence item or document into a numbered exhibit
+#[utoipa::path(
+    post,
+    path = "/api/exhibits/promote",
+    request_body = PromoteExhibitRequest,
+    responses(
+        (status = 201, description = "Exhibit marked", body = TrialExhibit),
+        (status = 400, description = "Provide exactly one source"),
+        (status = 404, description = "Source not found"),
+        (status = 409, description = "Source already promoted for this party"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "exhibits",
+    security(("bearer_auth" = []))
+)]
+pub async fn promote_exhibit(
+    State(service): State<Arc<ExhibitService>>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<PromoteExhibitRequest>,
+) -> Result<(StatusCode, Json<TrialExhibit>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let exhibit = service
+        .promote(PromoteExhibitParams {
+            evidence_id: req.evidence_id,
+            document_id: req.document_id,
+            party: req.party,
+            title: req.title,
+            description: req.description,
+            witness: req.witness,
+            created_by: user_id,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(exhibit)))
+}
+
+/// Update a trial exhibit
+#[utoipa::path(
+    put,
+    path = "/api/exhibits/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Exhibit ID")
+    ),
+    request_body = UpdateExhibitRequest,
+    responses(
+        (status = 200, description = "Exhibit updated", body = TrialExhibit),
+        (status = 404, description = "Exhibit not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "exhibits",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_exhibit(
+    State(service): State<Arc<ExhibitService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateExhibitRequest>,
+) -> Result<Json<TrialExhibit>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let exhibit = service
+        .update_exhibit(
+            id,
+            UpdateExhibitParams {
+                title: req.title,
+                description: req.description,
+                witness: req.witness,
+                file_type: req.file_type,
+                objection: req.objection,
+                updated_by: user_id,
+            },
+        )
+        .await?;
+    Ok(Json(exhibit))
+}
+
+/// Record that an exhibit was offered, admitted or excluded
+#[utoipa::path(
+    post,
+    path = "/api/exhibits/{id}/status",
+    params(
+        ("id" = Uuid, Path, description = "Exhibit ID")
+    ),
+    request_body = UpdateExhibitStatusRequest,
+    responses(
+        (status = 200, description = "Exhibit status updated", body = TrialExhibit),
+        (status = 404, description = "Exhibit not found"),
+        (status = 409, description = "Status change not allowed"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "exhibits",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_exhibit_status(
+    State(service): State<Arc<ExhibitService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateExhibitStatusRequest>,
+) -> Result<Json<TrialExhibit>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let exhibit = service
+        .set_status(id, req.status, req.objection, user_id)
+        .await?;
+    Ok(Json(exhibit))
+}
+
+/// Withdraw an exhibit that has not been offered
+#[utoipa::path(
+    delete,
+    path = "/api/exhibits/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Exhibit ID")
+    ),
+    responses(
+        (status = 204, description = "Exhibit withdrawn"),
+        (status = 404, description = "Exhibit not found"),
+        (status = 409, description = "Exhibit has already been offered"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "exhibits",
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_exhibit(
+    State(service): State<Arc<ExhibitService>>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    service.delete_exhibit(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Download the exhibit list in pretrial-order format
+#[utoipa::path(
+    get,
+    path = "/api/exhibits/export",
+    params(
+        ("case_id" = Uuid, Query, description = "Case ID"),
+        ("party" = Option<ExhibitParty>, Query, description = "Only this party's exhibits"),
+        ("format" = Option<String>, Query, description = "pdf (default) or docx")
+    ),
+    responses(
+        (status = 200, description = "Exhibit list", content_type = "application/pdf"),
+        (status = 200, description = "Exhibit list", content_type = "application/vnd.openxmlformats-officedocument.wordprocessingml.document"),
+        (status = 400, description = "Unsupported format"),
+        (status = 404, description = "Case not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "exhibits",
+    security(("bearer_auth" = []))
+)]
+pub async fn export_exhibit_list(
+    State(service): State<Arc<ExhibitService>>,
+    Query(query): Query<ExportExhibitListQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let format = ExhibitListFormat::parse(query.format.as_deref().unwrap_or("pdf"))?;
+    let export = service
+        .export_list(query.case_id, query.party, format)
+        .await?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, export.content_type.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", export.file_name),
+            ),
+        ],
+        export.content,
+    ))
+}
@@ -0,0 +1,163 @@
+//! Exhibit numbering, status rules and pretrial exhibit list rendering
+
+use crate::error::AppError;
+use crate::export::{render_table_report, write_docx_report, DocxColumn, PdfColumn};
+use crate::models::{ExhibitParty, ExhibitStatus, TrialExhibit};
+
+/// Columns of the exhibit list, following the AO 187 exhibit list layout
+pub const EXHIBIT_LIST_HEADERS: [&str; 8] = [
+    "Plf. No.",
+    "Def. No.",
+    "Date Offered",
+    "Marked",
+    "Admitted",
+    "Description of Exhibits",
+    "Witness",
+    "Objection",
+];
+
+const COLUMN_WEIGHTS: [f32; 8] = [0.7, 0.7, 0.9, 0.9, 1.0, 3.2, 1.3, 1.6];
+
+/// Output format of an exhibit list
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExhibitListFormat {
+    Pdf,
+    Docx,
+}
+
+impl ExhibitListFormat {
+    pub fn parse(value: &str) -> Result<Self, AppError> {
+        match value.to_lowercase().as_str() {
+            "pdf" => Ok(ExhibitListFormat::Pdf),
+            "docx" => Ok(ExhibitListFormat::Docx),
+            other => Err(AppError::Validation(format!(
+                "Unsupported exhibit list format: {}",
+                other
+            ))),
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExhibitListFormat::Pdf => "pdf",
+            ExhibitListFormat::Docx => "docx",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExhibitListFormat::Pdf => "application/pdf",
+            ExhibitListFormat::Docx => {
+                "application/vnd.openxmlformats-officedocument.wordprocessingml.document"
+            }
+        }
+    }
+}
+
+/// Exhibit number for a party's n-th exhibit, e.g. "P-1" or "D-12"
+pub fn exhibit_number(party: ExhibitParty, sequence: i32) -> String {
+    format!("{}-{}", party.prefix(), sequence)
+}
+
+/// Whether an exhibit may move from one status to another.
+///
+/// Exhibits are offered before a ruling, except that stipulated exhibits
+/// may be admitted straight from the list. An excluded exhibit may be
+/// offered again; admission is final.
+pub fn status_transition_allowed(from: ExhibitStatus, to: ExhibitStatus) -> bool {
+    matches!(
+        (from, to),
+        (ExhibitStatus::Marked, ExhibitStatus::Offered)
+            | (ExhibitStatus::Marked, ExhibitStatus::Admitted)
+            | (ExhibitStatus::Offered, ExhibitStatus::Admitted)
+            | (ExhibitStatus::Offered, ExhibitStatus::Excluded)
+            | (ExhibitStatus::Excluded, ExhibitStatus::Offered)
+    )
+}
+
+/// Flatten exhibits into rows matching `EXHIBIT_LIST_HEADERS`, plaintiff
+/// exhibits first, then defendant, then joint, each in numeric order
+pub fn exhibit_list_rows(exhibits: &[TrialExhibit]) -> Vec<Vec<String>> {
+    let mut sorted: Vec<&TrialExhibit> = exhibits.iter().collect();
+    sorted.sort_by_key(|e| (party_order(e.party), e.exhibit_sequence));
+
+    sorted
+        .into_iter()
+        .map(|e| {
+            let (plaintiff_number, defendant_number) = match e.party {
+                ExhibitParty::Plaintiff => (e.exhibit_number.clone(), String::new()),
+                ExhibitParty::Defendant => (String::new(), e.exhibit_number.clone()),
+                ExhibitParty::Joint => (e.exhibit_number.clone(), e.exhibit_number.clone()),
+            };
+            let admitted = match (e.status, e.ruled_at) {
+                (ExhibitStatus::Admitted, Some(at)) => at.format("%m/%d/%Y").to_string(),
+                (ExhibitStatus::Admitted, None) => "Admitted".to_string(),
+                (ExhibitStatus::Excluded, Some(at)) => {
+                    format!("Excluded {}", at.format("%m/%d/%Y"))
+                }
+                (ExhibitStatus::Excluded, None) => "Excluded".to_string(),
+                _ => String::new(),
+            };
+            let description = match &e.description {
+                Some(description) if !description.trim().is_empty() => {
+                    format!("{}\n{}", e.title, description)
+                }
+                _ => e.title.clone(),
+            };
+
+            vec![
+                plaintiff_number,
+                defendant_number,
+                e.offered_at
+                    .map(|at| at.format("%m/%d/%Y").to_string())
+                    .unwrap_or_default(),
+                e.date_marked.format("%m/%d/%Y").to_string(),
+                admitted,
+                description,
+                e.witness.clone().unwrap_or_default(),
+                e.objection.clone().unwrap_or_default(),
+            ]
+        })
+        .collect()
+}
+
+/// Render an exhibit list; `heading` carries the court, caption and judge
+pub fn render_exhibit_list(
+    format: ExhibitListFormat,
+    heading: &[String],
+    exhibits: &[TrialExhibit],
+) -> Result<Vec<u8>, AppError> {
+    let rows = exhibit_list_rows(exhibits);
+
+    match format {
+        ExhibitListFormat::Pdf => {
+            let columns: Vec<PdfColumn> = EXHIBIT_LIST_HEADERS
+                .iter()
+                .zip(COLUMN_WEIGHTS)
+                .map(|(header, weight)| PdfColumn { header, weight })
+                .collect();
+            Ok(render_table_report(
+                "Exhibit List",
+                heading,
+                &columns,
+                &rows,
+            ))
+        }
+        ExhibitListFormat::Docx => {
+            let columns: Vec<DocxColumn> = EXHIBIT_LIST_HEADERS
+                .iter()
+                .zip(COLUMN_WEIGHTS)
+                .map(|(header, weight)| DocxColumn { header, weight })
+                .collect();
+            write_docx_report("EXHIBIT LIST", heading, &columns, &rows)
+        }
+    }
+}
+
+fn party_order(party: ExhibitParty) -> u8 {
+    match party {
+        ExhibitParty::Plaintiff => 0,
+        ExhibitParty::Defendant => 1,
+        ExhibitParty::Joint => 2,
+    }
+}
@@ -0,0 +1,472 @@
+use crate::api::exhibits::list::{
+    exhibit_number, render_exhibit_list, status_transition_allowed, ExhibitListFormat,
+};
+use crate::error::AppError;
+use crate::models::{ExhibitParty, ExhibitStatus, TrialExhibit};
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// Parameters for marking a new trial exhibit
+pub struct CreateExhibitParams {
+    /// ID of the case the exhibit is offered in
+    pub case_id: Uuid,
+    /// Offering party; determines the exhibit number prefix
+    pub party: ExhibitParty,
+    /// Short title shown on the exhibit list
+    pub title: String,
+    /// Kind of exhibit (e.g., "Document", "Photograph", "Physical")
+    pub file_type: String,
+    /// Longer description (optional)
+    pub description: Option<String>,
+    /// Sponsoring witness (optional)
+    pub witness: Option<String>,
+    /// When the exhibit was marked (defaults to now)
+    pub date_marked: Option<DateTime<Utc>>,
+    /// ID of the user marking the exhibit
+    pub created_by: Uuid,
+}
+
+/// Parameters for promoting an evidence item or document into an exhibit
+pub struct PromoteExhibitParams {
+    /// Evidence item to promote
+    pub evidence_id: Option<Uuid>,
+    /// Document to promote
+    pub document_id: Option<Uuid>,
+    /// Offering party
+    pub party: ExhibitParty,
+    /// Title override (defaults to the source's title)
+    pub title: Option<String>,
+    /// Description override (defaults to the evidence description)
+    pub description: Option<String>,
+    /// Sponsoring witness (optional)
+    pub witness: Option<String>,
+    /// ID of the user promoting the source
+    pub created_by: Uuid,
+}
+
+/// Parameters for updating an exhibit's descriptive fields
+pub struct UpdateExhibitParams {
+    /// Optional new title
+    pub title: Option<String>,
+    /// Optional new description
+    pub description: Option<String>,
+    /// Optional new sponsoring witness
+    pub witness: Option<String>,
+    /// Optional new exhibit kind
+    pub file_type: Option<String>,
+    /// Optional new objection
+    pub objection: Option<String>,
+    /// ID of the user making the change
+    pub updated_by: Uuid,
+}
+
+/// Rendered exhibit list
+pub struct ExhibitListExport {
+    /// Suggested file name of the list
+    pub file_name: String,
+    /// MIME type of the rendered list
+    pub content_type: &'static str,
+    /// Rendered list bytes
+    pub content: Vec<u8>,
+}
+
+/// Source an exhibit is created from
+struct ExhibitSource {
+    evidence_id: Option<Uuid>,
+    document_id: Option<Uuid>,
+}
+
+/// Case and descriptive fields of an item being promoted
+#[derive(sqlx::FromRow)]
+struct PromotionSource {
+    case_id: Uuid,
+    title: String,
+    file_type: String,
+    description: Option<String>,
+}
+
+pub struct ExhibitService {
+    pool: PgPool,
+}
+
+impl ExhibitService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// List exhibits for a case, optionally filtered by party and status
+    pub async fn list_exhibits(
+        &self,
+        case_id: Uuid,
+        party: Option<ExhibitParty>,
+        status: Option<ExhibitStatus>,
+    ) -> Result<Vec<TrialExhibit>, AppError> {
+        let exhibits = sqlx::query_as::<_, TrialExhibit>(
+            r#"
+            SELECT * FROM trial_exhibits
+            WHERE case_id = $1 AND deleted_at IS NULL
+              AND ($2::exhibit_party IS NULL OR party = $2)
+              AND ($3::exhibit_status IS NULL OR status = $3)
+            ORDER BY party, exhibit_sequence
+            "#,
+        )
+        .bind(case_id)
+        .bind(party)
+        .bind(status)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(exhibits)
+    }
+
+    /// Get an exhibit by ID
+    pub async fn get_exhibit(&self, id: Uuid) -> Result<TrialExhibit, AppError> {
+        sqlx::query_as::<_, TrialExhibit>(
+            "SELECT * FROM trial_exhibits WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound("Exhibit not found".to_string()))
+    }
+
+    /// Mark a new exhibit with the party's next exhibit number
+    pub async fn create_exhibit(
+        &self,
+        params: CreateExhibitParams,
+    ) -> Result<TrialExhibit, AppError> {
+        self.insert_exhibit(
+            params,
+            ExhibitSource {
+                evidence_id: None,
+                document_id: None,
+            },
+        )
+        .await
+    }
+
+    /// Promote an evidence item or a document into a numbered exhibit.
+    ///
+    /// A source can be promoted once per party.
+    pub async fn promote(&self, params: PromoteExhibitParams) -> Result<TrialExhibit, AppError> {
+        let source = match (params.evidence_id, params.document_id) {
+            (Some(evidence_id), None) => sqlx::query_as::<_, PromotionSource>(
+                r#"
+                SELECT case_id, title, type::text AS file_type, description FROM evidence_items
+                WHERE id = $1 AND deleted_at IS NULL
+                "#,
+            )
+            .bind(evidence_id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or(AppError::NotFound("Evidence item not found".to_string()))?,
+            (None, Some(document_id)) => sqlx::query_as::<_, PromotionSource>(
+                r#"
+                SELECT case_id, title, type AS file_type, NULL::text AS description FROM documents
+                WHERE id = $1 AND deleted_at IS NULL
+                "#,
+            )
+            .bind(document_id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or(AppError::NotFound("Document not found".to_string()))?,
+            _ => {
+                return Err(AppError::Validation(
+                    "Provide exactly one of evidence_id or document_id".to_string(),
+                ))
+            }
+        };
+
+        let already_promoted: Option<String> = sqlx::query_scalar(
+            r#"
+            SELECT exhibit_number FROM trial_exhibits
+            WHERE party = $1 AND deleted_at IS NULL
+              AND (evidence_id = $2 OR document_id = $3)
+            LIMIT 1
+            "#,
+        )
+        .bind(params.party)
+        .bind(params.evidence_id)
+        .bind(params.document_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        if let Some(number) = already_promoted {
+            return Err(AppError::Conflict(format!(
+                "Already promoted as exhibit {}",
+                number
+            )));
+        }
+
+        self.insert_exhibit(
+            CreateExhibitParams {
+                case_id: source.case_id,
+                party: params.party,
+                title: params.title.unwrap_or(source.title),
+                file_type: source.file_type,
+                description: params.description.or(source.description),
+                witness: params.witness,
+                date_marked: None,
+                created_by: params.created_by,
+            },
+            ExhibitSource {
+                evidence_id: params.evidence_id,
+                document_id: params.document_id,
+            },
+        )
+        .await
+    }
+
+    /// Update an exhibit's descriptive fields; its number and party are fixed
+    pub async fn update_exhibit(
+        &self,
+        id: Uuid,
+        params: UpdateExhibitParams,
+    ) -> Result<TrialExhibit, AppError> {
+        let existing = self.get_exhibit(id).await?;
+
+        let title = params.title.unwrap_or(existing.title);
+        validate_title(&title)?;
+
+        sqlx::query_as::<_, TrialExhibit>(
+            r#"
+            UPDATE trial_exhibits
+            SET title = $1, description = $2, witness = $3, file_type = $4, objection = $5,
+                updated_at = $6, updated_by = $7, version = version + 1
+            WHERE id = $8 AND deleted_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(&title)
+        .bind(params.description.or(existing.description))
+        .bind(params.witness.or(existing.witness))
+        .bind(params.file_type.unwrap_or(existing.file_type))
+        .bind(params.objection.or(existing.objection))
+        .bind(Utc::now())
+        .bind(params.updated_by)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound("Exhibit not found".to_string()))
+    }
+
+    /// Move an exhibit to offered, admitted or excluded.
+    ///
+    /// Rulings on exhibits promoted from evidence are mirrored onto the
+    /// evidence item's admissibility.
+    pub async fn set_status(
+        &self,
+        id: Uuid,
+        status: ExhibitStatus,
+        objection: Option<String>,
+        updated_by: Uuid,
+    ) -> Result<TrialExhibit, AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        let current = sqlx::query_as::<_, TrialExhibit>(
+            "SELECT * FROM trial_exhibits WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(AppError::NotFound("Exhibit not found".to_string()))?;
+
+        if !status_transition_allowed(current.status, status) {
+            return Err(AppError::Conflict(format!(
+                "Exhibit {} cannot move from {:?} to {:?}",
+                current.exhibit_number, current.status, status
+            )));
+        }
+
+        let now = Utc::now();
+        let (offered_at, ruled_at) = match status {
+            ExhibitStatus::Offered => (Some(now), None),
+            ExhibitStatus::Admitted | ExhibitStatus::Excluded => (current.offered_at, Some(now)),
+            ExhibitStatus::Marked => (current.offered_at, current.ruled_at),
+        };
+
+        let exhibit = sqlx::query_as::<_, TrialExhibit>(
+            r#"
+            UPDATE trial_exhibits
+            SET status = $1, offered_at = $2, ruled_at = $3, objection = $4,
+                updated_at = $5, updated_by = $6, version = version + 1
+            WHERE id = $7
+            RETURNING *
+            "#,
+        )
+        .bind(status)
+        .bind(offered_at)
+        .bind(ruled_at)
+        .bind(objection.or(current.objection))
+        .bind(now)
+        .bind(updated_by)
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let admissibility = match status {
+            ExhibitStatus::Admitted => Some("Admissible"),
+            ExhibitStatus::Excluded => Some("Inadmissible"),
+            _ => None,
+        };
+        if let (Some(evidence_id), Some(admissibility)) = (exhibit.evidence_id, admissibility) {
+            sqlx::query(
+                r#"
+                UPDATE evidence_items
+                SET admissibility = $1::admissibility_status, updated_at = $2, updated_by = $3
+                WHERE id = $4
+                "#,
+            )
+            .bind(admissibility)
+            .bind(now)
+            .bind(updated_by)
+            .bind(evidence_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(exhibit)
+    }
+
+    /// Withdraw an exhibit that has not been offered yet.
+    ///
+    /// Its number is not reused.
+    pub async fn delete_exhibit(&self, id: Uuid) -> Result<(), AppError> {
+        let exhibit = self.get_exhibit(id).await?;
+        if exhibit.status != ExhibitStatus::Marked {
+            return Err(AppError::Conflict(format!(
+                "Exhibit {} has been offered and stays on the record",
+                exhibit.exhibit_number
+            )));
+        }
+
+        sqlx::query("UPDATE trial_exhibits SET deleted_at = $1, updated_at = $1 WHERE id = $2")
+            .bind(Utc::now())
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Render the case's exhibit list in pretrial-order format
+    pub async fn export_list(
+        &self,
+        case_id: Uuid,
+        party: Option<ExhibitParty>,
+        format: ExhibitListFormat,
+    ) -> Result<ExhibitListExport, AppError> {
+        let (title, court, judge): (String, Option<String>, Option<String>) = sqlx::query_as(
+            "SELECT title, court, judge FROM cases WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(case_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound("Case not found".to_string()))?;
+
+        let exhibits = self.list_exhibits(case_id, party, None).await?;
+
+        let mut heading = Vec::new();
+        if let Some(court) = court.filter(|c| !c.trim().is_empty()) {
+            heading.push(court.to_uppercase());
+        }
+        heading.push(title);
+        if let Some(judge) = judge.filter(|j| !j.trim().is_empty()) {
+            heading.push(format!("Presiding Judge: {}", judge));
+        }
+        if let Some(party) = party {
+            heading.push(format!("{:?} Exhibits", party));
+        }
+
+        Ok(ExhibitListExport {
+            file_name: format!("exhibit-list.{}", format.extension()),
+            content_type: format.content_type(),
+            content: render_exhibit_list(format, &heading, &exhibits)?,
+        })
+    }
+
+    async fn insert_exhibit(
+        &self,
+        params: CreateExhibitParams,
+        source: ExhibitSource,
+    ) -> Result<TrialExhibit, AppError> {
+        validate_title(&params.title)?;
+
+        let mut tx = self.pool.begin().await?;
+
+        // Serialize numbering per case
+        sqlx::query_scalar::<_, Uuid>(
+            "SELECT id FROM cases WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+        )
+        .bind(params.case_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(AppError::NotFound("Case not found".to_string()))?;
+
+        // Withdrawn exhibits keep their numbers, and numbers marked before
+        // sequencing are skipped
+        let mut sequence: i32 = sqlx::query_scalar(
+            "SELECT COALESCE(MAX(exhibit_sequence), 0) + 1 FROM trial_exhibits WHERE case_id = $1 AND party = $2",
+        )
+        .bind(params.case_id)
+        .bind(params.party)
+        .fetch_one(&mut *tx)
+        .await?;
+        let taken: HashSet<String> =
+            sqlx::query_scalar("SELECT exhibit_number FROM trial_exhibits WHERE case_id = $1")
+                .bind(params.case_id)
+                .fetch_all(&mut *tx)
+                .await?
+                .into_iter()
+                .collect();
+        while taken.contains(&exhibit_number(params.party, sequence)) {
+            sequence += 1;
+        }
+
+        let now = Utc::now();
+        let exhibit = sqlx::query_as::<_, TrialExhibit>(
+            r#"
+            INSERT INTO trial_exhibits (
+                id, case_id, exhibit_number, exhibit_sequence, title, date_marked, party,
+                status, file_type, description, witness, evidence_id, document_id,
+                created_at, updated_at, created_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $14, $15)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(params.case_id)
+        .bind(exhibit_number(params.party, sequence))
+        .bind(sequence)
+        .bind(params.title.trim())
+        .bind(params.date_marked.unwrap_or(now))
+        .bind(params.party)
+        .bind(ExhibitStatus::Marked)
+        .bind(&params.file_type)
+        .bind(&params.description)
+        .bind(&params.witness)
+        .bind(source.evidence_id)
+        .bind(source.document_id)
+        .bind(now)
+        .bind(params.created_by)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(exhibit)
+    }
+}
+
+fn validate_title(title: &str) -> Result<(), AppError> {
+    if title.trim().is_empty() {
+        return Err(AppError::Validation(
+            "Exhibit title may not be empty".to_string(),
+        ));
+    }
+    Ok(())
+}
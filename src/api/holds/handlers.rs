@@ -0,0 +1,339 @@
+use crate::api::holds::service::{CreateLegalHoldParams, HoldCustodianParams, LegalHoldService};
+use crate::error::AppError;
+use crate::models::{Claims, ESISource, LegalHold, LegalHoldCustodian, LegalHoldResponse};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ListLegalHoldsQuery {
+    pub case_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct HoldCustodianRequest {
+    pub custodian: String,
+    pub user_id: Option<Uuid>,
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateLegalHoldRequest {
+    pub case_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub notice_text: String,
+    pub covers_entire_case: Option<bool>,
+    pub reminder_interval_days: Option<i32>,
+    pub custodians: Option<Vec<HoldCustodianRequest>>,
+    pub document_ids: Option<Vec<Uuid>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct HoldDocumentsRequest {
+    pub document_ids: Vec<Uuid>,
+}
+
+impl From<HoldCustodianRequest> for HoldCustodianParams {
+    fn from(req: HoldCustodianRequest) -> Self {
+        Self {
+            custodian: req.custodian,
+            user_id: req.user_id,
+            email: req.email,
+        }
+    }
+}
+
+/// List legal holds for a case
+#[utoipa::path(
+    get,
+    path = "/api/legal-holds",
+    params(
+        ("case_id" = Uuid, Query, description = "Case ID to filter legal holds")
+    ),
+    responses(
+        (status = 200, description = "List of legal holds", body = Vec<LegalHold>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "legal-holds",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_legal_holds(
+    State(service): State<Arc<LegalHoldService>>,
+    Query(query): Query<ListLegalHoldsQuery>,
+) -> Result<Json<Vec<LegalHold>>, AppError> {
+    let holds = service.list_holds(query.case_id).await?;
+    Ok(Json(holds))
+}
+
+/// Get a legal hold with its custodians and document set
+#[utoipa::path(
+    get,
+    path = "/api/legal-holds/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Legal hold ID")
+    ),
+    responses(
+        (status = 200, description = "Legal hold details", body = LegalHoldResponse),
+        (status = 404, description = "Legal hold not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "legal-holds",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_legal_hold(
+    State(service): State<Arc<LegalHoldService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<LegalHoldResponse>, AppError> {
+    let hold = service.get_hold(id).await?;
+    Ok(Json(hold))
+}
+
+/// Issue a legal hold
+#[utoipa::path(
+    post,
+    path = "/api/legal-holds",
+    request_body = CreateLegalHoldRequest,
+    responses(
+        (status = 201, description = "Legal hold created", body = LegalHoldResponse),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "Case not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "legal-holds",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_legal_hold(
+    State(service): State<Arc<LegalHoldService>>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<CreateLegalHoldRequest>,
+) -> Result<(StatusCode, Json<LegalHoldResponse>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let hold = service
+        .create_hold(CreateLegalHoldParams {
+            case_id: req.case_id,
+            name: req.name,
+            description: req.description,
+            notice_text: req.notice_text,
+            covers_entire_case: req.covers_entire_case.unwrap_or(false),
+            reminder_interval_days: req.reminder_interval_days,
+            custodians: req
+                .custodians
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            document_ids: req.document_ids.unwrap_or_default(),
+            created_by: user_id,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(hold)))
+}
+
+/// Release a legal hold
+#[utoipa::path(
+    post,
+    path = "/api/legal-holds/{id}/release",
+    params(
+        ("id" = Uuid, Path, description = "Legal hold ID")
+    ),
+    responses(
+        (status = 200, description = "Legal hold released", body = LegalHold),
+        (status = 404, description = "Active legal hold not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "legal-holds",
+    security(("bearer_auth" = []))
+)]
+pub async fn release_legal_hold(
+    State(service): State<Arc<LegalHoldService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<LegalHold>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let hold = service.release_hold(id, user_id).await?;
+    Ok(Json(hold))
+}
+
+/// Add a custodian to a legal hold
+#[utoipa::path(
+    post,
+    path = "/api/legal-holds/{id}/custodians",
+    params(
+        ("id" = Uuid, Path, description = "Legal hold ID")
+    ),
+    request_body = HoldCustodianRequest,
+    responses(
+        (status = 201, description = "Custodian added", body = LegalHoldCustodian),
+        (status = 400, description = "Custodian has no ESI sources in this case"),
+        (status = 409, description = "Custodian already on hold, or hold released"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "legal-holds",
+    security(("bearer_auth" = []))
+)]
+pub async fn add_hold_custodian(
+    State(service): State<Arc<LegalHoldService>>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<HoldCustodianRequest>,
+) -> Result<(StatusCode, Json<LegalHoldCustodian>), AppError> {
+    let custodian = service.add_custodian(id, req.into()).await?;
+    Ok((StatusCode::CREATED, Json(custodian)))
+}
+
+/// Remove a custodian from a legal hold
+#[utoipa::path(
+    delete,
+    path = "/api/legal-holds/{id}/custodians/{custodian_id}",
+    params(
+        ("id" = Uuid, Path, description = "Legal hold ID"),
+        ("custodian_id" = Uuid, Path, description = "Hold custodian ID")
+    ),
+    responses(
+        (status = 204, description = "Custodian removed"),
+        (status = 404, description = "Custodian not found on this hold"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "legal-holds",
+    security(("bearer_auth" = []))
+)]
+pub async fn remove_hold_custodian(
+    State(service): State<Arc<LegalHoldService>>,
+    Path((id, custodian_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    service.remove_custodian(id, custodian_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Record a custodian's acknowledgement of the hold notice
+#[utoipa::path(
+    post,
+    path = "/api/legal-holds/{id}/custodians/{custodian_id}/acknowledge",
+    params(
+        ("id" = Uuid, Path, description = "Legal hold ID"),
+        ("custodian_id" = Uuid, Path, description = "Hold custodian ID")
+    ),
+    responses(
+        (status = 200, description = "Acknowledgement recorded", body = LegalHoldCustodian),
+        (status = 400, description = "Notice has not been sent"),
+        (status = 404, description = "Custodian not found on this hold"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "legal-holds",
+    security(("bearer_auth" = []))
+)]
+pub async fn acknowledge_hold_notice(
+    State(service): State<Arc<LegalHoldService>>,
+    Path((id, custodian_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<LegalHoldCustodian>, AppError> {
+    let custodian = service.acknowledge(id, custodian_id).await?;
+    Ok(Json(custodian))
+}
+
+/// Send the hold notice to custodians who have not received it
+#[utoipa::path(
+    post,
+    path = "/api/legal-holds/{id}/notices",
+    params(
+        ("id" = Uuid, Path, description = "Legal hold ID")
+    ),
+    responses(
+        (status = 200, description = "Custodians notified", body = Vec<LegalHoldCustodian>),
+        (status = 404, description = "Legal hold not found"),
+        (status = 409, description = "Legal hold has been released"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "legal-holds",
+    security(("bearer_auth" = []))
+)]
+pub async fn issue_hold_notices(
+    State(service): State<Arc<LegalHoldService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<LegalHoldCustodian>>, AppError> {
+    let notified = service.issue_notices(id).await?;
+    Ok(Json(notified))
+}
+
+/// Add documents to a legal hold
+#[utoipa::path(
+    post,
+    path = "/api/legal-holds/{id}/documents",
+    params(
+        ("id" = Uuid, Path, description = "Legal hold ID")
+    ),
+    request_body = HoldDocumentsRequest,
+    responses(
+        (status = 200, description = "Documents added", body = LegalHoldResponse),
+        (status = 400, description = "Documents not found in this case"),
+        (status = 409, description = "Legal hold has been released"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "legal-holds",
+    security(("bearer_auth" = []))
+)]
+pub async fn add_hold_documents(
+    State(service): State<Arc<LegalHoldService>>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<HoldDocumentsRequest>,
+) -> Result<Json<LegalHoldResponse>, AppError> {
+    let hold = service.add_documents(id, req.document_ids).await?;
+    Ok(Json(hold))
+}
+
+/// Remove a document from a legal hold
+#[utoipa::path(
+    delete,
+    path = "/api/legal-holds/{id}/documents/{document_id}",
+    params(
+        ("id" = Uuid, Path, description = "Legal hold ID"),
+        ("document_id" = Uuid, Path, description = "Document ID")
+    ),
+    responses(
+        (status = 204, description = "Document removed"),
+        (status = 404, description = "Document is not on this hold"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "legal-holds",
+    security(("bearer_auth" = []))
+)]
+pub async fn remove_hold_document(
+    State(service): State<Arc<LegalHoldService>>,
+    Path((id, document_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    service.remove_document(id, document_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// List ESI sources preserved by a legal hold's custodians
+#[utoipa::path(
+    get,
+    path = "/api/legal-holds/{id}/esi-sources",
+    params(
+        ("id" = Uuid, Path, description = "Legal hold ID")
+    ),
+    responses(
+        (status = 200, description = "ESI sources under hold", body = Vec<ESISource>),
+        (status = 404, description = "Legal hold not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "legal-holds",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_hold_esi_sources(
+    State(service): State<Arc<LegalHoldService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<ESISource>>, AppError> {
+    let sources = service.list_esi_sources(id).await?;
+    Ok(Json(sources))
+}
@@ -0,0 +1,610 @@
+use crate::error::AppError;
+use crate::models::{ESISource, LegalHold, LegalHoldCustodian, LegalHoldResponse, LegalHoldStatus};
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{FromRow, PgPool};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// How often the reminder sweep runs
+pub const REMINDER_SWEEP_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+/// Parameters for placing a custodian under a hold
+pub struct HoldCustodianParams {
+    /// Custodian name as recorded on one of the case's ESI sources
+    pub custodian: String,
+    /// User account of the custodian, used for in-app notices (optional)
+    pub user_id: Option<Uuid>,
+    /// Email address the notice is addressed to (optional)
+    pub email: Option<String>,
+}
+
+/// Parameters for creating a legal hold
+pub struct CreateLegalHoldParams {
+    /// ID of the case the hold belongs to
+    pub case_id: Uuid,
+    /// Name of the hold (e.g., "Acme litigation hold")
+    pub name: String,
+    /// Internal description (optional)
+    pub description: Option<String>,
+    /// Text of the notice sent to custodians
+    pub notice_text: String,
+    /// Preserve every document in the case, not just the document set
+    pub covers_entire_case: bool,
+    /// Days between reminders to custodians who have not acknowledged (defaults to 30)
+    pub reminder_interval_days: Option<i32>,
+    /// Custodians placed under the hold
+    pub custodians: Vec<HoldCustodianParams>,
+    /// Documents placed under the hold
+    pub document_ids: Vec<Uuid>,
+    /// ID of the user issuing the hold
+    pub created_by: Uuid,
+}
+
+#[derive(FromRow)]
+struct PendingReminder {
+    #[sqlx(flatten)]
+    custodian: LegalHoldCustodian,
+    hold_name: String,
+    reminder_interval_days: i32,
+}
+
+pub struct LegalHoldService {
+    pool: PgPool,
+}
+
+impl LegalHoldService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// List legal holds for a case
+    pub async fn list_holds(&self, case_id: Uuid) -> Result<Vec<LegalHold>, AppError> {
+        let holds = sqlx::query_as::<_, LegalHold>(
+            "SELECT * FROM legal_holds WHERE case_id = $1 AND deleted_at IS NULL ORDER BY issued_at DESC",
+        )
+        .bind(case_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(holds)
+    }
+
+    /// Get a legal hold with its custodians and document set
+    pub async fn get_hold(&self, id: Uuid) -> Result<LegalHoldResponse, AppError> {
+        let hold = self.find_hold(id).await?;
+
+        let custodians = sqlx::query_as::<_, LegalHoldCustodian>(
+            "SELECT * FROM legal_hold_custodians WHERE legal_hold_id = $1 ORDER BY custodian",
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let document_ids = sqlx::query_scalar::<_, Uuid>(
+            "SELECT document_id FROM legal_hold_documents WHERE legal_hold_id = $1 ORDER BY created_at",
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(LegalHoldResponse {
+            hold,
+            custodians,
+            document_ids,
+        })
+    }
+
+    /// Issue a legal hold over custodians and documents of a case
+    pub async fn create_hold(
+        &self,
+        params: CreateLegalHoldParams,
+    ) -> Result<LegalHoldResponse, AppError> {
+        if params.name.trim().is_empty() || params.notice_text.trim().is_empty() {
+            return Err(AppError::Validation(
+                "A legal hold needs a name and notice text".to_string(),
+            ));
+        }
+
+        let reminder_interval_days = params.reminder_interval_days.unwrap_or(30);
+        if reminder_interval_days < 1 {
+            return Err(AppError::Validation(
+                "Reminder interval must be at least one day".to_string(),
+            ));
+        }
+
+        let mut tx = self.pool.begin().await?;
+
+        let case_exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM cases WHERE id = $1 AND deleted_at IS NULL)",
+        )
+        .bind(params.case_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        if !case_exists {
+            return Err(AppError::NotFound("Case not found".to_string()));
+        }
+
+        let now = Utc::now();
+        let hold = sqlx::query_as::<_, LegalHold>(
+            r#"
+            INSERT INTO legal_holds (
+                id, case_id, name, description, notice_text, status, covers_entire_case,
+                reminder_interval_days, issued_at, created_at, updated_at, created_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(params.case_id)
+        .bind(params.name.trim())
+        .bind(&params.description)
+        .bind(&params.notice_text)
+        .bind(LegalHoldStatus::Active)
+        .bind(params.covers_entire_case)
+        .bind(reminder_interval_days)
+        .bind(now)
+        .bind(now)
+        .bind(now)
+        .bind(params.created_by)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        for custodian in &params.custodians {
+            insert_custodian(&mut tx, &hold, custodian).await?;
+        }
+        insert_documents(&mut tx, &hold, &params.document_ids).await?;
+
+        tx.commit().await?;
+
+        self.get_hold(hold.id).await
+    }
+
+    /// Add a custodian to an active hold
+    pub async fn add_custodian(
+        &self,
+        hold_id: Uuid,
+        params: HoldCustodianParams,
+    ) -> Result<LegalHoldCustodian, AppError> {
+        let hold = self.find_active_hold(hold_id).await?;
+
+        let mut tx = self.pool.begin().await?;
+        let custodian = insert_custodian(&mut tx, &hold, &params).await?;
+        tx.commit().await?;
+
+        Ok(custodian)
+    }
+
+    /// Remove a custodian from a hold
+    pub async fn remove_custodian(
+        &self,
+        hold_id: Uuid,
+        custodian_id: Uuid,
+    ) -> Result<(), AppError> {
+        let result =
+            sqlx::query("DELETE FROM legal_hold_custodians WHERE id = $1 AND legal_hold_id = $2")
+                .bind(custodian_id)
+                .bind(hold_id)
+                .execute(&self.pool)
+                .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(
+                "Custodian not found on this hold".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Add documents to the document set of an active hold
+    pub async fn add_documents(
+        &self,
+        hold_id: Uuid,
+        document_ids: Vec<Uuid>,
+    ) -> Result<LegalHoldResponse, AppError> {
+        let hold = self.find_active_hold(hold_id).await?;
+
+        let mut tx = self.pool.begin().await?;
+        insert_documents(&mut tx, &hold, &document_ids).await?;
+        tx.commit().await?;
+
+        self.get_hold(hold_id).await
+    }
+
+    /// Remove a document from the document set of a hold
+    pub async fn remove_document(&self, hold_id: Uuid, document_id: Uuid) -> Result<(), AppError> {
+        let result = sqlx::query(
+            "DELETE FROM legal_hold_documents WHERE legal_hold_id = $1 AND document_id = $2",
+        )
+        .bind(hold_id)
+        .bind(document_id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(
+                "Document is not on this hold".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Release a hold, lifting its preservation obligations
+    pub async fn release_hold(&self, id: Uuid, released_by: Uuid) -> Result<LegalHold, AppError> {
+        let now = Utc::now();
+
+        sqlx::query_as::<_, LegalHold>(
+            r#"
+            UPDATE legal_holds
+            SET status = 'Released', released_at = $1, released_by = $2, updated_at = $1,
+                updated_by = $2, version = version + 1
+            WHERE id = $3 AND status = 'Active' AND deleted_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(now)
+        .bind(released_by)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound(
+            "Active legal hold not found".to_string(),
+        ))
+    }
+
+    /// Send the hold notice to every custodian who has not received it yet
+    pub async fn issue_notices(&self, hold_id: Uuid) -> Result<Vec<LegalHoldCustodian>, AppError> {
+        let hold = self.find_active_hold(hold_id).await?;
+        let now = Utc::now();
+        let mut tx = self.pool.begin().await?;
+
+        let notified = sqlx::query_as::<_, LegalHoldCustodian>(
+            r#"
+            UPDATE legal_hold_custodians SET notice_sent_at = $1, updated_at = $1
+            WHERE legal_hold_id = $2 AND notice_sent_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(now)
+        .bind(hold_id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for custodian in &notified {
+            notify_custodian(
+                &mut tx,
+                custodian,
+                &format!("Legal hold notice: {}. {}", hold.name, hold.notice_text),
+            )
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(notified)
+    }
+
+    /// Record a custodian's acknowledgement of a hold notice
+    pub async fn acknowledge(
+        &self,
+        hold_id: Uuid,
+        custodian_id: Uuid,
+    ) -> Result<LegalHoldCustodian, AppError> {
+        let now = Utc::now();
+        let mut tx = self.pool.begin().await?;
+
+        let notice_sent_at = sqlx::query_scalar::<_, Option<DateTime<Utc>>>(
+            r#"
+            SELECT notice_sent_at FROM legal_hold_custodians
+            WHERE id = $1 AND legal_hold_id = $2
+            FOR UPDATE
+            "#,
+        )
+        .bind(custodian_id)
+        .bind(hold_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(AppError::NotFound(
+            "Custodian not found on this hold".to_string(),
+        ))?;
+
+        if notice_sent_at.is_none() {
+            return Err(AppError::Validation(
+                "The hold notice has not been sent to this custodian".to_string(),
+            ));
+        }
+
+        let custodian = sqlx::query_as::<_, LegalHoldCustodian>(
+            r#"
+            UPDATE legal_hold_custodians
+            SET acknowledged_at = COALESCE(acknowledged_at, $1), updated_at = $1
+            WHERE id = $2
+            RETURNING *
+            "#,
+        )
+        .bind(now)
+        .bind(custodian_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(custodian)
+    }
+
+    /// ESI sources held by the custodians of a hold
+    pub async fn list_esi_sources(&self, hold_id: Uuid) -> Result<Vec<ESISource>, AppError> {
+        let hold = self.find_hold(hold_id).await?;
+
+        let sources = sqlx::query_as::<_, ESISource>(
+            r#"
+            SELECT e.* FROM esi_sources e
+            JOIN legal_hold_custodians c ON c.custodian = e.custodian
+            WHERE c.legal_hold_id = $1 AND e.case_id = $2 AND e.deleted_at IS NULL
+            ORDER BY e.custodian, e.name
+            "#,
+        )
+        .bind(hold_id)
+        .bind(hold.case_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(sources)
+    }
+
+    /// Remind custodians of active holds who have not acknowledged their notice.
+    ///
+    /// Returns the number of reminders sent.
+    pub async fn send_due_reminders(&self) -> Result<usize, AppError> {
+        let now = Utc::now();
+        let pending = sqlx::query_as::<_, PendingReminder>(
+            r#"
+            SELECT c.*, h.name AS hold_name, h.reminder_interval_days
+            FROM legal_hold_custodians c
+            JOIN legal_holds h ON h.id = c.legal_hold_id
+            WHERE h.status = 'Active' AND h.deleted_at IS NULL
+              AND c.notice_sent_at IS NOT NULL AND c.acknowledged_at IS NULL
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut sent = 0;
+        for PendingReminder {
+            custodian,
+            hold_name,
+            reminder_interval_days,
+        } in pending
+        {
+            if !reminder_due(&custodian, reminder_interval_days, now) {
+                continue;
+            }
+
+            let mut tx = self.pool.begin().await?;
+            sqlx::query(
+                r#"
+                UPDATE legal_hold_custodians
+                SET last_reminder_at = $1, reminder_count = reminder_count + 1, updated_at = $1
+                WHERE id = $2
+                "#,
+            )
+            .bind(now)
+            .bind(custodian.id)
+            .execute(&mut *tx)
+            .await?;
+            notify_custodian(
+                &mut tx,
+                &custodian,
+                &format!(
+                    "Reminder: please acknowledge the legal hold notice for {}",
+                    hold_name
+                ),
+            )
+            .await?;
+            tx.commit().await?;
+            sent += 1;
+        }
+
+        Ok(sent)
+    }
+
+    async fn find_hold(&self, id: Uuid) -> Result<LegalHold, AppError> {
+        sqlx::query_as::<_, LegalHold>(
+            "SELECT * FROM legal_holds WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound("Legal hold not found".to_string()))
+    }
+
+    async fn find_active_hold(&self, id: Uuid) -> Result<LegalHold, AppError> {
+        let hold = self.find_hold(id).await?;
+        if hold.status != LegalHoldStatus::Active {
+            return Err(AppError::Conflict(
+                "Legal hold has been released".to_string(),
+            ));
+        }
+        Ok(hold)
+    }
+}
+
+/// Whether an unacknowledged custodian is due another reminder
+pub fn reminder_due(
+    custodian: &LegalHoldCustodian,
+    interval_days: i32,
+    now: DateTime<Utc>,
+) -> bool {
+    if custodian.acknowledged_at.is_some() {
+        return false;
+    }
+
+    match custodian.last_reminder_at.or(custodian.notice_sent_at) {
+        Some(last) => now - last >= Duration::days(interval_days as i64),
+        None => false,
+    }
+}
+
+/// Active holds preserving a document, directly or through a case-wide hold
+pub async fn active_holds_for_document(
+    pool: &PgPool,
+    document_id: Uuid,
+) -> Result<Vec<LegalHold>, AppError> {
+    let holds = sqlx::query_as::<_, LegalHold>(
+        r#"
+        SELECT h.* FROM legal_holds h
+        JOIN documents d ON d.id = $1
+        WHERE h.status = 'Active' AND h.deleted_at IS NULL
+          AND (
+              (h.covers_entire_case AND h.case_id = d.case_id)
+              OR EXISTS (
+                  SELECT 1 FROM legal_hold_documents hd
+                  WHERE hd.legal_hold_id = h.id AND hd.document_id = d.id
+              )
+          )
+        "#,
+    )
+    .bind(document_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(holds)
+}
+
+/// Active holds on a case
+pub async fn active_holds_for_case(
+    pool: &PgPool,
+    case_id: Uuid,
+) -> Result<Vec<LegalHold>, AppError> {
+    let holds = sqlx::query_as::<_, LegalHold>(
+        "SELECT * FROM legal_holds WHERE case_id = $1 AND status = 'Active' AND deleted_at IS NULL",
+    )
+    .bind(case_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(holds)
+}
+
+/// Conflict error naming the holds that block a delete
+pub fn held_error(what: &str, holds: &[LegalHold]) -> AppError {
+    let names: Vec<&str> = holds.iter().map(|h| h.name.as_str()).collect();
+    AppError::Conflict(format!(
+        "{} is under active legal hold: {}",
+        what,
+        names.join(", ")
+    ))
+}
+
+async fn insert_custodian(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    hold: &LegalHold,
+    params: &HoldCustodianParams,
+) -> Result<LegalHoldCustodian, AppError> {
+    let custodian = params.custodian.trim();
+
+    let known: bool = sqlx::query_scalar(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM esi_sources
+            WHERE case_id = $1 AND custodian = $2 AND deleted_at IS NULL
+        )
+        "#,
+    )
+    .bind(hold.case_id)
+    .bind(custodian)
+    .fetch_one(&mut **tx)
+    .await?;
+    if !known {
+        return Err(AppError::Validation(format!(
+            "{} is not a custodian of any ESI source in this case",
+            custodian
+        )));
+    }
+
+    let now = Utc::now();
+    sqlx::query_as::<_, LegalHoldCustodian>(
+        r#"
+        INSERT INTO legal_hold_custodians (
+            id, legal_hold_id, custodian, user_id, email, created_at, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        ON CONFLICT (legal_hold_id, custodian) DO NOTHING
+        RETURNING *
+        "#,
+    )
+    .bind(Uuid::new_v4())
+    .bind(hold.id)
+    .bind(custodian)
+    .bind(params.user_id)
+    .bind(&params.email)
+    .bind(now)
+    .bind(now)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(AppError::Conflict(format!(
+        "{} is already a custodian on this hold",
+        custodian
+    )))
+}
+
+async fn insert_documents(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    hold: &LegalHold,
+    document_ids: &[Uuid],
+) -> Result<(), AppError> {
+    let unique: HashSet<Uuid> = document_ids.iter().copied().collect();
+    let ids: Vec<Uuid> = unique.into_iter().collect();
+
+    let found: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM documents WHERE id = ANY($1) AND case_id = $2")
+            .bind(&ids)
+            .bind(hold.case_id)
+            .fetch_one(&mut **tx)
+            .await?;
+    if found != ids.len() as i64 {
+        return Err(AppError::BadRequest(
+            "One or more documents were not found in this case".to_string(),
+        ));
+    }
+
+    sqlx::query(
+        r#"
+        INSERT INTO legal_hold_documents (legal_hold_id, document_id, created_at)
+        SELECT $1, id, $2 FROM UNNEST($3::uuid[]) AS id
+        ON CONFLICT DO NOTHING
+        "#,
+    )
+    .bind(hold.id)
+    .bind(Utc::now())
+    .bind(&ids)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+async fn notify_custodian(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    custodian: &LegalHoldCustodian,
+    text: &str,
+) -> Result<(), AppError> {
+    // Custodians without a user account are notified outside the system
+    let Some(user_id) = custodian.user_id else {
+        return Ok(());
+    };
+
+    sqlx::query(
+        "INSERT INTO notifications (id, user_id, text, time, read, type) VALUES ($1, $2, $3, $4, false, $5)",
+    )
+    .bind(Uuid::new_v4())
+    .bind(user_id)
+    .bind(text)
+    .bind(Utc::now())
+    .bind("legal_hold")
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
@@ -1,9 +1,21 @@
+pub mod analytics;
+pub mod calendars;
 pub mod cases;
 pub mod dashboard;
+pub mod deadlines;
+pub mod discovery_requests;
 pub mod docket;
 pub mod documents;
 pub mod evidence;
+pub mod exhibits;
 pub mod health;
+pub mod holds;
 pub mod motions;
+pub mod pacer;
+pub mod privilege;
+pub mod productions;
+pub mod redactions;
+pub mod retention;
 pub mod tasks;
 pub mod users;
+pub mod workflows;
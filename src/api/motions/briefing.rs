@@ -0,0 +1,63 @@
+//! Briefing schedules counted back from a motion's hearing date
+
+use crate::api::deadlines::counting::{compute_due_date, DueDate};
+use crate::models::{BriefingRule, DayCount, MotionFilingRole};
+use chrono::NaiveDate;
+
+/// Most days before a hearing a brief may be due under a rule
+pub const MAX_DAYS_BEFORE_HEARING: i32 = 365;
+
+/// Papers that have a due date on a briefing schedule, in briefing order
+pub const BRIEFED_ROLES: [MotionFilingRole; 2] =
+    [MotionFilingRole::Opposition, MotionFilingRole::Reply];
+
+/// Whether a paper has a due date on a briefing schedule
+pub fn is_briefed(role: MotionFilingRole) -> bool {
+    BRIEFED_ROLES.contains(&role)
+}
+
+/// Count a brief's due date back from the hearing.
+///
+/// A due date that falls on a day the court is closed moves to the open
+/// day before it, so the brief is never due after the rule allows.
+pub fn briefing_due_date(
+    hearing_date: NaiveDate,
+    days_before_hearing: i32,
+    day_count: DayCount,
+    is_closed: impl Fn(NaiveDate) -> bool,
+) -> Result<DueDate, String> {
+    compute_due_date(
+        hearing_date,
+        -days_before_hearing,
+        day_count,
+        None,
+        false,
+        is_closed,
+    )
+}
+
+/// Date a brief is due: the latest stipulated extension, else the manual
+/// override, else the date computed from the hearing
+pub fn effective_due_date(
+    computed_date: Option<NaiveDate>,
+    override_date: Option<NaiveDate>,
+    extended_to: Option<NaiveDate>,
+) -> Option<NaiveDate> {
+    extended_to.or(override_date).or(computed_date)
+}
+
+/// Rule that sets a paper's due date among those matching a motion.
+///
+/// A rule for the motion's type beats one for every type, then a rule for
+/// the court beats one for the jurisdiction, which beats a general rule.
+/// Ties go to the rule listed last.
+pub fn applicable_rule(rules: &[BriefingRule], role: MotionFilingRole) -> Option<&BriefingRule> {
+    rules
+        .iter()
+        .filter(|rule| rule.role == role)
+        .max_by_key(|rule| {
+            (rule.motion_type.is_some() as u8) * 4
+                + (rule.court.is_some() as u8) * 2
+                + rule.jurisdiction.is_some() as u8
+        })
+}
@@ -0,0 +1,30 @@
+//! How docketing a motion's papers advances its status
+
+use crate::api::motions::workflow::stage;
+use crate::models::{MotionFilingRole, MotionStatus};
+
+/// Status a motion reaches once a paper in a role is docketed
+pub fn status_for_role(role: MotionFilingRole) -> MotionStatus {
+    match role {
+        MotionFilingRole::Filing => MotionStatus::Filed,
+        MotionFilingRole::Opposition => MotionStatus::OppositionServed,
+        MotionFilingRole::Reply => MotionStatus::ReplyServed,
+        MotionFilingRole::Order => MotionStatus::Decided,
+    }
+}
+
+/// New status of a motion after a paper is docketed, or `None` when the
+/// motion is already at or past that stage.
+///
+/// Statuses only move forward, so docketing an opposition after a hearing
+/// has been set leaves the motion as it is. Withdrawn motions never move.
+pub fn status_after_docketing(
+    current: MotionStatus,
+    role: MotionFilingRole,
+) -> Option<MotionStatus> {
+    if current == MotionStatus::Withdrawn {
+        return None;
+    }
+    let next = status_for_role(role);
+    (stage(next) > stage(current)).then_some(next)
+}
@@ -1,12 +1,19 @@
-use crate::api::motions::service::MotionService;
+use crate::api::motions::service::{
+    CreateBriefingRuleParams, CreateMotionParams, LinkMotionEntryParams, MotionService,
+    OverrideBriefingParams, RecordExtensionParams, UpdateBriefingRuleParams, UpdateMotionParams,
+};
 use crate::error::AppError;
-use crate::models::Motion;
+use crate::models::{
+    BriefingRule, BriefingSchedule, Claims, DayCount, Motion, MotionFilingRole, MotionFilings,
+    MotionOutcome, MotionStatus, MotionStatusHistory, MotionType,
+};
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
     response::Json,
+    Extension,
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::Deserialize;
 use std::sync::Arc;
 use utoipa::ToSchema;
@@ -22,18 +29,78 @@ pub struct CreateMotionRequest {
     pub case_id: Uuid,
     pub title: String,
     pub motion_type: String,
-    pub status: String,
+    /// Draft or Filed (defaults to Draft)
+    pub status: Option<MotionStatus>,
     pub filing_date: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateMotionRequest {
     pub title: Option<String>,
-    pub status: Option<String>,
-    pub outcome: Option<String>,
+    /// Next status in the motion workflow, or Withdrawn
+    pub status: Option<MotionStatus>,
+    /// Required when the motion is decided, and only then
+    pub outcome: Option<MotionOutcome>,
     pub hearing_date: Option<DateTime<Utc>>,
 }
 
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct LinkMotionEntryRequest {
+    pub docket_entry_id: Uuid,
+    /// Paper the entry records: Filing, Opposition, Reply or Order
+    pub role: MotionFilingRole,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ListBriefingRulesQuery {
+    /// Opposition or Reply
+    pub role: Option<MotionFilingRole>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateBriefingRuleRequest {
+    /// Legal rule that prescribes the period
+    pub legal_rule_id: Uuid,
+    /// Limit the rule to motions of this type
+    pub motion_type: Option<MotionType>,
+    /// Opposition or Reply
+    pub role: MotionFilingRole,
+    /// Days before the hearing the brief is due
+    pub days_before_hearing: i32,
+    pub day_count: Option<DayCount>,
+    /// Limit the rule to cases in this court
+    pub court: Option<String>,
+    /// Limit the rule to cases in this jurisdiction
+    pub jurisdiction: Option<String>,
+    pub priority: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateBriefingRuleRequest {
+    pub days_before_hearing: Option<i32>,
+    pub day_count: Option<DayCount>,
+    pub court: Option<String>,
+    pub jurisdiction: Option<String>,
+    pub priority: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct OverrideBriefingRequest {
+    /// Date the brief is due; null restores the computed date
+    pub due_date: Option<NaiveDate>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RecordExtensionRequest {
+    pub new_due_date: NaiveDate,
+    /// Date the parties stipulated to the extension
+    pub stipulated_on: NaiveDate,
+    /// Docket entry of the stipulation or the order approving it
+    pub docket_entry_id: Option<Uuid>,
+    pub notes: Option<String>,
+}
+
 /// List motions for a case
 #[utoipa::path(
     get,
@@ -86,7 +153,7 @@ pub async fn get_motion(
     request_body = CreateMotionRequest,
     responses(
         (status = 201, description = "Motion created", body = Motion),
-        (status = 400, description = "Invalid request"),
+        (status = 400, description = "Invalid request, or a status other than Draft or Filed"),
         (status = 401, description = "Unauthorized")
     ),
     tag = "motions",
@@ -94,16 +161,21 @@ pub async fn get_motion(
 )]
 pub async fn create_motion(
     State(service): State<Arc<MotionService>>,
+    Extension(claims): Extension<Claims>,
     Json(req): Json<CreateMotionRequest>,
 ) -> Result<(StatusCode, Json<Motion>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
     let motion = service
-        .create_motion(
-            req.case_id,
-            req.title,
-            req.motion_type,
-            req.status,
-            req.filing_date,
-        )
+        .create_motion(CreateMotionParams {
+            case_id: req.case_id,
+            title: req.title,
+            motion_type: req.motion_type,
+            status: req.status,
+            filing_date: req.filing_date,
+            user_id,
+        })
         .await?;
     Ok((StatusCode::CREATED, Json(motion)))
 }
@@ -118,6 +190,7 @@ pub async fn create_motion(
     request_body = UpdateMotionRequest,
     responses(
         (status = 200, description = "Motion updated", body = Motion),
+        (status = 400, description = "Status change not allowed by the workflow, or outcome missing or premature"),
         (status = 404, description = "Motion not found"),
         (status = 401, description = "Unauthorized")
     ),
@@ -126,15 +199,51 @@ pub async fn create_motion(
 )]
 pub async fn update_motion(
     State(service): State<Arc<MotionService>>,
+    Extension(claims): Extension<Claims>,
     Path(id): Path<Uuid>,
     Json(req): Json<UpdateMotionRequest>,
 ) -> Result<Json<Motion>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
     let motion = service
-        .update_motion(id, req.title, req.status, req.outcome, req.hearing_date)
+        .update_motion(
+            id,
+            UpdateMotionParams {
+                title: req.title,
+                status: req.status,
+                outcome: req.outcome,
+                hearing_date: req.hearing_date,
+                user_id,
+            },
+        )
         .await?;
     Ok(Json(motion))
 }
 
+/// Get a motion's status, the statuses it may move to, and its history
+#[utoipa::path(
+    get,
+    path = "/api/motions/{id}/status-history",
+    params(
+        ("id" = Uuid, Path, description = "Motion ID")
+    ),
+    responses(
+        (status = 200, description = "Motion status history", body = MotionStatusHistory),
+        (status = 404, description = "Motion not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "motions",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_motion_status_history(
+    State(service): State<Arc<MotionService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<MotionStatusHistory>, AppError> {
+    let history = service.status_history(id).await?;
+    Ok(Json(history))
+}
+
 /// Delete a motion
 #[utoipa::path(
     delete,
@@ -157,3 +266,355 @@ pub async fn delete_motion(
     service.delete_motion(id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+/// Get a motion with the docket entries of its papers
+#[utoipa::path(
+    get,
+    path = "/api/motions/{id}/docket-entries",
+    params(
+        ("id" = Uuid, Path, description = "Motion ID")
+    ),
+    responses(
+        (status = 200, description = "Motion and its docket entries in docket order", body = MotionFilings),
+        (status = 404, description = "Motion not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "motions",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_motion_docket_entries(
+    State(service): State<Arc<MotionService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<MotionFilings>, AppError> {
+    let filings = service.get_filings(id).await?;
+    Ok(Json(filings))
+}
+
+/// Link a docket entry to a motion, advancing the motion's status
+#[utoipa::path(
+    post,
+    path = "/api/motions/{id}/docket-entries",
+    params(
+        ("id" = Uuid, Path, description = "Motion ID")
+    ),
+    request_body = LinkMotionEntryRequest,
+    responses(
+        (status = 201, description = "Entry linked", body = MotionFilings),
+        (status = 400, description = "Docket entry belongs to another case"),
+        (status = 404, description = "Motion or docket entry not found"),
+        (status = 409, description = "Entry already linked, or the motion already has a filing entry"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "motions",
+    security(("bearer_auth" = []))
+)]
+pub async fn link_motion_docket_entry(
+    State(service): State<Arc<MotionService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<LinkMotionEntryRequest>,
+) -> Result<(StatusCode, Json<MotionFilings>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let filings = service
+        .link_entry(
+            id,
+            LinkMotionEntryParams {
+                docket_entry_id: req.docket_entry_id,
+                role: req.role,
+                user_id,
+            },
+        )
+        .await?;
+    Ok((StatusCode::CREATED, Json(filings)))
+}
+
+/// Unlink a docket entry from a motion
+#[utoipa::path(
+    delete,
+    path = "/api/motions/{id}/docket-entries/{link_id}",
+    params(
+        ("id" = Uuid, Path, description = "Motion ID"),
+        ("link_id" = Uuid, Path, description = "Motion docket entry link ID")
+    ),
+    responses(
+        (status = 204, description = "Entry unlinked; the motion's status is kept"),
+        (status = 404, description = "Link not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "motions",
+    security(("bearer_auth" = []))
+)]
+pub async fn unlink_motion_docket_entry(
+    State(service): State<Arc<MotionService>>,
+    Path((id, link_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    service.unlink_entry(id, link_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// List briefing rules
+#[utoipa::path(
+    get,
+    path = "/api/briefing-rules",
+    params(
+        ("role" = Option<MotionFilingRole>, Query, description = "Only rules for this paper")
+    ),
+    responses(
+        (status = 200, description = "Briefing rules", body = Vec<BriefingRule>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "motions",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_briefing_rules(
+    State(service): State<Arc<MotionService>>,
+    Query(query): Query<ListBriefingRulesQuery>,
+) -> Result<Json<Vec<BriefingRule>>, AppError> {
+    let rules = service.list_briefing_rules(query.role).await?;
+    Ok(Json(rules))
+}
+
+/// Create a briefing rule
+#[utoipa::path(
+    post,
+    path = "/api/briefing-rules",
+    request_body = CreateBriefingRuleRequest,
+    responses(
+        (status = 201, description = "Briefing rule created", body = BriefingRule),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "Legal rule not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "motions",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_briefing_rule(
+    State(service): State<Arc<MotionService>>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<CreateBriefingRuleRequest>,
+) -> Result<(StatusCode, Json<BriefingRule>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let rule = service
+        .create_briefing_rule(CreateBriefingRuleParams {
+            legal_rule_id: req.legal_rule_id,
+            motion_type: req.motion_type,
+            role: req.role,
+            days_before_hearing: req.days_before_hearing,
+            day_count: req.day_count,
+            court: req.court,
+            jurisdiction: req.jurisdiction,
+            priority: req.priority,
+            user_id,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(rule)))
+}
+
+/// Update a briefing rule, recomputing the schedules it set
+#[utoipa::path(
+    put,
+    path = "/api/briefing-rules/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Briefing rule ID")
+    ),
+    request_body = UpdateBriefingRuleRequest,
+    responses(
+        (status = 200, description = "Briefing rule updated", body = BriefingRule),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "Briefing rule not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "motions",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_briefing_rule(
+    State(service): State<Arc<MotionService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateBriefingRuleRequest>,
+) -> Result<Json<BriefingRule>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let rule = service
+        .update_briefing_rule(
+            id,
+            UpdateBriefingRuleParams {
+                days_before_hearing: req.days_before_hearing,
+                day_count: req.day_count,
+                court: req.court,
+                jurisdiction: req.jurisdiction,
+                priority: req.priority,
+                user_id,
+            },
+        )
+        .await?;
+    Ok(Json(rule))
+}
+
+/// Delete a briefing rule, recomputing the schedules it set
+#[utoipa::path(
+    delete,
+    path = "/api/briefing-rules/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Briefing rule ID")
+    ),
+    responses(
+        (status = 204, description = "Briefing rule deleted"),
+        (status = 404, description = "Briefing rule not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "motions",
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_briefing_rule(
+    State(service): State<Arc<MotionService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    service.delete_briefing_rule(id, user_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Get a motion's briefing schedule
+#[utoipa::path(
+    get,
+    path = "/api/motions/{id}/briefing-schedule",
+    params(
+        ("id" = Uuid, Path, description = "Motion ID")
+    ),
+    responses(
+        (status = 200, description = "Opposition and reply due dates with their extensions", body = BriefingSchedule),
+        (status = 404, description = "Motion not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "motions",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_briefing_schedule(
+    State(service): State<Arc<MotionService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<BriefingSchedule>, AppError> {
+    let schedule = service.get_briefing_schedule(id).await?;
+    Ok(Json(schedule))
+}
+
+/// Recompute a motion's briefing schedule from the current rules
+#[utoipa::path(
+    post,
+    path = "/api/motions/{id}/briefing-schedule/recompute",
+    params(
+        ("id" = Uuid, Path, description = "Motion ID")
+    ),
+    responses(
+        (status = 200, description = "Recomputed briefing schedule", body = BriefingSchedule),
+        (status = 404, description = "Motion not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "motions",
+    security(("bearer_auth" = []))
+)]
+pub async fn recompute_briefing_schedule(
+    State(service): State<Arc<MotionService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<BriefingSchedule>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let schedule = service.recompute_briefing(id, user_id).await?;
+    Ok(Json(schedule))
+}
+
+/// Set or clear the manual due date of an opposition or reply
+#[utoipa::path(
+    put,
+    path = "/api/motions/{id}/briefing-schedule/{role}",
+    params(
+        ("id" = Uuid, Path, description = "Motion ID"),
+        ("role" = MotionFilingRole, Path, description = "Opposition or Reply")
+    ),
+    request_body = OverrideBriefingRequest,
+    responses(
+        (status = 200, description = "Updated briefing schedule", body = BriefingSchedule),
+        (status = 400, description = "Paper has no briefing due date"),
+        (status = 404, description = "Motion not found"),
+        (status = 409, description = "Due date has stipulated extensions"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "motions",
+    security(("bearer_auth" = []))
+)]
+pub async fn override_briefing_deadline(
+    State(service): State<Arc<MotionService>>,
+    Extension(claims): Extension<Claims>,
+    Path((id, role)): Path<(Uuid, MotionFilingRole)>,
+    Json(req): Json<OverrideBriefingRequest>,
+) -> Result<Json<BriefingSchedule>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let schedule = service
+        .override_briefing(
+            id,
+            role,
+            OverrideBriefingParams {
+                due_date: req.due_date,
+                reason: req.reason,
+                user_id,
+            },
+        )
+        .await?;
+    Ok(Json(schedule))
+}
+
+/// Record a stipulated extension of an opposition or reply due date
+#[utoipa::path(
+    post,
+    path = "/api/motions/{id}/briefing-schedule/{role}/extensions",
+    params(
+        ("id" = Uuid, Path, description = "Motion ID"),
+        ("role" = MotionFilingRole, Path, description = "Opposition or Reply")
+    ),
+    request_body = RecordExtensionRequest,
+    responses(
+        (status = 201, description = "Extension recorded", body = BriefingSchedule),
+        (status = 400, description = "New date does not extend the due date, or docket entry belongs to another case"),
+        (status = 404, description = "Motion, briefing deadline or docket entry not found"),
+        (status = 409, description = "Briefing deadline is not open"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "motions",
+    security(("bearer_auth" = []))
+)]
+pub async fn record_briefing_extension(
+    State(service): State<Arc<MotionService>>,
+    Extension(claims): Extension<Claims>,
+    Path((id, role)): Path<(Uuid, MotionFilingRole)>,
+    Json(req): Json<RecordExtensionRequest>,
+) -> Result<(StatusCode, Json<BriefingSchedule>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let schedule = service
+        .record_extension(
+            id,
+            role,
+            RecordExtensionParams {
+                new_due_date: req.new_due_date,
+                stipulated_on: req.stipulated_on,
+                docket_entry_id: req.docket_entry_id,
+                notes: req.notes,
+                user_id,
+            },
+        )
+        .await?;
+    Ok((StatusCode::CREATED, Json(schedule)))
+}
@@ -1,5 +1,8 @@
+pub mod briefing;
+pub mod filings;
 pub mod handlers;
 pub mod service;
+pub mod workflow;
 
 pub use handlers::*;
 pub use service::*;
@@ -1,9 +1,123 @@
+use crate::api::calendars::business_calendar;
+use crate::api::deadlines::end_of_day;
+use crate::api::evidence::apply_motion_outcome;
+use crate::api::motions::briefing::{
+    applicable_rule, briefing_due_date, effective_due_date, is_briefed, BRIEFED_ROLES,
+    MAX_DAYS_BEFORE_HEARING,
+};
+use crate::api::motions::filings::status_after_docketing;
+use crate::api::motions::workflow::{can_transition, next_statuses, INITIAL_STATUSES};
 use crate::error::AppError;
-use crate::models::Motion;
-use chrono::Utc;
+use crate::models::{
+    BriefingDeadline, BriefingExtension, BriefingRule, BriefingSchedule, CourtCalendar, DayCount,
+    DeadlineStatus, Motion, MotionDocketEntry, MotionFilingRole, MotionFilings, MotionOutcome,
+    MotionStatus, MotionStatusHistory, MotionStatusTransition, MotionType,
+};
+use chrono::{DateTime, NaiveDate, Utc};
 use sqlx::PgPool;
 use uuid::Uuid;
 
+/// Parameters for linking a docket entry to a motion
+pub struct LinkMotionEntryParams {
+    pub docket_entry_id: Uuid,
+    /// Paper the entry records
+    pub role: MotionFilingRole,
+    /// ID of the user linking the entry
+    pub user_id: Uuid,
+}
+
+/// Parameters for creating a motion
+pub struct CreateMotionParams {
+    pub case_id: Uuid,
+    pub title: String,
+    pub motion_type: String,
+    /// Draft or Filed (defaults to Draft)
+    pub status: Option<MotionStatus>,
+    pub filing_date: Option<DateTime<Utc>>,
+    /// ID of the user creating the motion
+    pub user_id: Uuid,
+}
+
+/// Parameters for updating a motion; omitted fields are unchanged
+pub struct UpdateMotionParams {
+    pub title: Option<String>,
+    /// Next status in the motion workflow
+    pub status: Option<MotionStatus>,
+    /// Outcome of a decided motion
+    pub outcome: Option<MotionOutcome>,
+    pub hearing_date: Option<DateTime<Utc>>,
+    /// ID of the user updating the motion
+    pub user_id: Uuid,
+}
+
+/// Parameters for creating a briefing rule
+pub struct CreateBriefingRuleParams {
+    /// Legal rule that prescribes the period
+    pub legal_rule_id: Uuid,
+    /// Motion type the rule is limited to (optional)
+    pub motion_type: Option<MotionType>,
+    /// Opposition or Reply
+    pub role: MotionFilingRole,
+    /// Days before the hearing the brief is due
+    pub days_before_hearing: i32,
+    /// Calendar or court days (defaults to calendar days)
+    pub day_count: Option<DayCount>,
+    /// Court the rule is limited to (optional)
+    pub court: Option<String>,
+    /// Jurisdiction the rule is limited to (optional)
+    pub jurisdiction: Option<String>,
+    /// Priority of the briefing tasks (defaults to "High")
+    pub priority: Option<String>,
+    /// ID of the user creating the rule
+    pub user_id: Uuid,
+}
+
+/// Parameters for updating a briefing rule; omitted fields are unchanged
+pub struct UpdateBriefingRuleParams {
+    pub days_before_hearing: Option<i32>,
+    pub day_count: Option<DayCount>,
+    pub court: Option<String>,
+    pub jurisdiction: Option<String>,
+    pub priority: Option<String>,
+    /// ID of the user updating the rule
+    pub user_id: Uuid,
+}
+
+/// Parameters for overriding a briefing due date
+pub struct OverrideBriefingParams {
+    /// Date the brief is due; `None` restores the computed date
+    pub due_date: Option<NaiveDate>,
+    pub reason: Option<String>,
+    /// ID of the user setting the override
+    pub user_id: Uuid,
+}
+
+/// Parameters for recording a stipulated extension
+pub struct RecordExtensionParams {
+    pub new_due_date: NaiveDate,
+    /// Date the parties stipulated to the extension
+    pub stipulated_on: NaiveDate,
+    /// Docket entry of the stipulation or approving order (optional)
+    pub docket_entry_id: Option<Uuid>,
+    pub notes: Option<String>,
+    /// ID of the user recording the extension
+    pub user_id: Uuid,
+}
+
+/// Motion with the case fields that decide which briefing rules apply
+#[derive(sqlx::FromRow)]
+struct BriefedMotion {
+    case_id: Uuid,
+    title: String,
+    motion_type: MotionType,
+    hearing_date: Option<DateTime<Utc>>,
+    deleted: bool,
+    court: Option<String>,
+    jurisdiction: Option<String>,
+    owner_id: Option<Uuid>,
+    owner_name: Option<String>,
+}
+
 pub struct MotionService {
     pool: PgPool,
 }
@@ -38,117 +152,1233 @@ impl MotionService {
         Ok(motion)
     }
 
-    /// Create a new motion
-    pub async fn create_motion(
-        &self,
-        case_id: Uuid,
-        title: String,
-        motion_type: String,
-        status: String,
-        filing_date: Option<chrono::DateTime<Utc>>,
-    ) -> Result<Motion, AppError> {
+    /// Create a new motion in the draft or filed status
+    pub async fn create_motion(&self, params: CreateMotionParams) -> Result<Motion, AppError> {
+        let status = params.status.unwrap_or(MotionStatus::Draft);
+        if !INITIAL_STATUSES.contains(&status) {
+            return Err(AppError::Validation(
+                "A motion must start as Draft or Filed".to_string(),
+            ));
+        }
+
         let id = Uuid::new_v4();
         let now = Utc::now();
+        let mut tx = self.pool.begin().await?;
 
         let motion = sqlx::query_as::<_, Motion>(
             r#"
             INSERT INTO motions (
-                id, case_id, title, type, status, filing_date, created_at, updated_at
+                id, case_id, title, type, status, filing_date, created_at, updated_at,
+                created_by, updated_by
             )
-            VALUES ($1, $2, $3, $4::motion_type, $5::motion_status, $6, $7, $8)
+            VALUES ($1, $2, $3, $4::motion_type, $5, $6, $7, $7, $8, $8)
             RETURNING *
             "#,
         )
         .bind(id)
-        .bind(case_id)
-        .bind(&title)
-        .bind(&motion_type)
-        .bind(&status)
-        .bind(filing_date)
+        .bind(params.case_id)
+        .bind(&params.title)
+        .bind(&params.motion_type)
+        .bind(status)
+        .bind(params.filing_date)
         .bind(now)
-        .bind(now)
-        .fetch_one(&self.pool)
+        .bind(params.user_id)
+        .fetch_one(&mut *tx)
         .await?;
 
+        record_transition(&mut tx, id, None, status, None, Some(params.user_id)).await?;
+        tx.commit().await?;
+
         Ok(motion)
     }
 
-    /// Update a motion
+    /// Update a motion.
+    ///
+    /// Status changes follow the motion workflow (see `next_statuses`) and
+    /// are recorded in the motion's status history. An outcome is required
+    /// to decide a motion and may only be recorded for a decided one.
+    /// Deciding a motion in limine resolves the admissibility challenges it
+    /// raised and updates the challenged evidence accordingly.
     pub async fn update_motion(
         &self,
         id: Uuid,
-        title: Option<String>,
-        status: Option<String>,
-        outcome: Option<String>,
-        hearing_date: Option<chrono::DateTime<Utc>>,
+        params: UpdateMotionParams,
     ) -> Result<Motion, AppError> {
-        let now = Utc::now();
-        let existing = self.get_motion(id).await?;
-
-        let updated_title = title.unwrap_or(existing.title);
-        let updated_status = status.unwrap_or_else(|| format!("{:?}", existing.status));
+        let mut tx = self.pool.begin().await?;
 
-        // Build dynamic query based on what fields are being updated
-        let base_query =
-            "UPDATE motions SET title = $1, status = $2::motion_status, updated_at = $3";
+        let existing = sqlx::query_as::<_, Motion>(
+            "SELECT * FROM motions WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(AppError::NotFound("Motion not found".to_string()))?;
 
-        let query = match (outcome.as_ref(), hearing_date) {
-            (Some(_), Some(_)) => {
-                format!("{}, outcome = $4::motion_outcome, hearing_date = $5 WHERE id = $6 AND deleted_at IS NULL RETURNING *", base_query)
-            }
-            (Some(_), None) => {
-                format!("{}, outcome = $4::motion_outcome WHERE id = $5 AND deleted_at IS NULL RETURNING *", base_query)
-            }
-            (None, Some(_)) => {
+        let status = params.status.unwrap_or(existing.status);
+        if status != existing.status && !can_transition(existing.status, status) {
+            let allowed = next_statuses(existing.status)
+                .iter()
+                .map(|s| format!("{:?}", s))
+                .collect::<Vec<_>>();
+            return Err(AppError::Validation(if allowed.is_empty() {
+                format!("A {:?} motion can no longer change status", existing.status)
+            } else {
                 format!(
-                    "{}, hearing_date = $4 WHERE id = $5 AND deleted_at IS NULL RETURNING *",
-                    base_query
+                    "A {:?} motion can only move to {}",
+                    existing.status,
+                    allowed.join(" or ")
                 )
-            }
-            (None, None) => {
-                format!(
-                    "{} WHERE id = $4 AND deleted_at IS NULL RETURNING *",
-                    base_query
-                )
-            }
-        };
+            }));
+        }
 
-        let mut q = sqlx::query_as::<_, Motion>(&query)
-            .bind(&updated_title)
-            .bind(&updated_status)
-            .bind(now);
-
-        // Bind additional parameters based on what's being updated
-        q = match (outcome.as_ref(), hearing_date) {
-            (Some(out), Some(hd)) => q.bind(out).bind(hd).bind(id),
-            (Some(out), None) => q.bind(out).bind(id),
-            (None, Some(hd)) => q.bind(hd).bind(id),
-            (None, None) => q.bind(id),
-        };
+        if params.outcome.is_some() && status != MotionStatus::Decided {
+            return Err(AppError::Validation(
+                "An outcome can only be recorded for a decided motion".to_string(),
+            ));
+        }
+        let outcome = params.outcome.or(existing.outcome);
+        if status == MotionStatus::Decided && status != existing.status && outcome.is_none() {
+            return Err(AppError::Validation(
+                "An outcome is required to decide a motion".to_string(),
+            ));
+        }
 
-        let motion = q
-            .fetch_optional(&self.pool)
-            .await?
-            .ok_or(AppError::NotFound("Motion not found".to_string()))?;
+        let motion = sqlx::query_as::<_, Motion>(
+            r#"
+            UPDATE motions
+            SET title = $1, status = $2, outcome = $3, hearing_date = $4, updated_at = $5,
+                updated_by = $6
+            WHERE id = $7
+            RETURNING *
+            "#,
+        )
+        .bind(params.title.as_deref().unwrap_or(&existing.title))
+        .bind(status)
+        .bind(outcome)
+        .bind(params.hearing_date.or(existing.hearing_date))
+        .bind(Utc::now())
+        .bind(params.user_id)
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await?;
 
-        Ok(motion)
+        if status != existing.status {
+            record_transition(
+                &mut tx,
+                id,
+                Some(existing.status),
+                status,
+                None,
+                Some(params.user_id),
+            )
+            .await?;
+        }
+        if let Some(outcome) = params.outcome {
+            apply_motion_outcome(&mut tx, motion.id, outcome, Some(params.user_id)).await?;
+        }
+        if params.hearing_date.is_some() {
+            sync_briefing_schedule(&mut tx, motion.id, Some(params.user_id)).await?;
+        }
+        tx.commit().await?;
+
+        // Briefing due dates follow the hearing
+        self.get_motion(id).await
     }
 
-    /// Soft delete a motion
+    /// A motion's status, the statuses it may move to, and its history
+    pub async fn status_history(&self, id: Uuid) -> Result<MotionStatusHistory, AppError> {
+        let motion = self.get_motion(id).await?;
+
+        let transitions = sqlx::query_as::<_, MotionStatusTransition>(
+            "SELECT * FROM motion_status_transitions WHERE motion_id = $1 ORDER BY transitioned_at, id",
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(MotionStatusHistory {
+            motion_id: motion.id,
+            status: motion.status,
+            next_statuses: next_statuses(motion.status),
+            transitions,
+        })
+    }
+
+    /// A motion with the docket entries of its papers
+    pub async fn get_filings(&self, id: Uuid) -> Result<MotionFilings, AppError> {
+        let motion = self.get_motion(id).await?;
+        let entries = motion_entries(&self.pool, id).await?;
+
+        Ok(MotionFilings { motion, entries })
+    }
+
+    /// Link a docket entry to a motion, advancing the motion's status
+    pub async fn link_entry(
+        &self,
+        id: Uuid,
+        params: LinkMotionEntryParams,
+    ) -> Result<MotionFilings, AppError> {
+        let mut tx = self.pool.begin().await?;
+        link_motion_entry(
+            &mut tx,
+            id,
+            params.docket_entry_id,
+            params.role,
+            Some(params.user_id),
+        )
+        .await?;
+        tx.commit().await?;
+
+        self.get_filings(id).await
+    }
+
+    /// Unlink a docket entry from a motion; the motion's status is kept
+    pub async fn unlink_entry(&self, id: Uuid, link_id: Uuid) -> Result<(), AppError> {
+        let result =
+            sqlx::query("DELETE FROM motion_docket_entries WHERE id = $1 AND motion_id = $2")
+                .bind(link_id)
+                .bind(id)
+                .execute(&self.pool)
+                .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(
+                "Motion docket entry link not found".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Soft delete a motion, vacating its open briefing deadlines
     pub async fn delete_motion(&self, id: Uuid) -> Result<(), AppError> {
         let now = Utc::now();
+        let mut tx = self.pool.begin().await?;
 
         let result =
             sqlx::query("UPDATE motions SET deleted_at = $1 WHERE id = $2 AND deleted_at IS NULL")
                 .bind(now)
                 .bind(id)
-                .execute(&self.pool)
+                .execute(&mut *tx)
                 .await?;
 
         if result.rows_affected() == 0 {
             return Err(AppError::NotFound("Motion not found".to_string()));
         }
 
+        sync_briefing_schedule(&mut tx, id, None).await?;
+        tx.commit().await?;
+
         Ok(())
     }
+
+    /// List briefing rules, optionally for one paper
+    pub async fn list_briefing_rules(
+        &self,
+        role: Option<MotionFilingRole>,
+    ) -> Result<Vec<BriefingRule>, AppError> {
+        let rules = sqlx::query_as::<_, BriefingRule>(
+            r#"
+            SELECT * FROM briefing_rules
+            WHERE deleted_at IS NULL AND ($1::motion_filing_role IS NULL OR role = $1)
+            ORDER BY role, days_before_hearing DESC, created_at
+            "#,
+        )
+        .bind(role)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rules)
+    }
+
+    /// Create a briefing rule.
+    ///
+    /// The rule applies to motions scheduled from now on; recompute a
+    /// motion's schedule to apply it to a hearing already set.
+    pub async fn create_briefing_rule(
+        &self,
+        params: CreateBriefingRuleParams,
+    ) -> Result<BriefingRule, AppError> {
+        let priority = params.priority.unwrap_or_else(|| "High".to_string());
+        validate_briefing_rule(params.role, params.days_before_hearing, &priority)?;
+
+        sqlx::query_scalar::<_, Uuid>(
+            "SELECT id FROM legal_rules WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(params.legal_rule_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound("Legal rule not found".to_string()))?;
+
+        let rule = sqlx::query_as::<_, BriefingRule>(
+            r#"
+            INSERT INTO briefing_rules (
+                id, legal_rule_id, motion_type, role, days_before_hearing, day_count,
+                court, jurisdiction, priority, created_at, updated_at, created_by, updated_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $10, $11, $11)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(params.legal_rule_id)
+        .bind(params.motion_type)
+        .bind(params.role)
+        .bind(params.days_before_hearing)
+        .bind(params.day_count.unwrap_or(DayCount::CalendarDays))
+        .bind(&params.court)
+        .bind(&params.jurisdiction)
+        .bind(&priority)
+        .bind(Utc::now())
+        .bind(params.user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(rule)
+    }
+
+    /// Update a briefing rule and recompute the schedules it set
+    pub async fn update_briefing_rule(
+        &self,
+        id: Uuid,
+        params: UpdateBriefingRuleParams,
+    ) -> Result<BriefingRule, AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        let existing = sqlx::query_as::<_, BriefingRule>(
+            "SELECT * FROM briefing_rules WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(AppError::NotFound("Briefing rule not found".to_string()))?;
+
+        let days_before_hearing = params
+            .days_before_hearing
+            .unwrap_or(existing.days_before_hearing);
+        let priority = params.priority.unwrap_or(existing.priority);
+        validate_briefing_rule(existing.role, days_before_hearing, &priority)?;
+
+        let rule = sqlx::query_as::<_, BriefingRule>(
+            r#"
+            UPDATE briefing_rules
+            SET days_before_hearing = $1, day_count = $2, court = $3, jurisdiction = $4,
+                priority = $5, updated_at = $6, updated_by = $7
+            WHERE id = $8
+            RETURNING *
+            "#,
+        )
+        .bind(days_before_hearing)
+        .bind(params.day_count.unwrap_or(existing.day_count))
+        .bind(params.court.or(existing.court))
+        .bind(params.jurisdiction.or(existing.jurisdiction))
+        .bind(&priority)
+        .bind(Utc::now())
+        .bind(params.user_id)
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sync_rule_briefing(&mut tx, id, Some(params.user_id)).await?;
+        tx.commit().await?;
+
+        Ok(rule)
+    }
+
+    /// Delete a briefing rule, recomputing the schedules it set
+    pub async fn delete_briefing_rule(&self, id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        let result = sqlx::query(
+            "UPDATE briefing_rules SET deleted_at = NOW(), updated_by = $2 WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound("Briefing rule not found".to_string()));
+        }
+
+        sync_rule_briefing(&mut tx, id, Some(user_id)).await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Briefing schedule of a motion
+    pub async fn get_briefing_schedule(&self, id: Uuid) -> Result<BriefingSchedule, AppError> {
+        let motion = self.get_motion(id).await?;
+
+        let deadlines = sqlx::query_as::<_, BriefingDeadline>(
+            "SELECT * FROM briefing_deadlines WHERE motion_id = $1 ORDER BY role",
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let extensions = sqlx::query_as::<_, BriefingExtension>(
+            r#"
+            SELECT x.* FROM briefing_extensions x
+            JOIN briefing_deadlines b ON b.id = x.briefing_deadline_id
+            WHERE b.motion_id = $1
+            ORDER BY x.created_at
+            "#,
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(BriefingSchedule {
+            motion_id: motion.id,
+            hearing_date: motion.hearing_date,
+            deadlines,
+            extensions,
+        })
+    }
+
+    /// Recompute a motion's briefing schedule, e.g. after adding rules
+    pub async fn recompute_briefing(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+    ) -> Result<BriefingSchedule, AppError> {
+        self.get_motion(id).await?;
+
+        let mut tx = self.pool.begin().await?;
+        sync_briefing_schedule(&mut tx, id, Some(user_id)).await?;
+        tx.commit().await?;
+
+        self.get_briefing_schedule(id).await
+    }
+
+    /// Set or clear the manual due date of a brief.
+    ///
+    /// Once an extension has been stipulated the due date can only move by
+    /// further extensions.
+    pub async fn override_briefing(
+        &self,
+        id: Uuid,
+        role: MotionFilingRole,
+        params: OverrideBriefingParams,
+    ) -> Result<BriefingSchedule, AppError> {
+        validate_briefed_role(role)?;
+        let reason = params
+            .reason
+            .map(|r| r.trim().to_string())
+            .filter(|r| !r.is_empty());
+
+        let mut tx = self.pool.begin().await?;
+        lock_briefed_motion(&mut tx, id).await?;
+        let existing = lock_briefing_deadline(&mut tx, id, role).await?;
+
+        match (existing, params.due_date) {
+            (None, None) => {}
+            (None, Some(due_date)) => {
+                sqlx::query(
+                    r#"
+                    INSERT INTO briefing_deadlines (
+                        id, motion_id, role, override_date, override_reason, due_date, status,
+                        created_at, updated_at, created_by, updated_by
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $4, 'Open', $6, $6, $7, $7)
+                    "#,
+                )
+                .bind(Uuid::new_v4())
+                .bind(id)
+                .bind(role)
+                .bind(due_date)
+                .bind(&reason)
+                .bind(Utc::now())
+                .bind(params.user_id)
+                .execute(&mut *tx)
+                .await?;
+            }
+            (Some(deadline), due_date) => {
+                let extended: bool = sqlx::query_scalar(
+                    "SELECT EXISTS (SELECT 1 FROM briefing_extensions WHERE briefing_deadline_id = $1)",
+                )
+                .bind(deadline.id)
+                .fetch_one(&mut *tx)
+                .await?;
+                if extended {
+                    return Err(AppError::Conflict(
+                        "Briefing deadline has stipulated extensions; record a further extension instead"
+                            .to_string(),
+                    ));
+                }
+
+                sqlx::query(
+                    r#"
+                    UPDATE briefing_deadlines
+                    SET override_date = $1, override_reason = $2, updated_at = $3, updated_by = $4
+                    WHERE id = $5
+                    "#,
+                )
+                .bind(due_date)
+                .bind(due_date.and(reason))
+                .bind(Utc::now())
+                .bind(params.user_id)
+                .bind(deadline.id)
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+
+        sync_briefing_schedule(&mut tx, id, Some(params.user_id)).await?;
+        tx.commit().await?;
+
+        self.get_briefing_schedule(id).await
+    }
+
+    /// Record a stipulated extension of an open briefing deadline
+    pub async fn record_extension(
+        &self,
+        id: Uuid,
+        role: MotionFilingRole,
+        params: RecordExtensionParams,
+    ) -> Result<BriefingSchedule, AppError> {
+        validate_briefed_role(role)?;
+
+        let mut tx = self.pool.begin().await?;
+        let case_id = lock_briefed_motion(&mut tx, id).await?;
+        let deadline =
+            lock_briefing_deadline(&mut tx, id, role)
+                .await?
+                .ok_or(AppError::NotFound(
+                    "Briefing deadline not found".to_string(),
+                ))?;
+
+        if deadline.status != DeadlineStatus::Open {
+            return Err(AppError::Conflict(
+                "Briefing deadline is not open".to_string(),
+            ));
+        }
+        if params.new_due_date <= deadline.due_date {
+            return Err(AppError::Validation(format!(
+                "An extension must move the due date past {}",
+                deadline.due_date
+            )));
+        }
+
+        if let Some(docket_entry_id) = params.docket_entry_id {
+            let entry_case_id = sqlx::query_scalar::<_, Uuid>(
+                "SELECT case_id FROM docket_entries WHERE id = $1 AND deleted_at IS NULL",
+            )
+            .bind(docket_entry_id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or(AppError::NotFound("Docket entry not found".to_string()))?;
+            if entry_case_id != case_id {
+                return Err(AppError::Validation(
+                    "Docket entry belongs to a different case than the motion".to_string(),
+                ));
+            }
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO briefing_extensions (
+                id, briefing_deadline_id, previous_due_date, new_due_date, stipulated_on,
+                docket_entry_id, notes, created_at, created_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(deadline.id)
+        .bind(deadline.due_date)
+        .bind(params.new_due_date)
+        .bind(params.stipulated_on)
+        .bind(params.docket_entry_id)
+        .bind(&params.notes)
+        .bind(Utc::now())
+        .bind(params.user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        sync_briefing_schedule(&mut tx, id, Some(params.user_id)).await?;
+        tx.commit().await?;
+
+        self.get_briefing_schedule(id).await
+    }
+}
+
+/// Link a docket entry to a motion in the same case.
+///
+/// The motion's status moves forward to match the paper docketed (see
+/// `status_after_docketing`), recording the move against the entry in the
+/// motion's status history. Docketing the motion itself sets its filing
+/// date when none is recorded.
+pub async fn link_motion_entry(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    motion_id: Uuid,
+    docket_entry_id: Uuid,
+    role: MotionFilingRole,
+    user_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    let motion = sqlx::query_as::<_, Motion>(
+        "SELECT * FROM motions WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+    )
+    .bind(motion_id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(AppError::NotFound("Motion not found".to_string()))?;
+
+    let (case_id, entry_date) = sqlx::query_as::<_, (Uuid, DateTime<Utc>)>(
+        "SELECT case_id, date FROM docket_entries WHERE id = $1 AND deleted_at IS NULL",
+    )
+    .bind(docket_entry_id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(AppError::NotFound("Docket entry not found".to_string()))?;
+    if case_id != motion.case_id {
+        return Err(AppError::Validation(
+            "Docket entry belongs to a different case than the motion".to_string(),
+        ));
+    }
+
+    let already_linked: bool = sqlx::query_scalar(
+        "SELECT EXISTS (SELECT 1 FROM motion_docket_entries WHERE motion_id = $1 AND docket_entry_id = $2)",
+    )
+    .bind(motion_id)
+    .bind(docket_entry_id)
+    .fetch_one(&mut **tx)
+    .await?;
+    if already_linked {
+        return Err(AppError::Conflict(
+            "Docket entry is already linked to this motion".to_string(),
+        ));
+    }
+
+    if role == MotionFilingRole::Filing {
+        let has_filing: bool = sqlx::query_scalar(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM motion_docket_entries l
+                JOIN docket_entries d ON d.id = l.docket_entry_id
+                WHERE l.motion_id = $1 AND l.role = 'Filing' AND d.deleted_at IS NULL
+            )
+            "#,
+        )
+        .bind(motion_id)
+        .fetch_one(&mut **tx)
+        .await?;
+        if has_filing {
+            return Err(AppError::Conflict(
+                "Motion already has a filing entry".to_string(),
+            ));
+        }
+    }
+
+    sqlx::query(
+        r#"
+        INSERT INTO motion_docket_entries (id, motion_id, docket_entry_id, role, created_at, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        "#,
+    )
+    .bind(Uuid::new_v4())
+    .bind(motion_id)
+    .bind(docket_entry_id)
+    .bind(role)
+    .bind(Utc::now())
+    .bind(user_id)
+    .execute(&mut **tx)
+    .await?;
+
+    if is_briefed(role) {
+        complete_briefing_deadline(tx, motion_id, role, user_id).await?;
+    }
+
+    let next_status = status_after_docketing(motion.status, role);
+    if let Some(next_status) = next_status {
+        record_transition(
+            tx,
+            motion_id,
+            Some(motion.status),
+            next_status,
+            Some(docket_entry_id),
+            user_id,
+        )
+        .await?;
+    }
+    let status = next_status.unwrap_or(motion.status);
+    let filing_date = match role {
+        MotionFilingRole::Filing => motion.filing_date.or(Some(entry_date)),
+        _ => motion.filing_date,
+    };
+    sqlx::query(
+        "UPDATE motions SET status = $1, filing_date = $2, updated_at = $3, updated_by = COALESCE($4, updated_by) WHERE id = $5",
+    )
+    .bind(status)
+    .bind(filing_date)
+    .bind(Utc::now())
+    .bind(user_id)
+    .bind(motion_id)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+async fn record_transition(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    motion_id: Uuid,
+    from_status: Option<MotionStatus>,
+    to_status: MotionStatus,
+    docket_entry_id: Option<Uuid>,
+    user_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        INSERT INTO motion_status_transitions (
+            id, motion_id, from_status, to_status, docket_entry_id, transitioned_at, transitioned_by
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        "#,
+    )
+    .bind(Uuid::new_v4())
+    .bind(motion_id)
+    .bind(from_status)
+    .bind(to_status)
+    .bind(docket_entry_id)
+    .bind(Utc::now())
+    .bind(user_id)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+/// Bring a motion's briefing schedule in line with its hearing date.
+///
+/// The opposition and reply are each due the number of days before the
+/// hearing set by the most specific briefing rule for the motion's type,
+/// court and jurisdiction, counted on the court's calendar. Manual
+/// overrides and stipulated extensions take precedence over the computed
+/// date. Every due date has a task and a calendar event; due dates left
+/// without a date, e.g. when the hearing is vacated, are vacated and their
+/// task and event removed.
+pub async fn sync_briefing_schedule(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    motion_id: Uuid,
+    user_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    let motion = sqlx::query_as::<_, BriefedMotion>(
+        r#"
+        SELECT m.case_id, m.title, m.type AS motion_type, m.hearing_date,
+               m.deleted_at IS NOT NULL AS deleted, c.court, c.jurisdiction, c.owner_id,
+               u.username AS owner_name
+        FROM motions m
+        JOIN cases c ON c.id = m.case_id
+        LEFT JOIN users u ON u.id = c.owner_id
+        WHERE m.id = $1
+        FOR UPDATE OF m
+        "#,
+    )
+    .bind(motion_id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(AppError::NotFound("Motion not found".to_string()))?;
+
+    let rules = match motion.hearing_date {
+        Some(_) if !motion.deleted => {
+            sqlx::query_as::<_, BriefingRule>(
+                r#"
+                SELECT * FROM briefing_rules
+                WHERE deleted_at IS NULL
+                  AND (motion_type IS NULL OR motion_type = $1)
+                  AND (court IS NULL OR court = $2)
+                  AND (jurisdiction IS NULL OR jurisdiction = $3)
+                ORDER BY created_at
+                "#,
+            )
+            .bind(motion.motion_type)
+            .bind(&motion.court)
+            .bind(&motion.jurisdiction)
+            .fetch_all(&mut **tx)
+            .await?
+        }
+        _ => Vec::new(),
+    };
+
+    let existing = sqlx::query_as::<_, BriefingDeadline>(
+        "SELECT * FROM briefing_deadlines WHERE motion_id = $1 FOR UPDATE",
+    )
+    .bind(motion_id)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    let calendar = business_calendar(
+        &mut **tx,
+        motion.court.as_deref(),
+        motion.jurisdiction.as_deref(),
+    )
+    .await?;
+    let now = Utc::now();
+
+    for role in BRIEFED_ROLES {
+        let current = existing.iter().find(|d| d.role == role);
+        let rule = applicable_rule(&rules, role);
+        let computed = rule
+            .zip(motion.hearing_date)
+            .map(|(rule, hearing_date)| {
+                briefing_due_date(
+                    hearing_date.date_naive(),
+                    rule.days_before_hearing,
+                    rule.day_count,
+                    |date| calendar.is_closed(date),
+                )
+            })
+            .transpose()
+            .map_err(AppError::Validation)?;
+        let extended_to = match current {
+            Some(deadline) => sqlx::query_scalar::<_, Option<NaiveDate>>(
+                "SELECT MAX(new_due_date) FROM briefing_extensions WHERE briefing_deadline_id = $1",
+            )
+            .bind(deadline.id)
+            .fetch_one(&mut **tx)
+            .await?,
+            None => None,
+        };
+        let due_date = effective_due_date(
+            computed.as_ref().map(|c| c.due_date),
+            current.and_then(|d| d.override_date),
+            extended_to,
+        )
+        .filter(|_| !motion.deleted);
+
+        let Some(due_date) = due_date else {
+            if let Some(current) = current {
+                vacate_briefing_deadline(tx, current, now, user_id).await?;
+            }
+            continue;
+        };
+
+        let linked_rule = match rule {
+            Some(rule) => Some(
+                sqlx::query_scalar::<_, String>("SELECT code FROM legal_rules WHERE id = $1")
+                    .bind(rule.legal_rule_id)
+                    .fetch_one(&mut **tx)
+                    .await?,
+            ),
+            None => None,
+        };
+        let mut notes = Vec::new();
+        if let (Some(code), Some(computed)) = (&linked_rule, &computed) {
+            notes.push(format!("{} ({})", computed.computation, code));
+        }
+        if let Some(override_date) = current.and_then(|d| d.override_date) {
+            notes.push(format!("due date set by hand to {}", override_date));
+        }
+        if let Some(extended_to) = extended_to {
+            notes.push(format!("extended by stipulation to {}", extended_to));
+        }
+        let title: String = format!("{:?} due: {}", role, motion.title)
+            .chars()
+            .take(500)
+            .collect();
+        let description = format!(
+            "{:?} on motion \"{}\" due {}. {}",
+            role,
+            motion.title,
+            due_date,
+            notes.join("; ")
+        );
+        let priority = rule.map(|r| r.priority.as_str()).unwrap_or("High");
+
+        let task_id = match current.and_then(|d| d.task_id) {
+            Some(task_id) => {
+                sqlx::query(
+                    r#"
+                    UPDATE workflow_tasks
+                    SET title = $1, due_date = $2, priority = $3, description = $4,
+                        deleted_at = NULL, updated_at = $5, updated_by = $6
+                    WHERE id = $7
+                    "#,
+                )
+                .bind(&title)
+                .bind(end_of_day(due_date))
+                .bind(priority)
+                .bind(&description)
+                .bind(now)
+                .bind(user_id)
+                .bind(task_id)
+                .execute(&mut **tx)
+                .await?;
+                task_id
+            }
+            None => {
+                let task_id = Uuid::new_v4();
+                sqlx::query(
+                    r#"
+                    INSERT INTO workflow_tasks (
+                        id, title, status, assignee, assignee_id, due_date, priority, description,
+                        case_id, related_module, related_item_id, related_item_title,
+                        automated_trigger, linked_rules, created_at, updated_at, created_by, updated_by
+                    )
+                    VALUES ($1, $2, 'Pending', $3, $4, $5, $6, $7, $8, 'Motions', $9, $10, 'Briefing', $11, $12, $12, $13, $13)
+                    "#,
+                )
+                .bind(task_id)
+                .bind(&title)
+                .bind(motion.owner_name.as_deref().unwrap_or("Unassigned"))
+                .bind(motion.owner_id)
+                .bind(end_of_day(due_date))
+                .bind(priority)
+                .bind(&description)
+                .bind(motion.case_id)
+                .bind(motion_id.to_string())
+                .bind(&motion.title)
+                .bind(linked_rule.iter().cloned().collect::<Vec<_>>())
+                .bind(now)
+                .bind(user_id)
+                .execute(&mut **tx)
+                .await?;
+                task_id
+            }
+        };
+
+        let calendar_event_id = match current.and_then(|d| d.calendar_event_id) {
+            Some(event_id) => {
+                sqlx::query(
+                    r#"
+                    UPDATE calendar_events
+                    SET title = $1, event_date = $2, description = $3, deleted_at = NULL,
+                        updated_at = $4, updated_by = $5
+                    WHERE id = $6
+                    "#,
+                )
+                .bind(&title)
+                .bind(due_date)
+                .bind(&description)
+                .bind(now)
+                .bind(user_id)
+                .bind(event_id)
+                .execute(&mut **tx)
+                .await?;
+                event_id
+            }
+            None => {
+                let event_id = Uuid::new_v4();
+                sqlx::query(
+                    r#"
+                    INSERT INTO calendar_events (
+                        id, case_id, title, event_date, description, related_module,
+                        related_item_id, created_at, updated_at, created_by, updated_by
+                    )
+                    VALUES ($1, $2, $3, $4, $5, 'Motions', $6, $7, $7, $8, $8)
+                    "#,
+                )
+                .bind(event_id)
+                .bind(motion.case_id)
+                .bind(&title)
+                .bind(due_date)
+                .bind(&description)
+                .bind(motion_id.to_string())
+                .bind(now)
+                .bind(user_id)
+                .execute(&mut **tx)
+                .await?;
+                event_id
+            }
+        };
+
+        match current {
+            Some(current) => {
+                sqlx::query(
+                    r#"
+                    UPDATE briefing_deadlines
+                    SET briefing_rule_id = $1, computed_date = $2, computation = $3, due_date = $4,
+                        status = CASE WHEN status = 'Vacated' THEN 'Open'::deadline_status ELSE status END,
+                        task_id = $5, calendar_event_id = $6, updated_at = $7, updated_by = $8
+                    WHERE id = $9
+                    "#,
+                )
+                .bind(rule.map(|r| r.id))
+                .bind(computed.as_ref().map(|c| c.due_date))
+                .bind(computed.as_ref().map(|c| &c.computation))
+                .bind(due_date)
+                .bind(task_id)
+                .bind(calendar_event_id)
+                .bind(now)
+                .bind(user_id)
+                .bind(current.id)
+                .execute(&mut **tx)
+                .await?;
+            }
+            None => {
+                sqlx::query(
+                    r#"
+                    INSERT INTO briefing_deadlines (
+                        id, motion_id, role, briefing_rule_id, computed_date, computation, due_date,
+                        status, task_id, calendar_event_id, created_at, updated_at, created_by, updated_by
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, 'Open', $8, $9, $10, $10, $11, $11)
+                    "#,
+                )
+                .bind(Uuid::new_v4())
+                .bind(motion_id)
+                .bind(role)
+                .bind(rule.map(|r| r.id))
+                .bind(computed.as_ref().map(|c| c.due_date))
+                .bind(computed.as_ref().map(|c| &c.computation))
+                .bind(due_date)
+                .bind(task_id)
+                .bind(calendar_event_id)
+                .bind(now)
+                .bind(user_id)
+                .execute(&mut **tx)
+                .await?;
+            }
+        }
+    }
+
+    sqlx::query(
+        r#"
+        UPDATE motions m
+        SET opposition_due_date = (
+                SELECT (b.due_date + TIME '23:59:59') AT TIME ZONE 'UTC' FROM briefing_deadlines b
+                WHERE b.motion_id = m.id AND b.role = 'Opposition' AND b.status <> 'Vacated'
+            ),
+            reply_due_date = (
+                SELECT (b.due_date + TIME '23:59:59') AT TIME ZONE 'UTC' FROM briefing_deadlines b
+                WHERE b.motion_id = m.id AND b.role = 'Reply' AND b.status <> 'Vacated'
+            )
+        WHERE m.id = $1
+        "#,
+    )
+    .bind(motion_id)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+/// Recount open briefing deadlines in the courts a calendar covers after
+/// its holidays or closures change.
+///
+/// With a window, only schedules whose count back from the hearing
+/// overlaps it are recounted.
+pub async fn resync_calendar_briefing(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    calendar: &CourtCalendar,
+    window: Option<(NaiveDate, NaiveDate)>,
+    user_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    let (from, to) = window.unzip();
+    let motion_ids = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        SELECT DISTINCT b.motion_id
+        FROM briefing_deadlines b
+        JOIN motions m ON m.id = b.motion_id
+        JOIN cases c ON c.id = m.case_id
+        WHERE b.status = 'Open' AND b.computed_date IS NOT NULL AND m.hearing_date IS NOT NULL
+          AND ($1::VARCHAR IS NULL OR c.court = $1)
+          AND ($2::VARCHAR IS NULL OR c.jurisdiction = $2)
+          AND ($3::DATE IS NULL OR (m.hearing_date AT TIME ZONE 'UTC')::DATE >= $3)
+          AND ($4::DATE IS NULL OR b.computed_date <= $4)
+        ORDER BY b.motion_id
+        "#,
+    )
+    .bind(&calendar.court)
+    .bind(&calendar.jurisdiction)
+    .bind(from)
+    .bind(to)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    for motion_id in motion_ids {
+        sync_briefing_schedule(tx, motion_id, user_id).await?;
+    }
+
+    Ok(())
+}
+
+async fn sync_rule_briefing(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    briefing_rule_id: Uuid,
+    user_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    let motion_ids = sqlx::query_scalar::<_, Uuid>(
+        "SELECT DISTINCT motion_id FROM briefing_deadlines WHERE briefing_rule_id = $1 ORDER BY motion_id",
+    )
+    .bind(briefing_rule_id)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    for motion_id in motion_ids {
+        sync_briefing_schedule(tx, motion_id, user_id).await?;
+    }
+
+    Ok(())
+}
+
+async fn vacate_briefing_deadline(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    deadline: &BriefingDeadline,
+    now: DateTime<Utc>,
+    user_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        UPDATE briefing_deadlines
+        SET briefing_rule_id = NULL, computed_date = NULL, computation = NULL,
+            status = CASE WHEN status = 'Open' THEN 'Vacated'::deadline_status ELSE status END,
+            updated_at = $1, updated_by = $2
+        WHERE id = $3
+        "#,
+    )
+    .bind(now)
+    .bind(user_id)
+    .bind(deadline.id)
+    .execute(&mut **tx)
+    .await?;
+
+    if deadline.status == DeadlineStatus::Open {
+        if let Some(task_id) = deadline.task_id {
+            sqlx::query(
+                "UPDATE workflow_tasks SET deleted_at = $1, updated_at = $1, updated_by = $2 WHERE id = $3",
+            )
+            .bind(now)
+            .bind(user_id)
+            .bind(task_id)
+            .execute(&mut **tx)
+            .await?;
+        }
+        if let Some(event_id) = deadline.calendar_event_id {
+            sqlx::query(
+                "UPDATE calendar_events SET deleted_at = $1, updated_at = $1, updated_by = $2 WHERE id = $3",
+            )
+            .bind(now)
+            .bind(user_id)
+            .bind(event_id)
+            .execute(&mut **tx)
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Mark a brief's open due date met once the brief is docketed
+async fn complete_briefing_deadline(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    motion_id: Uuid,
+    role: MotionFilingRole,
+    user_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    let now = Utc::now();
+    let task_id = sqlx::query_scalar::<_, Option<Uuid>>(
+        r#"
+        UPDATE briefing_deadlines
+        SET status = 'Completed', completed_at = $1, updated_at = $1, updated_by = $2
+        WHERE motion_id = $3 AND role = $4 AND status = 'Open'
+        RETURNING task_id
+        "#,
+    )
+    .bind(now)
+    .bind(user_id)
+    .bind(motion_id)
+    .bind(role)
+    .fetch_optional(&mut **tx)
+    .await?
+    .flatten();
+
+    if let Some(task_id) = task_id {
+        sqlx::query(
+            "UPDATE workflow_tasks SET status = 'Completed', completion = 100, updated_at = $1, updated_by = $2 WHERE id = $3",
+        )
+        .bind(now)
+        .bind(user_id)
+        .bind(task_id)
+        .execute(&mut **tx)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Lock a motion for a change to its briefing schedule, returning its case
+async fn lock_briefed_motion(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    motion_id: Uuid,
+) -> Result<Uuid, AppError> {
+    sqlx::query_scalar::<_, Uuid>(
+        "SELECT case_id FROM motions WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+    )
+    .bind(motion_id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(AppError::NotFound("Motion not found".to_string()))
+}
+
+async fn lock_briefing_deadline(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    motion_id: Uuid,
+    role: MotionFilingRole,
+) -> Result<Option<BriefingDeadline>, AppError> {
+    let deadline = sqlx::query_as::<_, BriefingDeadline>(
+        "SELECT * FROM briefing_deadlines WHERE motion_id = $1 AND role = $2 FOR UPDATE",
+    )
+    .bind(motion_id)
+    .bind(role)
+    .fetch_optional(&mut **tx)
+    .await?;
+
+    Ok(deadline)
+}
+
+fn validate_briefed_role(role: MotionFilingRole) -> Result<(), AppError> {
+    if !is_briefed(role) {
+        return Err(AppError::Validation(
+            "Only the opposition and reply have briefing due dates".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_briefing_rule(
+    role: MotionFilingRole,
+    days_before_hearing: i32,
+    priority: &str,
+) -> Result<(), AppError> {
+    validate_briefed_role(role)?;
+    if !(1..=MAX_DAYS_BEFORE_HEARING).contains(&days_before_hearing) {
+        return Err(AppError::Validation(format!(
+            "Days before hearing must be between 1 and {}",
+            MAX_DAYS_BEFORE_HEARING
+        )));
+    }
+    if !matches!(priority, "Low" | "Medium" | "High" | "Critical") {
+        return Err(AppError::Validation(
+            "Priority must be Low, Medium, High or Critical".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+async fn motion_entries<'e, E>(
+    executor: E,
+    motion_id: Uuid,
+) -> Result<Vec<MotionDocketEntry>, AppError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let entries = sqlx::query_as::<_, MotionDocketEntry>(
+        r#"
+        SELECT l.id, l.motion_id, l.docket_entry_id, l.role, d.sequence_number,
+               d.date AS entry_date, l.created_at, l.created_by
+        FROM motion_docket_entries l
+        JOIN docket_entries d ON d.id = l.docket_entry_id
+        WHERE l.motion_id = $1 AND d.deleted_at IS NULL
+        ORDER BY d.sequence_number
+        "#,
+    )
+    .bind(motion_id)
+    .fetch_all(executor)
+    .await?;
+
+    Ok(entries)
 }
@@ -0,0 +1,61 @@
+//! The status flow a motion follows from drafting to decision
+
+use crate::models::MotionStatus;
+
+/// Statuses in the order a motion moves through them
+pub const STATUS_FLOW: [MotionStatus; 7] = [
+    MotionStatus::Draft,
+    MotionStatus::Filed,
+    MotionStatus::OppositionServed,
+    MotionStatus::ReplyServed,
+    MotionStatus::HearingSet,
+    MotionStatus::Submitted,
+    MotionStatus::Decided,
+];
+
+/// Statuses a motion starts in, e.g. when entered after it was filed
+pub const INITIAL_STATUSES: [MotionStatus; 2] = [MotionStatus::Draft, MotionStatus::Filed];
+
+/// Whether a motion is finished with: decided or withdrawn
+pub fn is_terminal(status: MotionStatus) -> bool {
+    matches!(status, MotionStatus::Decided | MotionStatus::Withdrawn)
+}
+
+/// Whether a motion still awaits a decision; the dashboard counts these
+pub fn is_pending(status: MotionStatus) -> bool {
+    !is_terminal(status)
+}
+
+/// Statuses a motion may move to by hand: the next step of the flow, or
+/// `Withdrawn` at any point before it is decided
+pub fn next_statuses(current: MotionStatus) -> Vec<MotionStatus> {
+    if is_terminal(current) {
+        return Vec::new();
+    }
+    let mut next: Vec<MotionStatus> = STATUS_FLOW
+        .iter()
+        .skip_while(|s| **s != current)
+        .skip(1)
+        .take(1)
+        .copied()
+        .collect();
+    next.push(MotionStatus::Withdrawn);
+    next
+}
+
+/// Whether a motion may move by hand from one status to another
+pub fn can_transition(from: MotionStatus, to: MotionStatus) -> bool {
+    next_statuses(from).contains(&to)
+}
+
+/// Position of a status in the flow; decided and withdrawn motions are
+/// both at the end
+pub fn stage(status: MotionStatus) -> u8 {
+    match status {
+        MotionStatus::Withdrawn => STATUS_FLOW.len() as u8 - 1,
+        status => STATUS_FLOW
+            .iter()
+            .position(|s| *s == status)
+            .expect("every other status is in the flow") as u8,
+    }
+}
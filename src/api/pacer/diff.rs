@@ -0,0 +1,165 @@
+//! Matching docket report entries against the docket on file
+
+use crate::api::pacer::parser::PacerDocketEntry;
+use crate::models::DocketEntryType;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Longest docket entry title; the full text is kept as the description
+pub const ENTRY_TITLE_MAX_CHARS: usize = 200;
+
+/// Docket entry on file, as compared against a report
+#[derive(Debug, Clone, FromRow)]
+pub struct ExistingEntry {
+    pub id: Uuid,
+    pub pacer_sequence_number: Option<i32>,
+    pub date: DateTime<Utc>,
+    pub description: Option<String>,
+    pub is_sealed: bool,
+}
+
+/// How a report entry relates to the docket on file
+#[derive(Debug, Clone, PartialEq)]
+pub enum EntryMatch<'a> {
+    New(&'a PacerDocketEntry),
+    Unchanged {
+        id: Uuid,
+    },
+    Changed {
+        id: Uuid,
+        entry: &'a PacerDocketEntry,
+        changed_fields: Vec<&'static str>,
+        /// Text on file, withheld when the entry is sealed
+        previous_text: Option<String>,
+        sealed: bool,
+    },
+}
+
+/// Comparison of a docket report with the docket on file
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocketDiff<'a> {
+    pub matches: Vec<EntryMatch<'a>>,
+    /// PACER numbers on file that the report does not list
+    pub missing_from_report: Vec<i32>,
+}
+
+/// Match report entries to entries on file.
+///
+/// Numbered entries match on their PACER number. Unnumbered ones (minute
+/// entries and the like) have nothing stable to match on, so they match an
+/// unnumbered entry on file with the same date and text, or are new.
+pub fn diff_entries<'a>(
+    existing: &[ExistingEntry],
+    incoming: &'a [PacerDocketEntry],
+) -> DocketDiff<'a> {
+    let mut claimed: Vec<Uuid> = Vec::new();
+    let mut matches = Vec::with_capacity(incoming.len());
+
+    for entry in incoming {
+        let on_file = match entry.number {
+            Some(number) => existing
+                .iter()
+                .find(|e| e.pacer_sequence_number == Some(number) && !claimed.contains(&e.id)),
+            None => existing.iter().find(|e| {
+                e.pacer_sequence_number.is_none()
+                    && !claimed.contains(&e.id)
+                    && e.date.date_naive() == entry.date_filed
+                    && e.description.as_deref() == Some(entry.text.as_str())
+            }),
+        };
+
+        let Some(on_file) = on_file else {
+            matches.push(EntryMatch::New(entry));
+            continue;
+        };
+        claimed.push(on_file.id);
+
+        let mut changed_fields = Vec::new();
+        if on_file.date.date_naive() != entry.date_filed {
+            changed_fields.push("date");
+        }
+        if on_file.description.as_deref() != Some(entry.text.as_str()) {
+            changed_fields.push("text");
+        }
+
+        matches.push(if changed_fields.is_empty() {
+            EntryMatch::Unchanged { id: on_file.id }
+        } else {
+            EntryMatch::Changed {
+                id: on_file.id,
+                entry,
+                changed_fields,
+                previous_text: on_file.description.clone().filter(|_| !on_file.is_sealed),
+                sealed: on_file.is_sealed,
+            }
+        });
+    }
+
+    let mut missing_from_report: Vec<i32> = existing
+        .iter()
+        .filter_map(|e| e.pacer_sequence_number)
+        .filter(|number| !incoming.iter().any(|i| i.number == Some(*number)))
+        .collect();
+    missing_from_report.sort_unstable();
+    missing_from_report.dedup();
+
+    DocketDiff {
+        matches,
+        missing_from_report,
+    }
+}
+
+/// Docket entry type suggested by the opening of the docket text
+pub fn entry_type(text: &str) -> DocketEntryType {
+    let upper = text.to_uppercase();
+    if upper.starts_with("MINUTE ENTRY") {
+        DocketEntryType::MinuteEntry
+    } else if upper.starts_with("NOTICE") {
+        DocketEntryType::Notice
+    } else if upper.starts_with("EXHIBIT") {
+        DocketEntryType::Exhibit
+    } else if upper.starts_with("HEARING") || upper.starts_with("TELEPHONE CONFERENCE") {
+        DocketEntryType::Hearing
+    } else if upper.starts_with("ORDER")
+        || upper.contains("SO ORDERED")
+        || upper.contains("(SIGNED BY")
+    {
+        DocketEntryType::Order
+    } else {
+        DocketEntryType::Filing
+    }
+}
+
+/// Short title for an entry: its first sentence, cut at a word boundary
+pub fn entry_title(text: &str) -> String {
+    let sentence = text
+        .find(". ")
+        .or_else(|| text.find(".("))
+        .map(|end| &text[..end])
+        .unwrap_or(text)
+        .trim();
+    if sentence.chars().count() <= ENTRY_TITLE_MAX_CHARS {
+        return sentence.to_string();
+    }
+
+    let mut title = String::new();
+    for word in sentence.split(' ') {
+        if title.chars().count() + word.chars().count() + 4 > ENTRY_TITLE_MAX_CHARS {
+            break;
+        }
+        if !title.is_empty() {
+            title.push(' ');
+        }
+        title.push_str(word);
+    }
+    title.push_str("...");
+    title
+}
+
+/// Midnight UTC on a report date
+pub fn report_timestamp(date: NaiveDate) -> DateTime<Utc> {
+    date.and_hms_opt(0, 0, 0)
+        .map(|t| t.and_utc())
+        .unwrap_or_default()
+}
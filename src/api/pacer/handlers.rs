@@ -0,0 +1,99 @@
+use crate::api::pacer::service::{ImportDocketParams, PacerService};
+use crate::error::AppError;
+use crate::models::{Claims, PacerImportResult};
+use axum::{
+    extract::{Multipart, Query, State},
+    response::Json,
+    Extension,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ImportDocketQuery {
+    /// Report what would change without saving anything
+    pub dry_run: Option<bool>,
+}
+
+/// Multipart upload of a saved CM/ECF docket report
+#[derive(Debug, ToSchema)]
+pub struct ImportDocketRequest {
+    /// Docket report HTML
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+    /// Case to import into; otherwise matched on court and case number
+    pub case_id: Option<Uuid>,
+    /// Client of the case, required when the import creates it
+    pub client: Option<String>,
+}
+
+/// Import a CM/ECF docket report into a case, its parties and its docket
+#[utoipa::path(
+    post,
+    path = "/api/pacer/import",
+    params(
+        ("dry_run" = Option<bool>, Query, description = "Report changes without saving them")
+    ),
+    request_body(content = ImportDocketRequest, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Import result with the entries added or changed", body = PacerImportResult),
+        (status = 400, description = "Not a docket report, or a client is needed to create the case"),
+        (status = 404, description = "Case not found"),
+        (status = 409, description = "Case is linked to a different PACER case"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "pacer",
+    security(("bearer_auth" = []))
+)]
+pub async fn import_docket_report(
+    State(service): State<Arc<PacerService>>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<ImportDocketQuery>,
+    mut multipart: Multipart,
+) -> Result<Json<PacerImportResult>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let mut html = None;
+    let mut case_id = None;
+    let mut client = None;
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| AppError::BadRequest(format!("Invalid multipart body: {}", e)))?
+    {
+        let name = field.name().unwrap_or_default().to_string();
+        let value = field
+            .bytes()
+            .await
+            .map_err(|e| AppError::BadRequest(format!("Failed to read upload: {}", e)))?;
+        match name.as_str() {
+            "file" => html = Some(String::from_utf8_lossy(&value).into_owned()),
+            "case_id" => {
+                case_id = Some(
+                    Uuid::parse_str(String::from_utf8_lossy(&value).trim())
+                        .map_err(|_| AppError::BadRequest("Invalid case_id".to_string()))?,
+                )
+            }
+            "client" => client = Some(String::from_utf8_lossy(&value).into_owned()),
+            _ => {}
+        }
+    }
+
+    let html = html.ok_or(AppError::BadRequest(
+        "Multipart body must contain a \"file\" field".to_string(),
+    ))?;
+
+    let result = service
+        .import_docket(ImportDocketParams {
+            html,
+            case_id,
+            client,
+            dry_run: query.dry_run.unwrap_or(false),
+            user_id,
+        })
+        .await?;
+    Ok(Json(result))
+}
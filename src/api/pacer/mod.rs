@@ -0,0 +1,7 @@
+pub mod diff;
+pub mod handlers;
+pub mod parser;
+pub mod service;
+
+pub use handlers::*;
+pub use service::*;
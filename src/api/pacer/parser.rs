@@ -0,0 +1,376 @@
+//! Parser for CM/ECF civil docket report HTML, as saved from PACER
+
+use crate::error::AppError;
+use chrono::NaiveDate;
+use scraper::{ElementRef, Html, Node, Selector};
+use serde::{Deserialize, Serialize};
+
+/// Case, parties and entries read from a docket report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PacerDocket {
+    /// Court name, e.g. "Southern District of New York"
+    pub court: String,
+    /// CM/ECF case number, e.g. "1:23-cv-04567-JPO"
+    pub case_number: String,
+    pub case_title: String,
+    pub assigned_to: Option<String>,
+    pub referred_to: Option<String>,
+    pub cause: Option<String>,
+    pub date_filed: Option<NaiveDate>,
+    pub date_terminated: Option<NaiveDate>,
+    pub jury_demand: Option<String>,
+    pub nature_of_suit: Option<String>,
+    pub jurisdiction: Option<String>,
+    pub parties: Vec<PacerParty>,
+    pub entries: Vec<PacerDocketEntry>,
+}
+
+/// Party as listed on the docket report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PacerParty {
+    pub name: String,
+    /// Party heading on the report, e.g. "Plaintiff" or "Counter Claimant"
+    pub role: String,
+    /// Lines listed under the name, such as a termination date
+    pub details: Vec<String>,
+    pub attorneys: Vec<PacerAttorney>,
+}
+
+/// Attorney of record for a party
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PacerAttorney {
+    pub name: String,
+    /// Firm and address lines
+    pub address_lines: Vec<String>,
+    pub phone: Option<String>,
+    pub fax: Option<String>,
+    pub email: Option<String>,
+    /// Notations such as "LEAD ATTORNEY" or "ATTORNEY TO BE NOTICED"
+    pub designations: Vec<String>,
+}
+
+/// Row of the docket text table
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PacerDocketEntry {
+    pub date_filed: NaiveDate,
+    /// Docket number; minute entries and other text-only entries have none
+    pub number: Option<i32>,
+    pub text: String,
+}
+
+impl PacerDocketEntry {
+    /// Filer named in the docket text ("Document filed by ..." or "Filed by ...")
+    pub fn filed_by(&self) -> Option<String> {
+        let lower = self.text.to_lowercase();
+        let start = lower.find("filed by ")? + "filed by ".len();
+        let rest = &self.text[start..];
+        let end = rest
+            .find(".(")
+            .or_else(|| rest.find(". "))
+            .or_else(|| rest.find('('));
+        let name = rest[..end.unwrap_or(rest.len())].trim();
+        (!name.is_empty()).then(|| name.to_string())
+    }
+}
+
+/// Parse a saved CM/ECF docket report
+pub fn parse_docket_report(html: &str) -> Result<PacerDocket, AppError> {
+    let document = Html::parse_document(html);
+
+    let heading = document
+        .select(&selector("h3"))
+        .map(|h| text_lines(h))
+        .find(|lines| lines.iter().any(|l| l.contains("DOCKET FOR CASE #:")))
+        .ok_or(AppError::Validation(
+            "Not a CM/ECF docket report: no case heading found".to_string(),
+        ))?;
+    let case_number = heading
+        .iter()
+        .find_map(|l| l.split_once("DOCKET FOR CASE #:"))
+        .map(|(_, number)| number.trim().to_string())
+        .filter(|number| !number.is_empty())
+        .ok_or(AppError::Validation(
+            "Docket report has no case number".to_string(),
+        ))?;
+    let court = heading
+        .iter()
+        .filter(|l| !l.contains("DOCKET FOR CASE") && !l.starts_with("U.S. "))
+        .map(|l| strip_division(l))
+        .next()
+        .unwrap_or_default();
+
+    let mut docket = PacerDocket {
+        court,
+        case_number,
+        case_title: String::new(),
+        assigned_to: None,
+        referred_to: None,
+        cause: None,
+        date_filed: None,
+        date_terminated: None,
+        jury_demand: None,
+        nature_of_suit: None,
+        jurisdiction: None,
+        parties: Vec::new(),
+        entries: Vec::new(),
+    };
+
+    let tables: Vec<ElementRef> = document.select(&selector("table")).collect();
+
+    // Case information
+    if let Some(info) = tables.iter().find(|t| {
+        text_lines(**t)
+            .iter()
+            .any(|l| l.starts_with("Assigned to:"))
+    }) {
+        for line in text_lines(*info) {
+            match line.split_once(':') {
+                Some((label, value)) if is_case_label(label) => {
+                    let value = value.trim().to_string();
+                    let value = (!value.is_empty()).then_some(value);
+                    match label {
+                        "Assigned to" => docket.assigned_to = value,
+                        "Referred to" => docket.referred_to = value,
+                        "Cause" => docket.cause = value,
+                        "Date Filed" => docket.date_filed = value.as_deref().and_then(parse_date),
+                        "Date Terminated" => {
+                            docket.date_terminated = value.as_deref().and_then(parse_date)
+                        }
+                        "Jury Demand" => docket.jury_demand = value,
+                        "Nature of Suit" => docket.nature_of_suit = value,
+                        "Jurisdiction" => docket.jurisdiction = value,
+                        _ => {}
+                    }
+                }
+                _ if docket.case_title.is_empty() => docket.case_title = line,
+                _ => {}
+            }
+        }
+    }
+
+    // Parties and their attorneys, grouped under underlined role headings
+    if let Some(parties) = tables.iter().find(|t| {
+        t.select(&selector("b > u")).next().is_some() && t.select(&selector("th")).next().is_none()
+    }) {
+        let mut role: Option<String> = None;
+        for row in direct_rows(*parties) {
+            if let Some(heading) = row.select(&selector("b > u")).next() {
+                role = Some(normalize(&heading.text().collect::<String>()));
+                continue;
+            }
+            let (Some(role), Some(name_cell)) = (&role, cells(row).into_iter().next()) else {
+                continue;
+            };
+            let Some(name) = name_cell.select(&selector("b")).next() else {
+                continue;
+            };
+            let name = normalize(&name.text().collect::<String>());
+            if name.is_empty() {
+                continue;
+            }
+            let details = text_lines(name_cell).into_iter().skip(1).collect();
+            let attorneys = cells(row)
+                .into_iter()
+                .nth(2)
+                .map(parse_attorneys)
+                .unwrap_or_default();
+            docket.parties.push(PacerParty {
+                name,
+                role: role.clone(),
+                details,
+                attorneys,
+            });
+        }
+    }
+
+    // Docket text table
+    if let Some(entries) = tables.iter().find(|t| {
+        direct_rows(**t)
+            .first()
+            .is_some_and(|r| normalize(&r.text().collect::<String>()).contains("Docket Text"))
+    }) {
+        for row in direct_rows(*entries).into_iter().skip(1) {
+            let cells = cells(row);
+            if cells.len() < 3 {
+                continue;
+            }
+            let Some(date_filed) = parse_date(&normalize(&cells[0].text().collect::<String>()))
+            else {
+                continue;
+            };
+            let number = normalize(&cells[1].text().collect::<String>())
+                .parse::<i32>()
+                .ok();
+            docket.entries.push(PacerDocketEntry {
+                date_filed,
+                number,
+                text: normalize(&cells[2].text().collect::<String>()),
+            });
+        }
+    }
+
+    Ok(docket)
+}
+
+fn selector(css: &str) -> Selector {
+    Selector::parse(css).expect("static selector")
+}
+
+fn is_case_label(label: &str) -> bool {
+    matches!(
+        label,
+        "Assigned to"
+            | "Referred to"
+            | "Cause"
+            | "Date Filed"
+            | "Date Terminated"
+            | "Jury Demand"
+            | "Nature of Suit"
+            | "Jurisdiction"
+            | "Demand"
+            | "Case in other court"
+    )
+}
+
+/// "Southern District of New York (Foley Square)" -> "Southern District of New York"
+fn strip_division(line: &str) -> String {
+    match line.find(" (") {
+        Some(index) if line.ends_with(')') => line[..index].to_string(),
+        _ => line.to_string(),
+    }
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%m/%d/%Y").ok()
+}
+
+/// Collapse whitespace, including non-breaking spaces
+fn normalize(text: &str) -> String {
+    text.split(|c: char| c.is_whitespace() || c == '\u{a0}')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Non-empty lines of an element's text, split at `<br>` and block boundaries
+fn text_lines(element: ElementRef) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for node in element.descendants() {
+        match node.value() {
+            Node::Text(text) => current.push_str(text),
+            Node::Element(e) if matches!(e.name(), "br" | "td" | "tr" | "p" | "div") => {
+                lines.push(std::mem::take(&mut current));
+            }
+            _ => {}
+        }
+    }
+    lines.push(current);
+    lines
+        .iter()
+        .map(|l| normalize(l))
+        .filter(|l| !l.is_empty())
+        .collect()
+}
+
+/// Rows of a table, not of tables nested in it
+fn direct_rows(table: ElementRef) -> Vec<ElementRef> {
+    table
+        .select(&selector("tr"))
+        .filter(|row| {
+            row.ancestors()
+                .filter_map(ElementRef::wrap)
+                .find(|a| a.value().name() == "table")
+                .is_some_and(|t| t.id() == table.id())
+        })
+        .collect()
+}
+
+fn cells(row: ElementRef) -> Vec<ElementRef> {
+    row.children()
+        .filter_map(ElementRef::wrap)
+        .filter(|c| matches!(c.value().name(), "td" | "th"))
+        .collect()
+}
+
+/// Attorneys listed in a "represented by" cell; each starts with a bold name
+fn parse_attorneys(cell: ElementRef) -> Vec<PacerAttorney> {
+    let mut attorneys: Vec<PacerAttorney> = Vec::new();
+    let mut line = String::new();
+    let mut italic = false;
+
+    let flush = |attorneys: &mut Vec<PacerAttorney>, line: &mut String, italic: bool| {
+        let text = normalize(line);
+        line.clear();
+        let Some(attorney) = attorneys.last_mut() else {
+            return;
+        };
+        if text.is_empty() {
+            return;
+        }
+        if italic {
+            attorney.designations.push(text);
+        } else if let Some(email) = text.strip_prefix("Email:") {
+            attorney.email = Some(email.trim().to_string());
+        } else if let Some(fax) = text.strip_prefix("Fax:") {
+            attorney.fax = Some(fax.trim().to_string());
+        } else if attorney.phone.is_none() && is_phone_number(&text) {
+            attorney.phone = Some(text);
+        } else {
+            attorney.address_lines.push(text);
+        }
+    };
+
+    for node in cell.descendants() {
+        match node.value() {
+            Node::Element(e) if e.name() == "b" => {
+                flush(&mut attorneys, &mut line, italic);
+                let name = ElementRef::wrap(node)
+                    .map(|b| normalize(&b.text().collect::<String>()))
+                    .unwrap_or_default();
+                if !name.is_empty() {
+                    attorneys.push(PacerAttorney {
+                        name,
+                        address_lines: Vec::new(),
+                        phone: None,
+                        fax: None,
+                        email: None,
+                        designations: Vec::new(),
+                    });
+                }
+            }
+            Node::Element(e) if e.name() == "br" => {
+                flush(&mut attorneys, &mut line, italic);
+                italic = false;
+            }
+            Node::Text(text) => {
+                let in_bold = node
+                    .ancestors()
+                    .filter_map(ElementRef::wrap)
+                    .take_while(|a| a.id() != cell.id())
+                    .any(|a| a.value().name() == "b");
+                if !in_bold {
+                    italic |= node
+                        .ancestors()
+                        .filter_map(ElementRef::wrap)
+                        .take_while(|a| a.id() != cell.id())
+                        .any(|a| a.value().name() == "i");
+                    line.push_str(text);
+                }
+            }
+            _ => {}
+        }
+    }
+    flush(&mut attorneys, &mut line, italic);
+
+    attorneys
+}
+
+/// "212-555-0142" or "(646) 555-0199"
+fn is_phone_number(text: &str) -> bool {
+    let digits = text.chars().filter(|c| c.is_ascii_digit()).count();
+    digits >= 10
+        && text
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '-' | '(' | ')' | ' ' | '.' | '+'))
+}
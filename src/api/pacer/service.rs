@@ -0,0 +1,563 @@
+use crate::api::deadlines::sync_entry_deadlines;
+use crate::api::docket::{has_grant, lock_docket, log_access, next_sequence_number};
+use crate::api::pacer::diff::{
+    diff_entries, entry_title, entry_type, report_timestamp, EntryMatch, ExistingEntry,
+};
+use crate::api::pacer::parser::{parse_docket_report, PacerDocket, PacerParty};
+use crate::error::AppError;
+use crate::models::{
+    Case, CaseStatus, MatterType, PacerEntryChange, PacerEntryChangeKind, PacerImportResult, Party,
+    SealedAccessAction,
+};
+use chrono::Utc;
+use serde_json::json;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Largest docket report accepted for import
+pub const MAX_DOCKET_REPORT_BYTES: usize = 20 * 1024 * 1024;
+
+/// Parameters for importing a saved CM/ECF docket report
+pub struct ImportDocketParams {
+    /// Docket report HTML as saved from PACER
+    pub html: String,
+    /// Case to import into (optional; otherwise matched on court and case number)
+    pub case_id: Option<Uuid>,
+    /// Client of a case created by the import (required when no case matches)
+    pub client: Option<String>,
+    /// Compare against the docket on file without saving anything
+    pub dry_run: bool,
+    /// ID of the user running the import
+    pub user_id: Uuid,
+}
+
+pub struct PacerService {
+    pool: PgPool,
+}
+
+impl PacerService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Import a docket report into a case, its parties and its docket.
+    ///
+    /// Re-importing the same report changes nothing. Entries match on their
+    /// PACER number; entries whose date or text changed since the last
+    /// import are updated and reported, and PACER numbers on file that the
+    /// report no longer lists are flagged but kept. Changing a sealed entry
+    /// takes a grant to it, like any other update, and its previous text is
+    /// not reported.
+    pub async fn import_docket(
+        &self,
+        params: ImportDocketParams,
+    ) -> Result<PacerImportResult, AppError> {
+        let docket = parse_docket_report(&params.html)?;
+        let mut tx = self.pool.begin().await?;
+
+        let (case, case_created) = match params.case_id {
+            Some(case_id) => (find_case(&mut tx, case_id, &docket).await?, false),
+            None => match find_case_by_number(&mut tx, &docket).await? {
+                Some(case) => (case, false),
+                None => {
+                    let client = params
+                        .client
+                        .as_deref()
+                        .map(str::trim)
+                        .filter(|c| !c.is_empty())
+                        .ok_or(AppError::Validation(format!(
+                            "No case matches {}; a client is required to create it",
+                            docket.case_number
+                        )))?;
+                    (
+                        create_case(&mut tx, &docket, client, params.user_id).await?,
+                        true,
+                    )
+                }
+            },
+        };
+
+        lock_docket(&mut tx, case.id).await?;
+
+        let case_changes = if case_created {
+            Vec::new()
+        } else {
+            update_case(&mut tx, &case, &docket, params.user_id).await?
+        };
+
+        let mut result = PacerImportResult {
+            case_id: Some(case.id),
+            case_created,
+            dry_run: params.dry_run,
+            court: docket.court.clone(),
+            case_number: docket.case_number.clone(),
+            case_changes,
+            parties_added: 0,
+            parties_updated: 0,
+            entries_added: 0,
+            entries_changed: 0,
+            entries_unchanged: 0,
+            entry_changes: Vec::new(),
+            missing_from_report: Vec::new(),
+        };
+
+        for party in &docket.parties {
+            match upsert_party(&mut tx, case.id, party, params.user_id).await? {
+                PartyUpsert::Added => result.parties_added += 1,
+                PartyUpsert::Updated => result.parties_updated += 1,
+                PartyUpsert::Unchanged => {}
+            }
+        }
+
+        let existing = sqlx::query_as::<_, ExistingEntry>(
+            r#"
+            SELECT id, pacer_sequence_number, date, description,
+                   COALESCE(is_sealed, false) AS is_sealed
+            FROM docket_entries
+            WHERE case_id = $1 AND deleted_at IS NULL
+            ORDER BY sequence_number
+            "#,
+        )
+        .bind(case.id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let diff = diff_entries(&existing, &docket.entries);
+        let now = Utc::now();
+        for entry_match in diff.matches {
+            match entry_match {
+                EntryMatch::Unchanged { .. } => result.entries_unchanged += 1,
+                EntryMatch::New(entry) => {
+                    let sequence_number = next_sequence_number(&mut tx, case.id).await?;
+                    sqlx::query(
+                        r#"
+                        INSERT INTO docket_entries (
+                            id, case_id, sequence_number, pacer_sequence_number, type, title,
+                            description, date, filed_by, created_at, updated_at, created_by
+                        )
+                        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $10, $11)
+                        "#,
+                    )
+                    .bind(Uuid::new_v4())
+                    .bind(case.id)
+                    .bind(sequence_number)
+                    .bind(entry.number)
+                    .bind(entry_type(&entry.text))
+                    .bind(entry_title(&entry.text))
+                    .bind(&entry.text)
+                    .bind(report_timestamp(entry.date_filed))
+                    .bind(entry.filed_by())
+                    .bind(now)
+                    .bind(params.user_id)
+                    .execute(&mut *tx)
+                    .await?;
+
+                    result.entries_added += 1;
+                    result.entry_changes.push(PacerEntryChange {
+                        kind: PacerEntryChangeKind::Added,
+                        pacer_sequence_number: entry.number,
+                        date_filed: entry.date_filed,
+                        changed_fields: Vec::new(),
+                        previous_text: None,
+                        text: entry.text.clone(),
+                    });
+                }
+                EntryMatch::Changed {
+                    id,
+                    entry,
+                    changed_fields,
+                    previous_text,
+                    sealed,
+                } => {
+                    if sealed {
+                        if !has_grant(&mut *tx, id, params.user_id).await? {
+                            log_access(
+                                &self.pool,
+                                id,
+                                params.user_id,
+                                SealedAccessAction::Denied,
+                                None,
+                                Some("PACER import refused without a grant"),
+                            )
+                            .await?;
+                            return Err(AppError::Authorization(match entry.number {
+                                Some(number) => format!("Docket entry {} is sealed", number),
+                                None => "A docket entry the report changes is sealed".to_string(),
+                            }));
+                        }
+                        log_access(
+                            &mut *tx,
+                            id,
+                            params.user_id,
+                            SealedAccessAction::Modified,
+                            None,
+                            Some("Updated from a PACER docket report"),
+                        )
+                        .await?;
+                    }
+                    sqlx::query(
+                        r#"
+                        UPDATE docket_entries
+                        SET type = $1, title = $2, description = $3, date = $4, filed_by = $5,
+                            updated_at = $6, updated_by = $7
+                        WHERE id = $8
+                        "#,
+                    )
+                    .bind(entry_type(&entry.text))
+                    .bind(entry_title(&entry.text))
+                    .bind(&entry.text)
+                    .bind(report_timestamp(entry.date_filed))
+                    .bind(entry.filed_by())
+                    .bind(now)
+                    .bind(params.user_id)
+                    .bind(id)
+                    .execute(&mut *tx)
+                    .await?;
+                    sync_entry_deadlines(&mut tx, id, Some(params.user_id)).await?;
+
+                    result.entries_changed += 1;
+                    result.entry_changes.push(PacerEntryChange {
+                        kind: PacerEntryChangeKind::Changed,
+                        pacer_sequence_number: entry.number,
+                        date_filed: entry.date_filed,
+                        changed_fields: changed_fields.into_iter().map(String::from).collect(),
+                        previous_text,
+                        text: entry.text.clone(),
+                    });
+                }
+            }
+        }
+        result.missing_from_report = diff.missing_from_report;
+
+        if !result.missing_from_report.is_empty() {
+            tracing::warn!(
+                case_id = %case.id,
+                missing = ?result.missing_from_report,
+                "PACER docket report no longer lists entries on file"
+            );
+        }
+
+        if params.dry_run {
+            tx.rollback().await?;
+            if case_created {
+                result.case_id = None;
+            }
+        } else {
+            tx.commit().await?;
+        }
+
+        Ok(result)
+    }
+}
+
+enum PartyUpsert {
+    Added,
+    Updated,
+    Unchanged,
+}
+
+/// Summary of the report kept on the case
+fn case_pacer_data(docket: &PacerDocket) -> serde_json::Value {
+    json!({
+        "court": docket.court,
+        "case_number": docket.case_number,
+        "cause": docket.cause,
+        "jury_demand": docket.jury_demand,
+        "date_filed": docket.date_filed,
+        "date_terminated": docket.date_terminated,
+    })
+}
+
+/// Case named in the request, which must not already track another PACER case
+async fn find_case(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    case_id: Uuid,
+    docket: &PacerDocket,
+) -> Result<Case, AppError> {
+    let case = sqlx::query_as::<_, Case>(
+        "SELECT * FROM cases WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+    )
+    .bind(case_id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(AppError::NotFound("Case not found".to_string()))?;
+
+    let tracked = case
+        .pacer_data
+        .as_ref()
+        .and_then(|d| d.get("case_number"))
+        .and_then(|n| n.as_str());
+    match tracked {
+        Some(number) if number != docket.case_number => Err(AppError::Conflict(format!(
+            "Case is linked to PACER case {}, not {}",
+            number, docket.case_number
+        ))),
+        _ => Ok(case),
+    }
+}
+
+async fn find_case_by_number(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    docket: &PacerDocket,
+) -> Result<Option<Case>, AppError> {
+    let case = sqlx::query_as::<_, Case>(
+        r#"
+        SELECT * FROM cases
+        WHERE pacer_data->>'court' = $1 AND pacer_data->>'case_number' = $2
+          AND deleted_at IS NULL
+        FOR UPDATE
+        "#,
+    )
+    .bind(&docket.court)
+    .bind(&docket.case_number)
+    .fetch_optional(&mut **tx)
+    .await?;
+
+    Ok(case)
+}
+
+async fn create_case(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    docket: &PacerDocket,
+    client: &str,
+    user_id: Uuid,
+) -> Result<Case, AppError> {
+    let status = if docket.date_terminated.is_some() {
+        CaseStatus::Closed
+    } else {
+        CaseStatus::Discovery
+    };
+    let title = if docket.case_title.is_empty() {
+        docket.case_number.clone()
+    } else {
+        docket.case_title.clone()
+    };
+
+    let case = sqlx::query_as::<_, Case>(
+        r#"
+        INSERT INTO cases (
+            title, client, matter_type, status, filing_date, jurisdiction, court, judge,
+            magistrate_judge, nature_of_suit, date_terminated, pacer_data, owner_id,
+            created_by, created_at, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $13, NOW(), NOW())
+        RETURNING *
+        "#,
+    )
+    .bind(&title)
+    .bind(client)
+    .bind(MatterType::Litigation)
+    .bind(status)
+    .bind(
+        docket
+            .date_filed
+            .map(report_timestamp)
+            .unwrap_or_else(Utc::now),
+    )
+    .bind(&docket.jurisdiction)
+    .bind(&docket.court)
+    .bind(&docket.assigned_to)
+    .bind(&docket.referred_to)
+    .bind(&docket.nature_of_suit)
+    .bind(docket.date_terminated.map(report_timestamp))
+    .bind(case_pacer_data(docket))
+    .bind(user_id)
+    .fetch_one(&mut **tx)
+    .await?;
+
+    Ok(case)
+}
+
+/// Refresh the court details of a case from the report, returning the names
+/// of the fields that changed
+async fn update_case(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    case: &Case,
+    docket: &PacerDocket,
+    user_id: Uuid,
+) -> Result<Vec<String>, AppError> {
+    let date_terminated = docket.date_terminated.map(report_timestamp);
+    let mut changes = Vec::new();
+    let mut track = |name: &str, current: Option<&str>, incoming: Option<&str>| {
+        if incoming.is_some() && current != incoming {
+            changes.push(name.to_string());
+        }
+    };
+    track("court", case.court.as_deref(), Some(&docket.court));
+    track(
+        "judge",
+        case.judge.as_deref(),
+        docket.assigned_to.as_deref(),
+    );
+    track(
+        "magistrate_judge",
+        case.magistrate_judge.as_deref(),
+        docket.referred_to.as_deref(),
+    );
+    track(
+        "nature_of_suit",
+        case.nature_of_suit.as_deref(),
+        docket.nature_of_suit.as_deref(),
+    );
+    track(
+        "jurisdiction",
+        case.jurisdiction.as_deref(),
+        docket.jurisdiction.as_deref(),
+    );
+    if date_terminated.is_some() && case.date_terminated != date_terminated {
+        changes.push("date_terminated".to_string());
+    }
+
+    let pacer_data = case_pacer_data(docket);
+    if changes.is_empty() && case.pacer_data.as_ref() == Some(&pacer_data) {
+        return Ok(changes);
+    }
+
+    sqlx::query(
+        r#"
+        UPDATE cases
+        SET court = $1,
+            judge = COALESCE($2, judge),
+            magistrate_judge = COALESCE($3, magistrate_judge),
+            nature_of_suit = COALESCE($4, nature_of_suit),
+            jurisdiction = COALESCE($5, jurisdiction),
+            date_terminated = COALESCE($6, date_terminated),
+            pacer_data = $7,
+            updated_at = NOW(),
+            updated_by = $8
+        WHERE id = $9
+        "#,
+    )
+    .bind(&docket.court)
+    .bind(&docket.assigned_to)
+    .bind(&docket.referred_to)
+    .bind(&docket.nature_of_suit)
+    .bind(&docket.jurisdiction)
+    .bind(date_terminated)
+    .bind(pacer_data)
+    .bind(user_id)
+    .bind(case.id)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(changes)
+}
+
+/// Add a party listed on the report, or refresh its attorneys
+async fn upsert_party(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    case_id: Uuid,
+    party: &PacerParty,
+    user_id: Uuid,
+) -> Result<PartyUpsert, AppError> {
+    let attorneys = serde_json::to_value(&party.attorneys)
+        .map_err(|e| AppError::InternalServerError(format!("Failed to encode attorneys: {}", e)))?;
+    let pacer_data = json!({ "role": party.role, "details": party.details });
+    let counsel = party
+        .attorneys
+        .iter()
+        .find(|a| a.designations.iter().any(|d| d == "LEAD ATTORNEY"))
+        .or(party.attorneys.first())
+        .map(|a| a.name.clone());
+    let representation_type = if party.attorneys.is_empty() {
+        "Pro Se"
+    } else {
+        "Counsel"
+    };
+
+    let existing = sqlx::query_as::<_, Party>(
+        r#"
+        SELECT * FROM parties
+        WHERE case_id = $1 AND lower(name) = lower($2) AND role = $3 AND deleted_at IS NULL
+        LIMIT 1
+        "#,
+    )
+    .bind(case_id)
+    .bind(&party.name)
+    .bind(&party.role)
+    .fetch_optional(&mut **tx)
+    .await?;
+
+    match existing {
+        Some(existing)
+            if existing.attorneys.as_ref() == Some(&attorneys)
+                && existing.pacer_data.as_ref() == Some(&pacer_data) =>
+        {
+            Ok(PartyUpsert::Unchanged)
+        }
+        Some(existing) => {
+            sqlx::query(
+                r#"
+                UPDATE parties
+                SET attorneys = $1, counsel = $2, representation_type = $3, pacer_data = $4,
+                    updated_at = NOW(), updated_by = $5
+                WHERE id = $6
+                "#,
+            )
+            .bind(&attorneys)
+            .bind(&counsel)
+            .bind(representation_type)
+            .bind(&pacer_data)
+            .bind(user_id)
+            .bind(existing.id)
+            .execute(&mut **tx)
+            .await?;
+            Ok(PartyUpsert::Updated)
+        }
+        None => {
+            sqlx::query(
+                r#"
+                INSERT INTO parties (
+                    case_id, name, role, type, counsel, representation_type, attorneys,
+                    pacer_data, created_by, created_at, updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, NOW(), NOW())
+                "#,
+            )
+            .bind(case_id)
+            .bind(&party.name)
+            .bind(&party.role)
+            .bind(party_type(&party.name))
+            .bind(&counsel)
+            .bind(representation_type)
+            .bind(&attorneys)
+            .bind(&pacer_data)
+            .bind(user_id)
+            .execute(&mut **tx)
+            .await?;
+            Ok(PartyUpsert::Added)
+        }
+    }
+}
+
+/// Party type guessed from the name, as PACER does not report it
+pub fn party_type(name: &str) -> &'static str {
+    let upper = format!(" {} ", name.to_uppercase().replace([',', '.'], " "));
+    const GOVERNMENT: [&str; 6] = [
+        " UNITED STATES ",
+        " STATE OF ",
+        " CITY OF ",
+        " COUNTY OF ",
+        " DEPARTMENT OF ",
+        " COMMONWEALTH OF ",
+    ];
+    const CORPORATE: [&str; 10] = [
+        " INC ",
+        " LLC ",
+        " CORP ",
+        " CORPORATION ",
+        " COMPANY ",
+        " CO ",
+        " LTD ",
+        " LP ",
+        " LLP ",
+        " PC ",
+    ];
+    if GOVERNMENT.iter().any(|g| upper.contains(g)) || upper.starts_with(" PEOPLE OF ") {
+        "Government"
+    } else if CORPORATE.iter().any(|c| upper.contains(c)) {
+        "Corporation"
+    } else {
+        "Individual"
+    }
+}
@@ -0,0 +1,253 @@
+use crate::api::privilege::log::LogFormat;
+use crate::api::privilege::service::{
+    CreateDesignationParams, PrivilegeService, UpdateDesignationParams,
+};
+use crate::error::AppError;
+use crate::models::{Claims, PrivilegeBasis, PrivilegeDesignation, PrivilegeLogEntry};
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Json},
+    Extension,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PrivilegeCaseQuery {
+    pub case_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ExportPrivilegeLogQuery {
+    pub case_id: Uuid,
+    /// One of "csv", "xlsx" or "pdf" (defaults to "csv")
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateDesignationRequest {
+    pub document_id: Option<Uuid>,
+    pub communication_id: Option<Uuid>,
+    pub basis: PrivilegeBasis,
+    pub author: String,
+    pub recipients: Option<Vec<String>>,
+    pub item_date: Option<DateTime<Utc>>,
+    pub description: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateDesignationRequest {
+    pub basis: Option<PrivilegeBasis>,
+    pub author: Option<String>,
+    pub recipients: Option<Vec<String>>,
+    pub item_date: Option<DateTime<Utc>>,
+    pub description: Option<String>,
+}
+
+fn user_id(claims: &Claims) -> Result<Uuid, AppError> {
+    Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))
+}
+
+/// List privilege designations for a case
+#[utoipa::path(
+    get,
+    path = "/api/privilege/designations",
+    params(
+        ("case_id" = Uuid, Query, description = "Case ID to filter designations")
+    ),
+    responses(
+        (status = 200, description = "List of privilege designations", body = Vec<PrivilegeDesignation>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "privilege",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_designations(
+    State(service): State<Arc<PrivilegeService>>,
+    Query(query): Query<PrivilegeCaseQuery>,
+) -> Result<Json<Vec<PrivilegeDesignation>>, AppError> {
+    let designations = service.list_designations(query.case_id).await?;
+    Ok(Json(designations))
+}
+
+/// Get a privilege designation
+#[utoipa::path(
+    get,
+    path = "/api/privilege/designations/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Privilege designation ID")
+    ),
+    responses(
+        (status = 200, description = "Privilege designation details", body = PrivilegeDesignation),
+        (status = 404, description = "Privilege designation not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "privilege",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_designation(
+    State(service): State<Arc<PrivilegeService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<PrivilegeDesignation>, AppError> {
+    let designation = service.get_designation(id).await?;
+    Ok(Json(designation))
+}
+
+/// Designate a document or communication as privileged
+#[utoipa::path(
+    post,
+    path = "/api/privilege/designations",
+    request_body = CreateDesignationRequest,
+    responses(
+        (status = 201, description = "Privilege designation created", body = PrivilegeDesignation),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "Document or communication not found"),
+        (status = 409, description = "Item is already designated as privileged"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "privilege",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_designation(
+    State(service): State<Arc<PrivilegeService>>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<CreateDesignationRequest>,
+) -> Result<(StatusCode, Json<PrivilegeDesignation>), AppError> {
+    let designation = service
+        .create_designation(CreateDesignationParams {
+            document_id: req.document_id,
+            communication_id: req.communication_id,
+            basis: req.basis,
+            author: req.author,
+            recipients: req.recipients.unwrap_or_default(),
+            item_date: req.item_date,
+            description: req.description,
+            created_by: user_id(&claims)?,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(designation)))
+}
+
+/// Update a privilege designation
+#[utoipa::path(
+    put,
+    path = "/api/privilege/designations/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Privilege designation ID")
+    ),
+    request_body = UpdateDesignationRequest,
+    responses(
+        (status = 200, description = "Privilege designation updated", body = PrivilegeDesignation),
+        (status = 404, description = "Privilege designation not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "privilege",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_designation(
+    State(service): State<Arc<PrivilegeService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateDesignationRequest>,
+) -> Result<Json<PrivilegeDesignation>, AppError> {
+    let designation = service
+        .update_designation(
+            id,
+            UpdateDesignationParams {
+                basis: req.basis,
+                author: req.author,
+                recipients: req.recipients,
+                item_date: req.item_date,
+                description: req.description,
+                updated_by: user_id(&claims)?,
+            },
+        )
+        .await?;
+    Ok(Json(designation))
+}
+
+/// Withdraw a privilege designation
+#[utoipa::path(
+    delete,
+    path = "/api/privilege/designations/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Privilege designation ID")
+    ),
+    responses(
+        (status = 204, description = "Privilege designation withdrawn"),
+        (status = 404, description = "Privilege designation not found"),
+        (status = 409, description = "The item was withheld from a production under the designation"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "privilege",
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_designation(
+    State(service): State<Arc<PrivilegeService>>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    service.delete_designation(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Get the privilege log for a case
+#[utoipa::path(
+    get,
+    path = "/api/privilege/log",
+    params(
+        ("case_id" = Uuid, Query, description = "Case ID")
+    ),
+    responses(
+        (status = 200, description = "Privilege log entries", body = Vec<PrivilegeLogEntry>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "privilege",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_privilege_log(
+    State(service): State<Arc<PrivilegeService>>,
+    Query(query): Query<PrivilegeCaseQuery>,
+) -> Result<Json<Vec<PrivilegeLogEntry>>, AppError> {
+    let entries = service.generate_log(query.case_id).await?;
+    Ok(Json(entries))
+}
+
+/// Download the privilege log for a case as CSV, XLSX or PDF
+#[utoipa::path(
+    get,
+    path = "/api/privilege/log/export",
+    params(
+        ("case_id" = Uuid, Query, description = "Case ID"),
+        ("format" = Option<String>, Query, description = "csv, xlsx or pdf (defaults to csv)")
+    ),
+    responses(
+        (status = 200, description = "Rendered privilege log"),
+        (status = 400, description = "Unsupported format"),
+        (status = 404, description = "Case not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "privilege",
+    security(("bearer_auth" = []))
+)]
+pub async fn export_privilege_log(
+    State(service): State<Arc<PrivilegeService>>,
+    Query(query): Query<ExportPrivilegeLogQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let format = LogFormat::parse(query.format.as_deref().unwrap_or("csv"))?;
+    let export = service.export_log(query.case_id, format).await?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, export.content_type.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", export.file_name),
+            ),
+        ],
+        export.content,
+    ))
+}
@@ -0,0 +1,112 @@
+use crate::error::AppError;
+use crate::export::{render_table_report, write_csv, write_xlsx, PdfColumn};
+use crate::models::PrivilegeLogEntry;
+
+/// Column headers of an exported privilege log
+pub const LOG_HEADERS: [&str; 8] = [
+    "Log No.",
+    "Date",
+    "Type",
+    "Author",
+    "Recipients",
+    "Privilege Basis",
+    "Description",
+    "Withheld From",
+];
+
+/// Export formats supported by the privilege log download
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    Csv,
+    Xlsx,
+    Pdf,
+}
+
+impl LogFormat {
+    pub fn parse(value: &str) -> Result<Self, AppError> {
+        match value.to_lowercase().as_str() {
+            "csv" => Ok(LogFormat::Csv),
+            "xlsx" => Ok(LogFormat::Xlsx),
+            "pdf" => Ok(LogFormat::Pdf),
+            other => Err(AppError::Validation(format!(
+                "Unsupported privilege log format: {}",
+                other
+            ))),
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            LogFormat::Csv => "csv",
+            LogFormat::Xlsx => "xlsx",
+            LogFormat::Pdf => "pdf",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            LogFormat::Csv => "text/csv; charset=utf-8",
+            LogFormat::Xlsx => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+            LogFormat::Pdf => "application/pdf",
+        }
+    }
+}
+
+/// Log number of the n-th entry (zero-based), e.g. "PRIV-0001"
+pub fn log_number(index: usize) -> String {
+    format!("PRIV-{:04}", index + 1)
+}
+
+/// Flatten log entries into export rows matching `LOG_HEADERS`
+pub fn log_rows(entries: &[PrivilegeLogEntry]) -> Vec<Vec<String>> {
+    entries
+        .iter()
+        .map(|entry| {
+            vec![
+                entry.log_number.clone(),
+                entry
+                    .item_date
+                    .map(|d| d.format("%m/%d/%Y").to_string())
+                    .unwrap_or_default(),
+                entry.item_type.clone(),
+                entry.author.clone(),
+                entry.recipients.join("; "),
+                entry.basis.label().to_string(),
+                entry.description.clone(),
+                entry.withheld_from.join("; "),
+            ]
+        })
+        .collect()
+}
+
+/// Render a privilege log in the requested format
+pub fn render_log(
+    format: LogFormat,
+    case_title: &str,
+    entries: &[PrivilegeLogEntry],
+) -> Result<Vec<u8>, AppError> {
+    let rows = log_rows(entries);
+
+    match format {
+        LogFormat::Csv => Ok(write_csv(&LOG_HEADERS, &rows).into_bytes()),
+        LogFormat::Xlsx => write_xlsx("Privilege Log", &LOG_HEADERS, &rows),
+        LogFormat::Pdf => {
+            let weights = [0.8, 0.8, 0.9, 1.2, 1.6, 1.0, 3.0, 1.4];
+            let columns: Vec<PdfColumn> = LOG_HEADERS
+                .iter()
+                .zip(weights)
+                .map(|(header, weight)| PdfColumn { header, weight })
+                .collect();
+            let subtitle = vec![
+                case_title.to_string(),
+                format!("{} item(s) withheld", entries.len()),
+            ];
+            Ok(render_table_report(
+                "Privilege Log",
+                &subtitle,
+                &columns,
+                &rows,
+            ))
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod handlers;
+pub mod log;
+pub mod service;
+
+pub use handlers::*;
+pub use service::*;
@@ -0,0 +1,406 @@
+use crate::api::privilege::log::{log_number, render_log, LogFormat};
+use crate::error::AppError;
+use crate::models::{PrivilegeBasis, PrivilegeDesignation, PrivilegeLogEntry};
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+/// Parameters for designating a document or communication as privileged
+pub struct CreateDesignationParams {
+    /// Privileged document (exactly one of document or communication)
+    pub document_id: Option<Uuid>,
+    /// Privileged communication (exactly one of document or communication)
+    pub communication_id: Option<Uuid>,
+    /// Privilege asserted over the item
+    pub basis: PrivilegeBasis,
+    /// Author of the withheld item
+    pub author: String,
+    /// Recipients of the withheld item
+    pub recipients: Vec<String>,
+    /// Date of the item (defaults to the document or communication date)
+    pub item_date: Option<DateTime<Utc>>,
+    /// Description sufficient to assess the claim without revealing privileged content
+    pub description: String,
+    /// ID of the user making the designation
+    pub created_by: Uuid,
+}
+
+/// Parameters for updating a privilege designation
+pub struct UpdateDesignationParams {
+    pub basis: Option<PrivilegeBasis>,
+    pub author: Option<String>,
+    pub recipients: Option<Vec<String>>,
+    pub item_date: Option<DateTime<Utc>>,
+    pub description: Option<String>,
+    /// ID of the user updating the designation
+    pub updated_by: Uuid,
+}
+
+/// Downloadable privilege log
+pub struct PrivilegeLogExport {
+    /// Suggested file name of the log
+    pub file_name: String,
+    /// MIME type of the rendered log
+    pub content_type: &'static str,
+    /// Rendered log bytes
+    pub content: Vec<u8>,
+}
+
+#[derive(FromRow)]
+struct PrivilegeLogRow {
+    id: Uuid,
+    document_id: Option<Uuid>,
+    communication_id: Option<Uuid>,
+    item_date: Option<DateTime<Utc>>,
+    item_type: String,
+    author: String,
+    recipients: Vec<String>,
+    basis: PrivilegeBasis,
+    description: String,
+    withheld_from: Vec<String>,
+}
+
+pub struct PrivilegeService {
+    pool: PgPool,
+}
+
+impl PrivilegeService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// List active privilege designations for a case
+    pub async fn list_designations(
+        &self,
+        case_id: Uuid,
+    ) -> Result<Vec<PrivilegeDesignation>, AppError> {
+        let designations = sqlx::query_as::<_, PrivilegeDesignation>(
+            "SELECT * FROM privilege_designations WHERE case_id = $1 AND deleted_at IS NULL ORDER BY created_at",
+        )
+        .bind(case_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(designations)
+    }
+
+    /// Get a privilege designation by ID
+    pub async fn get_designation(&self, id: Uuid) -> Result<PrivilegeDesignation, AppError> {
+        sqlx::query_as::<_, PrivilegeDesignation>(
+            "SELECT * FROM privilege_designations WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound(
+            "Privilege designation not found".to_string(),
+        ))
+    }
+
+    /// Designate a document or communication as privileged.
+    ///
+    /// The case is taken from the designated item. Communications are also
+    /// flagged `is_privileged` so existing views stay consistent.
+    pub async fn create_designation(
+        &self,
+        params: CreateDesignationParams,
+    ) -> Result<PrivilegeDesignation, AppError> {
+        validate_text("author", &params.author)?;
+        validate_text("description", &params.description)?;
+
+        let mut tx = self.pool.begin().await?;
+
+        let (case_id, default_date): (Uuid, DateTime<Utc>) = match (
+            params.document_id,
+            params.communication_id,
+        ) {
+            (Some(document_id), None) => sqlx::query_as(
+                "SELECT case_id, upload_date FROM documents WHERE id = $1 AND deleted_at IS NULL",
+            )
+            .bind(document_id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or(AppError::NotFound("Document not found".to_string()))?,
+            (None, Some(communication_id)) => sqlx::query_as(
+                "SELECT case_id, date FROM communications WHERE id = $1 AND deleted_at IS NULL",
+            )
+            .bind(communication_id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or(AppError::NotFound("Communication not found".to_string()))?,
+            _ => {
+                return Err(AppError::Validation(
+                    "Designate exactly one of document_id or communication_id".to_string(),
+                ))
+            }
+        };
+
+        let existing: Option<Uuid> = sqlx::query_scalar(
+            r#"
+            SELECT id FROM privilege_designations
+            WHERE deleted_at IS NULL
+              AND (document_id = $1 OR communication_id = $2)
+            "#,
+        )
+        .bind(params.document_id)
+        .bind(params.communication_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        if existing.is_some() {
+            return Err(AppError::Conflict(
+                "Item is already designated as privileged".to_string(),
+            ));
+        }
+
+        // Only a flag this designation sets is cleared when it is withdrawn
+        let flagged_communication = match params.communication_id {
+            Some(communication_id) => {
+                let privileged: Option<bool> = sqlx::query_scalar(
+                    "SELECT is_privileged FROM communications WHERE id = $1 FOR UPDATE",
+                )
+                .bind(communication_id)
+                .fetch_one(&mut *tx)
+                .await?;
+                !privileged.unwrap_or(false)
+            }
+            None => false,
+        };
+
+        let now = Utc::now();
+        let designation = sqlx::query_as::<_, PrivilegeDesignation>(
+            r#"
+            INSERT INTO privilege_designations (
+                id, case_id, document_id, communication_id, basis, author, recipients,
+                item_date, description, flagged_communication, created_at, updated_at,
+                created_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(case_id)
+        .bind(params.document_id)
+        .bind(params.communication_id)
+        .bind(&params.basis)
+        .bind(params.author.trim())
+        .bind(&params.recipients)
+        .bind(params.item_date.unwrap_or(default_date))
+        .bind(params.description.trim())
+        .bind(flagged_communication)
+        .bind(now)
+        .bind(now)
+        .bind(params.created_by)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if let (Some(communication_id), true) = (params.communication_id, flagged_communication) {
+            set_communication_privileged(&mut tx, communication_id, true).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(designation)
+    }
+
+    /// Update the logged details of a privilege designation
+    pub async fn update_designation(
+        &self,
+        id: Uuid,
+        params: UpdateDesignationParams,
+    ) -> Result<PrivilegeDesignation, AppError> {
+        if let Some(author) = &params.author {
+            validate_text("author", author)?;
+        }
+        if let Some(description) = &params.description {
+            validate_text("description", description)?;
+        }
+
+        let current = self.get_designation(id).await?;
+
+        let designation = sqlx::query_as::<_, PrivilegeDesignation>(
+            r#"
+            UPDATE privilege_designations
+            SET basis = $1, author = $2, recipients = $3, item_date = $4, description = $5,
+                updated_at = $6, updated_by = $7, version = version + 1
+            WHERE id = $8 AND deleted_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(params.basis.unwrap_or(current.basis))
+        .bind(
+            params
+                .author
+                .map(|a| a.trim().to_string())
+                .unwrap_or(current.author),
+        )
+        .bind(params.recipients.unwrap_or(current.recipients))
+        .bind(params.item_date.or(current.item_date))
+        .bind(
+            params
+                .description
+                .map(|d| d.trim().to_string())
+                .unwrap_or(current.description),
+        )
+        .bind(Utc::now())
+        .bind(params.updated_by)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound(
+            "Privilege designation not found".to_string(),
+        ))?;
+
+        Ok(designation)
+    }
+
+    /// Withdraw a privilege designation.
+    ///
+    /// The item drops off the privilege log, so a designation an item was
+    /// withheld from a production under cannot be withdrawn. A communication
+    /// flag the designation set is cleared.
+    pub async fn delete_designation(&self, id: Uuid) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query_scalar::<_, Uuid>(
+            "SELECT id FROM privilege_designations WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(AppError::NotFound(
+            "Privilege designation not found".to_string(),
+        ))?;
+
+        let withheld_from: Vec<String> = sqlx::query_scalar(
+            r#"
+            SELECT ps.name FROM production_withheld_items w
+            JOIN production_sets ps ON ps.id = w.production_set_id
+            WHERE w.privilege_designation_id = $1 AND ps.deleted_at IS NULL
+            ORDER BY ps.date
+            "#,
+        )
+        .bind(id)
+        .fetch_all(&mut *tx)
+        .await?;
+        if !withheld_from.is_empty() {
+            return Err(AppError::Conflict(format!(
+                "The item was withheld from {} and must stay on the privilege log",
+                withheld_from.join(", ")
+            )));
+        }
+
+        let (communication_id, flagged_communication): (Option<Uuid>, bool) = sqlx::query_as(
+            r#"
+            UPDATE privilege_designations SET deleted_at = $1, updated_at = $1
+            WHERE id = $2
+            RETURNING communication_id, flagged_communication
+            "#,
+        )
+        .bind(Utc::now())
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if let (Some(communication_id), true) = (communication_id, flagged_communication) {
+            set_communication_privileged(&mut tx, communication_id, false).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Generate the privilege log for a case from its active designations
+    pub async fn generate_log(&self, case_id: Uuid) -> Result<Vec<PrivilegeLogEntry>, AppError> {
+        let rows = sqlx::query_as::<_, PrivilegeLogRow>(
+            r#"
+            SELECT p.id, p.document_id, p.communication_id,
+                   COALESCE(p.item_date, d.upload_date, c.date) AS item_date,
+                   COALESCE(d.type, c.type, '') AS item_type,
+                   p.author, p.recipients, p.basis, p.description,
+                   ARRAY(
+                       SELECT ps.name FROM production_withheld_items w
+                       JOIN production_sets ps ON ps.id = w.production_set_id
+                       WHERE w.privilege_designation_id = p.id AND ps.deleted_at IS NULL
+                       ORDER BY ps.date
+                   ) AS withheld_from
+            FROM privilege_designations p
+            LEFT JOIN documents d ON d.id = p.document_id
+            LEFT JOIN communications c ON c.id = p.communication_id
+            WHERE p.case_id = $1 AND p.deleted_at IS NULL
+            ORDER BY COALESCE(p.item_date, d.upload_date, c.date) NULLS LAST, p.created_at
+            "#,
+        )
+        .bind(case_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let entries = rows
+            .into_iter()
+            .enumerate()
+            .map(|(index, row)| PrivilegeLogEntry {
+                log_number: log_number(index),
+                designation_id: row.id,
+                document_id: row.document_id,
+                communication_id: row.communication_id,
+                item_date: row.item_date,
+                item_type: row.item_type,
+                author: row.author,
+                recipients: row.recipients,
+                basis: row.basis,
+                description: row.description,
+                withheld_from: row.withheld_from,
+            })
+            .collect();
+
+        Ok(entries)
+    }
+
+    /// Render the privilege log for a case as CSV, XLSX or PDF
+    pub async fn export_log(
+        &self,
+        case_id: Uuid,
+        format: LogFormat,
+    ) -> Result<PrivilegeLogExport, AppError> {
+        let case_title: String =
+            sqlx::query_scalar("SELECT title FROM cases WHERE id = $1 AND deleted_at IS NULL")
+                .bind(case_id)
+                .fetch_optional(&self.pool)
+                .await?
+                .ok_or(AppError::NotFound("Case not found".to_string()))?;
+
+        let entries = self.generate_log(case_id).await?;
+
+        Ok(PrivilegeLogExport {
+            file_name: format!("privilege-log.{}", format.extension()),
+            content_type: format.content_type(),
+            content: render_log(format, &case_title, &entries)?,
+        })
+    }
+}
+
+fn validate_text(field: &str, value: &str) -> Result<(), AppError> {
+    if value.trim().is_empty() {
+        return Err(AppError::Validation(format!(
+            "Privilege log {} may not be empty",
+            field
+        )));
+    }
+    Ok(())
+}
+
+async fn set_communication_privileged(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    communication_id: Uuid,
+    privileged: bool,
+) -> Result<(), AppError> {
+    sqlx::query("UPDATE communications SET is_privileged = $1, updated_at = $2 WHERE id = $3")
+        .bind(privileged)
+        .bind(Utc::now())
+        .bind(communication_id)
+        .execute(&mut **tx)
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,152 @@
+use crate::error::AppError;
+use crate::export::{wrap_text, PdfDocument, PdfFont, PdfPage, PAGE_WIDTH};
+
+/// Lines of document text rendered on each produced page
+pub const LINES_PER_PAGE: usize = 50;
+/// Characters per line before text is wrapped
+pub const CHARS_PER_LINE: usize = 90;
+/// Left margin of the body text in points
+pub const BODY_LEFT: f32 = 36.0;
+/// Baseline of the first body line in points
+pub const BODY_TOP: f32 = 740.0;
+/// Distance between body baselines in points
+pub const BODY_LINE_HEIGHT: f32 = 13.0;
+/// Font size of the body text
+pub const BODY_FONT_SIZE: f32 = 10.0;
+/// Character standing in for burned-in redacted text
+pub const REDACTION_MASK: char = '\u{2588}';
+/// Default number of digits in a Bates number
+pub const DEFAULT_PADDING: i32 = 8;
+/// Largest supported number of digits in a Bates number
+pub const MAX_PADDING: i32 = 10;
+
+/// Format a Bates number, e.g. `ABC00000042`
+pub fn format_bates(prefix: &str, number: i32, padding: i32) -> String {
+    format!(
+        "{}{:0width$}",
+        prefix,
+        number,
+        width = padding.max(1) as usize
+    )
+}
+
+/// Format a Bates range, collapsing single-page ranges to one number
+pub fn format_bates_range(prefix: &str, begin: i32, end: i32, padding: i32) -> String {
+    if begin == end {
+        format_bates(prefix, begin, padding)
+    } else {
+        format!(
+            "{} - {}",
+            format_bates(prefix, begin, padding),
+            format_bates(prefix, end, padding)
+        )
+    }
+}
+
+/// Validate a Bates prefix (letters, digits, `-` and `_` only)
+pub fn validate_prefix(prefix: &str) -> Result<(), AppError> {
+    if prefix.is_empty() || prefix.len() > 50 {
+        return Err(AppError::Validation(
+            "Bates prefix must be between 1 and 50 characters".to_string(),
+        ));
+    }
+
+    if !prefix
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(AppError::Validation(
+            "Bates prefix may only contain letters, digits, '-' and '_'".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Split document text into produced pages of wrapped lines.
+///
+/// Documents without text still occupy a single (blank) page so that every
+/// produced document consumes at least one Bates number.
+pub fn paginate(content: Option<&str>) -> Vec<Vec<String>> {
+    let lines = content
+        .map(|c| wrap_text(c, CHARS_PER_LINE))
+        .unwrap_or_default();
+
+    if lines.is_empty() {
+        return vec![Vec::new()];
+    }
+
+    lines
+        .chunks(LINES_PER_PAGE)
+        .map(|chunk| chunk.to_vec())
+        .collect()
+}
+
+/// Render pages as a PDF stamped with Bates numbers and an optional legend
+pub fn render_stamped_pdf(
+    title: &str,
+    pages: &[Vec<String>],
+    prefix: &str,
+    begin: i32,
+    padding: i32,
+    legend: Option<&str>,
+) -> Vec<u8> {
+    let mut pdf = PdfDocument::new().with_title(title);
+
+    for (index, lines) in pages.iter().enumerate() {
+        let mut page = PdfPage::new();
+
+        let mut y = BODY_TOP;
+        for line in lines {
+            draw_body_line(&mut page, y, line);
+            y -= BODY_LINE_HEIGHT;
+        }
+
+        if let Some(legend) = legend {
+            page.text(PdfFont::HelveticaBold, 9.0, 36.0, 30.0, legend);
+        }
+        let number = format_bates(prefix, begin + index as i32, padding);
+        page.text_right(
+            PdfFont::HelveticaBold,
+            9.0,
+            PAGE_WIDTH - 36.0,
+            30.0,
+            &number,
+        );
+
+        pdf.add_page(page);
+    }
+
+    pdf.render()
+}
+
+/// Draw a line of body text, painting redacted characters as solid boxes.
+///
+/// Masked characters are never written as text, so nothing remains under
+/// the boxes to be copied out of the PDF.
+fn draw_body_line(page: &mut PdfPage, y: f32, line: &str) {
+    let char_width = PdfFont::Courier.char_width(BODY_FONT_SIZE);
+    let visible: String = line
+        .chars()
+        .map(|c| if c == REDACTION_MASK { ' ' } else { c })
+        .collect();
+    page.text(PdfFont::Courier, BODY_FONT_SIZE, BODY_LEFT, y, &visible);
+
+    let mut run_start = None;
+    for (index, c) in line.chars().chain(std::iter::once(' ')).enumerate() {
+        match (c == REDACTION_MASK, run_start) {
+            (true, None) => run_start = Some(index),
+            (false, Some(start)) => {
+                page.fill_rect(
+                    BODY_LEFT + start as f32 * char_width,
+                    y - BODY_FONT_SIZE * 0.25,
+                    (index - start) as f32 * char_width,
+                    BODY_FONT_SIZE,
+                    0.0,
+                );
+                run_start = None;
+            }
+            _ => {}
+        }
+    }
+}
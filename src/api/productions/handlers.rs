@@ -0,0 +1,229 @@
+use crate::api::productions::service::ProductionService;
+use crate::error::AppError;
+use crate::models::{Claims, LoadFileField, ProductionSet, ProductionSetResponse};
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Json},
+    Extension,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ListProductionsQuery {
+    pub case_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateProductionRequest {
+    pub case_id: Uuid,
+    pub name: String,
+    pub document_ids: Vec<Uuid>,
+    pub prefix: String,
+    pub start_number: Option<i32>,
+    pub padding: Option<i32>,
+    pub confidentiality: Option<String>,
+    pub format: Option<String>,
+    pub load_file_fields: Option<Vec<LoadFileField>>,
+}
+
+/// List production sets for a case
+#[utoipa::path(
+    get,
+    path = "/api/productions",
+    params(
+        ("case_id" = Uuid, Query, description = "Case ID to filter production sets")
+    ),
+    responses(
+        (status = 200, description = "List of production sets", body = Vec<ProductionSet>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "productions",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_productions(
+    State(service): State<Arc<ProductionService>>,
+    Query(query): Query<ListProductionsQuery>,
+) -> Result<Json<Vec<ProductionSet>>, AppError> {
+    let productions = service.list_productions(query.case_id).await?;
+    Ok(Json(productions))
+}
+
+/// Get a production set with its Bates ranges
+#[utoipa::path(
+    get,
+    path = "/api/productions/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Production set ID")
+    ),
+    responses(
+        (status = 200, description = "Production set details", body = ProductionSetResponse),
+        (status = 404, description = "Production set not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "productions",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_production(
+    State(service): State<Arc<ProductionService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ProductionSetResponse>, AppError> {
+    let production = service.get_production(id).await?;
+    Ok(Json(production))
+}
+
+/// Create a production set and assign Bates numbers
+#[utoipa::path(
+    post,
+    path = "/api/productions",
+    request_body = CreateProductionRequest,
+    responses(
+        (status = 201, description = "Production set created", body = ProductionSetResponse),
+        (status = 400, description = "Invalid request"),
+        (status = 409, description = "Bates numbers already used in this case"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "productions",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_production(
+    State(service): State<Arc<ProductionService>>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<CreateProductionRequest>,
+) -> Result<(StatusCode, Json<ProductionSetResponse>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let production = service
+        .create_production(crate::api::productions::service::CreateProductionParams {
+            case_id: req.case_id,
+            name: req.name,
+            document_ids: req.document_ids,
+            prefix: req.prefix,
+            start_number: req.start_number,
+            padding: req.padding,
+            confidentiality: req.confidentiality,
+            format: req.format,
+            load_file_fields: req.load_file_fields,
+            created_by: user_id,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(production)))
+}
+
+/// Download a produced document stamped with its Bates numbers
+#[utoipa::path(
+    get,
+    path = "/api/productions/{id}/documents/{document_id}/pdf",
+    params(
+        ("id" = Uuid, Path, description = "Production set ID"),
+        ("document_id" = Uuid, Path, description = "Document ID")
+    ),
+    responses(
+        (status = 200, description = "Stamped PDF", content_type = "application/pdf"),
+        (status = 404, description = "Document is not part of this production"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "productions",
+    security(("bearer_auth" = []))
+)]
+pub async fn download_stamped_document(
+    State(service): State<Arc<ProductionService>>,
+    Path((id, document_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, AppError> {
+    let stamped = service.render_stamped_document(id, document_id).await?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/pdf".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}.pdf\"", stamped.begin_bates),
+            ),
+        ],
+        stamped.pdf,
+    ))
+}
+
+/// Get the DAT load file field mapping of a production
+#[utoipa::path(
+    get,
+    path = "/api/productions/{id}/load-file-fields",
+    params(
+        ("id" = Uuid, Path, description = "Production set ID")
+    ),
+    responses(
+        (status = 200, description = "DAT field mapping", body = Vec<LoadFileField>),
+        (status = 404, description = "Production set not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "productions",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_load_file_fields(
+    State(service): State<Arc<ProductionService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<LoadFileField>>, AppError> {
+    let fields = service.get_load_file_fields(id).await?;
+    Ok(Json(fields))
+}
+
+/// Replace the DAT load file field mapping of a production
+#[utoipa::path(
+    put,
+    path = "/api/productions/{id}/load-file-fields",
+    params(
+        ("id" = Uuid, Path, description = "Production set ID")
+    ),
+    request_body = Vec<LoadFileField>,
+    responses(
+        (status = 200, description = "DAT field mapping updated", body = Vec<LoadFileField>),
+        (status = 400, description = "Invalid field mapping"),
+        (status = 404, description = "Production set not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "productions",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_load_file_fields(
+    State(service): State<Arc<ProductionService>>,
+    Path(id): Path<Uuid>,
+    Json(fields): Json<Vec<LoadFileField>>,
+) -> Result<Json<Vec<LoadFileField>>, AppError> {
+    let fields = service.update_load_file_fields(id, fields).await?;
+    Ok(Json(fields))
+}
+
+/// Download a production as a ZIP archive with DAT/OPT load files
+#[utoipa::path(
+    get,
+    path = "/api/productions/{id}/export",
+    params(
+        ("id" = Uuid, Path, description = "Production set ID")
+    ),
+    responses(
+        (status = 200, description = "Load file archive", content_type = "application/zip"),
+        (status = 404, description = "Production set not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "productions",
+    security(("bearer_auth" = []))
+)]
+pub async fn export_production(
+    State(service): State<Arc<ProductionService>>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let export = service.export_production(id).await?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/zip".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", export.file_name),
+            ),
+        ],
+        export.archive,
+    ))
+}
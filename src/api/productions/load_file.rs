@@ -0,0 +1,173 @@
+use crate::api::productions::bates::format_bates;
+use crate::error::AppError;
+use crate::models::{Document, LoadFileField, LoadFileSource, ProductionDocument, ProductionSet};
+use std::collections::HashSet;
+
+/// Concordance field delimiter (ASCII 20, displayed as ¶)
+pub const DAT_DELIMITER: char = '\u{14}';
+/// Concordance text qualifier
+pub const DAT_QUOTE: char = 'þ';
+/// Replacement for line breaks inside DAT values
+pub const DAT_NEWLINE: char = '®';
+/// Volume name used for every production export
+pub const VOLUME: &str = "VOL001";
+
+/// A produced document together with its Bates assignment
+pub struct LoadFileDocument<'a> {
+    pub document: &'a Document,
+    pub produced: &'a ProductionDocument,
+}
+
+/// Validate a DAT column mapping
+pub fn validate_fields(fields: &[LoadFileField]) -> Result<(), AppError> {
+    if fields.is_empty() {
+        return Err(AppError::Validation(
+            "A load file needs at least one field".to_string(),
+        ));
+    }
+
+    let mut seen = HashSet::new();
+    for field in fields {
+        let name = field.name.trim();
+        if name.is_empty() {
+            return Err(AppError::Validation(
+                "Load file field names may not be empty".to_string(),
+            ));
+        }
+        if name.contains(DAT_DELIMITER) || name.contains(DAT_QUOTE) {
+            return Err(AppError::Validation(format!(
+                "Load file field name {} contains a reserved character",
+                name
+            )));
+        }
+        if !seen.insert(name.to_uppercase()) {
+            return Err(AppError::Validation(format!(
+                "Duplicate load file field {}",
+                name
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Archive path of a document's native file.
+///
+/// Documents are stored as text, so their natives are text files whatever
+/// extension the title carries.
+pub fn native_path(begin_bates: &str) -> String {
+    format!("{}/NATIVES/{}.txt", VOLUME, begin_bates)
+}
+
+/// Archive path of a document's extracted text
+pub fn text_path(begin_bates: &str) -> String {
+    format!("{}/TEXT/{}.txt", VOLUME, begin_bates)
+}
+
+/// Archive path of a document's stamped image
+pub fn image_path(begin_bates: &str) -> String {
+    format!("{}/IMAGES/{}.pdf", VOLUME, begin_bates)
+}
+
+/// Load files reference archive paths with Windows separators
+fn load_file_path(path: &str) -> String {
+    format!(".\\{}", path.replace('/', "\\"))
+}
+
+fn dat_value(value: &str) -> String {
+    value
+        .replace("\r\n", "\n")
+        .replace(['\r', '\n'], &DAT_NEWLINE.to_string())
+        .replace([DAT_QUOTE, DAT_DELIMITER], " ")
+}
+
+fn field_value(
+    source: LoadFileSource,
+    production: &ProductionSet,
+    entry: &LoadFileDocument,
+    padding: i32,
+) -> String {
+    let produced = entry.produced;
+    let document = entry.document;
+    let begin = format_bates(&produced.bates_prefix, produced.bates_begin, padding);
+
+    match source {
+        LoadFileSource::BatesBegin => begin,
+        LoadFileSource::BatesEnd => {
+            format_bates(&produced.bates_prefix, produced.bates_end, padding)
+        }
+        LoadFileSource::PageCount => produced.page_count.to_string(),
+        LoadFileSource::DocumentId => document.id.to_string(),
+        LoadFileSource::Title => document.title.clone(),
+        LoadFileSource::DocType => document.doc_type.clone(),
+        LoadFileSource::Tags => document.tags.join("; "),
+        LoadFileSource::UploadDate => document.upload_date.format("%m/%d/%Y").to_string(),
+        LoadFileSource::LastModified => document.last_modified.format("%m/%d/%Y").to_string(),
+        LoadFileSource::Confidentiality => production.confidentiality.clone().unwrap_or_default(),
+        LoadFileSource::ProductionName => production.name.clone(),
+        // Redacted documents are produced without natives
+        LoadFileSource::NativePath if produced.redacted_version_id.is_some() => String::new(),
+        LoadFileSource::NativePath => load_file_path(&native_path(&begin)),
+        LoadFileSource::TextPath => load_file_path(&text_path(&begin)),
+    }
+}
+
+/// Build a Concordance DAT file (header row plus one row per document)
+pub fn build_dat(
+    fields: &[LoadFileField],
+    production: &ProductionSet,
+    documents: &[LoadFileDocument],
+    padding: i32,
+) -> String {
+    let row = |values: Vec<String>| -> String {
+        values
+            .iter()
+            .map(|v| format!("{}{}{}", DAT_QUOTE, dat_value(v), DAT_QUOTE))
+            .collect::<Vec<_>>()
+            .join(&DAT_DELIMITER.to_string())
+    };
+
+    let mut lines = Vec::with_capacity(documents.len() + 1);
+    lines.push(row(fields
+        .iter()
+        .map(|f| f.name.trim().to_string())
+        .collect()));
+    for entry in documents {
+        lines.push(row(fields
+            .iter()
+            .map(|f| field_value(f.source, production, entry, padding))
+            .collect()));
+    }
+
+    let mut dat = String::from('\u{feff}');
+    dat.push_str(&lines.join("\r\n"));
+    dat.push_str("\r\n");
+    dat
+}
+
+/// Build an Opticon OPT image cross-reference (one row per page)
+pub fn build_opt(documents: &[LoadFileDocument], padding: i32) -> String {
+    let mut lines = Vec::new();
+
+    for entry in documents {
+        let produced = entry.produced;
+        let begin = format_bates(&produced.bates_prefix, produced.bates_begin, padding);
+        let image = load_file_path(&image_path(&begin));
+
+        for page in 0..produced.page_count {
+            let key = format_bates(&produced.bates_prefix, produced.bates_begin + page, padding);
+            if page == 0 {
+                lines.push(format!(
+                    "{},{},{},Y,,,{}",
+                    key, VOLUME, image, produced.page_count
+                ));
+            } else {
+                lines.push(format!("{},{},{},,,,", key, VOLUME, image));
+            }
+        }
+    }
+
+    let mut opt = lines.join("\r\n");
+    opt.push_str("\r\n");
+    opt
+}
@@ -0,0 +1,7 @@
+pub mod bates;
+pub mod handlers;
+pub mod load_file;
+pub mod service;
+
+pub use handlers::*;
+pub use service::*;
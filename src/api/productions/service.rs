@@ -0,0 +1,659 @@
+use crate::api::productions::bates::{
+    format_bates, format_bates_range, paginate, render_stamped_pdf, validate_prefix,
+    DEFAULT_PADDING, MAX_PADDING,
+};
+use crate::api::productions::load_file::{
+    build_dat, build_opt, image_path, native_path, text_path, validate_fields, LoadFileDocument,
+    VOLUME,
+};
+use crate::error::AppError;
+use crate::export::ZipArchiveBuilder;
+use crate::models::{
+    Document, LoadFileField, ProductionDocument, ProductionSet, ProductionSetResponse,
+};
+use chrono::Utc;
+use sqlx::PgPool;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// Parameters for creating a new production set
+pub struct CreateProductionParams {
+    /// ID of the case the documents are produced from
+    pub case_id: Uuid,
+    /// Name of the production (e.g., "Plaintiff's First Production")
+    pub name: String,
+    /// Documents to produce, in production order
+    pub document_ids: Vec<Uuid>,
+    /// Bates prefix (e.g., "ACME")
+    pub prefix: String,
+    /// First Bates number to assign (defaults to the next unused number)
+    pub start_number: Option<i32>,
+    /// Number of digits in each Bates number (defaults to 8)
+    pub padding: Option<i32>,
+    /// Confidentiality legend stamped on every page (optional)
+    pub confidentiality: Option<String>,
+    /// Production format (defaults to "PDF")
+    pub format: Option<String>,
+    /// DAT load file column mapping (defaults to the standard mapping)
+    pub load_file_fields: Option<Vec<LoadFileField>>,
+    /// ID of the user creating the production
+    pub created_by: Uuid,
+}
+
+/// Downloadable load file archive for a production
+pub struct ProductionExport {
+    /// Suggested file name of the archive
+    pub file_name: String,
+    /// ZIP archive bytes
+    pub archive: Vec<u8>,
+}
+
+/// Stamped PDF output for a single produced document
+pub struct StampedDocument {
+    /// Bates number of the first page, used as the file name
+    pub begin_bates: String,
+    /// Rendered PDF bytes
+    pub pdf: Vec<u8>,
+}
+
+pub struct ProductionService {
+    pool: PgPool,
+}
+
+impl ProductionService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// List production sets for a case
+    pub async fn list_productions(&self, case_id: Uuid) -> Result<Vec<ProductionSet>, AppError> {
+        let productions = sqlx::query_as::<_, ProductionSet>(
+            "SELECT * FROM production_sets WHERE case_id = $1 AND deleted_at IS NULL ORDER BY date DESC",
+        )
+        .bind(case_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(productions)
+    }
+
+    /// Get a production set with its per-document Bates ranges
+    pub async fn get_production(&self, id: Uuid) -> Result<ProductionSetResponse, AppError> {
+        let production = sqlx::query_as::<_, ProductionSet>(
+            "SELECT * FROM production_sets WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound("Production set not found".to_string()))?;
+
+        let documents = self.list_production_documents(id).await?;
+        let withheld_document_ids = self.list_withheld_document_ids(id).await?;
+
+        Ok(ProductionSetResponse {
+            production,
+            documents,
+            withheld_document_ids,
+        })
+    }
+
+    /// IDs of the selected documents that were withheld from a production as privileged
+    pub async fn list_withheld_document_ids(
+        &self,
+        production_set_id: Uuid,
+    ) -> Result<Vec<Uuid>, AppError> {
+        let ids = sqlx::query_scalar::<_, Uuid>(
+            "SELECT document_id FROM production_withheld_items WHERE production_set_id = $1 ORDER BY created_at",
+        )
+        .bind(production_set_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(ids)
+    }
+
+    /// List the per-document Bates ranges of a production in production order
+    pub async fn list_production_documents(
+        &self,
+        production_set_id: Uuid,
+    ) -> Result<Vec<ProductionDocument>, AppError> {
+        let documents = sqlx::query_as::<_, ProductionDocument>(
+            "SELECT * FROM production_documents WHERE production_set_id = $1 ORDER BY sort_order",
+        )
+        .bind(production_set_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(documents)
+    }
+
+    /// Create a production set, assigning contiguous Bates numbers per page.
+    ///
+    /// Numbers are allocated from a per-case, per-prefix counter that is locked
+    /// for the duration of the transaction, so two productions in the same case
+    /// can never share a Bates number. Documents with an active privilege
+    /// designation are withheld and recorded against the production instead.
+    /// Redacted documents are produced from their burned-in version only.
+    pub async fn create_production(
+        &self,
+        params: CreateProductionParams,
+    ) -> Result<ProductionSetResponse, AppError> {
+        validate_prefix(&params.prefix)?;
+
+        let padding = params.padding.unwrap_or(DEFAULT_PADDING);
+        if !(1..=MAX_PADDING).contains(&padding) {
+            return Err(AppError::Validation(format!(
+                "Bates padding must be between 1 and {}",
+                MAX_PADDING
+            )));
+        }
+
+        if params.document_ids.is_empty() {
+            return Err(AppError::Validation(
+                "A production must contain at least one document".to_string(),
+            ));
+        }
+
+        let mut seen = HashSet::new();
+        if !params.document_ids.iter().all(|id| seen.insert(*id)) {
+            return Err(AppError::Validation(
+                "A document may only appear once in a production".to_string(),
+            ));
+        }
+
+        let load_file_fields = match params.load_file_fields {
+            Some(fields) => {
+                validate_fields(&fields)?;
+                Some(
+                    serde_json::to_value(&fields)
+                        .map_err(|e| AppError::InternalServerError(e.to_string()))?,
+                )
+            }
+            None => None,
+        };
+
+        let mut tx = self.pool.begin().await?;
+
+        let mut documents = sqlx::query_as::<_, Document>(
+            "SELECT * FROM documents WHERE id = ANY($1) AND case_id = $2 AND deleted_at IS NULL",
+        )
+        .bind(&params.document_ids)
+        .bind(params.case_id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        if documents.len() != params.document_ids.len() {
+            return Err(AppError::BadRequest(
+                "One or more documents were not found in this case".to_string(),
+            ));
+        }
+
+        // Privileged documents are withheld rather than produced
+        let withheld: Vec<(Uuid, Uuid)> = sqlx::query_as(
+            "SELECT id, document_id FROM privilege_designations WHERE document_id = ANY($1) AND deleted_at IS NULL",
+        )
+        .bind(&params.document_ids)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let withheld_ids: HashSet<Uuid> = withheld.iter().map(|(_, doc)| *doc).collect();
+        let produced_ids: Vec<Uuid> = params
+            .document_ids
+            .iter()
+            .filter(|id| !withheld_ids.contains(id))
+            .copied()
+            .collect();
+
+        if produced_ids.is_empty() {
+            return Err(AppError::Validation(
+                "All selected documents are privileged; nothing to produce".to_string(),
+            ));
+        }
+
+        // Redactions must be reviewed and burned in before a document is produced
+        let unredacted: Vec<String> = sqlx::query_scalar(
+            r#"
+            SELECT d.title FROM documents d
+            WHERE d.id = ANY($1) AND d.redacted_version_id IS NULL
+              AND EXISTS (
+                  SELECT 1 FROM redactions r
+                  WHERE r.document_id = d.id AND r.deleted_at IS NULL AND r.status <> 'Rejected'
+              )
+            "#,
+        )
+        .bind(&produced_ids)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        if !unredacted.is_empty() {
+            return Err(AppError::Validation(format!(
+                "Redactions have not been approved and applied for: {}",
+                unredacted.join(", ")
+            )));
+        }
+
+        let redacted: Vec<(Uuid, Uuid, Option<String>)> = sqlx::query_as(
+            r#"
+            SELECT d.id, v.id, v.content_snapshot FROM documents d
+            JOIN document_versions v ON v.id = d.redacted_version_id
+            WHERE d.id = ANY($1)
+            "#,
+        )
+        .bind(&produced_ids)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let mut redacted_versions = HashMap::new();
+        for (document_id, version_id, content) in redacted {
+            if let Some(document) = documents.iter_mut().find(|d| d.id == document_id) {
+                document.content = content;
+            }
+            redacted_versions.insert(document_id, version_id);
+        }
+
+        sqlx::query(
+            "INSERT INTO bates_counters (case_id, prefix, next_number) VALUES ($1, $2, 1) ON CONFLICT DO NOTHING",
+        )
+        .bind(params.case_id)
+        .bind(&params.prefix)
+        .execute(&mut *tx)
+        .await?;
+
+        let next_number: i32 = sqlx::query_scalar(
+            "SELECT next_number FROM bates_counters WHERE case_id = $1 AND prefix = $2 FOR UPDATE",
+        )
+        .bind(params.case_id)
+        .bind(&params.prefix)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let start = params.start_number.unwrap_or(next_number);
+        if start < 1 {
+            return Err(AppError::Validation(
+                "Bates start number must be positive".to_string(),
+            ));
+        }
+        if start < next_number {
+            return Err(AppError::Conflict(format!(
+                "Bates numbers for prefix {} are already used up to {} in this case",
+                params.prefix,
+                next_number - 1
+            )));
+        }
+
+        // Assign ranges in the order the documents were selected
+        let mut ranges = Vec::with_capacity(documents.len());
+        let mut cursor = start;
+        let mut total_bytes = 0usize;
+        for document_id in &produced_ids {
+            let document = documents
+                .iter()
+                .find(|d| d.id == *document_id)
+                .ok_or(AppError::NotFound("Document not found".to_string()))?;
+            let page_count =
+                i32::try_from(paginate(document.content.as_deref()).len()).unwrap_or(i32::MAX);
+            let next = cursor.checked_add(page_count).ok_or_else(|| {
+                AppError::Validation(format!(
+                    "Bates numbers starting at {} run past the largest supported number",
+                    start
+                ))
+            })?;
+            total_bytes += document.content.as_deref().map(str::len).unwrap_or(0);
+            ranges.push((
+                document.id,
+                cursor,
+                next - 1,
+                page_count,
+                redacted_versions.get(&document.id).copied(),
+            ));
+            cursor = next;
+        }
+        let end = cursor - 1;
+
+        if end.to_string().len() > padding as usize {
+            return Err(AppError::Validation(format!(
+                "Bates number {} does not fit in {} digits",
+                end, padding
+            )));
+        }
+
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+        let format = params.format.unwrap_or_else(|| "PDF".to_string());
+
+        let production = sqlx::query_as::<_, ProductionSet>(
+            r#"
+            INSERT INTO production_sets (
+                id, case_id, name, date, bates_range, doc_count, size, format, status,
+                bates_prefix, bates_start, bates_end, bates_padding, confidentiality,
+                load_file_fields, created_at, updated_at, created_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18)
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(params.case_id)
+        .bind(&params.name)
+        .bind(now)
+        .bind(format_bates_range(&params.prefix, start, end, padding))
+        .bind(ranges.len() as i32)
+        .bind(format_size(total_bytes))
+        .bind(&format)
+        .bind("Stamped")
+        .bind(&params.prefix)
+        .bind(start)
+        .bind(end)
+        .bind(padding)
+        .bind(&params.confidentiality)
+        .bind(&load_file_fields)
+        .bind(now)
+        .bind(now)
+        .bind(params.created_by)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let mut produced = Vec::with_capacity(ranges.len());
+        for (sort_order, (document_id, begin, end, page_count, redacted_version_id)) in
+            ranges.into_iter().enumerate()
+        {
+            let row = sqlx::query_as::<_, ProductionDocument>(
+                r#"
+                INSERT INTO production_documents (
+                    id, production_set_id, case_id, document_id, sort_order,
+                    bates_prefix, bates_begin, bates_end, page_count, redacted_version_id,
+                    created_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                RETURNING *
+                "#,
+            )
+            .bind(Uuid::new_v4())
+            .bind(id)
+            .bind(params.case_id)
+            .bind(document_id)
+            .bind(sort_order as i32)
+            .bind(&params.prefix)
+            .bind(begin)
+            .bind(end)
+            .bind(page_count)
+            .bind(redacted_version_id)
+            .bind(now)
+            .fetch_one(&mut *tx)
+            .await?;
+            produced.push(row);
+        }
+
+        let mut withheld_document_ids = Vec::with_capacity(withheld.len());
+        for document_id in params
+            .document_ids
+            .iter()
+            .filter(|id| withheld_ids.contains(id))
+        {
+            let (designation_id, _) =
+                withheld
+                    .iter()
+                    .find(|(_, doc)| doc == document_id)
+                    .ok_or(AppError::NotFound(
+                        "Privilege designation not found".to_string(),
+                    ))?;
+
+            sqlx::query(
+                r#"
+                INSERT INTO production_withheld_items (
+                    id, production_set_id, privilege_designation_id, document_id, created_at
+                )
+                VALUES ($1, $2, $3, $4, $5)
+                "#,
+            )
+            .bind(Uuid::new_v4())
+            .bind(id)
+            .bind(designation_id)
+            .bind(document_id)
+            .bind(now)
+            .execute(&mut *tx)
+            .await?;
+            withheld_document_ids.push(*document_id);
+        }
+
+        sqlx::query(
+            "UPDATE bates_counters SET next_number = $1, updated_at = $2 WHERE case_id = $3 AND prefix = $4",
+        )
+        .bind(end + 1)
+        .bind(now)
+        .bind(params.case_id)
+        .bind(&params.prefix)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(ProductionSetResponse {
+            production,
+            documents: produced,
+            withheld_document_ids,
+        })
+    }
+
+    /// Render a produced document as a PDF stamped with its Bates numbers
+    pub async fn render_stamped_document(
+        &self,
+        production_set_id: Uuid,
+        document_id: Uuid,
+    ) -> Result<StampedDocument, AppError> {
+        let production = self.get_production(production_set_id).await?.production;
+
+        let produced = sqlx::query_as::<_, ProductionDocument>(
+            "SELECT * FROM production_documents WHERE production_set_id = $1 AND document_id = $2",
+        )
+        .bind(production_set_id)
+        .bind(document_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound(
+            "Document is not part of this production".to_string(),
+        ))?;
+
+        let mut documents = sqlx::query_as::<_, Document>("SELECT * FROM documents WHERE id = $1")
+            .bind(document_id)
+            .fetch_all(&self.pool)
+            .await?;
+        self.use_produced_content(&mut documents, std::slice::from_ref(&produced))
+            .await?;
+        let document = documents
+            .pop()
+            .ok_or(AppError::NotFound("Document not found".to_string()))?;
+
+        let padding = production.bates_padding.unwrap_or(DEFAULT_PADDING);
+        let pages = paginate(document.content.as_deref());
+        let pdf = render_stamped_pdf(
+            &document.title,
+            &pages,
+            &produced.bates_prefix,
+            produced.bates_begin,
+            padding,
+            production.confidentiality.as_deref(),
+        );
+
+        Ok(StampedDocument {
+            begin_bates: format_bates(&produced.bates_prefix, produced.bates_begin, padding),
+            pdf,
+        })
+    }
+
+    /// DAT column mapping of a production, falling back to the defaults
+    pub async fn get_load_file_fields(&self, id: Uuid) -> Result<Vec<LoadFileField>, AppError> {
+        let production = self.get_production(id).await?.production;
+        Ok(load_file_fields(&production))
+    }
+
+    /// Replace the DAT column mapping of a production
+    pub async fn update_load_file_fields(
+        &self,
+        id: Uuid,
+        fields: Vec<LoadFileField>,
+    ) -> Result<Vec<LoadFileField>, AppError> {
+        validate_fields(&fields)?;
+
+        let value = serde_json::to_value(&fields)
+            .map_err(|e| AppError::InternalServerError(e.to_string()))?;
+
+        let result = sqlx::query(
+            "UPDATE production_sets SET load_file_fields = $1, updated_at = $2 WHERE id = $3 AND deleted_at IS NULL",
+        )
+        .bind(value)
+        .bind(Utc::now())
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound("Production set not found".to_string()));
+        }
+
+        Ok(fields)
+    }
+
+    /// Build a load file archive for a production.
+    ///
+    /// The archive contains a DAT (metadata) and OPT (image cross-reference)
+    /// load file plus NATIVES, TEXT and IMAGES folders named by Bates number.
+    pub async fn export_production(&self, id: Uuid) -> Result<ProductionExport, AppError> {
+        let ProductionSetResponse {
+            production,
+            documents: produced,
+            ..
+        } = self.get_production(id).await?;
+
+        let document_ids: Vec<Uuid> = produced.iter().map(|p| p.document_id).collect();
+        let mut documents =
+            sqlx::query_as::<_, Document>("SELECT * FROM documents WHERE id = ANY($1)")
+                .bind(&document_ids)
+                .fetch_all(&self.pool)
+                .await?;
+        self.use_produced_content(&mut documents, &produced).await?;
+
+        let padding = production.bates_padding.unwrap_or(DEFAULT_PADDING);
+        let mut entries = Vec::with_capacity(produced.len());
+        for row in &produced {
+            let document = documents
+                .iter()
+                .find(|d| d.id == row.document_id)
+                .ok_or(AppError::NotFound("Document not found".to_string()))?;
+            entries.push(LoadFileDocument {
+                document,
+                produced: row,
+            });
+        }
+
+        let base_name = production
+            .bates_prefix
+            .clone()
+            .unwrap_or_else(|| "PRODUCTION".to_string());
+
+        let mut archive = ZipArchiveBuilder::new();
+        archive.add_directory(&format!("{}/DATA/", VOLUME))?;
+        archive.add_directory(&format!("{}/NATIVES/", VOLUME))?;
+        archive.add_directory(&format!("{}/TEXT/", VOLUME))?;
+        archive.add_directory(&format!("{}/IMAGES/", VOLUME))?;
+
+        let fields = load_file_fields(&production);
+        archive.add_file(
+            &format!("{}/DATA/{}.dat", VOLUME, base_name),
+            build_dat(&fields, &production, &entries, padding).as_bytes(),
+        )?;
+        archive.add_file(
+            &format!("{}/DATA/{}.opt", VOLUME, base_name),
+            build_opt(&entries, padding).as_bytes(),
+        )?;
+
+        for entry in &entries {
+            let produced = entry.produced;
+            let document = entry.document;
+            let begin = format_bates(&produced.bates_prefix, produced.bates_begin, padding);
+            let content = document.content.as_deref().unwrap_or("");
+
+            // Natives would carry the unredacted original, so redacted
+            // documents are produced as images and text only
+            if produced.redacted_version_id.is_none() {
+                archive.add_file(&native_path(&begin), content.as_bytes())?;
+            }
+            archive.add_file(&text_path(&begin), content.as_bytes())?;
+
+            let pages = paginate(document.content.as_deref());
+            let pdf = render_stamped_pdf(
+                &document.title,
+                &pages,
+                &produced.bates_prefix,
+                produced.bates_begin,
+                padding,
+                production.confidentiality.as_deref(),
+            );
+            archive.add_file(&image_path(&begin), &pdf)?;
+        }
+
+        Ok(ProductionExport {
+            file_name: format!("{}.zip", base_name),
+            archive: archive.finish()?,
+        })
+    }
+
+    /// Replace document content with the redacted version that was produced
+    async fn use_produced_content(
+        &self,
+        documents: &mut [Document],
+        produced: &[ProductionDocument],
+    ) -> Result<(), AppError> {
+        let version_ids: Vec<Uuid> = produced
+            .iter()
+            .filter_map(|p| p.redacted_version_id)
+            .collect();
+        if version_ids.is_empty() {
+            return Ok(());
+        }
+
+        let versions: Vec<(Uuid, Option<String>)> =
+            sqlx::query_as("SELECT id, content_snapshot FROM document_versions WHERE id = ANY($1)")
+                .bind(&version_ids)
+                .fetch_all(&self.pool)
+                .await?;
+
+        for row in produced {
+            let Some(version_id) = row.redacted_version_id else {
+                continue;
+            };
+            let content = versions
+                .iter()
+                .find(|(id, _)| *id == version_id)
+                .map(|(_, content)| content.clone())
+                .ok_or(AppError::NotFound(
+                    "Redacted document version not found".to_string(),
+                ))?;
+            if let Some(document) = documents.iter_mut().find(|d| d.id == row.document_id) {
+                document.content = content;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Stored DAT mapping of a production, or the default mapping
+fn load_file_fields(production: &ProductionSet) -> Vec<LoadFileField> {
+    production
+        .load_file_fields
+        .clone()
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_else(LoadFileField::defaults)
+}
+
+/// Human-readable size of the produced text, e.g. "12.4 KB"
+fn format_size(bytes: usize) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
@@ -0,0 +1,79 @@
+use crate::api::productions::bates::{
+    paginate, BODY_FONT_SIZE, BODY_LEFT, BODY_LINE_HEIGHT, BODY_TOP, REDACTION_MASK,
+};
+use crate::export::PdfFont;
+
+/// Rectangle on a rendered page, in PDF points with the origin bottom-left
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PageArea {
+    /// 1-based page number
+    pub page_number: usize,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// Burn redactions into document text.
+///
+/// Text spans are character offsets into `content`; only non-whitespace is
+/// masked so the document wraps exactly as it did when the page areas were
+/// drawn. Page areas then mask every character they overlap. The result is
+/// the paginated text with one line per rendered line, which paginates back
+/// to the same pages.
+pub fn burn_in(content: Option<&str>, spans: &[(usize, usize)], areas: &[PageArea]) -> String {
+    let mut chars: Vec<char> = content.unwrap_or("").chars().collect();
+    for &(start, end) in spans {
+        let end = end.min(chars.len());
+        for c in chars.iter_mut().take(end).skip(start) {
+            if !c.is_whitespace() {
+                *c = REDACTION_MASK;
+            }
+        }
+    }
+
+    let masked: String = chars.into_iter().collect();
+    let mut pages = paginate(Some(&masked));
+    let char_width = PdfFont::Courier.char_width(BODY_FONT_SIZE);
+
+    for area in areas {
+        let Some(lines) = area
+            .page_number
+            .checked_sub(1)
+            .and_then(|index| pages.get_mut(index))
+        else {
+            continue;
+        };
+
+        for (row, line) in lines.iter_mut().enumerate() {
+            let baseline = BODY_TOP - BODY_LINE_HEIGHT * row as f32;
+            let bottom = baseline - BODY_FONT_SIZE * 0.25;
+            let top = bottom + BODY_FONT_SIZE;
+            if top <= area.y || bottom >= area.y + area.height {
+                continue;
+            }
+
+            *line = line
+                .chars()
+                .enumerate()
+                .map(|(column, c)| {
+                    let left = BODY_LEFT + column as f32 * char_width;
+                    let right = left + char_width;
+                    if right > area.x && left < area.x + area.width {
+                        REDACTION_MASK
+                    } else {
+                        c
+                    }
+                })
+                .collect();
+        }
+    }
+
+    let lines: Vec<String> = pages.into_iter().flatten().collect();
+    let mut text = lines.join("\n");
+    // A trailing blank line only survives re-pagination with a final newline
+    if lines.last().is_some_and(|line| line.is_empty()) {
+        text.push('\n');
+    }
+    text
+}
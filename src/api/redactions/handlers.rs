@@ -0,0 +1,205 @@
+use crate::api::redactions::service::{CreateRedactionParams, RedactionService};
+use crate::error::AppError;
+use crate::models::{Claims, DocumentVersion, Redaction, RedactionReason};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateRedactionRequest {
+    pub start_offset: Option<i32>,
+    pub end_offset: Option<i32>,
+    pub page_number: Option<i32>,
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    pub width: Option<f64>,
+    pub height: Option<f64>,
+    pub reason: RedactionReason,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReviewRedactionRequest {
+    pub approve: bool,
+    pub note: Option<String>,
+}
+
+/// List redactions on a document
+#[utoipa::path(
+    get,
+    path = "/api/documents/{id}/redactions",
+    params(
+        ("id" = Uuid, Path, description = "Document ID")
+    ),
+    responses(
+        (status = 200, description = "List of redactions", body = Vec<Redaction>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "redactions",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_redactions(
+    State(service): State<Arc<RedactionService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<Redaction>>, AppError> {
+    let redactions = service.list_redactions(id).await?;
+    Ok(Json(redactions))
+}
+
+/// Propose a redaction of a text span or page area
+#[utoipa::path(
+    post,
+    path = "/api/documents/{id}/redactions",
+    params(
+        ("id" = Uuid, Path, description = "Document ID")
+    ),
+    request_body = CreateRedactionRequest,
+    responses(
+        (status = 201, description = "Redaction proposed", body = Redaction),
+        (status = 400, description = "Invalid redaction"),
+        (status = 404, description = "Document not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "redactions",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_redaction(
+    State(service): State<Arc<RedactionService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<CreateRedactionRequest>,
+) -> Result<(StatusCode, Json<Redaction>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let redaction = service
+        .create_redaction(CreateRedactionParams {
+            document_id: id,
+            start_offset: req.start_offset,
+            end_offset: req.end_offset,
+            page_number: req.page_number,
+            x: req.x,
+            y: req.y,
+            width: req.width,
+            height: req.height,
+            reason: req.reason,
+            note: req.note,
+            created_by: user_id,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(redaction)))
+}
+
+/// Approve or reject a proposed redaction
+#[utoipa::path(
+    post,
+    path = "/api/redactions/{id}/review",
+    params(
+        ("id" = Uuid, Path, description = "Redaction ID")
+    ),
+    request_body = ReviewRedactionRequest,
+    responses(
+        (status = 200, description = "Redaction reviewed", body = Redaction),
+        (status = 403, description = "Authors cannot review their own redactions"),
+        (status = 404, description = "Redaction not found"),
+        (status = 409, description = "Redaction was already reviewed"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "redactions",
+    security(("bearer_auth" = []))
+)]
+pub async fn review_redaction(
+    State(service): State<Arc<RedactionService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<ReviewRedactionRequest>,
+) -> Result<Json<Redaction>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let redaction = service
+        .review_redaction(id, req.approve, req.note, user_id)
+        .await?;
+    Ok(Json(redaction))
+}
+
+/// Delete a redaction
+#[utoipa::path(
+    delete,
+    path = "/api/redactions/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Redaction ID")
+    ),
+    responses(
+        (status = 204, description = "Redaction deleted"),
+        (status = 404, description = "Redaction not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "redactions",
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_redaction(
+    State(service): State<Arc<RedactionService>>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    service.delete_redaction(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Burn approved redactions into a new redacted document version
+#[utoipa::path(
+    post,
+    path = "/api/documents/{id}/redactions/apply",
+    params(
+        ("id" = Uuid, Path, description = "Document ID")
+    ),
+    responses(
+        (status = 201, description = "Redacted version created", body = DocumentVersion),
+        (status = 400, description = "Redactions are pending review or none are approved"),
+        (status = 404, description = "Document not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "redactions",
+    security(("bearer_auth" = []))
+)]
+pub async fn apply_redactions(
+    State(service): State<Arc<RedactionService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> Result<(StatusCode, Json<DocumentVersion>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let version = service.apply_redactions(id, &claims.email, user_id).await?;
+    Ok((StatusCode::CREATED, Json(version)))
+}
+
+/// Get the current burned-in redacted version of a document
+#[utoipa::path(
+    get,
+    path = "/api/documents/{id}/redacted",
+    params(
+        ("id" = Uuid, Path, description = "Document ID")
+    ),
+    responses(
+        (status = 200, description = "Redacted document version", body = DocumentVersion),
+        (status = 404, description = "Document has no applied redactions"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "redactions",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_redacted_version(
+    State(service): State<Arc<RedactionService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<DocumentVersion>, AppError> {
+    let version = service.get_redacted_version(id).await?;
+    Ok(Json(version))
+}
@@ -0,0 +1,6 @@
+pub mod burn;
+pub mod handlers;
+pub mod service;
+
+pub use handlers::*;
+pub use service::*;
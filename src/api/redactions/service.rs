@@ -0,0 +1,367 @@
+use crate::api::productions::bates::paginate;
+use crate::api::redactions::burn::{burn_in, PageArea};
+use crate::error::AppError;
+use crate::models::{Document, DocumentVersion, Redaction, RedactionReason, RedactionStatus};
+use chrono::Utc;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Parameters for annotating a document with a redaction
+pub struct CreateRedactionParams {
+    /// Document being redacted
+    pub document_id: Uuid,
+    /// First redacted character of the document text (text redactions)
+    pub start_offset: Option<i32>,
+    /// Character after the last redacted one (text redactions)
+    pub end_offset: Option<i32>,
+    /// 1-based page of the rendered document (page redactions)
+    pub page_number: Option<i32>,
+    /// Page area in PDF points, origin bottom-left (page redactions)
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    pub width: Option<f64>,
+    pub height: Option<f64>,
+    /// Why the content is redacted
+    pub reason: RedactionReason,
+    /// Reviewer-facing note (optional)
+    pub note: Option<String>,
+    /// ID of the user proposing the redaction
+    pub created_by: Uuid,
+}
+
+pub struct RedactionService {
+    pool: PgPool,
+}
+
+impl RedactionService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// List active redactions on a document
+    pub async fn list_redactions(&self, document_id: Uuid) -> Result<Vec<Redaction>, AppError> {
+        let redactions = sqlx::query_as::<_, Redaction>(
+            r#"
+            SELECT * FROM redactions
+            WHERE document_id = $1 AND deleted_at IS NULL
+            ORDER BY page_number NULLS FIRST, start_offset, created_at
+            "#,
+        )
+        .bind(document_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(redactions)
+    }
+
+    /// Get a redaction by ID
+    pub async fn get_redaction(&self, id: Uuid) -> Result<Redaction, AppError> {
+        sqlx::query_as::<_, Redaction>(
+            "SELECT * FROM redactions WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound("Redaction not found".to_string()))
+    }
+
+    /// Propose a redaction of a text span or page area.
+    ///
+    /// Any change to a document's redactions invalidates its burned-in
+    /// version until the redactions are reviewed and applied again.
+    pub async fn create_redaction(
+        &self,
+        params: CreateRedactionParams,
+    ) -> Result<Redaction, AppError> {
+        let document = self.get_document(params.document_id).await?;
+        let content = document.content.as_deref().unwrap_or("");
+
+        match (params.start_offset, params.end_offset, params.page_number) {
+            (Some(start), Some(end), None) => {
+                let length = content.chars().count() as i32;
+                if start < 0 || end <= start || end > length {
+                    return Err(AppError::Validation(format!(
+                        "Text redaction must cover a range within 0..{}",
+                        length
+                    )));
+                }
+            }
+            (None, None, Some(page)) => {
+                let pages = paginate(document.content.as_deref()).len() as i32;
+                if page < 1 || page > pages {
+                    return Err(AppError::Validation(format!(
+                        "Page redaction must be on a page between 1 and {}",
+                        pages
+                    )));
+                }
+                match (params.x, params.y, params.width, params.height) {
+                    (Some(x), Some(y), Some(width), Some(height))
+                        if x >= 0.0 && y >= 0.0 && width > 0.0 && height > 0.0 => {}
+                    _ => {
+                        return Err(AppError::Validation(
+                            "Page redaction needs a positive x, y, width and height".to_string(),
+                        ))
+                    }
+                }
+            }
+            _ => {
+                return Err(AppError::Validation(
+                    "Provide either start_offset and end_offset or a page_number".to_string(),
+                ))
+            }
+        }
+
+        let is_page = params.page_number.is_some();
+        let now = Utc::now();
+        let mut tx = self.pool.begin().await?;
+
+        let redaction = sqlx::query_as::<_, Redaction>(
+            r#"
+            INSERT INTO redactions (
+                id, document_id, case_id, start_offset, end_offset, page_number,
+                x, y, width, height, reason, note, status, created_at, updated_at, created_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(document.id)
+        .bind(document.case_id)
+        .bind(params.start_offset)
+        .bind(params.end_offset)
+        .bind(params.page_number)
+        .bind(params.x.filter(|_| is_page))
+        .bind(params.y.filter(|_| is_page))
+        .bind(params.width.filter(|_| is_page))
+        .bind(params.height.filter(|_| is_page))
+        .bind(&params.reason)
+        .bind(&params.note)
+        .bind(RedactionStatus::Proposed)
+        .bind(now)
+        .bind(now)
+        .bind(params.created_by)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        invalidate_redacted_version(&mut tx, document.id).await?;
+        tx.commit().await?;
+
+        Ok(redaction)
+    }
+
+    /// Approve or reject a proposed redaction.
+    ///
+    /// Reviews are a second-person check: the user who proposed a redaction
+    /// cannot review it.
+    pub async fn review_redaction(
+        &self,
+        id: Uuid,
+        approve: bool,
+        review_note: Option<String>,
+        reviewer: Uuid,
+    ) -> Result<Redaction, AppError> {
+        let current = self.get_redaction(id).await?;
+
+        if current.status != RedactionStatus::Proposed {
+            return Err(AppError::Conflict(
+                "Only proposed redactions can be reviewed".to_string(),
+            ));
+        }
+        if current.created_by == Some(reviewer) {
+            return Err(AppError::Authorization(
+                "Redactions must be reviewed by someone other than their author".to_string(),
+            ));
+        }
+
+        let status = if approve {
+            RedactionStatus::Approved
+        } else {
+            RedactionStatus::Rejected
+        };
+        let now = Utc::now();
+        let mut tx = self.pool.begin().await?;
+
+        let redaction = sqlx::query_as::<_, Redaction>(
+            r#"
+            UPDATE redactions
+            SET status = $1, reviewed_by = $2, reviewed_at = $3, review_note = $4,
+                updated_at = $3, updated_by = $2, version = version + 1
+            WHERE id = $5 AND status = 'Proposed' AND deleted_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(status)
+        .bind(reviewer)
+        .bind(now)
+        .bind(&review_note)
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(AppError::Conflict(
+            "Only proposed redactions can be reviewed".to_string(),
+        ))?;
+
+        invalidate_redacted_version(&mut tx, redaction.document_id).await?;
+        tx.commit().await?;
+
+        Ok(redaction)
+    }
+
+    /// Remove a redaction
+    pub async fn delete_redaction(&self, id: Uuid) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        let document_id: Uuid = sqlx::query_scalar(
+            r#"
+            UPDATE redactions SET deleted_at = $1, updated_at = $1
+            WHERE id = $2 AND deleted_at IS NULL
+            RETURNING document_id
+            "#,
+        )
+        .bind(Utc::now())
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(AppError::NotFound("Redaction not found".to_string()))?;
+
+        invalidate_redacted_version(&mut tx, document_id).await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Burn approved redactions into a new derived document version.
+    ///
+    /// Every redaction must have been reviewed first. The original content is
+    /// left untouched; productions use the derived version instead.
+    pub async fn apply_redactions(
+        &self,
+        document_id: Uuid,
+        uploaded_by: &str,
+        user_id: Uuid,
+    ) -> Result<DocumentVersion, AppError> {
+        let document = self.get_document(document_id).await?;
+        let redactions = self.list_redactions(document_id).await?;
+
+        if redactions
+            .iter()
+            .any(|r| r.status == RedactionStatus::Proposed)
+        {
+            return Err(AppError::Validation(
+                "All redactions must be reviewed before they are applied".to_string(),
+            ));
+        }
+
+        let approved: Vec<&Redaction> = redactions
+            .iter()
+            .filter(|r| r.status == RedactionStatus::Approved)
+            .collect();
+        if approved.is_empty() {
+            return Err(AppError::Validation(
+                "Document has no approved redactions to apply".to_string(),
+            ));
+        }
+
+        let spans: Vec<(usize, usize)> = approved
+            .iter()
+            .filter_map(|r| Some((r.start_offset? as usize, r.end_offset? as usize)))
+            .collect();
+        let areas: Vec<PageArea> = approved
+            .iter()
+            .filter_map(|r| {
+                Some(PageArea {
+                    page_number: r.page_number? as usize,
+                    x: r.x? as f32,
+                    y: r.y? as f32,
+                    width: r.width? as f32,
+                    height: r.height? as f32,
+                })
+            })
+            .collect();
+
+        let redacted = burn_in(document.content.as_deref(), &spans, &areas);
+        let now = Utc::now();
+        let mut tx = self.pool.begin().await?;
+
+        let version_number: i32 = sqlx::query_scalar(
+            "SELECT COALESCE(MAX(version_number), 0) + 1 FROM document_versions WHERE document_id = $1",
+        )
+        .bind(document_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let version = sqlx::query_as::<_, DocumentVersion>(
+            r#"
+            INSERT INTO document_versions (
+                id, document_id, version_number, uploaded_by, upload_date,
+                content_snapshot, author_id, is_redacted, created_at, created_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, true, $5, $7)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(document_id)
+        .bind(version_number)
+        .bind(uploaded_by)
+        .bind(now)
+        .bind(&redacted)
+        .bind(user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query("UPDATE documents SET redacted_version_id = $1, updated_at = $2 WHERE id = $3")
+            .bind(version.id)
+            .bind(now)
+            .bind(document_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(version)
+    }
+
+    /// Current burned-in redacted version of a document
+    pub async fn get_redacted_version(
+        &self,
+        document_id: Uuid,
+    ) -> Result<DocumentVersion, AppError> {
+        sqlx::query_as::<_, DocumentVersion>(
+            r#"
+            SELECT v.* FROM document_versions v
+            JOIN documents d ON d.redacted_version_id = v.id
+            WHERE d.id = $1 AND d.deleted_at IS NULL
+            "#,
+        )
+        .bind(document_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound(
+            "Document has no applied redactions".to_string(),
+        ))
+    }
+
+    async fn get_document(&self, id: Uuid) -> Result<Document, AppError> {
+        sqlx::query_as::<_, Document>(
+            "SELECT * FROM documents WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound("Document not found".to_string()))
+    }
+}
+
+/// Detach a document's burned-in version after its redactions change
+async fn invalidate_redacted_version(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    document_id: Uuid,
+) -> Result<(), AppError> {
+    sqlx::query("UPDATE documents SET redacted_version_id = NULL WHERE id = $1")
+        .bind(document_id)
+        .execute(&mut **tx)
+        .await?;
+    Ok(())
+}
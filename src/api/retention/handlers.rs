@@ -0,0 +1,199 @@
+use crate::api::retention::service::{RetentionPolicyParams, RetentionService};
+use crate::error::AppError;
+use crate::models::{Claims, Document, MatterType, RetentionPolicy, RetentionPurgeResult};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RetentionPolicyRequest {
+    pub name: String,
+    pub doc_type: Option<String>,
+    pub matter_type: Option<MatterType>,
+    pub retention_days: i32,
+    pub description: Option<String>,
+}
+
+impl RetentionPolicyRequest {
+    fn into_params(self, user_id: Uuid) -> RetentionPolicyParams {
+        RetentionPolicyParams {
+            name: self.name,
+            doc_type: self.doc_type,
+            matter_type: self.matter_type,
+            retention_days: self.retention_days,
+            description: self.description,
+            user_id,
+        }
+    }
+}
+
+/// List retention policies
+#[utoipa::path(
+    get,
+    path = "/api/retention-policies",
+    responses(
+        (status = 200, description = "List of retention policies", body = Vec<RetentionPolicy>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "retention",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_retention_policies(
+    State(service): State<Arc<RetentionService>>,
+) -> Result<Json<Vec<RetentionPolicy>>, AppError> {
+    let policies = service.list_policies().await?;
+    Ok(Json(policies))
+}
+
+/// Get a retention policy
+#[utoipa::path(
+    get,
+    path = "/api/retention-policies/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Retention policy ID")
+    ),
+    responses(
+        (status = 200, description = "Retention policy details", body = RetentionPolicy),
+        (status = 404, description = "Retention policy not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "retention",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_retention_policy(
+    State(service): State<Arc<RetentionService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<RetentionPolicy>, AppError> {
+    let policy = service.get_policy(id).await?;
+    Ok(Json(policy))
+}
+
+/// Create a retention policy
+#[utoipa::path(
+    post,
+    path = "/api/retention-policies",
+    request_body = RetentionPolicyRequest,
+    responses(
+        (status = 201, description = "Retention policy created", body = RetentionPolicy),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "retention",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_retention_policy(
+    State(service): State<Arc<RetentionService>>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<RetentionPolicyRequest>,
+) -> Result<(StatusCode, Json<RetentionPolicy>), AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let policy = service.create_policy(req.into_params(user_id)).await?;
+    Ok((StatusCode::CREATED, Json(policy)))
+}
+
+/// Replace a retention policy
+#[utoipa::path(
+    put,
+    path = "/api/retention-policies/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Retention policy ID")
+    ),
+    request_body = RetentionPolicyRequest,
+    responses(
+        (status = 200, description = "Retention policy updated", body = RetentionPolicy),
+        (status = 404, description = "Retention policy not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "retention",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_retention_policy(
+    State(service): State<Arc<RetentionService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<RetentionPolicyRequest>,
+) -> Result<Json<RetentionPolicy>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))?;
+
+    let policy = service.update_policy(id, req.into_params(user_id)).await?;
+    Ok(Json(policy))
+}
+
+/// Delete a retention policy
+#[utoipa::path(
+    delete,
+    path = "/api/retention-policies/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Retention policy ID")
+    ),
+    responses(
+        (status = 204, description = "Retention policy deleted"),
+        (status = 404, description = "Retention policy not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "retention",
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_retention_policy(
+    State(service): State<Arc<RetentionService>>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    service.delete_policy(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// List documents past their retention period under a policy
+#[utoipa::path(
+    get,
+    path = "/api/retention-policies/{id}/expired",
+    params(
+        ("id" = Uuid, Path, description = "Retention policy ID")
+    ),
+    responses(
+        (status = 200, description = "Expired documents", body = Vec<Document>),
+        (status = 404, description = "Retention policy not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "retention",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_expired_documents(
+    State(service): State<Arc<RetentionService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<Document>>, AppError> {
+    let documents = service.expired_documents(id).await?;
+    Ok(Json(documents))
+}
+
+/// Purge expired documents under a policy, skipping documents on legal hold
+#[utoipa::path(
+    post,
+    path = "/api/retention-policies/{id}/purge",
+    params(
+        ("id" = Uuid, Path, description = "Retention policy ID")
+    ),
+    responses(
+        (status = 200, description = "Purge result", body = RetentionPurgeResult),
+        (status = 404, description = "Retention policy not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "retention",
+    security(("bearer_auth" = []))
+)]
+pub async fn purge_expired_documents(
+    State(service): State<Arc<RetentionService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<RetentionPurgeResult>, AppError> {
+    let result = service.apply_policy(id).await?;
+    Ok(Json(result))
+}
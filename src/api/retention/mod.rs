@@ -0,0 +1,5 @@
+pub mod handlers;
+pub mod service;
+
+pub use handlers::*;
+pub use service::*;
@@ -0,0 +1,224 @@
+use crate::error::AppError;
+use crate::models::{Document, MatterType, RetentionPolicy, RetentionPurgeResult};
+use chrono::Utc;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Parameters for creating or replacing a retention policy
+pub struct RetentionPolicyParams {
+    /// Name of the policy (e.g., "Closed litigation correspondence")
+    pub name: String,
+    /// Document type the policy applies to (all types when omitted)
+    pub doc_type: Option<String>,
+    /// Matter type of the case the policy applies to (all matters when omitted)
+    pub matter_type: Option<MatterType>,
+    /// Days after a document's last modification before it expires
+    pub retention_days: i32,
+    /// Description of the policy (optional)
+    pub description: Option<String>,
+    /// ID of the user creating or updating the policy
+    pub user_id: Uuid,
+}
+
+/// Longest retention period a policy may set (100 years)
+pub const MAX_RETENTION_DAYS: i32 = 36525;
+
+/// Documents matched by a policy, expired and not yet deleted
+const EXPIRED_DOCUMENTS: &str = r#"
+    SELECT d.* FROM documents d
+    JOIN cases c ON c.id = d.case_id
+    WHERE d.deleted_at IS NULL
+      AND ($1::text IS NULL OR d.type = $1)
+      AND ($2::matter_type IS NULL OR c.matter_type = $2)
+      AND d.last_modified < $3 - make_interval(days => $4)
+"#;
+
+pub struct RetentionService {
+    pool: PgPool,
+}
+
+impl RetentionService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// List retention policies
+    pub async fn list_policies(&self) -> Result<Vec<RetentionPolicy>, AppError> {
+        let policies = sqlx::query_as::<_, RetentionPolicy>(
+            "SELECT * FROM retention_policies WHERE deleted_at IS NULL ORDER BY name",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(policies)
+    }
+
+    /// Get a retention policy by ID
+    pub async fn get_policy(&self, id: Uuid) -> Result<RetentionPolicy, AppError> {
+        sqlx::query_as::<_, RetentionPolicy>(
+            "SELECT * FROM retention_policies WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound("Retention policy not found".to_string()))
+    }
+
+    /// Create a retention policy
+    pub async fn create_policy(
+        &self,
+        params: RetentionPolicyParams,
+    ) -> Result<RetentionPolicy, AppError> {
+        validate_policy(&params)?;
+        let now = Utc::now();
+
+        let policy = sqlx::query_as::<_, RetentionPolicy>(
+            r#"
+            INSERT INTO retention_policies (
+                id, name, doc_type, matter_type, retention_days, description,
+                created_at, updated_at, created_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(params.name.trim())
+        .bind(&params.doc_type)
+        .bind(&params.matter_type)
+        .bind(params.retention_days)
+        .bind(&params.description)
+        .bind(now)
+        .bind(now)
+        .bind(params.user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(policy)
+    }
+
+    /// Replace a retention policy
+    pub async fn update_policy(
+        &self,
+        id: Uuid,
+        params: RetentionPolicyParams,
+    ) -> Result<RetentionPolicy, AppError> {
+        validate_policy(&params)?;
+
+        sqlx::query_as::<_, RetentionPolicy>(
+            r#"
+            UPDATE retention_policies
+            SET name = $1, doc_type = $2, matter_type = $3, retention_days = $4,
+                description = $5, updated_at = $6, updated_by = $7, version = version + 1
+            WHERE id = $8 AND deleted_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(params.name.trim())
+        .bind(&params.doc_type)
+        .bind(&params.matter_type)
+        .bind(params.retention_days)
+        .bind(&params.description)
+        .bind(Utc::now())
+        .bind(params.user_id)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound("Retention policy not found".to_string()))
+    }
+
+    /// Delete a retention policy
+    pub async fn delete_policy(&self, id: Uuid) -> Result<(), AppError> {
+        let result = sqlx::query(
+            "UPDATE retention_policies SET deleted_at = $1 WHERE id = $2 AND deleted_at IS NULL",
+        )
+        .bind(Utc::now())
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound("Retention policy not found".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Documents past their retention period under a policy, including held ones
+    pub async fn expired_documents(&self, id: Uuid) -> Result<Vec<Document>, AppError> {
+        let policy = self.get_policy(id).await?;
+
+        let documents = sqlx::query_as::<_, Document>(&format!(
+            "{} ORDER BY d.last_modified",
+            EXPIRED_DOCUMENTS
+        ))
+        .bind(&policy.doc_type)
+        .bind(&policy.matter_type)
+        .bind(Utc::now())
+        .bind(policy.retention_days)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(documents)
+    }
+
+    /// Purge expired documents under a policy.
+    ///
+    /// Documents under an active legal hold are skipped and reported back.
+    pub async fn apply_policy(&self, id: Uuid) -> Result<RetentionPurgeResult, AppError> {
+        let policy = self.get_policy(id).await?;
+        let now = Utc::now();
+        let mut tx = self.pool.begin().await?;
+
+        let held_document_ids: Vec<Uuid> = sqlx::query_scalar(&format!(
+            "SELECT expired.id FROM ({}) expired WHERE document_on_hold(expired.id, expired.case_id)",
+            EXPIRED_DOCUMENTS
+        ))
+        .bind(&policy.doc_type)
+        .bind(&policy.matter_type)
+        .bind(now)
+        .bind(policy.retention_days)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let purged_document_ids: Vec<Uuid> = sqlx::query_scalar(&format!(
+            r#"
+            UPDATE documents SET deleted_at = $3, updated_at = $3
+            WHERE id IN (
+                SELECT expired.id FROM ({}) expired
+                WHERE NOT document_on_hold(expired.id, expired.case_id)
+            )
+            RETURNING id
+            "#,
+            EXPIRED_DOCUMENTS
+        ))
+        .bind(&policy.doc_type)
+        .bind(&policy.matter_type)
+        .bind(now)
+        .bind(policy.retention_days)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(RetentionPurgeResult {
+            purged_document_ids,
+            held_document_ids,
+        })
+    }
+}
+
+fn validate_policy(params: &RetentionPolicyParams) -> Result<(), AppError> {
+    if params.name.trim().is_empty() {
+        return Err(AppError::Validation(
+            "Retention policy name may not be empty".to_string(),
+        ));
+    }
+    if !(1..=MAX_RETENTION_DAYS).contains(&params.retention_days) {
+        return Err(AppError::Validation(format!(
+            "Retention period must be between 1 and {} days",
+            MAX_RETENTION_DAYS
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,100 @@
+//! Ordering of tasks within Kanban board columns.
+//!
+//! Tasks are ordered by lexicographic ranks over `0-9a-z`. A task dropped
+//! between two others gets a rank between theirs, so no other task is
+//! renumbered. Ranks never end in `0`, which keeps a rank available below
+//! any other.
+
+use crate::models::TaskStatus;
+
+/// Board columns, left to right
+pub const BOARD_COLUMNS: [TaskStatus; 5] = [
+    TaskStatus::Pending,
+    TaskStatus::InProgress,
+    TaskStatus::Review,
+    TaskStatus::Done,
+    TaskStatus::Completed,
+];
+
+/// Ranks longer than this are worth respreading across their column
+pub const MAX_RANK_LEN: usize = 64;
+
+const DIGITS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+const BASE: usize = DIGITS.len();
+
+fn digit_value(digit: u8) -> usize {
+    DIGITS
+        .iter()
+        .position(|&d| d == digit)
+        .expect("rank digits are 0-9a-z")
+}
+
+/// A rank sorting after `before` (or first) and before `after` (or last).
+///
+/// Returns `None` when `before` does not sort before `after`, e.g. when two
+/// neighbours share a rank; the column then needs respreading.
+pub fn rank_between(before: Option<&str>, after: Option<&str>) -> Option<String> {
+    let low = before.unwrap_or("");
+    // Nothing sorts between a rank and the same rank followed by zeros
+    let high = after.map(|after| after.trim_end_matches('0'));
+    if high.is_some_and(|high| low >= high) {
+        return None;
+    }
+    Some(midpoint(low.as_bytes(), high.map(str::as_bytes)))
+}
+
+/// `low` must sort before `high`, which must not end in `0`
+fn midpoint(low: &[u8], high: Option<&[u8]>) -> String {
+    if let Some(high) = high {
+        // Keep the prefix both share, reading missing digits of `low` as 0
+        let shared = high
+            .iter()
+            .enumerate()
+            .take_while(|&(i, &digit)| low.get(i).copied().unwrap_or(b'0') == digit)
+            .count();
+        if shared > 0 {
+            let rest = midpoint(low.get(shared..).unwrap_or(&[]), Some(&high[shared..]));
+            return format!("{}{}", String::from_utf8_lossy(&high[..shared]), rest);
+        }
+    }
+
+    let low_digit = low.first().map_or(0, |&d| digit_value(d));
+    let high_digit = high.map_or(BASE, |high| digit_value(high[0]));
+    if high_digit - low_digit > 1 {
+        return ((DIGITS[(low_digit + high_digit) / 2]) as char).to_string();
+    }
+
+    match high {
+        // The first digit of `high` alone sorts between the two
+        Some(high) if high.len() > 1 => (high[0] as char).to_string(),
+        _ => format!(
+            "{}{}",
+            DIGITS[low_digit] as char,
+            midpoint(low.get(1..).unwrap_or(&[]), None)
+        ),
+    }
+}
+
+/// `count` ascending ranks spread evenly, for reordering a whole column
+pub fn spread_ranks(count: usize) -> Vec<String> {
+    let mut width = 1;
+    let mut span = BASE;
+    while span <= count * 2 {
+        width += 1;
+        span *= BASE;
+    }
+    let step = span / (count + 1);
+
+    (1..=count)
+        .map(|i| {
+            let mut value = i * step;
+            let mut digits = vec![b'0'; width];
+            for digit in digits.iter_mut().rev() {
+                *digit = DIGITS[value % BASE];
+                value /= BASE;
+            }
+            let rank = String::from_utf8(digits).expect("rank digits are ASCII");
+            rank.trim_end_matches('0').to_string()
+        })
+        .collect()
+}
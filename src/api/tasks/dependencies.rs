@@ -0,0 +1,169 @@
+//! Finish-to-start dependencies between tasks: a task may not start before
+//! every task it depends on is due
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Dates and predecessors of a task, as far as scheduling is concerned
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskDates {
+    pub start: Option<DateTime<Utc>>,
+    pub due: DateTime<Utc>,
+    pub dependencies: Vec<Uuid>,
+    /// Finished tasks keep their dates
+    pub closed: bool,
+}
+
+impl TaskDates {
+    /// When work on the task begins; a task without a start date is treated
+    /// as starting when it is due
+    pub fn begins(&self) -> DateTime<Utc> {
+        self.start.unwrap_or(self.due)
+    }
+}
+
+/// Whether making `task` depend on `dependencies` would close a loop.
+///
+/// `predecessors` maps tasks to what they depend on; it needs to cover
+/// every task reachable from `dependencies`.
+pub fn creates_cycle(
+    task: Uuid,
+    dependencies: &[Uuid],
+    predecessors: &HashMap<Uuid, Vec<Uuid>>,
+) -> bool {
+    let mut seen = HashSet::new();
+    let mut stack = dependencies.to_vec();
+    while let Some(id) = stack.pop() {
+        if id == task {
+            return true;
+        }
+        if seen.insert(id) {
+            if let Some(next) = predecessors.get(&id) {
+                stack.extend(next.iter().copied());
+            }
+        }
+    }
+    false
+}
+
+/// Earliest time a task may begin given its predecessors' due dates
+pub fn earliest_start(
+    dependencies: &[Uuid],
+    tasks: &HashMap<Uuid, TaskDates>,
+) -> Option<DateTime<Utc>> {
+    dependencies
+        .iter()
+        .filter_map(|id| tasks.get(id))
+        .map(|dates| dates.due)
+        .max()
+}
+
+/// Tasks of `tasks` ordered so that each comes after the tasks it depends
+/// on. Dependencies on tasks not in the map are ignored.
+pub fn topological_order(tasks: &HashMap<Uuid, TaskDates>) -> Vec<Uuid> {
+    let mut pending: HashMap<Uuid, usize> = HashMap::new();
+    let mut successors: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for (&id, dates) in tasks {
+        let known: HashSet<Uuid> = dates
+            .dependencies
+            .iter()
+            .copied()
+            .filter(|dep| tasks.contains_key(dep))
+            .collect();
+        pending.insert(id, known.len());
+        for dep in known {
+            successors.entry(dep).or_default().push(id);
+        }
+    }
+
+    let mut ready: Vec<Uuid> = pending
+        .iter()
+        .filter(|(_, &count)| count == 0)
+        .map(|(&id, _)| id)
+        .collect();
+    ready.sort_by_key(|id| (tasks[id].due, *id));
+
+    let mut order = Vec::with_capacity(tasks.len());
+    while let Some(id) = ready.pop() {
+        order.push(id);
+        for next in successors.get(&id).into_iter().flatten() {
+            let count = pending.get_mut(next).expect("successor is a known task");
+            *count -= 1;
+            if *count == 0 {
+                ready.push(*next);
+            }
+        }
+    }
+    order
+}
+
+/// Push open tasks later so none begins before its predecessors are due,
+/// keeping each task's length. Returns the tasks that moved with their new
+/// dates; tasks are never pulled earlier.
+pub fn push_dependents(tasks: &HashMap<Uuid, TaskDates>) -> Vec<(Uuid, TaskDates)> {
+    let mut scheduled = tasks.clone();
+    let mut moved = Vec::new();
+
+    for id in topological_order(tasks) {
+        let dates = &scheduled[&id];
+        if dates.closed {
+            continue;
+        }
+        let Some(earliest) = earliest_start(&dates.dependencies, &scheduled) else {
+            continue;
+        };
+        let shift = earliest - dates.begins();
+        if shift <= chrono::Duration::zero() {
+            continue;
+        }
+
+        let pushed = TaskDates {
+            start: dates.start.map(|start| start + shift),
+            due: dates.due + shift,
+            ..dates.clone()
+        };
+        moved.push((id, pushed.clone()));
+        scheduled.insert(id, pushed);
+    }
+
+    moved
+}
+
+/// Total float of each task: how far it could slip without delaying the
+/// last due date among `tasks`. Tasks with no float form the critical path.
+pub fn total_float(tasks: &HashMap<Uuid, TaskDates>) -> HashMap<Uuid, chrono::Duration> {
+    let Some(finish) = tasks.values().map(|dates| dates.due).max() else {
+        return HashMap::new();
+    };
+
+    let mut successors: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for (&id, dates) in tasks {
+        for dep in dates
+            .dependencies
+            .iter()
+            .filter(|dep| tasks.contains_key(dep))
+        {
+            successors.entry(*dep).or_default().push(id);
+        }
+    }
+
+    // Latest time each task may begin without delaying the finish
+    let mut latest_start: HashMap<Uuid, DateTime<Utc>> = HashMap::new();
+    let mut float = HashMap::new();
+    for id in topological_order(tasks).into_iter().rev() {
+        let dates = &tasks[&id];
+        let latest_finish = successors
+            .get(&id)
+            .into_iter()
+            .flatten()
+            .filter_map(|next| latest_start.get(next))
+            .min()
+            .copied()
+            .unwrap_or(finish);
+        latest_start.insert(id, latest_finish - (dates.due - dates.begins()));
+        float.insert(id, latest_finish - dates.due);
+    }
+    float
+}
@@ -0,0 +1,12 @@
+//! Which tasks escalation rules apply to
+
+/// Task priorities, lowest first
+pub const PRIORITIES: [&str; 4] = ["Low", "Medium", "High", "Critical"];
+
+/// Priorities at or above `min_priority`; empty if it is not a priority
+pub fn priorities_at_least(min_priority: &str) -> &'static [&'static str] {
+    match PRIORITIES.iter().position(|&p| p == min_priority) {
+        Some(index) => &PRIORITIES[index..],
+        None => &[],
+    }
+}
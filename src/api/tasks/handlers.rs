@@ -1,21 +1,51 @@
 use axum::{
     extract::{Path, Query, State},
+    http::StatusCode,
     response::IntoResponse,
-    Json,
+    Extension, Json,
 };
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::{error::AppError, models::WorkflowTask};
+use crate::{
+    error::AppError,
+    models::{
+        BoardWipLimit, BulkUpdateTasksRequest, Claims, CreateEscalationRuleRequest,
+        CreateTaskRequest, EscalationRule, MoveTaskRequest, SetTaskDependenciesRequest,
+        SetTaskRecurrenceRequest, SetWipLimitRequest, TaskBoard, TaskEscalation, TaskStatus,
+        UpdateEscalationRuleRequest, UpdateTaskRequest, WorkflowTask,
+    },
+};
+
+use super::{
+    CreateEscalationRuleParams, CreateTaskParams, TaskFilters, TaskService,
+    UpdateEscalationRuleParams, UpdateTaskParams,
+};
 
-use super::TaskService;
+#[derive(Debug, Deserialize)]
+pub struct BoardQuery {
+    pub case_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+}
 
 #[derive(Debug, Deserialize)]
 pub struct ListTasksQuery {
     pub case_id: Option<Uuid>,
-    pub status: Option<String>,
+    pub project_id: Option<Uuid>,
+    pub status: Option<TaskStatus>,
     pub assignee_id: Option<Uuid>,
+    pub priority: Option<String>,
+    pub due_from: Option<DateTime<Utc>>,
+    pub due_to: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub overdue: bool,
+}
+
+fn user_id(claims: &Claims) -> Result<Uuid, AppError> {
+    Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))
 }
 
 /// List workflow tasks
@@ -23,8 +53,19 @@ pub struct ListTasksQuery {
     get,
     path = "/api/tasks",
     tag = "tasks",
+    params(
+        ("case_id" = Option<Uuid>, Query, description = "Filter by case"),
+        ("project_id" = Option<Uuid>, Query, description = "Filter by project"),
+        ("status" = Option<TaskStatus>, Query, description = "Filter by status"),
+        ("assignee_id" = Option<Uuid>, Query, description = "Filter by assigned user"),
+        ("priority" = Option<String>, Query, description = "Filter by priority"),
+        ("due_from" = Option<DateTime<Utc>>, Query, description = "Only tasks due at or after this time"),
+        ("due_to" = Option<DateTime<Utc>>, Query, description = "Only tasks due at or before this time"),
+        ("overdue" = Option<bool>, Query, description = "Only tasks past due and not done")
+    ),
     responses(
-        (status = 200, description = "Tasks retrieved successfully", body = Vec<WorkflowTask>),
+        (status = 200, description = "Tasks retrieved successfully, soonest due first", body = Vec<WorkflowTask>),
+        (status = 400, description = "Invalid due window"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -35,7 +76,18 @@ pub async fn list_tasks(
     State(service): State<Arc<TaskService>>,
     Query(params): Query<ListTasksQuery>,
 ) -> Result<impl IntoResponse, AppError> {
-    let tasks = service.list_tasks(params).await?;
+    let tasks = service
+        .list_tasks(TaskFilters {
+            case_id: params.case_id,
+            project_id: params.project_id,
+            status: params.status,
+            assignee_id: params.assignee_id,
+            priority: params.priority,
+            due_from: params.due_from,
+            due_to: params.due_to,
+            overdue: params.overdue,
+        })
+        .await?;
     Ok(Json(tasks))
 }
 
@@ -63,3 +115,505 @@ pub async fn get_task(
     let task = service.get_task(id).await?;
     Ok(Json(task))
 }
+
+/// Create a task
+#[utoipa::path(
+    post,
+    path = "/api/tasks",
+    tag = "tasks",
+    request_body = CreateTaskRequest,
+    responses(
+        (status = 201, description = "Task created", body = WorkflowTask),
+        (status = 400, description = "Invalid title, priority, dates or recurrence rule"),
+        (status = 404, description = "Assignee or case not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_task(
+    State(service): State<Arc<TaskService>>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<CreateTaskRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user_id = user_id(&claims)?;
+
+    let task = service
+        .create_task(CreateTaskParams {
+            title: req.title,
+            assignee: req.assignee,
+            assignee_id: req.assignee_id,
+            start_date: req.start_date,
+            due_date: req.due_date,
+            priority: req.priority,
+            description: req.description,
+            case_id: req.case_id,
+            project_id: req.project_id,
+            recurrence: req.recurrence,
+            recurrence_trigger: req.recurrence_trigger,
+            user_id,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(task)))
+}
+
+/// Update a task
+#[utoipa::path(
+    put,
+    path = "/api/tasks/{id}",
+    tag = "tasks",
+    params(
+        ("id" = Uuid, Path, description = "Task ID")
+    ),
+    request_body = UpdateTaskRequest,
+    responses(
+        (status = 200, description = "Task updated", body = WorkflowTask),
+        (status = 400, description = "Invalid field or status transition"),
+        (status = 404, description = "Task or assignee not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_task(
+    State(service): State<Arc<TaskService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateTaskRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user_id = user_id(&claims)?;
+
+    let task = service
+        .update_task(
+            id,
+            UpdateTaskParams {
+                title: req.title,
+                assignee_id: req.assignee_id,
+                start_date: req.start_date,
+                due_date: req.due_date,
+                priority: req.priority,
+                description: req.description,
+                completion: req.completion,
+                status: req.status,
+                user_id,
+            },
+        )
+        .await?;
+    Ok(Json(task))
+}
+
+/// Mark a task completed
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{id}/complete",
+    tag = "tasks",
+    params(
+        ("id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Task completed", body = WorkflowTask),
+        (status = 404, description = "Task not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn complete_task(
+    State(service): State<Arc<TaskService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let user_id = user_id(&claims)?;
+
+    let task = service.complete_task(id, user_id).await?;
+    Ok(Json(task))
+}
+
+/// Replace the tasks a task depends on
+#[utoipa::path(
+    put,
+    path = "/api/tasks/{id}/dependencies",
+    tag = "tasks",
+    params(
+        ("id" = Uuid, Path, description = "Task ID")
+    ),
+    request_body = SetTaskDependenciesRequest,
+    responses(
+        (status = 200, description = "Dependencies replaced; the task and its dependents were pushed back as needed", body = WorkflowTask),
+        (status = 400, description = "The dependencies would form a cycle"),
+        (status = 404, description = "Task or a task it depends on not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn set_task_dependencies(
+    State(service): State<Arc<TaskService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<SetTaskDependenciesRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user_id = user_id(&claims)?;
+
+    let task = service
+        .set_dependencies(id, req.depends_on, user_id)
+        .await?;
+    Ok(Json(task))
+}
+
+/// Apply the same changes to several tasks
+#[utoipa::path(
+    post,
+    path = "/api/tasks/bulk",
+    tag = "tasks",
+    request_body = BulkUpdateTasksRequest,
+    responses(
+        (status = 200, description = "All tasks updated", body = Vec<WorkflowTask>),
+        (status = 400, description = "Invalid change for at least one task; none were updated"),
+        (status = 404, description = "A task or the assignee was not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn bulk_update_tasks(
+    State(service): State<Arc<TaskService>>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<BulkUpdateTasksRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user_id = user_id(&claims)?;
+
+    let tasks = service
+        .bulk_update(
+            req.task_ids,
+            UpdateTaskParams {
+                title: None,
+                assignee_id: req.assignee_id,
+                start_date: None,
+                due_date: req.due_date,
+                priority: req.priority,
+                description: None,
+                completion: None,
+                status: req.status,
+                user_id,
+            },
+        )
+        .await?;
+    Ok(Json(tasks))
+}
+
+/// Delete a task
+#[utoipa::path(
+    delete,
+    path = "/api/tasks/{id}",
+    tag = "tasks",
+    params(
+        ("id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 204, description = "Task deleted"),
+        (status = 404, description = "Task not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_task(
+    State(service): State<Arc<TaskService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let user_id = user_id(&claims)?;
+
+    service.delete_task(id, user_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Make a task recur, or stop its series
+#[utoipa::path(
+    put,
+    path = "/api/tasks/{id}/recurrence",
+    tag = "tasks",
+    params(
+        ("id" = Uuid, Path, description = "Task ID")
+    ),
+    request_body = SetTaskRecurrenceRequest,
+    responses(
+        (status = 200, description = "Recurrence set", body = WorkflowTask),
+        (status = 400, description = "Invalid recurrence rule"),
+        (status = 404, description = "Task not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn set_task_recurrence(
+    State(service): State<Arc<TaskService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<SetTaskRecurrenceRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user_id = user_id(&claims)?;
+
+    let task = service
+        .set_recurrence(id, req.rule, req.trigger, user_id)
+        .await?;
+    Ok(Json(task))
+}
+
+/// List the escalations of a task
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{id}/escalations",
+    tag = "tasks",
+    params(
+        ("id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Escalations, oldest first", body = Vec<TaskEscalation>),
+        (status = 404, description = "Task not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_task_escalations(
+    State(service): State<Arc<TaskService>>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let escalations = service.list_task_escalations(id).await?;
+    Ok(Json(escalations))
+}
+
+/// List escalation rules
+#[utoipa::path(
+    get,
+    path = "/api/escalation-rules",
+    tag = "tasks",
+    responses(
+        (status = 200, description = "Escalation rules", body = Vec<EscalationRule>),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_escalation_rules(
+    State(service): State<Arc<TaskService>>,
+) -> Result<impl IntoResponse, AppError> {
+    let rules = service.list_escalation_rules().await?;
+    Ok(Json(rules))
+}
+
+/// Create an escalation rule
+#[utoipa::path(
+    post,
+    path = "/api/escalation-rules",
+    tag = "tasks",
+    request_body = CreateEscalationRuleRequest,
+    responses(
+        (status = 201, description = "Escalation rule created", body = EscalationRule),
+        (status = 400, description = "Invalid name, priority or overdue hours"),
+        (status = 404, description = "Supervisor not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_escalation_rule(
+    State(service): State<Arc<TaskService>>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<CreateEscalationRuleRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user_id = user_id(&claims)?;
+
+    let rule = service
+        .create_escalation_rule(CreateEscalationRuleParams {
+            name: req.name,
+            min_priority: req.min_priority,
+            overdue_hours: req.overdue_hours,
+            action: req.action,
+            supervisor_id: req.supervisor_id,
+            case_id: req.case_id,
+            user_id,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(rule)))
+}
+
+/// Update an escalation rule
+#[utoipa::path(
+    put,
+    path = "/api/escalation-rules/{id}",
+    tag = "tasks",
+    params(
+        ("id" = Uuid, Path, description = "Escalation rule ID")
+    ),
+    request_body = UpdateEscalationRuleRequest,
+    responses(
+        (status = 200, description = "Escalation rule updated", body = EscalationRule),
+        (status = 400, description = "Invalid name, priority or overdue hours"),
+        (status = 404, description = "Escalation rule or supervisor not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_escalation_rule(
+    State(service): State<Arc<TaskService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateEscalationRuleRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user_id = user_id(&claims)?;
+
+    let rule = service
+        .update_escalation_rule(
+            id,
+            UpdateEscalationRuleParams {
+                name: req.name,
+                min_priority: req.min_priority,
+                overdue_hours: req.overdue_hours,
+                action: req.action,
+                supervisor_id: req.supervisor_id,
+                is_active: req.is_active,
+                user_id,
+            },
+        )
+        .await?;
+    Ok(Json(rule))
+}
+
+/// Delete an escalation rule
+#[utoipa::path(
+    delete,
+    path = "/api/escalation-rules/{id}",
+    tag = "tasks",
+    params(
+        ("id" = Uuid, Path, description = "Escalation rule ID")
+    ),
+    responses(
+        (status = 204, description = "Escalation rule deleted"),
+        (status = 404, description = "Escalation rule not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_escalation_rule(
+    State(service): State<Arc<TaskService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let user_id = user_id(&claims)?;
+
+    service.delete_escalation_rule(id, user_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Get the Kanban board of a case or a project
+#[utoipa::path(
+    get,
+    path = "/api/boards",
+    tag = "tasks",
+    params(
+        ("case_id" = Option<Uuid>, Query, description = "Case whose board to get"),
+        ("project_id" = Option<Uuid>, Query, description = "Project whose board to get")
+    ),
+    responses(
+        (status = 200, description = "Board columns in status order, tasks in board order", body = TaskBoard),
+        (status = 400, description = "Neither or both of case_id and project_id given"),
+        (status = 404, description = "Case or project not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_board(
+    State(service): State<Arc<TaskService>>,
+    Query(query): Query<BoardQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let board = service.get_board(query.case_id, query.project_id).await?;
+    Ok(Json(board))
+}
+
+/// Set or remove the WIP limit of a board column
+#[utoipa::path(
+    put,
+    path = "/api/boards/wip-limits",
+    tag = "tasks",
+    request_body = SetWipLimitRequest,
+    responses(
+        (status = 200, description = "WIP limits of the board", body = Vec<BoardWipLimit>),
+        (status = 400, description = "Invalid board or limit"),
+        (status = 404, description = "Case or project not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn set_wip_limit(
+    State(service): State<Arc<TaskService>>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<SetWipLimitRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user_id = user_id(&claims)?;
+
+    let limits = service
+        .set_wip_limit(
+            req.case_id,
+            req.project_id,
+            req.status,
+            req.wip_limit,
+            user_id,
+        )
+        .await?;
+    Ok(Json(limits))
+}
+
+/// Move a task to a board column and position
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{id}/move",
+    tag = "tasks",
+    params(
+        ("id" = Uuid, Path, description = "Task ID")
+    ),
+    request_body = MoveTaskRequest,
+    responses(
+        (status = 200, description = "Task moved", body = WorkflowTask),
+        (status = 400, description = "Status transition not allowed, or the task is not on the board"),
+        (status = 404, description = "Task not found"),
+        (status = 409, description = "The column is at its WIP limit"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn move_task(
+    State(service): State<Arc<TaskService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<MoveTaskRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user_id = user_id(&claims)?;
+
+    let task = service
+        .move_task(id, req.status, req.position, req.project_id, user_id)
+        .await?;
+    Ok(Json(task))
+}
@@ -1,5 +1,10 @@
+pub mod board;
+pub mod dependencies;
+pub mod escalation;
 pub mod handlers;
+pub mod recurrence;
 pub mod service;
+pub mod workflow;
 
 pub use handlers::*;
 pub use service::*;
@@ -0,0 +1,206 @@
+//! RRULE-style recurrence of tasks.
+//!
+//! Supports the subset of RFC 5545 rules practice obligations need:
+//! `FREQ` (DAILY, WEEKLY, MONTHLY or YEARLY), `INTERVAL`, `COUNT`, `UNTIL`
+//! (YYYYMMDD) and, for monthly and yearly rules, `BYMONTHDAY`. A quarterly
+//! reconciliation is `FREQ=MONTHLY;INTERVAL=3`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{Datelike, Duration, Months, NaiveDate};
+
+/// Largest INTERVAL accepted, e.g. every 1000 days or every 1000 years
+pub const MAX_INTERVAL: u32 = 1000;
+
+/// Latest year an UNTIL date may fall in
+pub const MAX_UNTIL_YEAR: i32 = 9999;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recurrence {
+    pub frequency: Frequency,
+    pub interval: u32,
+    /// Total number of occurrences, the first included
+    pub count: Option<u32>,
+    /// Last date an occurrence may fall on
+    pub until: Option<NaiveDate>,
+    /// Day of the month monthly and yearly occurrences fall on, clamped to
+    /// the end of shorter months
+    pub by_month_day: Option<u32>,
+}
+
+impl Recurrence {
+    /// Pin monthly and yearly rules to the day of the first occurrence, so
+    /// a series starting on the 31st does not drift after a short month
+    pub fn anchored(mut self, first: NaiveDate) -> Self {
+        if matches!(self.frequency, Frequency::Monthly | Frequency::Yearly)
+            && self.by_month_day.is_none()
+        {
+            self.by_month_day = Some(first.day());
+        }
+        self
+    }
+
+    /// Date of the occurrence after the one on `previous`, or `None` past
+    /// the last representable date
+    pub fn next_after(&self, previous: NaiveDate) -> Option<NaiveDate> {
+        let months = match self.frequency {
+            Frequency::Daily => {
+                return previous.checked_add_signed(Duration::days(self.interval.into()))
+            }
+            Frequency::Weekly => {
+                return previous.checked_add_signed(Duration::weeks(self.interval.into()))
+            }
+            Frequency::Monthly => self.interval,
+            Frequency::Yearly => self.interval.checked_mul(12)?,
+        };
+
+        let first_of_month = previous
+            .with_day(1)
+            .expect("every month has a first day")
+            .checked_add_months(Months::new(months))?;
+        let day = self
+            .by_month_day
+            .unwrap_or(previous.day())
+            .min(days_in_month(first_of_month)?);
+        first_of_month.with_day(day)
+    }
+
+    /// Whether occurrence number `occurrence` (the first is 1) on `date` is
+    /// still part of the series
+    pub fn allows(&self, occurrence: u32, date: NaiveDate) -> bool {
+        self.count.is_none_or(|count| occurrence <= count)
+            && self.until.is_none_or(|until| date <= until)
+    }
+}
+
+fn days_in_month(date: NaiveDate) -> Option<u32> {
+    let next = date
+        .with_day(1)
+        .expect("every month has a first day")
+        .checked_add_months(Months::new(1))?;
+    next.pred_opt().map(|last| last.day())
+}
+
+impl FromStr for Recurrence {
+    type Err = String;
+
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        let rule = rule.trim();
+        let rule = rule.strip_prefix("RRULE:").unwrap_or(rule);
+
+        let mut frequency = None;
+        let mut interval = 1;
+        let mut count = None;
+        let mut until = None;
+        let mut by_month_day = None;
+
+        for part in rule.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| format!("Expected KEY=VALUE, found \"{}\"", part))?;
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        other => return Err(format!("Unsupported FREQ \"{}\"", other)),
+                    })
+                }
+                "INTERVAL" => {
+                    interval = value
+                        .parse()
+                        .ok()
+                        .filter(|n: &u32| (1..=MAX_INTERVAL).contains(n))
+                        .ok_or_else(|| {
+                            format!("INTERVAL must be a number from 1 to {}", MAX_INTERVAL)
+                        })?
+                }
+                "COUNT" => {
+                    count = Some(
+                        value
+                            .parse()
+                            .ok()
+                            .filter(|&n: &u32| n > 0)
+                            .ok_or("COUNT must be a positive number")?,
+                    )
+                }
+                "UNTIL" => {
+                    let date = value.get(..8).unwrap_or(value);
+                    until = Some(
+                        NaiveDate::parse_from_str(date, "%Y%m%d")
+                            .ok()
+                            .filter(|date| date.year() <= MAX_UNTIL_YEAR)
+                            .ok_or_else(|| {
+                                format!(
+                                    "UNTIL must be a date as YYYYMMDD no later than {}",
+                                    MAX_UNTIL_YEAR
+                                )
+                            })?,
+                    )
+                }
+                "BYMONTHDAY" => {
+                    by_month_day = Some(
+                        value
+                            .parse()
+                            .ok()
+                            .filter(|n: &u32| (1..=31).contains(n))
+                            .ok_or("BYMONTHDAY must be a day from 1 to 31")?,
+                    )
+                }
+                other => return Err(format!("Unsupported rule part \"{}\"", other)),
+            }
+        }
+
+        let frequency = frequency.ok_or("FREQ is required")?;
+        if by_month_day.is_some() && !matches!(frequency, Frequency::Monthly | Frequency::Yearly) {
+            return Err("BYMONTHDAY only applies to monthly and yearly rules".to_string());
+        }
+        if count.is_some() && until.is_some() {
+            return Err("COUNT and UNTIL cannot both be set".to_string());
+        }
+
+        Ok(Self {
+            frequency,
+            interval,
+            count,
+            until,
+            by_month_day,
+        })
+    }
+}
+
+impl fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let frequency = match self.frequency {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        };
+        write!(f, "FREQ={}", frequency)?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if let Some(day) = self.by_month_day {
+            write!(f, ";BYMONTHDAY={}", day)?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until.format("%Y%m%d"))?;
+        }
+        Ok(())
+    }
+}
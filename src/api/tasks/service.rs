@@ -1,9 +1,103 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use uuid::Uuid;
 
-use crate::{error::AppError, models::WorkflowTask};
+use crate::{
+    api::workflows::roll_up_stage,
+    error::AppError,
+    models::{
+        BoardColumn, BoardWipLimit, EscalationAction, EscalationRule, RecurrenceTrigger, TaskBoard,
+        TaskEscalation, TaskStatus, WorkflowTask,
+    },
+};
+
+use super::board::{rank_between, spread_ranks, BOARD_COLUMNS, MAX_RANK_LEN};
+use super::dependencies::{creates_cycle, push_dependents, TaskDates};
+use super::escalation::priorities_at_least;
+use super::recurrence::Recurrence;
+use super::workflow::{can_transition, completion_for, is_closed, next_statuses};
 
-use super::handlers::ListTasksQuery;
+/// Filters for listing tasks; all are optional and combine with AND
+#[derive(Default)]
+pub struct TaskFilters {
+    pub case_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+    pub status: Option<TaskStatus>,
+    pub assignee_id: Option<Uuid>,
+    pub priority: Option<String>,
+    /// Only tasks due at or after this time
+    pub due_from: Option<DateTime<Utc>>,
+    /// Only tasks due at or before this time
+    pub due_to: Option<DateTime<Utc>>,
+    /// Only tasks past due and not yet done
+    pub overdue: bool,
+}
+
+/// Parameters for creating a task
+pub struct CreateTaskParams {
+    pub title: String,
+    /// Display name of the assignee; defaults to the assigned user's name
+    pub assignee: Option<String>,
+    pub assignee_id: Option<Uuid>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub due_date: DateTime<Utc>,
+    pub priority: String,
+    pub description: Option<String>,
+    pub case_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+    /// RRULE the task repeats on
+    pub recurrence: Option<String>,
+    pub recurrence_trigger: Option<RecurrenceTrigger>,
+    /// ID of the user creating the task
+    pub user_id: Uuid,
+}
+
+/// Parameters for updating a task; omitted fields are unchanged
+pub struct UpdateTaskParams {
+    pub title: Option<String>,
+    pub assignee_id: Option<Uuid>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub priority: Option<String>,
+    pub description: Option<String>,
+    pub completion: Option<i32>,
+    pub status: Option<TaskStatus>,
+    /// ID of the user updating the task
+    pub user_id: Uuid,
+}
+
+/// Parameters for creating an escalation rule
+pub struct CreateEscalationRuleParams {
+    pub name: String,
+    /// High or Critical
+    pub min_priority: String,
+    pub overdue_hours: i32,
+    pub action: EscalationAction,
+    pub supervisor_id: Uuid,
+    pub case_id: Option<Uuid>,
+    /// ID of the user creating the rule
+    pub user_id: Uuid,
+}
+
+/// Parameters for updating an escalation rule; omitted fields are unchanged
+pub struct UpdateEscalationRuleParams {
+    pub name: Option<String>,
+    pub min_priority: Option<String>,
+    pub overdue_hours: Option<i32>,
+    pub action: Option<EscalationAction>,
+    pub supervisor_id: Option<Uuid>,
+    pub is_active: Option<bool>,
+    /// ID of the user updating the rule
+    pub user_id: Uuid,
+}
+
+/// Maximum number of tasks a bulk update may change
+pub const MAX_BULK_TASKS: usize = 500;
+
+/// How often recurring tasks are spawned and overdue tasks escalated
+pub const TASK_SWEEP_INTERVAL: std::time::Duration = std::time::Duration::from_secs(15 * 60);
 
 pub struct TaskService {
     db: PgPool,
@@ -14,55 +108,1376 @@ impl TaskService {
         Self { db }
     }
 
-    pub async fn list_tasks(&self, params: ListTasksQuery) -> Result<Vec<WorkflowTask>, AppError> {
-        let mut query_str = String::from("SELECT * FROM workflow_tasks WHERE deleted_at IS NULL");
-        let mut bind_count = 0;
+    /// List tasks, soonest due first
+    pub async fn list_tasks(&self, filters: TaskFilters) -> Result<Vec<WorkflowTask>, AppError> {
+        if let (Some(from), Some(to)) = (filters.due_from, filters.due_to) {
+            if to < from {
+                return Err(AppError::Validation(
+                    "Due window must not end before it starts".to_string(),
+                ));
+            }
+        }
+
+        let tasks = sqlx::query_as::<_, WorkflowTask>(
+            r#"
+            SELECT * FROM workflow_tasks
+            WHERE deleted_at IS NULL
+              AND ($1::UUID IS NULL OR case_id = $1)
+              AND ($2::UUID IS NULL OR project_id = $2)
+              AND ($3::task_status IS NULL OR status = $3)
+              AND ($4::UUID IS NULL OR assignee_id = $4)
+              AND ($5::VARCHAR IS NULL OR priority = $5)
+              AND ($6::TIMESTAMPTZ IS NULL OR due_date >= $6)
+              AND ($7::TIMESTAMPTZ IS NULL OR due_date <= $7)
+              AND (NOT $8 OR (due_date < NOW() AND status NOT IN ('Done', 'Completed')))
+            ORDER BY due_date ASC
+            "#,
+        )
+        .bind(filters.case_id)
+        .bind(filters.project_id)
+        .bind(filters.status)
+        .bind(filters.assignee_id)
+        .bind(&filters.priority)
+        .bind(filters.due_from)
+        .bind(filters.due_to)
+        .bind(filters.overdue)
+        .fetch_all(&self.db)
+        .await?;
 
-        if params.case_id.is_some() {
-            bind_count += 1;
-            query_str.push_str(&format!(" AND case_id = ${}", bind_count));
+        Ok(tasks)
+    }
+
+    pub async fn get_task(&self, id: Uuid) -> Result<WorkflowTask, AppError> {
+        let task = sqlx::query_as::<_, WorkflowTask>(
+            "SELECT * FROM workflow_tasks WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.db)
+        .await?
+        .ok_or(AppError::NotFound("Task not found".to_string()))?;
+
+        Ok(task)
+    }
+
+    /// Create a pending task
+    pub async fn create_task(&self, params: CreateTaskParams) -> Result<WorkflowTask, AppError> {
+        let title = params.title.trim();
+        if title.is_empty() {
+            return Err(AppError::Validation("Task title is required".to_string()));
         }
+        validate_priority(&params.priority)?;
+        validate_dates(params.start_date, params.due_date)?;
+        let recurrence = params
+            .recurrence
+            .as_deref()
+            .map(|rule| parse_recurrence(rule, params.due_date))
+            .transpose()?;
 
-        if params.status.is_some() {
-            bind_count += 1;
-            query_str.push_str(&format!(" AND status::text = ${}", bind_count));
+        let assignee = match params.assignee_id {
+            Some(assignee_id) => {
+                let username = assignee_name(&self.db, assignee_id).await?;
+                params
+                    .assignee
+                    .map(|a| a.trim().to_string())
+                    .filter(|a| !a.is_empty())
+                    .unwrap_or(username)
+            }
+            None => params
+                .assignee
+                .map(|a| a.trim().to_string())
+                .filter(|a| !a.is_empty())
+                .unwrap_or_else(|| "Unassigned".to_string()),
+        };
+        if let Some(case_id) = params.case_id {
+            sqlx::query_scalar::<_, Uuid>(
+                "SELECT id FROM cases WHERE id = $1 AND deleted_at IS NULL",
+            )
+            .bind(case_id)
+            .fetch_optional(&self.db)
+            .await?
+            .ok_or(AppError::NotFound("Case not found".to_string()))?;
         }
 
-        if params.assignee_id.is_some() {
-            bind_count += 1;
-            query_str.push_str(&format!(" AND assignee_id = ${}", bind_count));
+        let id = Uuid::new_v4();
+        let mut tx = self.db.begin().await?;
+        check_wip_limits(
+            &mut tx,
+            id,
+            params.case_id,
+            params.project_id,
+            TaskStatus::Pending,
+        )
+        .await?;
+
+        let task = sqlx::query_as::<_, WorkflowTask>(
+            r#"
+            INSERT INTO workflow_tasks (
+                id, title, status, assignee, assignee_id, start_date, due_date, priority,
+                description, case_id, project_id, recurrence, recurrence_trigger, series_id,
+                occurrence, completion, created_at, updated_at, created_by, updated_by
+            )
+            VALUES (
+                $1, $2, 'Pending', $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, 0,
+                $15, $15, $16, $16
+            )
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(title)
+        .bind(&assignee)
+        .bind(params.assignee_id)
+        .bind(params.start_date)
+        .bind(params.due_date)
+        .bind(&params.priority)
+        .bind(&params.description)
+        .bind(params.case_id)
+        .bind(params.project_id)
+        .bind(recurrence.as_ref().map(|r| r.to_string()))
+        .bind(recurrence.as_ref().map(|_| {
+            params
+                .recurrence_trigger
+                .unwrap_or(RecurrenceTrigger::OnCompletion)
+        }))
+        .bind(recurrence.as_ref().map(|_| id))
+        .bind(recurrence.as_ref().map(|_| 1))
+        .bind(Utc::now())
+        .bind(params.user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(task)
+    }
+
+    /// Make a task recur, or with no rule stop its series after it. The
+    /// task starts a new series unless it already belongs to one.
+    pub async fn set_recurrence(
+        &self,
+        id: Uuid,
+        rule: Option<String>,
+        trigger: Option<RecurrenceTrigger>,
+        user_id: Uuid,
+    ) -> Result<WorkflowTask, AppError> {
+        let existing = self.get_task(id).await?;
+        let recurrence = rule
+            .as_deref()
+            .map(|rule| parse_recurrence(rule, existing.due_date))
+            .transpose()?;
+
+        let task = sqlx::query_as::<_, WorkflowTask>(
+            r#"
+            UPDATE workflow_tasks
+            SET recurrence = $1, recurrence_trigger = $2,
+                series_id = COALESCE(series_id, CASE WHEN $1 IS NOT NULL THEN id END),
+                occurrence = COALESCE(occurrence, CASE WHEN $1 IS NOT NULL THEN 1 END),
+                updated_at = $3, updated_by = $4, version = COALESCE(version, 1) + 1
+            WHERE id = $5 AND deleted_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(recurrence.as_ref().map(|r| r.to_string()))
+        .bind(
+            recurrence
+                .as_ref()
+                .map(|_| trigger.unwrap_or(RecurrenceTrigger::OnCompletion)),
+        )
+        .bind(Utc::now())
+        .bind(user_id)
+        .bind(id)
+        .fetch_optional(&self.db)
+        .await?
+        .ok_or(AppError::NotFound("Task not found".to_string()))?;
+
+        Ok(task)
+    }
+
+    /// Create the next task of every scheduled series whose latest task has
+    /// fallen due.
+    ///
+    /// Returns the number of tasks created.
+    pub async fn spawn_scheduled_occurrences(&self) -> Result<usize, AppError> {
+        let due = sqlx::query_as::<_, WorkflowTask>(
+            r#"
+            SELECT * FROM workflow_tasks t
+            WHERE t.deleted_at IS NULL AND t.recurrence IS NOT NULL
+              AND t.recurrence_trigger = 'OnSchedule' AND t.due_date <= NOW()
+              AND NOT EXISTS (
+                  SELECT 1 FROM workflow_tasks n
+                  WHERE n.series_id = t.series_id AND n.occurrence > t.occurrence
+              )
+            "#,
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        let mut spawned = 0;
+        for task in due {
+            let mut tx = self.db.begin().await?;
+            match spawn_next_occurrence(&mut tx, &task, None).await {
+                Ok(Some(_)) => spawned += 1,
+                Ok(None) => {}
+                // One series failing, e.g. on a full Pending column, does
+                // not hold back the others; it is retried next sweep
+                Err(e) => {
+                    tracing::warn!("Next occurrence of task {} not created: {}", task.id, e);
+                    continue;
+                }
+            }
+            tx.commit().await?;
         }
 
-        query_str.push_str(" ORDER BY due_date ASC");
+        Ok(spawned)
+    }
 
-        let mut query = sqlx::query_as::<_, WorkflowTask>(&query_str);
+    pub async fn list_escalation_rules(&self) -> Result<Vec<EscalationRule>, AppError> {
+        let rules = sqlx::query_as::<_, EscalationRule>(
+            "SELECT * FROM escalation_rules WHERE deleted_at IS NULL ORDER BY name",
+        )
+        .fetch_all(&self.db)
+        .await?;
 
-        if let Some(case_id) = params.case_id {
-            query = query.bind(case_id);
+        Ok(rules)
+    }
+
+    pub async fn create_escalation_rule(
+        &self,
+        params: CreateEscalationRuleParams,
+    ) -> Result<EscalationRule, AppError> {
+        validate_escalation_rule(&params.name, &params.min_priority, params.overdue_hours)?;
+        assignee_name(&self.db, params.supervisor_id).await?;
+
+        let rule = sqlx::query_as::<_, EscalationRule>(
+            r#"
+            INSERT INTO escalation_rules (
+                id, name, min_priority, overdue_hours, action, supervisor_id, case_id,
+                is_active, created_at, updated_at, created_by, updated_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, true, $8, $8, $9, $9)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(params.name.trim())
+        .bind(&params.min_priority)
+        .bind(params.overdue_hours)
+        .bind(params.action)
+        .bind(params.supervisor_id)
+        .bind(params.case_id)
+        .bind(Utc::now())
+        .bind(params.user_id)
+        .fetch_one(&self.db)
+        .await?;
+
+        Ok(rule)
+    }
+
+    pub async fn update_escalation_rule(
+        &self,
+        id: Uuid,
+        params: UpdateEscalationRuleParams,
+    ) -> Result<EscalationRule, AppError> {
+        let existing = sqlx::query_as::<_, EscalationRule>(
+            "SELECT * FROM escalation_rules WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.db)
+        .await?
+        .ok_or(AppError::NotFound("Escalation rule not found".to_string()))?;
+
+        let name = params.name.unwrap_or(existing.name);
+        let min_priority = params.min_priority.unwrap_or(existing.min_priority);
+        let overdue_hours = params.overdue_hours.unwrap_or(existing.overdue_hours);
+        validate_escalation_rule(&name, &min_priority, overdue_hours)?;
+        if let Some(supervisor_id) = params.supervisor_id {
+            assignee_name(&self.db, supervisor_id).await?;
         }
 
-        if let Some(ref status) = params.status {
-            query = query.bind(status);
+        let rule = sqlx::query_as::<_, EscalationRule>(
+            r#"
+            UPDATE escalation_rules
+            SET name = $1, min_priority = $2, overdue_hours = $3, action = $4,
+                supervisor_id = $5, is_active = $6, updated_at = $7, updated_by = $8
+            WHERE id = $9
+            RETURNING *
+            "#,
+        )
+        .bind(name.trim())
+        .bind(&min_priority)
+        .bind(overdue_hours)
+        .bind(params.action.unwrap_or(existing.action))
+        .bind(params.supervisor_id.unwrap_or(existing.supervisor_id))
+        .bind(params.is_active.unwrap_or(existing.is_active))
+        .bind(Utc::now())
+        .bind(params.user_id)
+        .bind(id)
+        .fetch_one(&self.db)
+        .await?;
+
+        Ok(rule)
+    }
+
+    pub async fn delete_escalation_rule(&self, id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+        let result = sqlx::query(
+            "UPDATE escalation_rules SET deleted_at = NOW(), updated_by = $2 WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .bind(user_id)
+        .execute(&self.db)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound("Escalation rule not found".to_string()));
         }
 
-        if let Some(assignee_id) = params.assignee_id {
-            query = query.bind(assignee_id);
+        Ok(())
+    }
+
+    /// Escalations of a task, oldest first
+    pub async fn list_task_escalations(&self, id: Uuid) -> Result<Vec<TaskEscalation>, AppError> {
+        self.get_task(id).await?;
+
+        let escalations = sqlx::query_as::<_, TaskEscalation>(
+            "SELECT * FROM task_escalations WHERE task_id = $1 ORDER BY escalated_at",
+        )
+        .bind(id)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(escalations)
+    }
+
+    /// Apply every active escalation rule to the open tasks it covers that
+    /// are overdue by its number of hours. Each rule escalates a task once.
+    ///
+    /// Returns the number of escalations made.
+    pub async fn escalate_overdue_tasks(&self) -> Result<usize, AppError> {
+        let rules = sqlx::query_as::<_, EscalationRule>(
+            "SELECT * FROM escalation_rules WHERE is_active AND deleted_at IS NULL",
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        let now = Utc::now();
+        let mut escalated = 0;
+        for rule in rules {
+            let cutoff = now - chrono::Duration::hours(rule.overdue_hours.into());
+            let tasks = sqlx::query_as::<_, WorkflowTask>(
+                r#"
+                SELECT * FROM workflow_tasks t
+                WHERE t.deleted_at IS NULL AND t.status NOT IN ('Done', 'Completed')
+                  AND t.priority = ANY($1) AND t.due_date <= $2
+                  AND ($3::UUID IS NULL OR t.case_id = $3)
+                  AND NOT EXISTS (
+                      SELECT 1 FROM task_escalations e WHERE e.task_id = t.id AND e.rule_id = $4
+                  )
+                "#,
+            )
+            .bind(priorities_at_least(&rule.min_priority))
+            .bind(cutoff)
+            .bind(rule.case_id)
+            .bind(rule.id)
+            .fetch_all(&self.db)
+            .await?;
+
+            for task in tasks {
+                let mut tx = self.db.begin().await?;
+                if escalate_task(&mut tx, &rule, &task).await? {
+                    escalated += 1;
+                }
+                tx.commit().await?;
+            }
         }
 
-        let tasks = query.fetch_all(&self.db).await?;
+        Ok(escalated)
+    }
+
+    /// Update a task.
+    ///
+    /// Status changes follow the task workflow (see `next_statuses`).
+    /// Done and completed tasks are 100% complete; open tasks stay below.
+    pub async fn update_task(
+        &self,
+        id: Uuid,
+        params: UpdateTaskParams,
+    ) -> Result<WorkflowTask, AppError> {
+        let mut tx = self.db.begin().await?;
+        let task = apply_task_update(&mut tx, id, params).await?;
+        tx.commit().await?;
 
+        Ok(task)
+    }
+
+    /// Mark a task completed
+    pub async fn complete_task(&self, id: Uuid, user_id: Uuid) -> Result<WorkflowTask, AppError> {
+        self.update_task(
+            id,
+            UpdateTaskParams {
+                title: None,
+                assignee_id: None,
+                start_date: None,
+                due_date: None,
+                priority: None,
+                description: None,
+                completion: None,
+                status: Some(TaskStatus::Completed),
+                user_id,
+            },
+        )
+        .await
+    }
+
+    /// Apply the same changes to several tasks; if any change is invalid
+    /// none is applied
+    pub async fn bulk_update(
+        &self,
+        task_ids: Vec<Uuid>,
+        params: UpdateTaskParams,
+    ) -> Result<Vec<WorkflowTask>, AppError> {
+        if task_ids.is_empty() {
+            return Err(AppError::Validation("No tasks given".to_string()));
+        }
+        if task_ids.len() > MAX_BULK_TASKS {
+            return Err(AppError::Validation(format!(
+                "At most {} tasks may be updated at once",
+                MAX_BULK_TASKS
+            )));
+        }
+
+        let mut ids = task_ids;
+        ids.sort();
+        ids.dedup();
+
+        let mut tx = self.db.begin().await?;
+        let mut tasks = Vec::with_capacity(ids.len());
+        for id in ids {
+            let task = apply_task_update(
+                &mut tx,
+                id,
+                UpdateTaskParams {
+                    title: None,
+                    assignee_id: params.assignee_id,
+                    start_date: None,
+                    due_date: params.due_date,
+                    priority: params.priority.clone(),
+                    description: None,
+                    completion: None,
+                    status: params.status,
+                    user_id: params.user_id,
+                },
+            )
+            .await?;
+            tasks.push(task);
+        }
+        tx.commit().await?;
+
+        tasks.sort_by_key(|t| t.due_date);
         Ok(tasks)
     }
 
-    pub async fn get_task(&self, id: Uuid) -> Result<WorkflowTask, AppError> {
+    /// Replace the tasks a task depends on, then push it and its dependents
+    /// so none starts before its predecessors are due
+    pub async fn set_dependencies(
+        &self,
+        id: Uuid,
+        depends_on: Vec<Uuid>,
+        user_id: Uuid,
+    ) -> Result<WorkflowTask, AppError> {
+        let mut dependencies = depends_on;
+        dependencies.sort();
+        dependencies.dedup();
+        if dependencies.contains(&id) {
+            return Err(AppError::Validation(
+                "A task cannot depend on itself".to_string(),
+            ));
+        }
+
+        let mut tx = self.db.begin().await?;
+
+        // Two concurrent edits could each pass the cycle check and together
+        // close a cycle. Dependencies may cross projects, so every dependency
+        // edit takes the same lock.
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1, 0))")
+            .bind("workflow_task_dependencies")
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query_scalar::<_, Uuid>(
+            "SELECT id FROM workflow_tasks WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(AppError::NotFound("Task not found".to_string()))?;
+
+        let found = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM workflow_tasks WHERE id = ANY($1) AND deleted_at IS NULL",
+        )
+        .bind(&dependencies)
+        .fetch_one(&mut *tx)
+        .await?;
+        if found as usize != dependencies.len() {
+            return Err(AppError::NotFound(
+                "A task it depends on was not found".to_string(),
+            ));
+        }
+
+        // Everything the new predecessors depend on, directly or not
+        let predecessors: HashMap<Uuid, Vec<Uuid>> = sqlx::query_as::<_, (Uuid, Vec<Uuid>)>(
+            r#"
+            WITH RECURSIVE ancestors(id) AS (
+                SELECT UNNEST($1::UUID[])
+                UNION
+                SELECT d.id FROM workflow_tasks t
+                JOIN ancestors a ON t.id = a.id
+                CROSS JOIN LATERAL UNNEST(t.dependencies) AS d(id)
+                WHERE t.deleted_at IS NULL
+            )
+            SELECT id, COALESCE(dependencies, '{}') FROM workflow_tasks
+            WHERE id IN (SELECT id FROM ancestors) AND deleted_at IS NULL
+            "#,
+        )
+        .bind(&dependencies)
+        .fetch_all(&mut *tx)
+        .await?
+        .into_iter()
+        .collect();
+
+        if creates_cycle(id, &dependencies, &predecessors) {
+            return Err(AppError::Validation(
+                "These dependencies would make the task depend on itself".to_string(),
+            ));
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE workflow_tasks
+            SET dependencies = $1, updated_at = $2, updated_by = $3,
+                version = COALESCE(version, 1) + 1
+            WHERE id = $4
+            "#,
+        )
+        .bind(&dependencies)
+        .bind(Utc::now())
+        .bind(user_id)
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+        push_dependent_tasks(&mut tx, id, user_id).await?;
+
+        let task = sqlx::query_as::<_, WorkflowTask>("SELECT * FROM workflow_tasks WHERE id = $1")
+            .bind(id)
+            .fetch_one(&mut *tx)
+            .await?;
+        tx.commit().await?;
+
+        Ok(task)
+    }
+
+    /// Kanban board of a case or of a project, one column per status
+    pub async fn get_board(
+        &self,
+        case_id: Option<Uuid>,
+        project_id: Option<Uuid>,
+    ) -> Result<TaskBoard, AppError> {
+        self.check_board(case_id, project_id).await?;
+
+        let mut tasks = sqlx::query_as::<_, WorkflowTask>(&format!(
+            r#"
+            SELECT * FROM workflow_tasks
+            WHERE deleted_at IS NULL
+              AND ($1::UUID IS NULL OR case_id = $1)
+              AND ($2::UUID IS NULL OR project_id = $2)
+            ORDER BY {} ASC NULLS LAST, due_date ASC, id
+            "#,
+            rank_column(project_id)
+        ))
+        .bind(case_id)
+        .bind(project_id)
+        .fetch_all(&self.db)
+        .await?;
+        let limits = self.board_wip_limits(case_id, project_id).await?;
+
+        let columns = BOARD_COLUMNS
+            .iter()
+            .map(|&status| {
+                let (column, rest) = tasks.drain(..).partition(|task| task.status == status);
+                tasks = rest;
+                BoardColumn {
+                    status,
+                    wip_limit: limits
+                        .iter()
+                        .find(|limit| limit.status == status)
+                        .map(|limit| limit.wip_limit),
+                    tasks: column,
+                }
+            })
+            .collect();
+
+        Ok(TaskBoard {
+            case_id,
+            project_id,
+            columns,
+        })
+    }
+
+    /// Set or, with no limit, remove the WIP limit of a board column.
+    /// Columns already over a new limit keep their tasks but take no more.
+    pub async fn set_wip_limit(
+        &self,
+        case_id: Option<Uuid>,
+        project_id: Option<Uuid>,
+        status: TaskStatus,
+        wip_limit: Option<i32>,
+        user_id: Uuid,
+    ) -> Result<Vec<BoardWipLimit>, AppError> {
+        self.check_board(case_id, project_id).await?;
+        if wip_limit.is_some_and(|limit| limit <= 0) {
+            return Err(AppError::Validation(
+                "WIP limit must be positive".to_string(),
+            ));
+        }
+
+        let mut tx = self.db.begin().await?;
+        sqlx::query(
+            r#"
+            DELETE FROM board_wip_limits
+            WHERE status = $1
+              AND case_id IS NOT DISTINCT FROM $2 AND project_id IS NOT DISTINCT FROM $3
+            "#,
+        )
+        .bind(status)
+        .bind(case_id)
+        .bind(project_id)
+        .execute(&mut *tx)
+        .await?;
+
+        if let Some(wip_limit) = wip_limit {
+            sqlx::query(
+                r#"
+                INSERT INTO board_wip_limits (
+                    id, case_id, project_id, status, wip_limit, created_at, updated_at, updated_by
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $6, $7)
+                "#,
+            )
+            .bind(Uuid::new_v4())
+            .bind(case_id)
+            .bind(project_id)
+            .bind(status)
+            .bind(wip_limit)
+            .bind(Utc::now())
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        self.board_wip_limits(case_id, project_id).await
+    }
+
+    /// Move a task to a status column and a position in it in one step.
+    ///
+    /// The status change follows the task workflow and WIP limits. The
+    /// position counts the tasks of the project's board when `project_id`
+    /// is given and of the task's case board otherwise.
+    pub async fn move_task(
+        &self,
+        id: Uuid,
+        status: TaskStatus,
+        position: usize,
+        project_id: Option<Uuid>,
+        user_id: Uuid,
+    ) -> Result<WorkflowTask, AppError> {
+        let mut tx = self.db.begin().await?;
+
+        // Moves on the same board are serialized, so two tasks dropped into
+        // a column at once cannot be given the same rank. The board lock is
+        // taken before any task is locked.
+        let board_id = sqlx::query_scalar::<_, Option<Uuid>>(
+            "SELECT CASE WHEN $2::UUID IS NULL THEN case_id ELSE $2 END FROM workflow_tasks WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .bind(project_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(AppError::NotFound("Task not found".to_string()))?;
+        if let Some(board_id) = board_id {
+            sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1::TEXT, 0))")
+                .bind(board_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
         let task = sqlx::query_as::<_, WorkflowTask>(
-            "SELECT * FROM workflow_tasks WHERE id = $1 AND deleted_at IS NULL",
+            "SELECT * FROM workflow_tasks WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
         )
         .bind(id)
-        .fetch_optional(&self.db)
+        .fetch_optional(&mut *tx)
         .await?
         .ok_or(AppError::NotFound("Task not found".to_string()))?;
 
+        let case_id = match project_id {
+            Some(project_id) if task.project_id != Some(project_id) => {
+                return Err(AppError::Validation(
+                    "The task is not on this project's board".to_string(),
+                ))
+            }
+            Some(_) => None,
+            None => match task.case_id {
+                Some(case_id) if Some(case_id) == board_id => Some(case_id),
+                _ => {
+                    return Err(AppError::Validation(
+                        "The task is not on a case board".to_string(),
+                    ))
+                }
+            },
+        };
+
+        if status != task.status {
+            apply_task_update(
+                &mut tx,
+                id,
+                UpdateTaskParams {
+                    title: None,
+                    assignee_id: None,
+                    start_date: None,
+                    due_date: None,
+                    priority: None,
+                    description: None,
+                    completion: None,
+                    status: Some(status),
+                    user_id,
+                },
+            )
+            .await?;
+        }
+
+        let rank_column = rank_column(project_id);
+        let mut column = sqlx::query_as::<_, (Uuid, Option<String>)>(&format!(
+            r#"
+            SELECT id, {0} FROM workflow_tasks
+            WHERE deleted_at IS NULL AND status = $1 AND id <> $2
+              AND ($3::UUID IS NULL OR case_id = $3)
+              AND ($4::UUID IS NULL OR project_id = $4)
+            ORDER BY {0} ASC NULLS LAST, due_date ASC, id
+            FOR UPDATE
+            "#,
+            rank_column
+        ))
+        .bind(status)
+        .bind(id)
+        .bind(case_id)
+        .bind(project_id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        if column.iter().any(|(_, rank)| rank.is_none()) {
+            respread_column(&mut tx, rank_column, &mut column).await?;
+        }
+        let position = position.min(column.len());
+        let rank_at = |column: &[(Uuid, Option<String>)]| {
+            let before = position.checked_sub(1).and_then(|i| column[i].1.as_deref());
+            let after = column.get(position).and_then(|(_, rank)| rank.as_deref());
+            rank_between(before, after)
+        };
+        // Neighbours sharing a rank, or a rank grown too long, call for
+        // spreading the column out again
+        let rank = match rank_at(&column) {
+            Some(rank) if rank.len() <= MAX_RANK_LEN => rank,
+            _ => {
+                respread_column(&mut tx, rank_column, &mut column).await?;
+                rank_at(&column).ok_or(AppError::InternalServerError(
+                    "Board column ranks are out of order".to_string(),
+                ))?
+            }
+        };
+
+        let task = sqlx::query_as::<_, WorkflowTask>(&format!(
+            r#"
+            UPDATE workflow_tasks
+            SET {} = $1, updated_at = $2, updated_by = $3
+            WHERE id = $4
+            RETURNING *
+            "#,
+            rank_column
+        ))
+        .bind(&rank)
+        .bind(Utc::now())
+        .bind(user_id)
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
         Ok(task)
     }
+
+    /// Check that exactly one of a case and a project names an existing board
+    async fn check_board(
+        &self,
+        case_id: Option<Uuid>,
+        project_id: Option<Uuid>,
+    ) -> Result<(), AppError> {
+        let (table, id, name) = match (case_id, project_id) {
+            (Some(case_id), None) => ("cases", case_id, "Case"),
+            (None, Some(project_id)) => ("projects", project_id, "Project"),
+            _ => {
+                return Err(AppError::Validation(
+                    "A board is for either a case_id or a project_id".to_string(),
+                ))
+            }
+        };
+
+        sqlx::query_scalar::<_, Uuid>(&format!(
+            "SELECT id FROM {} WHERE id = $1 AND deleted_at IS NULL",
+            table
+        ))
+        .bind(id)
+        .fetch_optional(&self.db)
+        .await?
+        .ok_or(AppError::NotFound(format!("{} not found", name)))?;
+
+        Ok(())
+    }
+
+    async fn board_wip_limits(
+        &self,
+        case_id: Option<Uuid>,
+        project_id: Option<Uuid>,
+    ) -> Result<Vec<BoardWipLimit>, AppError> {
+        let limits = sqlx::query_as::<_, BoardWipLimit>(
+            "SELECT * FROM board_wip_limits WHERE case_id = $1 OR project_id = $2",
+        )
+        .bind(case_id)
+        .bind(project_id)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(limits)
+    }
+
+    /// Soft delete a task
+    pub async fn delete_task(&self, id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+        let mut tx = self.db.begin().await?;
+
+        let stage_id = sqlx::query_scalar::<_, Option<Uuid>>(
+            "UPDATE workflow_tasks SET deleted_at = NOW(), updated_by = $2 WHERE id = $1 AND deleted_at IS NULL RETURNING stage_id",
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(AppError::NotFound("Task not found".to_string()))?;
+
+        if let Some(stage_id) = stage_id {
+            roll_up_stage(&mut tx, stage_id).await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
+
+async fn apply_task_update(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    id: Uuid,
+    params: UpdateTaskParams,
+) -> Result<WorkflowTask, AppError> {
+    let existing = sqlx::query_as::<_, WorkflowTask>(
+        "SELECT * FROM workflow_tasks WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+    )
+    .bind(id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(AppError::NotFound("Task not found".to_string()))?;
+
+    let title = params.title.unwrap_or(existing.title);
+    if title.trim().is_empty() {
+        return Err(AppError::Validation("Task title is required".to_string()));
+    }
+    let priority = params.priority.unwrap_or(existing.priority);
+    validate_priority(&priority)?;
+    let start_date = params.start_date.or(existing.start_date);
+    let due_date = params.due_date.unwrap_or(existing.due_date);
+    validate_dates(start_date, due_date)?;
+    let rescheduled = start_date != existing.start_date || due_date != existing.due_date;
+    if rescheduled {
+        check_predecessors(
+            tx,
+            existing.dependencies.as_deref().unwrap_or_default(),
+            start_date.unwrap_or(due_date),
+        )
+        .await?;
+    }
+
+    let status = params.status.unwrap_or(existing.status);
+    if status != existing.status && !can_transition(existing.status, status) {
+        let allowed = next_statuses(existing.status)
+            .iter()
+            .map(|s| format!("{:?}", s))
+            .collect::<Vec<_>>();
+        return Err(AppError::Validation(format!(
+            "A {:?} task can only move to {}",
+            existing.status,
+            allowed.join(" or ")
+        )));
+    }
+    if status != existing.status {
+        check_wip_limits(tx, id, existing.case_id, existing.project_id, status).await?;
+    }
+
+    let completion = params
+        .completion
+        .unwrap_or(existing.completion.unwrap_or(0));
+    if !(0..=100).contains(&completion) {
+        return Err(AppError::Validation(
+            "Completion must be between 0 and 100".to_string(),
+        ));
+    }
+
+    let (assignee, assignee_id) = match params.assignee_id {
+        Some(assignee_id) => (
+            assignee_name(&mut **tx, assignee_id).await?,
+            Some(assignee_id),
+        ),
+        None => (existing.assignee, existing.assignee_id),
+    };
+
+    let task = sqlx::query_as::<_, WorkflowTask>(
+        r#"
+        UPDATE workflow_tasks
+        SET title = $1, assignee = $2, assignee_id = $3, start_date = $4, due_date = $5,
+            priority = $6, description = $7, completion = $8, status = $9, updated_at = $10,
+            updated_by = $11, version = COALESCE(version, 1) + 1
+        WHERE id = $12
+        RETURNING *
+        "#,
+    )
+    .bind(title.trim())
+    .bind(&assignee)
+    .bind(assignee_id)
+    .bind(start_date)
+    .bind(due_date)
+    .bind(&priority)
+    .bind(params.description.or(existing.description))
+    .bind(completion_for(status, completion))
+    .bind(status)
+    .bind(Utc::now())
+    .bind(params.user_id)
+    .bind(id)
+    .fetch_one(&mut **tx)
+    .await?;
+
+    if rescheduled {
+        push_dependent_tasks(tx, id, params.user_id).await?;
+    }
+    if !is_closed(existing.status)
+        && is_closed(status)
+        && task.recurrence_trigger == Some(RecurrenceTrigger::OnCompletion)
+    {
+        spawn_next_occurrence(tx, &task, Some(params.user_id)).await?;
+    }
+    if let Some(stage_id) = task.stage_id {
+        roll_up_stage(tx, stage_id).await?;
+    }
+
+    Ok(task)
+}
+
+/// Reject moving or adding a task to a status column that is at its WIP
+/// limit on the task's case board or project board
+pub async fn check_wip_limits(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    task_id: Uuid,
+    case_id: Option<Uuid>,
+    project_id: Option<Uuid>,
+    status: TaskStatus,
+) -> Result<(), AppError> {
+    // Locking the limits serializes moves into the same column
+    let limits = sqlx::query_as::<_, BoardWipLimit>(
+        r#"
+        SELECT * FROM board_wip_limits
+        WHERE status = $1 AND (case_id = $2 OR project_id = $3)
+        FOR UPDATE
+        "#,
+    )
+    .bind(status)
+    .bind(case_id)
+    .bind(project_id)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    for limit in limits {
+        let in_column = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT COUNT(*) FROM workflow_tasks
+            WHERE deleted_at IS NULL AND status = $1 AND id <> $2
+              AND ($3::UUID IS NULL OR case_id = $3)
+              AND ($4::UUID IS NULL OR project_id = $4)
+            "#,
+        )
+        .bind(status)
+        .bind(task_id)
+        .bind(limit.case_id)
+        .bind(limit.project_id)
+        .fetch_one(&mut **tx)
+        .await?;
+
+        if in_column >= limit.wip_limit.into() {
+            let board = if limit.project_id.is_some() {
+                "project"
+            } else {
+                "case"
+            };
+            return Err(AppError::Conflict(format!(
+                "The {:?} column of this {} board is at its limit of {} tasks",
+                status, board, limit.wip_limit
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Rank column of a project's board, or of a case's board
+fn rank_column(project_id: Option<Uuid>) -> &'static str {
+    if project_id.is_some() {
+        "project_board_rank"
+    } else {
+        "case_board_rank"
+    }
+}
+
+/// Give every task of a board column an evenly spaced rank, in its current
+/// order
+async fn respread_column(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    rank_column: &str,
+    column: &mut [(Uuid, Option<String>)],
+) -> Result<(), AppError> {
+    let ranks = spread_ranks(column.len());
+    let sql = format!(
+        "UPDATE workflow_tasks SET {} = $1 WHERE id = $2",
+        rank_column
+    );
+    for ((id, rank), new_rank) in column.iter_mut().zip(ranks) {
+        sqlx::query(&sql)
+            .bind(&new_rank)
+            .bind(*id)
+            .execute(&mut **tx)
+            .await?;
+        *rank = Some(new_rank);
+    }
+    Ok(())
+}
+
+/// Create the task after `task` in its recurring series, unless the series
+/// has ended or the next task already exists
+async fn spawn_next_occurrence(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    task: &WorkflowTask,
+    user_id: Option<Uuid>,
+) -> Result<Option<WorkflowTask>, AppError> {
+    let Some(rule) = task.recurrence.as_deref() else {
+        return Ok(None);
+    };
+    let recurrence: Recurrence = rule
+        .parse()
+        .map_err(|e| AppError::Validation(format!("Invalid recurrence rule: {}", e)))?;
+
+    let occurrence = task.occurrence.unwrap_or(1) + 1;
+    let Some(next_date) = recurrence.next_after(task.due_date.date_naive()) else {
+        return Ok(None);
+    };
+    if !recurrence.allows(occurrence as u32, next_date) {
+        return Ok(None);
+    }
+    let shift = next_date.and_time(task.due_date.time()).and_utc() - task.due_date;
+
+    let id = Uuid::new_v4();
+    check_wip_limits(tx, id, task.case_id, task.project_id, TaskStatus::Pending).await?;
+
+    let next = sqlx::query_as::<_, WorkflowTask>(
+        r#"
+        INSERT INTO workflow_tasks (
+            id, title, status, assignee, assignee_id, start_date, due_date, priority,
+            description, case_id, project_id, recurrence, recurrence_trigger, series_id,
+            occurrence, completion, created_at, updated_at, created_by, updated_by
+        )
+        VALUES (
+            $1, $2, 'Pending', $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, 0,
+            $15, $15, $16, $16
+        )
+        ON CONFLICT (series_id, occurrence) DO NOTHING
+        RETURNING *
+        "#,
+    )
+    .bind(id)
+    .bind(&task.title)
+    .bind(&task.assignee)
+    .bind(task.assignee_id)
+    .bind(task.start_date.map(|start| start + shift))
+    .bind(task.due_date + shift)
+    .bind(&task.priority)
+    .bind(&task.description)
+    .bind(task.case_id)
+    .bind(task.project_id)
+    .bind(rule)
+    .bind(task.recurrence_trigger)
+    .bind(task.series_id.unwrap_or(task.id))
+    .bind(occurrence)
+    .bind(Utc::now())
+    .bind(user_id)
+    .fetch_optional(&mut **tx)
+    .await?;
+
+    Ok(next)
+}
+
+/// Escalate a task under a rule and notify the supervisor. Returns false if
+/// the rule already escalated it.
+async fn escalate_task(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    rule: &EscalationRule,
+    task: &WorkflowTask,
+) -> Result<bool, AppError> {
+    let recorded = sqlx::query(
+        r#"
+        INSERT INTO task_escalations (
+            id, task_id, rule_id, action, previous_assignee_id, supervisor_id, escalated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        ON CONFLICT (task_id, rule_id) DO NOTHING
+        "#,
+    )
+    .bind(Uuid::new_v4())
+    .bind(task.id)
+    .bind(rule.id)
+    .bind(rule.action)
+    .bind(task.assignee_id)
+    .bind(rule.supervisor_id)
+    .bind(Utc::now())
+    .execute(&mut **tx)
+    .await?;
+    if recorded.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    let text = match rule.action {
+        EscalationAction::Reassign => {
+            let supervisor = assignee_name(&mut **tx, rule.supervisor_id).await?;
+            sqlx::query(
+                r#"
+                UPDATE workflow_tasks
+                SET assignee = $1, assignee_id = $2, updated_at = NOW(),
+                    version = COALESCE(version, 1) + 1
+                WHERE id = $3
+                "#,
+            )
+            .bind(&supervisor)
+            .bind(rule.supervisor_id)
+            .bind(task.id)
+            .execute(&mut **tx)
+            .await?;
+            format!(
+                "Overdue {} priority task \"{}\" (was assigned to {}) has been reassigned to you",
+                task.priority, task.title, task.assignee
+            )
+        }
+        EscalationAction::Notify => format!(
+            "{} priority task \"{}\" assigned to {} is overdue since {}",
+            task.priority,
+            task.title,
+            task.assignee,
+            task.due_date.date_naive()
+        ),
+    };
+
+    sqlx::query(
+        "INSERT INTO notifications (id, user_id, text, time, read, type) VALUES ($1, $2, $3, $4, false, $5)",
+    )
+    .bind(Uuid::new_v4())
+    .bind(rule.supervisor_id)
+    .bind(text)
+    .bind(Utc::now())
+    .bind("task_escalation")
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(true)
+}
+
+/// Reject a start time before any of a task's predecessors is due
+async fn check_predecessors(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    dependencies: &[Uuid],
+    begins: DateTime<Utc>,
+) -> Result<(), AppError> {
+    if dependencies.is_empty() {
+        return Ok(());
+    }
+
+    let blocking = sqlx::query_as::<_, (String, DateTime<Utc>)>(
+        r#"
+        SELECT title, due_date FROM workflow_tasks
+        WHERE id = ANY($1) AND deleted_at IS NULL AND due_date > $2
+        ORDER BY due_date DESC
+        LIMIT 1
+        "#,
+    )
+    .bind(dependencies)
+    .bind(begins)
+    .fetch_optional(&mut **tx)
+    .await?;
+
+    if let Some((title, due_date)) = blocking {
+        return Err(AppError::Validation(format!(
+            "The task cannot start before \"{}\" is due on {}",
+            title,
+            due_date.date_naive()
+        )));
+    }
+    Ok(())
+}
+
+/// Push a task and everything that depends on it, directly or not, so that
+/// no open task begins before its predecessors are due
+async fn push_dependent_tasks(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    id: Uuid,
+    user_id: Uuid,
+) -> Result<(), AppError> {
+    let dependents = sqlx::query_as::<_, WorkflowTask>(
+        r#"
+        WITH RECURSIVE dependents(id) AS (
+            SELECT $1::UUID
+            UNION
+            SELECT t.id FROM workflow_tasks t
+            JOIN dependents d ON d.id = ANY(t.dependencies)
+            WHERE t.deleted_at IS NULL
+        )
+        SELECT * FROM workflow_tasks
+        WHERE id IN (SELECT id FROM dependents) AND deleted_at IS NULL
+        FOR UPDATE
+        "#,
+    )
+    .bind(id)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    let dependent_ids: HashSet<Uuid> = dependents.iter().map(|t| t.id).collect();
+    let predecessor_ids: Vec<Uuid> = dependents
+        .iter()
+        .flat_map(|t| t.dependencies.iter().flatten().copied())
+        .filter(|id| !dependent_ids.contains(id))
+        .collect();
+    let predecessors = sqlx::query_as::<_, WorkflowTask>(
+        "SELECT * FROM workflow_tasks WHERE id = ANY($1) AND deleted_at IS NULL",
+    )
+    .bind(&predecessor_ids)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    // Only the dependents may move; their other predecessors stay put
+    let tasks: HashMap<Uuid, TaskDates> = dependents
+        .iter()
+        .chain(&predecessors)
+        .map(|t| {
+            (
+                t.id,
+                TaskDates {
+                    start: t.start_date,
+                    due: t.due_date,
+                    dependencies: t.dependencies.clone().unwrap_or_default(),
+                    closed: is_closed(t.status) || !dependent_ids.contains(&t.id),
+                },
+            )
+        })
+        .collect();
+
+    for (task_id, dates) in push_dependents(&tasks) {
+        sqlx::query(
+            r#"
+            UPDATE workflow_tasks
+            SET start_date = $1, due_date = $2, updated_at = $3, updated_by = $4,
+                version = COALESCE(version, 1) + 1
+            WHERE id = $5
+            "#,
+        )
+        .bind(dates.start)
+        .bind(dates.due)
+        .bind(Utc::now())
+        .bind(user_id)
+        .bind(task_id)
+        .execute(&mut **tx)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Name shown for a task assigned to a user
+pub async fn assignee_name<'e, E>(executor: E, user_id: Uuid) -> Result<String, AppError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query_scalar::<_, String>("SELECT username FROM users WHERE id = $1 AND is_active")
+        .bind(user_id)
+        .fetch_optional(executor)
+        .await?
+        .ok_or(AppError::NotFound("Assignee not found".to_string()))
+}
+
+pub fn validate_priority(priority: &str) -> Result<(), AppError> {
+    if !matches!(priority, "Low" | "Medium" | "High" | "Critical") {
+        return Err(AppError::Validation(
+            "Priority must be Low, Medium, High or Critical".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Parse a recurrence rule, pinned to the day of the task's due date
+fn parse_recurrence(rule: &str, due_date: DateTime<Utc>) -> Result<Recurrence, AppError> {
+    let recurrence: Recurrence = rule
+        .parse()
+        .map_err(|e| AppError::Validation(format!("Invalid recurrence rule: {}", e)))?;
+    Ok(recurrence.anchored(due_date.date_naive()))
+}
+
+fn validate_escalation_rule(
+    name: &str,
+    min_priority: &str,
+    overdue_hours: i32,
+) -> Result<(), AppError> {
+    if name.trim().is_empty() {
+        return Err(AppError::Validation(
+            "Escalation rule name is required".to_string(),
+        ));
+    }
+    if !matches!(min_priority, "High" | "Critical") {
+        return Err(AppError::Validation(
+            "Escalation rules apply to High or Critical priority tasks".to_string(),
+        ));
+    }
+    if overdue_hours <= 0 {
+        return Err(AppError::Validation(
+            "Overdue hours must be positive".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_dates(
+    start_date: Option<DateTime<Utc>>,
+    due_date: DateTime<Utc>,
+) -> Result<(), AppError> {
+    if start_date.is_some_and(|start| start > due_date) {
+        return Err(AppError::Validation(
+            "Start date must not be after the due date".to_string(),
+        ));
+    }
+    Ok(())
 }
@@ -0,0 +1,39 @@
+//! Status flow of workflow tasks
+
+use crate::models::TaskStatus;
+
+/// Statuses a task may move to from its current one.
+///
+/// Work starts, goes to review and comes back or is done; done work is
+/// signed off as completed. Any open task may be completed directly, as
+/// automated deadlines do, and completed tasks may be reopened.
+pub fn next_statuses(current: TaskStatus) -> &'static [TaskStatus] {
+    use TaskStatus::*;
+    match current {
+        Pending => &[InProgress, Completed],
+        InProgress => &[Pending, Review, Done, Completed],
+        Review => &[InProgress, Done, Completed],
+        Done => &[InProgress, Completed],
+        Completed => &[InProgress],
+    }
+}
+
+/// Whether a task may move from one status to another
+pub fn can_transition(from: TaskStatus, to: TaskStatus) -> bool {
+    next_statuses(from).contains(&to)
+}
+
+/// Whether the work on a task is finished
+pub fn is_closed(status: TaskStatus) -> bool {
+    matches!(status, TaskStatus::Done | TaskStatus::Completed)
+}
+
+/// Completion percentage of a task after a status change: finished tasks
+/// are fully complete, reopened ones are not
+pub fn completion_for(status: TaskStatus, completion: i32) -> i32 {
+    if is_closed(status) {
+        100
+    } else {
+        completion.min(99)
+    }
+}
@@ -0,0 +1,324 @@
+use crate::api::workflows::service::{
+    ApplyTemplateParams, CreateTemplateParams, UpdateTemplateParams, WorkflowService,
+};
+use crate::error::AppError;
+use crate::models::{
+    Claims, Project, ProjectDetail, ProjectGantt, TemplateStage, WorkflowTemplate,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct ListTemplatesQuery {
+    pub category: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListProjectsQuery {
+    pub case_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateTemplateRequest {
+    pub title: String,
+    pub category: String,
+    /// Low, Medium or High
+    pub complexity: String,
+    /// Expected duration, e.g. "6 weeks"
+    pub duration: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub audit_ready: Option<bool>,
+    pub stages: Vec<TemplateStage>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateTemplateRequest {
+    pub title: Option<String>,
+    pub category: Option<String>,
+    pub complexity: Option<String>,
+    pub duration: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub audit_ready: Option<bool>,
+    pub stages: Option<Vec<TemplateStage>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ApplyTemplateRequest {
+    pub case_id: Uuid,
+    /// Date the template's task offsets are counted from (e.g. the trial date)
+    pub anchor_date: NaiveDate,
+    /// Project title; defaults to the template title
+    pub title: Option<String>,
+    /// Project lead; defaults to the applying user
+    pub lead: Option<String>,
+    /// User every task is assigned to
+    pub assignee_id: Option<Uuid>,
+    /// Project priority (defaults to Medium)
+    pub priority: Option<String>,
+}
+
+fn user_id(claims: &Claims) -> Result<Uuid, AppError> {
+    Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::BadRequest("Invalid user ID in token".to_string()))
+}
+
+/// List workflow templates
+#[utoipa::path(
+    get,
+    path = "/api/workflow-templates",
+    params(
+        ("category" = Option<String>, Query, description = "Filter by category")
+    ),
+    responses(
+        (status = 200, description = "Workflow templates", body = Vec<WorkflowTemplate>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "workflows",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_templates(
+    State(service): State<Arc<WorkflowService>>,
+    Query(query): Query<ListTemplatesQuery>,
+) -> Result<Json<Vec<WorkflowTemplate>>, AppError> {
+    let templates = service.list_templates(query.category).await?;
+    Ok(Json(templates))
+}
+
+/// Get a workflow template
+#[utoipa::path(
+    get,
+    path = "/api/workflow-templates/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Workflow template ID")
+    ),
+    responses(
+        (status = 200, description = "Workflow template", body = WorkflowTemplate),
+        (status = 404, description = "Workflow template not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "workflows",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_template(
+    State(service): State<Arc<WorkflowService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<WorkflowTemplate>, AppError> {
+    let template = service.get_template(id).await?;
+    Ok(Json(template))
+}
+
+/// Create a workflow template
+#[utoipa::path(
+    post,
+    path = "/api/workflow-templates",
+    request_body = CreateTemplateRequest,
+    responses(
+        (status = 201, description = "Workflow template created", body = WorkflowTemplate),
+        (status = 400, description = "Invalid template or stages"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "workflows",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_template(
+    State(service): State<Arc<WorkflowService>>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<CreateTemplateRequest>,
+) -> Result<(StatusCode, Json<WorkflowTemplate>), AppError> {
+    let template = service
+        .create_template(CreateTemplateParams {
+            title: req.title,
+            category: req.category,
+            complexity: req.complexity,
+            duration: req.duration,
+            tags: req.tags,
+            audit_ready: req.audit_ready,
+            stages: req.stages,
+            user_id: user_id(&claims)?,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(template)))
+}
+
+/// Update a workflow template
+#[utoipa::path(
+    put,
+    path = "/api/workflow-templates/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Workflow template ID")
+    ),
+    request_body = UpdateTemplateRequest,
+    responses(
+        (status = 200, description = "Workflow template updated; existing projects are unchanged", body = WorkflowTemplate),
+        (status = 400, description = "Invalid template or stages"),
+        (status = 404, description = "Workflow template not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "workflows",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_template(
+    State(service): State<Arc<WorkflowService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateTemplateRequest>,
+) -> Result<Json<WorkflowTemplate>, AppError> {
+    let template = service
+        .update_template(
+            id,
+            UpdateTemplateParams {
+                title: req.title,
+                category: req.category,
+                complexity: req.complexity,
+                duration: req.duration,
+                tags: req.tags,
+                audit_ready: req.audit_ready,
+                stages: req.stages,
+                user_id: user_id(&claims)?,
+            },
+        )
+        .await?;
+    Ok(Json(template))
+}
+
+/// Delete a workflow template
+#[utoipa::path(
+    delete,
+    path = "/api/workflow-templates/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Workflow template ID")
+    ),
+    responses(
+        (status = 204, description = "Workflow template deleted"),
+        (status = 404, description = "Workflow template not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "workflows",
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_template(
+    State(service): State<Arc<WorkflowService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    service.delete_template(id, user_id(&claims)?).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Apply a workflow template to a case, creating a project with its stages
+/// and tasks
+#[utoipa::path(
+    post,
+    path = "/api/workflow-templates/{id}/apply",
+    params(
+        ("id" = Uuid, Path, description = "Workflow template ID")
+    ),
+    request_body = ApplyTemplateRequest,
+    responses(
+        (status = 201, description = "Project created", body = ProjectDetail),
+        (status = 400, description = "Invalid priority or template stages"),
+        (status = 404, description = "Workflow template, case or assignee not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "workflows",
+    security(("bearer_auth" = []))
+)]
+pub async fn apply_template(
+    State(service): State<Arc<WorkflowService>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<ApplyTemplateRequest>,
+) -> Result<(StatusCode, Json<ProjectDetail>), AppError> {
+    let project = service
+        .apply_template(
+            id,
+            ApplyTemplateParams {
+                case_id: req.case_id,
+                anchor_date: req.anchor_date,
+                title: req.title,
+                lead: req.lead,
+                assignee_id: req.assignee_id,
+                priority: req.priority,
+                user_id: user_id(&claims)?,
+            },
+        )
+        .await?;
+    Ok((StatusCode::CREATED, Json(project)))
+}
+
+/// List projects
+#[utoipa::path(
+    get,
+    path = "/api/projects",
+    params(
+        ("case_id" = Option<Uuid>, Query, description = "Filter by case")
+    ),
+    responses(
+        (status = 200, description = "Projects, soonest due first", body = Vec<Project>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "workflows",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_projects(
+    State(service): State<Arc<WorkflowService>>,
+    Query(query): Query<ListProjectsQuery>,
+) -> Result<Json<Vec<Project>>, AppError> {
+    let projects = service.list_projects(query.case_id).await?;
+    Ok(Json(projects))
+}
+
+/// Get a project with its stages and their tasks
+#[utoipa::path(
+    get,
+    path = "/api/projects/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Project ID")
+    ),
+    responses(
+        (status = 200, description = "Project with stages in order", body = ProjectDetail),
+        (status = 404, description = "Project not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "workflows",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_project(
+    State(service): State<Arc<WorkflowService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ProjectDetail>, AppError> {
+    let project = service.get_project(id).await?;
+    Ok(Json(project))
+}
+
+/// Get Gantt data and the critical path of a project
+#[utoipa::path(
+    get,
+    path = "/api/projects/{id}/gantt",
+    params(
+        ("id" = Uuid, Path, description = "Project ID")
+    ),
+    responses(
+        (status = 200, description = "Project tasks with dependencies, slack and the critical path", body = ProjectGantt),
+        (status = 404, description = "Project not found"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "workflows",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_project_gantt(
+    State(service): State<Arc<WorkflowService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ProjectGantt>, AppError> {
+    let gantt = service.get_project_gantt(id).await?;
+    Ok(Json(gantt))
+}
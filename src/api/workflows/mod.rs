@@ -0,0 +1,6 @@
+pub mod handlers;
+pub mod service;
+pub mod templates;
+
+pub use handlers::*;
+pub use service::*;
@@ -0,0 +1,556 @@
+use crate::api::deadlines::end_of_day;
+use crate::api::tasks::dependencies::{total_float, TaskDates};
+use crate::api::tasks::workflow::is_closed;
+use crate::api::tasks::{assignee_name, check_wip_limits, validate_priority};
+use crate::api::workflows::templates::{
+    project_status, schedule_task, stage_status, template_stages, validate_stages,
+    DEFAULT_TASK_PRIORITY,
+};
+use crate::error::AppError;
+use crate::models::{
+    GanttTask, Project, ProjectDetail, ProjectGantt, ProjectStage, ProjectStageTasks, StageStatus,
+    TaskStatus, TemplateStage, WorkflowTask, WorkflowTemplate,
+};
+use chrono::{Duration, NaiveDate, NaiveTime, Utc};
+use sqlx::{PgPool, Postgres, Transaction};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// Parameters for creating a workflow template
+pub struct CreateTemplateParams {
+    pub title: String,
+    pub category: String,
+    /// Low, Medium or High
+    pub complexity: String,
+    pub duration: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub audit_ready: Option<bool>,
+    pub stages: Vec<TemplateStage>,
+    /// ID of the user creating the template
+    pub user_id: Uuid,
+}
+
+/// Parameters for updating a workflow template; omitted fields are unchanged
+pub struct UpdateTemplateParams {
+    pub title: Option<String>,
+    pub category: Option<String>,
+    pub complexity: Option<String>,
+    pub duration: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub audit_ready: Option<bool>,
+    pub stages: Option<Vec<TemplateStage>>,
+    /// ID of the user updating the template
+    pub user_id: Uuid,
+}
+
+/// Parameters for applying a workflow template to a case
+pub struct ApplyTemplateParams {
+    pub case_id: Uuid,
+    /// Date the template's task offsets are counted from (e.g. the trial date)
+    pub anchor_date: NaiveDate,
+    /// Project title; defaults to the template title
+    pub title: Option<String>,
+    /// Project lead; defaults to the applying user
+    pub lead: Option<String>,
+    /// User every task is assigned to; tasks are unassigned otherwise
+    pub assignee_id: Option<Uuid>,
+    /// Project priority (defaults to Medium)
+    pub priority: Option<String>,
+    /// ID of the user applying the template
+    pub user_id: Uuid,
+}
+
+pub struct WorkflowService {
+    pool: PgPool,
+}
+
+impl WorkflowService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// List workflow templates, optionally in one category
+    pub async fn list_templates(
+        &self,
+        category: Option<String>,
+    ) -> Result<Vec<WorkflowTemplate>, AppError> {
+        let templates = sqlx::query_as::<_, WorkflowTemplate>(
+            r#"
+            SELECT * FROM workflow_templates
+            WHERE deleted_at IS NULL AND ($1::VARCHAR IS NULL OR category = $1)
+            ORDER BY category, title
+            "#,
+        )
+        .bind(&category)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(templates)
+    }
+
+    pub async fn get_template(&self, id: Uuid) -> Result<WorkflowTemplate, AppError> {
+        let template = sqlx::query_as::<_, WorkflowTemplate>(
+            "SELECT * FROM workflow_templates WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound(
+            "Workflow template not found".to_string(),
+        ))?;
+
+        Ok(template)
+    }
+
+    pub async fn create_template(
+        &self,
+        params: CreateTemplateParams,
+    ) -> Result<WorkflowTemplate, AppError> {
+        validate_template(&params.title, &params.category, &params.complexity)?;
+        validate_stages(&params.stages)?;
+
+        let stages = serde_json::to_value(&params.stages)
+            .map_err(|e| AppError::InternalServerError(e.to_string()))?;
+
+        let template = sqlx::query_as::<_, WorkflowTemplate>(
+            r#"
+            INSERT INTO workflow_templates (
+                id, title, category, complexity, duration, tags, audit_ready, stages,
+                created_at, updated_at, created_by, updated_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $9, $10, $10)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(params.title.trim())
+        .bind(params.category.trim())
+        .bind(&params.complexity)
+        .bind(&params.duration)
+        .bind(&params.tags)
+        .bind(params.audit_ready.unwrap_or(false))
+        .bind(stages)
+        .bind(Utc::now())
+        .bind(params.user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(template)
+    }
+
+    /// Update a workflow template. Projects already created from it are
+    /// unchanged.
+    pub async fn update_template(
+        &self,
+        id: Uuid,
+        params: UpdateTemplateParams,
+    ) -> Result<WorkflowTemplate, AppError> {
+        let existing = self.get_template(id).await?;
+
+        let title = params.title.unwrap_or(existing.title);
+        let category = params.category.unwrap_or(existing.category);
+        let complexity = params.complexity.unwrap_or(existing.complexity);
+        validate_template(&title, &category, &complexity)?;
+
+        let stages = match params.stages {
+            Some(stages) => {
+                validate_stages(&stages)?;
+                Some(
+                    serde_json::to_value(&stages)
+                        .map_err(|e| AppError::InternalServerError(e.to_string()))?,
+                )
+            }
+            None => existing.stages,
+        };
+
+        let template = sqlx::query_as::<_, WorkflowTemplate>(
+            r#"
+            UPDATE workflow_templates
+            SET title = $1, category = $2, complexity = $3, duration = $4, tags = $5,
+                audit_ready = $6, stages = $7, updated_at = $8, updated_by = $9,
+                version = COALESCE(version, 1) + 1
+            WHERE id = $10 AND deleted_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(title.trim())
+        .bind(category.trim())
+        .bind(&complexity)
+        .bind(params.duration.or(existing.duration))
+        .bind(params.tags.or(existing.tags))
+        .bind(params.audit_ready.or(existing.audit_ready))
+        .bind(stages)
+        .bind(Utc::now())
+        .bind(params.user_id)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound(
+            "Workflow template not found".to_string(),
+        ))?;
+
+        Ok(template)
+    }
+
+    /// Soft delete a workflow template; projects created from it are kept
+    pub async fn delete_template(&self, id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+        let result = sqlx::query(
+            "UPDATE workflow_templates SET deleted_at = NOW(), updated_by = $2 WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(
+                "Workflow template not found".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Create a project for a case from a template, with a stage per
+    /// template stage and a pending task per template task due relative to
+    /// the anchor date
+    pub async fn apply_template(
+        &self,
+        id: Uuid,
+        params: ApplyTemplateParams,
+    ) -> Result<ProjectDetail, AppError> {
+        let template = self.get_template(id).await?;
+        let stages = template_stages(&template)?;
+        validate_stages(&stages)?;
+
+        let priority = params
+            .priority
+            .unwrap_or_else(|| DEFAULT_TASK_PRIORITY.to_string());
+        validate_priority(&priority)?;
+
+        let title = params
+            .title
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .unwrap_or_else(|| template.title.clone());
+
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query_scalar::<_, Uuid>("SELECT id FROM cases WHERE id = $1 AND deleted_at IS NULL")
+            .bind(params.case_id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or(AppError::NotFound("Case not found".to_string()))?;
+
+        let assignee = match params.assignee_id {
+            Some(assignee_id) => assignee_name(&mut *tx, assignee_id).await?,
+            None => "Unassigned".to_string(),
+        };
+        let lead = match params.lead.map(|l| l.trim().to_string()) {
+            Some(lead) if !lead.is_empty() => lead,
+            _ => sqlx::query_scalar::<_, String>("SELECT username FROM users WHERE id = $1")
+                .bind(params.user_id)
+                .fetch_optional(&mut *tx)
+                .await?
+                .unwrap_or_else(|| "Unassigned".to_string()),
+        };
+
+        let project_due = stages
+            .iter()
+            .flat_map(|stage| &stage.tasks)
+            .map(|task| schedule_task(params.anchor_date, task).map(|(_, due)| due))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .max();
+        let now = Utc::now();
+
+        let project = sqlx::query_as::<_, Project>(
+            r#"
+            INSERT INTO projects (
+                id, case_id, title, description, status, priority, lead, due_date,
+                template_id, anchor_date, created_at, updated_at, created_by, updated_by
+            )
+            VALUES ($1, $2, $3, $4, 'Planning', $5, $6, $7, $8, $9, $10, $10, $11, $11)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(params.case_id)
+        .bind(&title)
+        .bind(format!("Created from template \"{}\"", template.title))
+        .bind(&priority)
+        .bind(&lead)
+        .bind(project_due.map(end_of_day))
+        .bind(template.id)
+        .bind(params.anchor_date)
+        .bind(now)
+        .bind(params.user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        for (position, stage) in stages.iter().enumerate() {
+            let stage_id = sqlx::query_scalar::<_, Uuid>(
+                r#"
+                INSERT INTO project_stages (id, project_id, position, name, status, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, 'Pending', $5, $5)
+                RETURNING id
+                "#,
+            )
+            .bind(Uuid::new_v4())
+            .bind(project.id)
+            .bind(position as i32 + 1)
+            .bind(stage.name.trim())
+            .bind(now)
+            .fetch_one(&mut *tx)
+            .await?;
+
+            for task in &stage.tasks {
+                let (start, due) = schedule_task(params.anchor_date, task)?;
+                let task_id = Uuid::new_v4();
+                check_wip_limits(
+                    &mut tx,
+                    task_id,
+                    Some(params.case_id),
+                    Some(project.id),
+                    TaskStatus::Pending,
+                )
+                .await?;
+
+                sqlx::query(
+                    r#"
+                    INSERT INTO workflow_tasks (
+                        id, title, status, assignee, assignee_id, start_date, due_date, priority,
+                        description, case_id, project_id, stage_id, related_module,
+                        related_item_id, related_item_title, completion, created_at, updated_at,
+                        created_by, updated_by
+                    )
+                    VALUES (
+                        $1, $2, 'Pending', $3, $4, $5, $6, $7, $8, $9, $10, $11, 'Workflows',
+                        $12, $13, 0, $14, $14, $15, $15
+                    )
+                    "#,
+                )
+                .bind(task_id)
+                .bind(task.title.trim())
+                .bind(&assignee)
+                .bind(params.assignee_id)
+                .bind(start.map(|date| date.and_time(NaiveTime::MIN).and_utc()))
+                .bind(end_of_day(due))
+                .bind(task.priority.as_deref().unwrap_or(DEFAULT_TASK_PRIORITY))
+                .bind(&task.description)
+                .bind(params.case_id)
+                .bind(project.id)
+                .bind(stage_id)
+                .bind(template.id.to_string())
+                .bind(&template.title)
+                .bind(now)
+                .bind(params.user_id)
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+
+        tx.commit().await?;
+
+        self.get_project(project.id).await
+    }
+
+    /// List projects, optionally for one case
+    pub async fn list_projects(&self, case_id: Option<Uuid>) -> Result<Vec<Project>, AppError> {
+        let projects = sqlx::query_as::<_, Project>(
+            r#"
+            SELECT * FROM projects
+            WHERE deleted_at IS NULL AND ($1::UUID IS NULL OR case_id = $1)
+            ORDER BY due_date ASC NULLS LAST, created_at
+            "#,
+        )
+        .bind(case_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(projects)
+    }
+
+    /// Get a project with its stages and their tasks
+    pub async fn get_project(&self, id: Uuid) -> Result<ProjectDetail, AppError> {
+        let project = sqlx::query_as::<_, Project>(
+            "SELECT * FROM projects WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound("Project not found".to_string()))?;
+
+        let stages = sqlx::query_as::<_, ProjectStage>(
+            "SELECT * FROM project_stages WHERE project_id = $1 ORDER BY position",
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut tasks = sqlx::query_as::<_, WorkflowTask>(
+            r#"
+            SELECT * FROM workflow_tasks
+            WHERE project_id = $1 AND stage_id IS NOT NULL AND deleted_at IS NULL
+            ORDER BY due_date ASC
+            "#,
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let stages = stages
+            .into_iter()
+            .map(|stage| {
+                let (stage_tasks, rest) = tasks
+                    .drain(..)
+                    .partition(|task| task.stage_id == Some(stage.id));
+                tasks = rest;
+                ProjectStageTasks {
+                    stage,
+                    tasks: stage_tasks,
+                }
+            })
+            .collect();
+
+        Ok(ProjectDetail { project, stages })
+    }
+
+    /// Gantt data for a project: each task's bar, its dependencies within
+    /// the project, its slack and whether it is on the critical path
+    pub async fn get_project_gantt(&self, id: Uuid) -> Result<ProjectGantt, AppError> {
+        sqlx::query_scalar::<_, Uuid>(
+            "SELECT id FROM projects WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound("Project not found".to_string()))?;
+
+        let tasks = sqlx::query_as::<_, WorkflowTask>(
+            "SELECT * FROM workflow_tasks WHERE project_id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let in_project: HashSet<Uuid> = tasks.iter().map(|t| t.id).collect();
+        let dates: HashMap<Uuid, TaskDates> = tasks
+            .iter()
+            .map(|t| {
+                (
+                    t.id,
+                    TaskDates {
+                        start: t.start_date,
+                        due: t.due_date,
+                        dependencies: t
+                            .dependencies
+                            .iter()
+                            .flatten()
+                            .copied()
+                            .filter(|dep| in_project.contains(dep))
+                            .collect(),
+                        closed: is_closed(t.status),
+                    },
+                )
+            })
+            .collect();
+        let float = total_float(&dates);
+
+        let mut bars: Vec<GanttTask> = tasks
+            .into_iter()
+            .map(|t| {
+                let task_dates = &dates[&t.id];
+                let slack = float[&t.id];
+                GanttTask {
+                    task_id: t.id,
+                    title: t.title,
+                    stage_id: t.stage_id,
+                    status: t.status,
+                    start: task_dates.begins(),
+                    due: t.due_date,
+                    completion: t.completion.unwrap_or(0),
+                    dependencies: task_dates.dependencies.clone(),
+                    slack_days: slack.num_days(),
+                    critical: slack <= Duration::zero(),
+                }
+            })
+            .collect();
+        bars.sort_by_key(|bar| (bar.start, bar.due, bar.task_id));
+
+        Ok(ProjectGantt {
+            project_id: id,
+            start: bars.iter().map(|bar| bar.start).min(),
+            finish: bars.iter().map(|bar| bar.due).max(),
+            critical_path: bars
+                .iter()
+                .filter(|bar| bar.critical)
+                .map(|bar| bar.task_id)
+                .collect(),
+            tasks: bars,
+        })
+    }
+}
+
+/// Recompute the status of a project stage from its tasks, and of its
+/// project from its stages. Called whenever a staged task changes.
+pub async fn roll_up_stage(
+    tx: &mut Transaction<'_, Postgres>,
+    stage_id: Uuid,
+) -> Result<(), AppError> {
+    let statuses = sqlx::query_scalar::<_, TaskStatus>(
+        "SELECT status FROM workflow_tasks WHERE stage_id = $1 AND deleted_at IS NULL",
+    )
+    .bind(stage_id)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    let project_id = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        UPDATE project_stages
+        SET status = $1,
+            completed_at = CASE WHEN $1 = 'Completed'::stage_status THEN COALESCE(completed_at, NOW()) END,
+            updated_at = NOW()
+        WHERE id = $2
+        RETURNING project_id
+        "#,
+    )
+    .bind(stage_status(&statuses))
+    .bind(stage_id)
+    .fetch_one(&mut **tx)
+    .await?;
+
+    let stages = sqlx::query_scalar::<_, StageStatus>(
+        "SELECT status FROM project_stages WHERE project_id = $1",
+    )
+    .bind(project_id)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    sqlx::query("UPDATE projects SET status = $1, updated_at = NOW() WHERE id = $2")
+        .bind(project_status(&stages))
+        .bind(project_id)
+        .execute(&mut **tx)
+        .await?;
+
+    Ok(())
+}
+
+fn validate_template(title: &str, category: &str, complexity: &str) -> Result<(), AppError> {
+    if title.trim().is_empty() {
+        return Err(AppError::Validation(
+            "Template title is required".to_string(),
+        ));
+    }
+    if category.trim().is_empty() {
+        return Err(AppError::Validation(
+            "Template category is required".to_string(),
+        ));
+    }
+    if !matches!(complexity, "Low" | "Medium" | "High") {
+        return Err(AppError::Validation(
+            "Complexity must be Low, Medium or High".to_string(),
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,141 @@
+//! Workflow template stages and how projects built from them are scheduled
+//! and rolled up
+
+use std::collections::HashSet;
+
+use chrono::{Duration, NaiveDate};
+
+use crate::{
+    api::tasks::{validate_priority, workflow::is_closed},
+    error::AppError,
+    models::{StageStatus, TaskStatus, TemplateStage, TemplateTask, WorkflowTemplate},
+};
+
+/// Priority of template tasks that do not set one
+pub const DEFAULT_TASK_PRIORITY: &str = "Medium";
+
+/// Most days a template task may be offset from the anchor date, either
+/// way, or last (about ten years)
+pub const MAX_TEMPLATE_DAYS: i32 = 3660;
+
+/// Check that a template has named, distinct stages, each with tasks
+pub fn validate_stages(stages: &[TemplateStage]) -> Result<(), AppError> {
+    if stages.is_empty() {
+        return Err(AppError::Validation(
+            "A template needs at least one stage".to_string(),
+        ));
+    }
+
+    let mut names = HashSet::new();
+    for stage in stages {
+        let name = stage.name.trim();
+        if name.is_empty() {
+            return Err(AppError::Validation("Stage name is required".to_string()));
+        }
+        if !names.insert(name.to_lowercase()) {
+            return Err(AppError::Validation(format!(
+                "Stage \"{}\" appears more than once",
+                name
+            )));
+        }
+        if stage.tasks.is_empty() {
+            return Err(AppError::Validation(format!(
+                "Stage \"{}\" has no tasks",
+                name
+            )));
+        }
+        for task in &stage.tasks {
+            if task.title.trim().is_empty() {
+                return Err(AppError::Validation(format!(
+                    "A task in stage \"{}\" has no title",
+                    name
+                )));
+            }
+            if let Some(priority) = &task.priority {
+                validate_priority(priority)?;
+            }
+            if task.duration_days.is_some_and(|days| days < 0) {
+                return Err(AppError::Validation(format!(
+                    "Task \"{}\" has a negative duration",
+                    task.title.trim()
+                )));
+            }
+            if task.offset_days.unsigned_abs() > MAX_TEMPLATE_DAYS as u32
+                || task
+                    .duration_days
+                    .is_some_and(|days| days > MAX_TEMPLATE_DAYS)
+            {
+                return Err(AppError::Validation(format!(
+                    "Task \"{}\" may be offset or last at most {} days",
+                    task.title.trim(),
+                    MAX_TEMPLATE_DAYS
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Stages stored on a template
+pub fn template_stages(template: &WorkflowTemplate) -> Result<Vec<TemplateStage>, AppError> {
+    match &template.stages {
+        Some(stages) => serde_json::from_value(stages.clone())
+            .map_err(|e| AppError::Validation(format!("Template stages are not valid: {}", e))),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Start and due dates of a template task counted from the anchor date
+pub fn schedule_task(
+    anchor: NaiveDate,
+    task: &TemplateTask,
+) -> Result<(Option<NaiveDate>, NaiveDate), AppError> {
+    let out_of_range = || {
+        AppError::Validation(format!(
+            "Task \"{}\" falls outside the supported dates from {}",
+            task.title.trim(),
+            anchor
+        ))
+    };
+    let due = anchor
+        .checked_add_signed(Duration::days(task.offset_days.into()))
+        .ok_or_else(out_of_range)?;
+    let start = task
+        .duration_days
+        .map(|days| {
+            due.checked_sub_signed(Duration::days(days.into()))
+                .ok_or_else(out_of_range)
+        })
+        .transpose()?;
+    Ok((start, due))
+}
+
+/// Status of a stage given the statuses of its tasks.
+///
+/// A stage is completed once all its tasks are done, and active once any
+/// work on it has started. A stage whose tasks were all removed has nothing
+/// left to do.
+pub fn stage_status(tasks: &[TaskStatus]) -> StageStatus {
+    if tasks.iter().all(|&status| is_closed(status)) {
+        StageStatus::Completed
+    } else if tasks.iter().any(|&status| status != TaskStatus::Pending) {
+        StageStatus::Active
+    } else {
+        StageStatus::Pending
+    }
+}
+
+/// Status of a project given the statuses of its stages
+pub fn project_status(stages: &[StageStatus]) -> &'static str {
+    if stages
+        .iter()
+        .all(|&status| status == StageStatus::Completed)
+    {
+        "Completed"
+    } else if stages.iter().any(|&status| status != StageStatus::Pending) {
+        "Active"
+    } else {
+        "Planning"
+    }
+}
@@ -0,0 +1,52 @@
+use crate::error::AppError;
+use std::io::{Cursor, Write};
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+/// In-memory ZIP archive builder for downloadable exports
+pub struct ZipArchiveBuilder {
+    writer: ZipWriter<Cursor<Vec<u8>>>,
+}
+
+impl ZipArchiveBuilder {
+    pub fn new() -> Self {
+        Self {
+            writer: ZipWriter::new(Cursor::new(Vec::new())),
+        }
+    }
+
+    /// Add a file at the given path inside the archive
+    pub fn add_file(&mut self, path: &str, contents: &[u8]) -> Result<(), AppError> {
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+        self.writer.start_file(path, options).map_err(|e| {
+            AppError::InternalServerError(format!("Failed to write archive: {}", e))
+        })?;
+        self.writer.write_all(contents).map_err(|e| {
+            AppError::InternalServerError(format!("Failed to write archive: {}", e))
+        })?;
+        Ok(())
+    }
+
+    /// Add an empty directory entry (e.g., "VOL001/NATIVES/")
+    pub fn add_directory(&mut self, path: &str) -> Result<(), AppError> {
+        self.writer
+            .add_directory(path, SimpleFileOptions::default())
+            .map_err(|e| {
+                AppError::InternalServerError(format!("Failed to write archive: {}", e))
+            })?;
+        Ok(())
+    }
+
+    /// Finish the archive and return its bytes
+    pub fn finish(self) -> Result<Vec<u8>, AppError> {
+        let cursor = self.writer.finish().map_err(|e| {
+            AppError::InternalServerError(format!("Failed to write archive: {}", e))
+        })?;
+        Ok(cursor.into_inner())
+    }
+}
+
+impl Default for ZipArchiveBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,23 @@
+/// Write rows as RFC 4180 CSV with a header row
+pub fn write_csv(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut out = String::new();
+    push_row(&mut out, headers.iter().map(|h| h.to_string()));
+    for row in rows {
+        push_row(&mut out, row.iter().cloned());
+    }
+    out
+}
+
+fn push_row(out: &mut String, values: impl Iterator<Item = String>) {
+    let line = values.map(|v| escape(&v)).collect::<Vec<_>>().join(",");
+    out.push_str(&line);
+    out.push_str("\r\n");
+}
+
+fn escape(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,126 @@
+use crate::error::AppError;
+use crate::export::{escape_xml, ZipArchiveBuilder};
+use std::fmt::Write;
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/></Types>"#;
+
+const ROOT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/></Relationships>"#;
+
+// Landscape US Letter with half-inch margins, in twentieths of a point
+const PAGE_WIDTH_TWIPS: u32 = 15840;
+const PAGE_HEIGHT_TWIPS: u32 = 12240;
+const MARGIN_TWIPS: u32 = 720;
+
+/// A column of a tabular DOCX report
+pub struct DocxColumn<'a> {
+    pub header: &'a str,
+    /// Share of the usable page width (shares are normalized)
+    pub weight: f32,
+}
+
+/// Write a landscape DOCX report with a centered title block and a bordered
+/// table whose header row repeats on every page.
+///
+/// Newlines inside a cell become line breaks.
+pub fn write_docx_report(
+    title: &str,
+    subtitle_lines: &[String],
+    columns: &[DocxColumn],
+    rows: &[Vec<String>],
+) -> Result<Vec<u8>, AppError> {
+    let usable = (PAGE_WIDTH_TWIPS - MARGIN_TWIPS * 2) as f32;
+    let total_weight: f32 = columns
+        .iter()
+        .map(|c| c.weight)
+        .sum::<f32>()
+        .max(f32::EPSILON);
+    let widths: Vec<u32> = columns
+        .iter()
+        .map(|c| (usable * c.weight / total_weight) as u32)
+        .collect();
+
+    let mut body = String::new();
+    push_paragraph(&mut body, title, true, 28);
+    for line in subtitle_lines {
+        push_paragraph(&mut body, line, false, 20);
+    }
+
+    body.push_str(
+        r#"<w:tbl><w:tblPr><w:tblW w:w="5000" w:type="pct"/><w:tblBorders><w:top w:val="single" w:sz="4" w:space="0" w:color="000000"/><w:left w:val="single" w:sz="4" w:space="0" w:color="000000"/><w:bottom w:val="single" w:sz="4" w:space="0" w:color="000000"/><w:right w:val="single" w:sz="4" w:space="0" w:color="000000"/><w:insideH w:val="single" w:sz="4" w:space="0" w:color="000000"/><w:insideV w:val="single" w:sz="4" w:space="0" w:color="000000"/></w:tblBorders><w:tblLayout w:type="fixed"/></w:tblPr><w:tblGrid>"#,
+    );
+    for width in &widths {
+        let _ = write!(body, r#"<w:gridCol w:w="{}"/>"#, width);
+    }
+    body.push_str("</w:tblGrid>");
+
+    body.push_str("<w:tr><w:trPr><w:tblHeader/></w:trPr>");
+    for (column, width) in columns.iter().zip(&widths) {
+        push_cell(&mut body, column.header, *width, true);
+    }
+    body.push_str("</w:tr>");
+
+    for row in rows {
+        body.push_str("<w:tr><w:trPr><w:cantSplit/></w:trPr>");
+        for (index, width) in widths.iter().enumerate() {
+            let value = row.get(index).map(String::as_str).unwrap_or("");
+            push_cell(&mut body, value, *width, false);
+        }
+        body.push_str("</w:tr>");
+    }
+    body.push_str("</w:tbl>");
+
+    let document = format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>{}<w:sectPr><w:pgSz w:w="{}" w:h="{}" w:orient="landscape"/><w:pgMar w:top="{m}" w:right="{m}" w:bottom="{m}" w:left="{m}" w:header="360" w:footer="360" w:gutter="0"/></w:sectPr></w:body></w:document>"#,
+        body,
+        PAGE_WIDTH_TWIPS,
+        PAGE_HEIGHT_TWIPS,
+        m = MARGIN_TWIPS
+    );
+
+    let mut archive = ZipArchiveBuilder::new();
+    archive.add_file("[Content_Types].xml", CONTENT_TYPES.as_bytes())?;
+    archive.add_file("_rels/.rels", ROOT_RELS.as_bytes())?;
+    archive.add_file("word/document.xml", document.as_bytes())?;
+    archive.finish()
+}
+
+/// Centered paragraph of the title block; `size` is in half-points
+fn push_paragraph(body: &mut String, text: &str, bold: bool, size: u32) {
+    let _ = write!(
+        body,
+        r#"<w:p><w:pPr><w:jc w:val="center"/></w:pPr><w:r><w:rPr>{}<w:sz w:val="{}"/></w:rPr><w:t xml:space="preserve">{}</w:t></w:r></w:p>"#,
+        if bold { "<w:b/>" } else { "" },
+        size,
+        escape_xml(text)
+    );
+}
+
+fn push_cell(body: &mut String, text: &str, width: u32, header: bool) {
+    let _ = write!(
+        body,
+        r#"<w:tc><w:tcPr><w:tcW w:w="{}" w:type="dxa"/>"#,
+        width
+    );
+    if header {
+        body.push_str(r#"<w:shd w:val="clear" w:color="auto" w:fill="D9D9D9"/>"#);
+    }
+    body.push_str("</w:tcPr><w:p><w:r><w:rPr>");
+    if header {
+        body.push_str("<w:b/>");
+    }
+    body.push_str(r#"<w:sz w:val="18"/></w:rPr>"#);
+    for (index, line) in text.split('\n').enumerate() {
+        if index > 0 {
+            body.push_str("<w:br/>");
+        }
+        let _ = write!(
+            body,
+            r#"<w:t xml:space="preserve">{}</w:t>"#,
+            escape_xml(line)
+        );
+    }
+    body.push_str("</w:r></w:p></w:tc>");
+}
@@ -0,0 +1,13 @@
+//! Output formats used by download and export endpoints
+
+pub mod archive;
+pub mod csv;
+pub mod docx;
+pub mod pdf;
+pub mod xlsx;
+
+pub use archive::*;
+pub use csv::*;
+pub use docx::*;
+pub use pdf::*;
+pub use xlsx::*;
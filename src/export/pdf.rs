@@ -0,0 +1,459 @@
+use std::fmt::Write;
+
+/// US Letter page width in points
+pub const PAGE_WIDTH: f32 = 612.0;
+/// US Letter page height in points
+pub const PAGE_HEIGHT: f32 = 792.0;
+
+/// Built-in PDF fonts available to every page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PdfFont {
+    Helvetica,
+    HelveticaBold,
+    Courier,
+}
+
+impl PdfFont {
+    fn resource_name(self) -> &'static str {
+        match self {
+            PdfFont::Helvetica => "F1",
+            PdfFont::HelveticaBold => "F2",
+            PdfFont::Courier => "F3",
+        }
+    }
+
+    fn base_font(self) -> &'static str {
+        match self {
+            PdfFont::Helvetica => "Helvetica",
+            PdfFont::HelveticaBold => "Helvetica-Bold",
+            PdfFont::Courier => "Courier",
+        }
+    }
+
+    /// Approximate advance width of a character at the given size
+    pub fn char_width(self, size: f32) -> f32 {
+        match self {
+            PdfFont::Courier => size * 0.6,
+            PdfFont::Helvetica | PdfFont::HelveticaBold => size * 0.55,
+        }
+    }
+}
+
+const FONTS: [PdfFont; 3] = [PdfFont::Helvetica, PdfFont::HelveticaBold, PdfFont::Courier];
+
+/// A single page of drawing operations
+#[derive(Debug, Clone, Default)]
+pub struct PdfPage {
+    content: String,
+}
+
+impl PdfPage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Draw a line of text with its baseline starting at (x, y)
+    pub fn text(&mut self, font: PdfFont, size: f32, x: f32, y: f32, text: &str) {
+        let _ = writeln!(
+            self.content,
+            "BT /{} {} Tf {} {} Td ({}) Tj ET",
+            font.resource_name(),
+            fmt_num(size),
+            fmt_num(x),
+            fmt_num(y),
+            escape_text(text)
+        );
+    }
+
+    /// Draw text right-aligned so that it ends at `right_x`
+    pub fn text_right(&mut self, font: PdfFont, size: f32, right_x: f32, y: f32, text: &str) {
+        let width = font.char_width(size) * text.chars().count() as f32;
+        self.text(font, size, right_x - width, y, text);
+    }
+
+    /// Draw a filled rectangle in the given gray level (0.0 = black)
+    pub fn fill_rect(&mut self, x: f32, y: f32, width: f32, height: f32, gray: f32) {
+        let _ = writeln!(
+            self.content,
+            "q {} g {} {} {} {} re f Q",
+            fmt_num(gray),
+            fmt_num(x),
+            fmt_num(y),
+            fmt_num(width),
+            fmt_num(height)
+        );
+    }
+
+    /// Draw an unfilled rectangle outline
+    pub fn stroke_rect(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        let _ = writeln!(
+            self.content,
+            "q 0.5 w {} {} {} {} re S Q",
+            fmt_num(x),
+            fmt_num(y),
+            fmt_num(width),
+            fmt_num(height)
+        );
+    }
+
+    /// Draw a straight line between two points
+    pub fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32) {
+        let _ = writeln!(
+            self.content,
+            "q 0.5 w {} {} m {} {} l S Q",
+            fmt_num(x1),
+            fmt_num(y1),
+            fmt_num(x2),
+            fmt_num(y2)
+        );
+    }
+}
+
+/// Minimal PDF writer producing Letter-sized pages with the standard fonts
+#[derive(Debug, Clone, Default)]
+pub struct PdfDocument {
+    title: Option<String>,
+    landscape: bool,
+    pages: Vec<PdfPage>,
+}
+
+impl PdfDocument {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the document title stored in the PDF metadata
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Use landscape pages (792 x 612 points)
+    pub fn landscape(mut self) -> Self {
+        self.landscape = true;
+        self
+    }
+
+    /// Width and height of each page in points
+    pub fn page_size(&self) -> (f32, f32) {
+        if self.landscape {
+            (PAGE_HEIGHT, PAGE_WIDTH)
+        } else {
+            (PAGE_WIDTH, PAGE_HEIGHT)
+        }
+    }
+
+    pub fn add_page(&mut self, page: PdfPage) {
+        self.pages.push(page);
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Serialize the document to PDF bytes
+    pub fn render(&self) -> Vec<u8> {
+        let mut pages = self.pages.clone();
+        if pages.is_empty() {
+            pages.push(PdfPage::new());
+        }
+
+        // Object layout: 1 catalog, 2 page tree, 3 info, fonts, then a
+        // (page, content) pair per page.
+        let font_base = 4;
+        let page_base = font_base + FONTS.len();
+        let object_count = page_base + pages.len() * 2 - 1;
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut offsets = Vec::with_capacity(object_count);
+        out.extend_from_slice(b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n");
+
+        let page_ids: Vec<usize> = (0..pages.len()).map(|i| page_base + i * 2).collect();
+
+        let mut push_object = |out: &mut Vec<u8>, id: usize, body: &[u8]| {
+            offsets.push(out.len());
+            out.extend_from_slice(format!("{} 0 obj\n", id).as_bytes());
+            out.extend_from_slice(body);
+            out.extend_from_slice(b"\nendobj\n");
+        };
+
+        push_object(&mut out, 1, b"<< /Type /Catalog /Pages 2 0 R >>");
+
+        let kids = page_ids
+            .iter()
+            .map(|id| format!("{} 0 R", id))
+            .collect::<Vec<_>>()
+            .join(" ");
+        push_object(
+            &mut out,
+            2,
+            format!("<< /Type /Pages /Kids [{}] /Count {} >>", kids, pages.len()).as_bytes(),
+        );
+
+        let title = self.title.as_deref().unwrap_or("");
+        push_object(
+            &mut out,
+            3,
+            format!(
+                "<< /Title ({}) /Producer (rusty_saas) >>",
+                escape_text(title)
+            )
+            .as_bytes(),
+        );
+
+        let font_resources = FONTS
+            .iter()
+            .enumerate()
+            .map(|(i, font)| format!("/{} {} 0 R", font.resource_name(), font_base + i))
+            .collect::<Vec<_>>()
+            .join(" ");
+        for (i, font) in FONTS.iter().enumerate() {
+            push_object(
+                &mut out,
+                font_base + i,
+                format!(
+                    "<< /Type /Font /Subtype /Type1 /BaseFont /{} /Encoding /WinAnsiEncoding >>",
+                    font.base_font()
+                )
+                .as_bytes(),
+            );
+        }
+
+        let (width, height) = self.page_size();
+        for (page, page_id) in pages.iter().zip(page_ids.iter()) {
+            let content_id = page_id + 1;
+            push_object(
+                &mut out,
+                *page_id,
+                format!(
+                    "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /Font << {} >> >> /Contents {} 0 R >>",
+                    fmt_num(width),
+                    fmt_num(height),
+                    font_resources,
+                    content_id
+                )
+                .as_bytes(),
+            );
+
+            let stream = encode_latin1(&page.content);
+            let mut body = format!("<< /Length {} >>\nstream\n", stream.len()).into_bytes();
+            body.extend_from_slice(&stream);
+            body.extend_from_slice(b"\nendstream");
+            push_object(&mut out, content_id, &body);
+        }
+
+        let xref_offset = out.len();
+        out.extend_from_slice(format!("xref\n0 {}\n", object_count + 1).as_bytes());
+        out.extend_from_slice(b"0000000000 65535 f \n");
+        for offset in &offsets {
+            out.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+        }
+        out.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R /Info 3 0 R >>\nstartxref\n{}\n%%EOF\n",
+                object_count + 1,
+                xref_offset
+            )
+            .as_bytes(),
+        );
+
+        out
+    }
+}
+
+/// A column of a tabular PDF report
+pub struct PdfColumn<'a> {
+    pub header: &'a str,
+    /// Share of the usable page width (shares are normalized)
+    pub weight: f32,
+}
+
+/// Render a landscape report with a title block and a table that repeats
+/// its header row on every page
+pub fn render_table_report(
+    title: &str,
+    subtitle_lines: &[String],
+    columns: &[PdfColumn],
+    rows: &[Vec<String>],
+) -> Vec<u8> {
+    const MARGIN: f32 = 36.0;
+    const FONT_SIZE: f32 = 8.0;
+    const LINE_HEIGHT: f32 = 10.0;
+    const CELL_PADDING: f32 = 3.0;
+
+    let mut pdf = PdfDocument::new().with_title(title).landscape();
+    let (page_width, page_height) = pdf.page_size();
+    let usable = page_width - MARGIN * 2.0;
+    let total_weight: f32 = columns
+        .iter()
+        .map(|c| c.weight)
+        .sum::<f32>()
+        .max(f32::EPSILON);
+    let widths: Vec<f32> = columns
+        .iter()
+        .map(|c| usable * c.weight / total_weight)
+        .collect();
+    let chars_per_column: Vec<usize> = widths
+        .iter()
+        .map(|w| {
+            ((w - CELL_PADDING * 2.0) / PdfFont::Helvetica.char_width(FONT_SIZE)).max(1.0) as usize
+        })
+        .collect();
+
+    let mut pages: Vec<PdfPage> = Vec::new();
+    let mut page = PdfPage::new();
+    let mut y = page_height - MARGIN;
+
+    // Title block (first page only)
+    page.text(PdfFont::HelveticaBold, 14.0, MARGIN, y - 14.0, title);
+    y -= 22.0;
+    for line in subtitle_lines {
+        page.text(PdfFont::Helvetica, 9.0, MARGIN, y - 9.0, line);
+        y -= 12.0;
+    }
+    y -= 6.0;
+
+    let draw_header = |page: &mut PdfPage, y: &mut f32| {
+        let height = LINE_HEIGHT + CELL_PADDING * 2.0;
+        page.fill_rect(MARGIN, *y - height, usable, height, 0.85);
+        let mut x = MARGIN;
+        for (column, width) in columns.iter().zip(widths.iter()) {
+            page.text(
+                PdfFont::HelveticaBold,
+                FONT_SIZE,
+                x + CELL_PADDING,
+                *y - CELL_PADDING - FONT_SIZE,
+                column.header,
+            );
+            x += width;
+        }
+        *y -= height;
+    };
+
+    draw_header(&mut page, &mut y);
+
+    for row in rows {
+        let cells: Vec<Vec<String>> = row
+            .iter()
+            .zip(chars_per_column.iter())
+            .map(|(value, chars)| {
+                let lines = wrap_text(value, *chars);
+                if lines.is_empty() {
+                    vec![String::new()]
+                } else {
+                    lines
+                }
+            })
+            .collect();
+        let line_count = cells.iter().map(Vec::len).max().unwrap_or(1);
+        let height = line_count as f32 * LINE_HEIGHT + CELL_PADDING * 2.0;
+
+        if y - height < MARGIN + 20.0 {
+            pages.push(std::mem::take(&mut page));
+            y = page_height - MARGIN;
+            draw_header(&mut page, &mut y);
+        }
+
+        let mut x = MARGIN;
+        for (lines, width) in cells.iter().zip(widths.iter()) {
+            let mut line_y = y - CELL_PADDING - FONT_SIZE;
+            for line in lines {
+                page.text(
+                    PdfFont::Helvetica,
+                    FONT_SIZE,
+                    x + CELL_PADDING,
+                    line_y,
+                    line,
+                );
+                line_y -= LINE_HEIGHT;
+            }
+            x += width;
+        }
+        y -= height;
+        page.line(MARGIN, y, MARGIN + usable, y);
+    }
+    pages.push(page);
+
+    let total = pages.len();
+    for (index, mut page) in pages.into_iter().enumerate() {
+        page.text_right(
+            PdfFont::Helvetica,
+            8.0,
+            page_width - MARGIN,
+            MARGIN / 2.0,
+            &format!("Page {} of {}", index + 1, total),
+        );
+        pdf.add_page(page);
+    }
+
+    pdf.render()
+}
+
+/// Wrap text into lines no longer than `width` characters
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+
+    for raw_line in text.lines() {
+        let mut current = String::new();
+        for word in raw_line.split_whitespace() {
+            let mut word = word.to_string();
+            while word.chars().count() > width {
+                if !current.is_empty() {
+                    lines.push(std::mem::take(&mut current));
+                }
+                let head: String = word.chars().take(width).collect();
+                word = word.chars().skip(width).collect();
+                lines.push(head);
+            }
+            if word.is_empty() {
+                continue;
+            }
+            let needed = if current.is_empty() {
+                word.chars().count()
+            } else {
+                current.chars().count() + 1 + word.chars().count()
+            };
+            if needed > width {
+                lines.push(std::mem::take(&mut current));
+            }
+            if !current.is_empty() {
+                current.push(' ');
+            }
+            current.push_str(&word);
+        }
+        lines.push(current);
+    }
+
+    lines
+}
+
+fn fmt_num(value: f32) -> String {
+    let rounded = (value * 100.0).round() / 100.0;
+    if rounded.fract() == 0.0 {
+        format!("{}", rounded as i64)
+    } else {
+        format!("{}", rounded)
+    }
+}
+
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '(' => escaped.push_str("\\("),
+            ')' => escaped.push_str("\\)"),
+            '\r' | '\n' | '\t' => escaped.push(' '),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Content streams use WinAnsiEncoding; anything outside Latin-1 is replaced
+fn encode_latin1(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| if (c as u32) < 256 { c as u8 } else { b'?' })
+        .collect()
+}
@@ -0,0 +1,98 @@
+use crate::error::AppError;
+use crate::export::ZipArchiveBuilder;
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/><Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/><Override PartName="/xl/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml"/></Types>"#;
+
+const ROOT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#;
+
+const WORKBOOK_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/></Relationships>"#;
+
+// Style 0 is the default; style 1 is bold for the header row
+const STYLES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<styleSheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><fonts count="2"><font><sz val="11"/><name val="Calibri"/></font><font><b/><sz val="11"/><name val="Calibri"/></font></fonts><fills count="2"><fill><patternFill patternType="none"/></fill><fill><patternFill patternType="gray125"/></fill></fills><borders count="1"><border><left/><right/><top/><bottom/><diagonal/></border></borders><cellStyleXfs count="1"><xf numFmtId="0" fontId="0" fillId="0" borderId="0"/></cellStyleXfs><cellXfs count="2"><xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0"/><xf numFmtId="0" fontId="1" fillId="0" borderId="0" xfId="0" applyFont="1"/></cellXfs></styleSheet>"#;
+
+/// Write a single-sheet XLSX workbook with a bold header row
+pub fn write_xlsx(
+    sheet_name: &str,
+    headers: &[&str],
+    rows: &[Vec<String>],
+) -> Result<Vec<u8>, AppError> {
+    let workbook = format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets><sheet name="{}" sheetId="1" r:id="rId1"/></sheets></workbook>"#,
+        escape_xml(&sheet_name.chars().take(31).collect::<String>())
+    );
+
+    let mut sheet = String::from(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData>"#,
+    );
+    push_row(&mut sheet, 1, headers.iter().map(|h| h.to_string()), 1);
+    for (index, row) in rows.iter().enumerate() {
+        push_row(&mut sheet, index + 2, row.iter().cloned(), 0);
+    }
+    sheet.push_str("</sheetData></worksheet>");
+
+    let mut archive = ZipArchiveBuilder::new();
+    archive.add_file("[Content_Types].xml", CONTENT_TYPES.as_bytes())?;
+    archive.add_file("_rels/.rels", ROOT_RELS.as_bytes())?;
+    archive.add_file("xl/workbook.xml", workbook.as_bytes())?;
+    archive.add_file("xl/_rels/workbook.xml.rels", WORKBOOK_RELS.as_bytes())?;
+    archive.add_file("xl/styles.xml", STYLES.as_bytes())?;
+    archive.add_file("xl/worksheets/sheet1.xml", sheet.as_bytes())?;
+    archive.finish()
+}
+
+fn push_row(
+    sheet: &mut String,
+    row_number: usize,
+    values: impl Iterator<Item = String>,
+    style: u8,
+) {
+    sheet.push_str(&format!(r#"<row r="{}">"#, row_number));
+    for (column, value) in values.enumerate() {
+        sheet.push_str(&format!(
+            r#"<c r="{}{}" t="inlineStr" s="{}"><is><t xml:space="preserve">{}</t></is></c>"#,
+            column_name(column),
+            row_number,
+            style,
+            escape_xml(&value)
+        ));
+    }
+    sheet.push_str("</row>");
+}
+
+/// Spreadsheet column letters for a zero-based index (0 = A, 26 = AA)
+fn column_name(mut index: usize) -> String {
+    let mut name = Vec::new();
+    loop {
+        name.push(b'A' + (index % 26) as u8);
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap_or_default()
+}
+
+/// Escape text for inclusion in XML element content or attributes
+pub fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Control characters other than tab/newline are not valid XML
+            c if (c as u32) < 0x20 && c != '\t' && c != '\n' && c != '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,32 @@
+//! Periodic background jobs started alongside the HTTP server
+
+use crate::error::AppError;
+use std::future::Future;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+/// Run `job` every `period`, logging how many records it touched.
+///
+/// The first run happens one period after startup. Failures are logged and
+/// the job keeps running on its schedule.
+pub fn spawn_periodic<F, Fut>(name: &'static str, period: Duration, job: F) -> JoinHandle<()>
+where
+    F: Fn() -> Fut + Send + 'static,
+    Fut: Future<Output = Result<usize, AppError>> + Send,
+{
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        // The first tick completes immediately
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+            match job().await {
+                Ok(0) => tracing::debug!("{}: nothing to do", name),
+                Ok(count) => tracing::info!("{}: processed {} record(s)", name, count),
+                Err(e) => tracing::error!("{} failed: {}", name, e),
+            }
+        }
+    })
+}
@@ -3,6 +3,8 @@ pub mod auth;
 pub mod config;
 pub mod db;
 pub mod error;
+pub mod export;
+pub mod jobs;
 pub mod middleware;
 pub mod models;
 
@@ -1,4 +1,5 @@
 use axum::{
+    extract::DefaultBodyLimit,
     middleware,
     routing::{delete, get, post, put},
     Router,
@@ -15,22 +16,64 @@ use utoipa_swagger_ui::SwaggerUi;
 
 use rusty_saas::{
     api::{
+        analytics::{handlers as analytics_handlers, AnalyticsService},
+        calendars::{handlers as calendar_handlers, CourtCalendarService},
         cases::{handlers as case_handlers, CaseService},
+        deadlines::{handlers as deadline_handlers, DeadlineService},
+        discovery_requests::{
+            handlers as discovery_handlers, DiscoveryService, DISCOVERY_SWEEP_INTERVAL,
+        },
         docket::{handlers as docket_handlers, DocketService},
         documents::{handlers as document_handlers, DocumentService},
-        evidence::{handlers as evidence_handlers, EvidenceService},
+        evidence::{
+            handlers as evidence_handlers,
+            integrity::{MAX_EVIDENCE_FILE_BYTES, VERIFICATION_SWEEP_INTERVAL},
+            EvidenceService,
+        },
+        exhibits::{handlers as exhibit_handlers, ExhibitService},
         health::{health_check, liveness_check, readiness_check},
+        holds::{handlers as hold_handlers, LegalHoldService, REMINDER_SWEEP_INTERVAL},
         motions::{handlers as motion_handlers, MotionService},
+        pacer::{handlers as pacer_handlers, PacerService, MAX_DOCKET_REPORT_BYTES},
+        privilege::{handlers as privilege_handlers, PrivilegeService},
+        productions::{handlers as production_handlers, ProductionService},
+        redactions::{handlers as redaction_handlers, RedactionService},
+        retention::{handlers as retention_handlers, RetentionService},
+        tasks::{handlers as task_handlers, TaskService, TASK_SWEEP_INTERVAL},
         users::{handlers as user_handlers, UserService},
+        workflows::{handlers as workflow_handlers, WorkflowService},
     },
     auth::AuthService,
     config::Config,
     db::Database,
+    jobs,
     middleware::{auth_middleware, metrics_middleware, request_id_middleware},
     models::{
-        Case, CaseResponse, CreateCaseRequest, CreateDocumentRequest, CreateUserRequest,
-        DocketEntry, Document, EvidenceItem, HealthResponse, LoginRequest, LoginResponse, Motion,
-        Party, UpdateCaseRequest, UpdateUserRequest, UserResponse,
+        AdmissibilityChallenge, BoardColumn, BoardWipLimit, BriefingDeadline, BriefingExtension,
+        BriefingRule, BriefingSchedule, BulkUpdateTasksRequest, BusinessDayResult, CalendarEvent,
+        Case, CaseResponse, ChainOfCustodyEvent, ChallengeStatus, ClosedDay, CourtCalendar,
+        CourtClosure, CourtClosureKind, CreateCaseRequest, CreateDocumentRequest,
+        CreateEscalationRuleRequest, CreateTaskRequest, CreateUserRequest, CustodyAction,
+        CustodyChain, DayCount, Deadline, DeadlineCalculation, DeadlineRule, DeadlineStatus,
+        DeadlineTrigger, DiscoveryDirection, DiscoveryRequest, DiscoveryRequestDetail,
+        DiscoveryRequestItem, DiscoveryStatus, DiscoveryType, DocketConsistencyReport, DocketEntry,
+        DocketEntryDocument, Document, DocumentVersion, ESISource, EscalationAction,
+        EscalationRule, EvidenceFile, EvidenceFileVerification, EvidenceItem, EvidenceScanResponse,
+        ExhibitParty, ExhibitStatus, GanttTask, HealthResponse, HolidayImportResult,
+        IntegrityStatus, LegalHold, LegalHoldCustodian, LegalHoldResponse, LegalHoldStatus,
+        LoadFileField, LoadFileSource, LoginRequest, LoginResponse, Motion, MotionDocketEntry,
+        MotionFilingRole, MotionFilings, MotionOutcomeReport, MotionStatusHistory,
+        MotionStatusTransition, MoveTaskRequest, OutcomeDimension, OutcomeStats, PacerDuplicate,
+        PacerEntryChange, PacerEntryChangeKind, PacerImportResult, Party, PrivilegeBasis,
+        PrivilegeDesignation, PrivilegeLogEntry, ProductionDocument, ProductionSet,
+        ProductionSetResponse, Project, ProjectDetail, ProjectGantt, ProjectStage,
+        ProjectStageTasks, RecurrenceTrigger, Redaction, RedactionReason, RedactionStatus,
+        RetentionPolicy, RetentionPurgeResult, SealedAccessAction, SealedEntryAccess,
+        SealedEntryGrant, SequenceGap, ServiceMethod, SetTaskDependenciesRequest,
+        SetTaskRecurrenceRequest, SetWipLimitRequest, StageStatus, TaskBoard, TaskEscalation,
+        TaskStatus, TemplateStage, TemplateTask, TrialExhibit, UpdateCaseRequest,
+        UpdateEscalationRuleRequest, UpdateTaskRequest, UpdateUserRequest, UserResponse,
+        WorkflowTask, WorkflowTemplate,
     },
 };
 
@@ -61,16 +104,155 @@ use rusty_saas::{
         docket_handlers::create_docket_entry,
         docket_handlers::update_docket_entry,
         docket_handlers::delete_docket_entry,
+        docket_handlers::get_docket_consistency,
+        docket_handlers::list_docket_entry_documents,
+        docket_handlers::attach_docket_entry_document,
+        docket_handlers::detach_docket_entry_document,
+        docket_handlers::seal_docket_entry,
+        docket_handlers::unseal_docket_entry,
+        docket_handlers::list_sealed_access_grants,
+        docket_handlers::grant_sealed_access,
+        docket_handlers::revoke_sealed_access,
+        docket_handlers::get_sealed_access_log,
+        docket_handlers::export_docket_sheet,
         evidence_handlers::list_evidence,
         evidence_handlers::get_evidence,
         evidence_handlers::create_evidence,
         evidence_handlers::update_evidence,
         evidence_handlers::delete_evidence,
+        evidence_handlers::record_custody_event,
+        evidence_handlers::get_custody_chain,
+        evidence_handlers::export_custody_report,
+        evidence_handlers::list_evidence_files,
+        evidence_handlers::upload_evidence_file,
+        evidence_handlers::verify_evidence_files,
+        evidence_handlers::render_evidence_labels,
+        evidence_handlers::scan_evidence_label,
+        evidence_handlers::list_challenges,
+        evidence_handlers::create_challenge,
+        evidence_handlers::update_challenge,
+        evidence_handlers::delete_challenge,
         motion_handlers::list_motions,
         motion_handlers::get_motion,
         motion_handlers::create_motion,
         motion_handlers::update_motion,
         motion_handlers::delete_motion,
+        motion_handlers::get_motion_status_history,
+        motion_handlers::get_motion_docket_entries,
+        motion_handlers::link_motion_docket_entry,
+        motion_handlers::unlink_motion_docket_entry,
+        motion_handlers::list_briefing_rules,
+        motion_handlers::create_briefing_rule,
+        motion_handlers::update_briefing_rule,
+        motion_handlers::delete_briefing_rule,
+        motion_handlers::get_briefing_schedule,
+        motion_handlers::recompute_briefing_schedule,
+        motion_handlers::override_briefing_deadline,
+        motion_handlers::record_briefing_extension,
+        production_handlers::list_productions,
+        production_handlers::get_production,
+        production_handlers::create_production,
+        production_handlers::download_stamped_document,
+        production_handlers::get_load_file_fields,
+        production_handlers::update_load_file_fields,
+        production_handlers::export_production,
+        privilege_handlers::list_designations,
+        privilege_handlers::get_designation,
+        privilege_handlers::create_designation,
+        privilege_handlers::update_designation,
+        privilege_handlers::delete_designation,
+        privilege_handlers::get_privilege_log,
+        privilege_handlers::export_privilege_log,
+        redaction_handlers::list_redactions,
+        redaction_handlers::create_redaction,
+        redaction_handlers::review_redaction,
+        redaction_handlers::delete_redaction,
+        redaction_handlers::apply_redactions,
+        redaction_handlers::get_redacted_version,
+        hold_handlers::list_legal_holds,
+        hold_handlers::get_legal_hold,
+        hold_handlers::create_legal_hold,
+        hold_handlers::release_legal_hold,
+        hold_handlers::add_hold_custodian,
+        hold_handlers::remove_hold_custodian,
+        hold_handlers::acknowledge_hold_notice,
+        hold_handlers::issue_hold_notices,
+        hold_handlers::add_hold_documents,
+        hold_handlers::remove_hold_document,
+        hold_handlers::list_hold_esi_sources,
+        retention_handlers::list_retention_policies,
+        retention_handlers::get_retention_policy,
+        retention_handlers::create_retention_policy,
+        retention_handlers::update_retention_policy,
+        retention_handlers::delete_retention_policy,
+        retention_handlers::list_expired_documents,
+        retention_handlers::purge_expired_documents,
+        exhibit_handlers::list_exhibits,
+        exhibit_handlers::get_exhibit,
+        exhibit_handlers::create_exhibit,
+        exhibit_handlers::promote_exhibit,
+        exhibit_handlers::update_exhibit,
+        exhibit_handlers::update_exhibit_status,
+        exhibit_handlers::delete_exhibit,
+        exhibit_handlers::export_exhibit_list,
+        pacer_handlers::import_docket_report,
+        deadline_handlers::list_deadline_rules,
+        deadline_handlers::create_deadline_rule,
+        deadline_handlers::update_deadline_rule,
+        deadline_handlers::delete_deadline_rule,
+        deadline_handlers::list_deadlines,
+        deadline_handlers::get_deadline,
+        deadline_handlers::complete_deadline,
+        deadline_handlers::recompute_deadlines,
+        deadline_handlers::calculate_deadline,
+        calendar_handlers::list_court_calendars,
+        calendar_handlers::create_court_calendar,
+        calendar_handlers::get_court_calendar,
+        calendar_handlers::update_court_calendar,
+        calendar_handlers::delete_court_calendar,
+        calendar_handlers::list_court_closures,
+        calendar_handlers::create_court_closure,
+        calendar_handlers::delete_court_closure,
+        calendar_handlers::import_court_holidays,
+        calendar_handlers::list_closed_days,
+        calendar_handlers::add_business_days,
+        calendar_handlers::list_calendar_events,
+        analytics_handlers::get_motion_outcomes,
+        analytics_handlers::export_motion_outcomes,
+        task_handlers::list_tasks,
+        task_handlers::create_task,
+        task_handlers::bulk_update_tasks,
+        task_handlers::get_task,
+        task_handlers::update_task,
+        task_handlers::complete_task,
+        task_handlers::delete_task,
+        task_handlers::set_task_dependencies,
+        task_handlers::set_task_recurrence,
+        task_handlers::list_task_escalations,
+        task_handlers::list_escalation_rules,
+        task_handlers::create_escalation_rule,
+        task_handlers::update_escalation_rule,
+        task_handlers::delete_escalation_rule,
+        task_handlers::get_board,
+        task_handlers::set_wip_limit,
+        task_handlers::move_task,
+        workflow_handlers::list_templates,
+        workflow_handlers::create_template,
+        workflow_handlers::get_template,
+        workflow_handlers::update_template,
+        workflow_handlers::delete_template,
+        workflow_handlers::apply_template,
+        workflow_handlers::list_projects,
+        workflow_handlers::get_project,
+        workflow_handlers::get_project_gantt,
+        discovery_handlers::list_discovery_requests,
+        discovery_handlers::create_discovery_request,
+        discovery_handlers::get_discovery_request,
+        discovery_handlers::update_discovery_request,
+        discovery_handlers::delete_discovery_request,
+        discovery_handlers::add_discovery_item,
+        discovery_handlers::update_discovery_item,
+        discovery_handlers::delete_discovery_item,
     ),
     components(
         schemas(
@@ -88,8 +270,109 @@ use rusty_saas::{
             Document,
             CreateDocumentRequest,
             DocketEntry,
+            SequenceGap,
+            PacerDuplicate,
+            DocketConsistencyReport,
+            DocketEntryDocument,
+            MotionFilingRole,
+            MotionDocketEntry,
+            MotionFilings,
+            MotionStatusTransition,
+            MotionStatusHistory,
+            BriefingRule,
+            BriefingDeadline,
+            BriefingExtension,
+            BriefingSchedule,
+            SealedAccessAction,
+            SealedEntryGrant,
+            SealedEntryAccess,
             EvidenceItem,
+            CustodyAction,
+            ChainOfCustodyEvent,
+            CustodyChain,
+            IntegrityStatus,
+            EvidenceFile,
+            EvidenceFileVerification,
+            EvidenceScanResponse,
+            ChallengeStatus,
+            AdmissibilityChallenge,
             Motion,
+            ProductionSet,
+            ProductionDocument,
+            ProductionSetResponse,
+            LoadFileField,
+            LoadFileSource,
+            PrivilegeBasis,
+            PrivilegeDesignation,
+            PrivilegeLogEntry,
+            DocumentVersion,
+            Redaction,
+            RedactionReason,
+            RedactionStatus,
+            ESISource,
+            LegalHold,
+            LegalHoldStatus,
+            LegalHoldCustodian,
+            LegalHoldResponse,
+            RetentionPolicy,
+            RetentionPurgeResult,
+            ExhibitParty,
+            ExhibitStatus,
+            TrialExhibit,
+            PacerEntryChangeKind,
+            PacerEntryChange,
+            PacerImportResult,
+            DeadlineTrigger,
+            ServiceMethod,
+            DayCount,
+            DeadlineStatus,
+            DeadlineRule,
+            Deadline,
+            DeadlineCalculation,
+            CourtClosureKind,
+            CourtCalendar,
+            CourtClosure,
+            ClosedDay,
+            BusinessDayResult,
+            HolidayImportResult,
+            CalendarEvent,
+            OutcomeDimension,
+            OutcomeStats,
+            MotionOutcomeReport,
+            WorkflowTask,
+            TaskStatus,
+            CreateTaskRequest,
+            UpdateTaskRequest,
+            BulkUpdateTasksRequest,
+            WorkflowTemplate,
+            TemplateStage,
+            TemplateTask,
+            Project,
+            ProjectStage,
+            StageStatus,
+            ProjectStageTasks,
+            ProjectDetail,
+            SetTaskDependenciesRequest,
+            GanttTask,
+            ProjectGantt,
+            RecurrenceTrigger,
+            SetTaskRecurrenceRequest,
+            EscalationAction,
+            EscalationRule,
+            TaskEscalation,
+            CreateEscalationRuleRequest,
+            UpdateEscalationRuleRequest,
+            BoardWipLimit,
+            BoardColumn,
+            TaskBoard,
+            MoveTaskRequest,
+            SetWipLimitRequest,
+            DiscoveryType,
+            DiscoveryDirection,
+            DiscoveryStatus,
+            DiscoveryRequest,
+            DiscoveryRequestItem,
+            DiscoveryRequestDetail,
         )
     ),
     modifiers(&SecurityAddon),
@@ -102,6 +385,19 @@ use rusty_saas::{
         (name = "docket", description = "Docket entry management endpoints"),
         (name = "evidence", description = "Evidence item management endpoints"),
         (name = "motions", description = "Motion management endpoints"),
+        (name = "productions", description = "Document production and Bates numbering endpoints"),
+        (name = "privilege", description = "Privilege designation and privilege log endpoints"),
+        (name = "redactions", description = "Document redaction and review endpoints"),
+        (name = "legal-holds", description = "Legal hold and custodian notice endpoints"),
+        (name = "retention", description = "Document retention policy endpoints"),
+        (name = "exhibits", description = "Trial exhibit and exhibit list endpoints"),
+        (name = "pacer", description = "PACER/CM-ECF docket report import endpoints"),
+        (name = "deadlines", description = "Court deadline rules and computed deadline endpoints"),
+        (name = "court-calendars", description = "Court holiday, closure, business-day and calendar event endpoints"),
+        (name = "analytics", description = "Judge and motion outcome analytics endpoints"),
+        (name = "tasks", description = "Workflow task endpoints"),
+        (name = "workflows", description = "Workflow template and project endpoints"),
+        (name = "discovery", description = "Propounded and received discovery request endpoints"),
     )
 )]
 struct ApiDoc;
@@ -167,6 +463,58 @@ async fn main() -> anyhow::Result<()> {
     let docket_service = Arc::new(DocketService::new(db.pool().clone()));
     let evidence_service = Arc::new(EvidenceService::new(db.pool().clone()));
     let motion_service = Arc::new(MotionService::new(db.pool().clone()));
+    let production_service = Arc::new(ProductionService::new(db.pool().clone()));
+    let privilege_service = Arc::new(PrivilegeService::new(db.pool().clone()));
+    let redaction_service = Arc::new(RedactionService::new(db.pool().clone()));
+    let hold_service = Arc::new(LegalHoldService::new(db.pool().clone()));
+    let retention_service = Arc::new(RetentionService::new(db.pool().clone()));
+    let exhibit_service = Arc::new(ExhibitService::new(db.pool().clone()));
+    let pacer_service = Arc::new(PacerService::new(db.pool().clone()));
+    let deadline_service = Arc::new(DeadlineService::new(db.pool().clone()));
+    let calendar_service = Arc::new(CourtCalendarService::new(db.pool().clone()));
+    let analytics_service = Arc::new(AnalyticsService::new(db.pool().clone()));
+    let task_service = Arc::new(TaskService::new(db.pool().clone()));
+    let workflow_service = Arc::new(WorkflowService::new(db.pool().clone()));
+    let discovery_service = Arc::new(DiscoveryService::new(db.pool().clone()));
+
+    // Start background jobs
+    let reminder_service = hold_service.clone();
+    jobs::spawn_periodic("legal hold reminders", REMINDER_SWEEP_INTERVAL, move || {
+        let service = reminder_service.clone();
+        async move { service.send_due_reminders().await }
+    });
+
+    let verification_service = evidence_service.clone();
+    jobs::spawn_periodic(
+        "evidence integrity verification",
+        VERIFICATION_SWEEP_INTERVAL,
+        move || {
+            let service = verification_service.clone();
+            async move { service.verify_due_files().await }
+        },
+    );
+
+    let recurrence_service = task_service.clone();
+    jobs::spawn_periodic("recurring task spawning", TASK_SWEEP_INTERVAL, move || {
+        let service = recurrence_service.clone();
+        async move { service.spawn_scheduled_occurrences().await }
+    });
+
+    let escalation_service = task_service.clone();
+    jobs::spawn_periodic("overdue task escalation", TASK_SWEEP_INTERVAL, move || {
+        let service = escalation_service.clone();
+        async move { service.escalate_overdue_tasks().await }
+    });
+
+    let overdue_discovery_service = discovery_service.clone();
+    jobs::spawn_periodic(
+        "overdue discovery responses",
+        DISCOVERY_SWEEP_INTERVAL,
+        move || {
+            let service = overdue_discovery_service.clone();
+            async move { service.mark_overdue_requests().await }
+        },
+    );
 
     // Configure CORS based on environment
     let cors = if config.server.environment == "production" {
@@ -261,12 +609,56 @@ async fn main() -> anyhow::Result<()> {
     let docket_protected_routes = Router::new()
         .route("/api/docket", get(docket_handlers::list_docket_entries))
         .route("/api/docket", post(docket_handlers::create_docket_entry))
+        .route(
+            "/api/docket/consistency",
+            get(docket_handlers::get_docket_consistency),
+        )
+        .route(
+            "/api/docket/export",
+            get(docket_handlers::export_docket_sheet),
+        )
         .route("/api/docket/:id", get(docket_handlers::get_docket_entry))
         .route("/api/docket/:id", put(docket_handlers::update_docket_entry))
         .route(
             "/api/docket/:id",
             delete(docket_handlers::delete_docket_entry),
         )
+        .route(
+            "/api/docket/:id/documents",
+            get(docket_handlers::list_docket_entry_documents),
+        )
+        .route(
+            "/api/docket/:id/documents",
+            post(docket_handlers::attach_docket_entry_document),
+        )
+        .route(
+            "/api/docket/:id/documents/:attachment_id",
+            delete(docket_handlers::detach_docket_entry_document),
+        )
+        .route(
+            "/api/docket/:id/seal",
+            post(docket_handlers::seal_docket_entry),
+        )
+        .route(
+            "/api/docket/:id/unseal",
+            post(docket_handlers::unseal_docket_entry),
+        )
+        .route(
+            "/api/docket/:id/access-grants",
+            get(docket_handlers::list_sealed_access_grants),
+        )
+        .route(
+            "/api/docket/:id/access-grants",
+            post(docket_handlers::grant_sealed_access),
+        )
+        .route(
+            "/api/docket/:id/access-grants/:grant_id",
+            delete(docket_handlers::revoke_sealed_access),
+        )
+        .route(
+            "/api/docket/:id/access-log",
+            get(docket_handlers::get_sealed_access_log),
+        )
         .with_state(docket_service)
         .route_layer(middleware::from_fn_with_state(
             auth_service.clone(),
@@ -283,6 +675,55 @@ async fn main() -> anyhow::Result<()> {
             "/api/evidence/:id",
             delete(evidence_handlers::delete_evidence),
         )
+        .route(
+            "/api/evidence/:id/custody",
+            get(evidence_handlers::get_custody_chain),
+        )
+        .route(
+            "/api/evidence/:id/custody",
+            post(evidence_handlers::record_custody_event),
+        )
+        .route(
+            "/api/evidence/:id/custody/report",
+            get(evidence_handlers::export_custody_report),
+        )
+        .route(
+            "/api/evidence/:id/files",
+            get(evidence_handlers::list_evidence_files),
+        )
+        .route(
+            "/api/evidence/:id/files",
+            post(evidence_handlers::upload_evidence_file)
+                .layer(DefaultBodyLimit::max(MAX_EVIDENCE_FILE_BYTES)),
+        )
+        .route(
+            "/api/evidence/:id/files/verify",
+            post(evidence_handlers::verify_evidence_files),
+        )
+        .route(
+            "/api/evidence/:id/challenges",
+            get(evidence_handlers::list_challenges),
+        )
+        .route(
+            "/api/evidence/:id/challenges",
+            post(evidence_handlers::create_challenge),
+        )
+        .route(
+            "/api/evidence/:id/challenges/:challenge_id",
+            put(evidence_handlers::update_challenge),
+        )
+        .route(
+            "/api/evidence/:id/challenges/:challenge_id",
+            delete(evidence_handlers::delete_challenge),
+        )
+        .route(
+            "/api/evidence/labels",
+            post(evidence_handlers::render_evidence_labels),
+        )
+        .route(
+            "/api/evidence/scan",
+            post(evidence_handlers::scan_evidence_label),
+        )
         .with_state(evidence_service)
         .route_layer(middleware::from_fn_with_state(
             auth_service.clone(),
@@ -296,12 +737,497 @@ async fn main() -> anyhow::Result<()> {
         .route("/api/motions/:id", get(motion_handlers::get_motion))
         .route("/api/motions/:id", put(motion_handlers::update_motion))
         .route("/api/motions/:id", delete(motion_handlers::delete_motion))
+        .route(
+            "/api/motions/:id/status-history",
+            get(motion_handlers::get_motion_status_history),
+        )
+        .route(
+            "/api/motions/:id/docket-entries",
+            get(motion_handlers::get_motion_docket_entries),
+        )
+        .route(
+            "/api/motions/:id/docket-entries",
+            post(motion_handlers::link_motion_docket_entry),
+        )
+        .route(
+            "/api/motions/:id/docket-entries/:link_id",
+            delete(motion_handlers::unlink_motion_docket_entry),
+        )
+        .route(
+            "/api/motions/:id/briefing-schedule",
+            get(motion_handlers::get_briefing_schedule),
+        )
+        .route(
+            "/api/motions/:id/briefing-schedule/recompute",
+            post(motion_handlers::recompute_briefing_schedule),
+        )
+        .route(
+            "/api/motions/:id/briefing-schedule/:role",
+            put(motion_handlers::override_briefing_deadline),
+        )
+        .route(
+            "/api/motions/:id/briefing-schedule/:role/extensions",
+            post(motion_handlers::record_briefing_extension),
+        )
+        .route(
+            "/api/briefing-rules",
+            get(motion_handlers::list_briefing_rules),
+        )
+        .route(
+            "/api/briefing-rules",
+            post(motion_handlers::create_briefing_rule),
+        )
+        .route(
+            "/api/briefing-rules/:id",
+            put(motion_handlers::update_briefing_rule),
+        )
+        .route(
+            "/api/briefing-rules/:id",
+            delete(motion_handlers::delete_briefing_rule),
+        )
         .with_state(motion_service)
         .route_layer(middleware::from_fn_with_state(
             auth_service.clone(),
             auth_middleware,
         ));
 
+    // Build production protected routes
+    let production_protected_routes = Router::new()
+        .route(
+            "/api/productions",
+            get(production_handlers::list_productions),
+        )
+        .route(
+            "/api/productions",
+            post(production_handlers::create_production),
+        )
+        .route(
+            "/api/productions/:id",
+            get(production_handlers::get_production),
+        )
+        .route(
+            "/api/productions/:id/documents/:document_id/pdf",
+            get(production_handlers::download_stamped_document),
+        )
+        .route(
+            "/api/productions/:id/load-file-fields",
+            get(production_handlers::get_load_file_fields),
+        )
+        .route(
+            "/api/productions/:id/load-file-fields",
+            put(production_handlers::update_load_file_fields),
+        )
+        .route(
+            "/api/productions/:id/export",
+            get(production_handlers::export_production),
+        )
+        .with_state(production_service)
+        .route_layer(middleware::from_fn_with_state(
+            auth_service.clone(),
+            auth_middleware,
+        ));
+
+    // Build privilege protected routes
+    let privilege_protected_routes = Router::new()
+        .route(
+            "/api/privilege/designations",
+            get(privilege_handlers::list_designations),
+        )
+        .route(
+            "/api/privilege/designations",
+            post(privilege_handlers::create_designation),
+        )
+        .route(
+            "/api/privilege/designations/:id",
+            get(privilege_handlers::get_designation),
+        )
+        .route(
+            "/api/privilege/designations/:id",
+            put(privilege_handlers::update_designation),
+        )
+        .route(
+            "/api/privilege/designations/:id",
+            delete(privilege_handlers::delete_designation),
+        )
+        .route(
+            "/api/privilege/log",
+            get(privilege_handlers::get_privilege_log),
+        )
+        .route(
+            "/api/privilege/log/export",
+            get(privilege_handlers::export_privilege_log),
+        )
+        .with_state(privilege_service)
+        .route_layer(middleware::from_fn_with_state(
+            auth_service.clone(),
+            auth_middleware,
+        ));
+
+    // Build redaction protected routes
+    let redaction_protected_routes = Router::new()
+        .route(
+            "/api/documents/:id/redactions",
+            get(redaction_handlers::list_redactions),
+        )
+        .route(
+            "/api/documents/:id/redactions",
+            post(redaction_handlers::create_redaction),
+        )
+        .route(
+            "/api/documents/:id/redactions/apply",
+            post(redaction_handlers::apply_redactions),
+        )
+        .route(
+            "/api/documents/:id/redacted",
+            get(redaction_handlers::get_redacted_version),
+        )
+        .route(
+            "/api/redactions/:id/review",
+            post(redaction_handlers::review_redaction),
+        )
+        .route(
+            "/api/redactions/:id",
+            delete(redaction_handlers::delete_redaction),
+        )
+        .with_state(redaction_service)
+        .route_layer(middleware::from_fn_with_state(
+            auth_service.clone(),
+            auth_middleware,
+        ));
+
+    // Build legal hold protected routes
+    let hold_protected_routes = Router::new()
+        .route("/api/legal-holds", get(hold_handlers::list_legal_holds))
+        .route("/api/legal-holds", post(hold_handlers::create_legal_hold))
+        .route("/api/legal-holds/:id", get(hold_handlers::get_legal_hold))
+        .route(
+            "/api/legal-holds/:id/release",
+            post(hold_handlers::release_legal_hold),
+        )
+        .route(
+            "/api/legal-holds/:id/custodians",
+            post(hold_handlers::add_hold_custodian),
+        )
+        .route(
+            "/api/legal-holds/:id/custodians/:custodian_id",
+            delete(hold_handlers::remove_hold_custodian),
+        )
+        .route(
+            "/api/legal-holds/:id/custodians/:custodian_id/acknowledge",
+            post(hold_handlers::acknowledge_hold_notice),
+        )
+        .route(
+            "/api/legal-holds/:id/notices",
+            post(hold_handlers::issue_hold_notices),
+        )
+        .route(
+            "/api/legal-holds/:id/documents",
+            post(hold_handlers::add_hold_documents),
+        )
+        .route(
+            "/api/legal-holds/:id/documents/:document_id",
+            delete(hold_handlers::remove_hold_document),
+        )
+        .route(
+            "/api/legal-holds/:id/esi-sources",
+            get(hold_handlers::list_hold_esi_sources),
+        )
+        .with_state(hold_service)
+        .route_layer(middleware::from_fn_with_state(
+            auth_service.clone(),
+            auth_middleware,
+        ));
+
+    // Build retention policy protected routes
+    let retention_protected_routes = Router::new()
+        .route(
+            "/api/retention-policies",
+            get(retention_handlers::list_retention_policies),
+        )
+        .route(
+            "/api/retention-policies",
+            post(retention_handlers::create_retention_policy),
+        )
+        .route(
+            "/api/retention-policies/:id",
+            get(retention_handlers::get_retention_policy),
+        )
+        .route(
+            "/api/retention-policies/:id",
+            put(retention_handlers::update_retention_policy),
+        )
+        .route(
+            "/api/retention-policies/:id",
+            delete(retention_handlers::delete_retention_policy),
+        )
+        .route(
+            "/api/retention-policies/:id/expired",
+            get(retention_handlers::list_expired_documents),
+        )
+        .route(
+            "/api/retention-policies/:id/purge",
+            post(retention_handlers::purge_expired_documents),
+        )
+        .with_state(retention_service)
+        .route_layer(middleware::from_fn_with_state(
+            auth_service.clone(),
+            auth_middleware,
+        ));
+
+    // Build exhibit protected routes
+    let exhibit_protected_routes = Router::new()
+        .route("/api/exhibits", get(exhibit_handlers::list_exhibits))
+        .route("/api/exhibits", post(exhibit_handlers::create_exhibit))
+        .route(
+            "/api/exhibits/promote",
+            post(exhibit_handlers::promote_exhibit),
+        )
+        .route(
+            "/api/exhibits/export",
+            get(exhibit_handlers::export_exhibit_list),
+        )
+        .route("/api/exhibits/:id", get(exhibit_handlers::get_exhibit))
+        .route("/api/exhibits/:id", put(exhibit_handlers::update_exhibit))
+        .route(
+            "/api/exhibits/:id",
+            delete(exhibit_handlers::delete_exhibit),
+        )
+        .route(
+            "/api/exhibits/:id/status",
+            post(exhibit_handlers::update_exhibit_status),
+        )
+        .with_state(exhibit_service)
+        .route_layer(middleware::from_fn_with_state(
+            auth_service.clone(),
+            auth_middleware,
+        ));
+
+    // Build PACER import protected routes
+    let pacer_protected_routes = Router::new()
+        .route(
+            "/api/pacer/import",
+            post(pacer_handlers::import_docket_report)
+                .layer(DefaultBodyLimit::max(MAX_DOCKET_REPORT_BYTES)),
+        )
+        .with_state(pacer_service)
+        .route_layer(middleware::from_fn_with_state(
+            auth_service.clone(),
+            auth_middleware,
+        ));
+
+    // Build deadline protected routes
+    let deadline_protected_routes = Router::new()
+        .route(
+            "/api/deadline-rules",
+            get(deadline_handlers::list_deadline_rules),
+        )
+        .route(
+            "/api/deadline-rules",
+            post(deadline_handlers::create_deadline_rule),
+        )
+        .route(
+            "/api/deadline-rules/:id",
+            put(deadline_handlers::update_deadline_rule),
+        )
+        .route(
+            "/api/deadline-rules/:id",
+            delete(deadline_handlers::delete_deadline_rule),
+        )
+        .route("/api/deadlines", get(deadline_handlers::list_deadlines))
+        .route(
+            "/api/deadlines/recompute",
+            post(deadline_handlers::recompute_deadlines),
+        )
+        .route(
+            "/api/deadlines/calculate",
+            post(deadline_handlers::calculate_deadline),
+        )
+        .route("/api/deadlines/:id", get(deadline_handlers::get_deadline))
+        .route(
+            "/api/deadlines/:id/complete",
+            post(deadline_handlers::complete_deadline),
+        )
+        .with_state(deadline_service)
+        .route_layer(middleware::from_fn_with_state(
+            auth_service.clone(),
+            auth_middleware,
+        ));
+
+    // Build court calendar protected routes
+    let calendar_protected_routes = Router::new()
+        .route(
+            "/api/court-calendars",
+            get(calendar_handlers::list_court_calendars),
+        )
+        .route(
+            "/api/court-calendars",
+            post(calendar_handlers::create_court_calendar),
+        )
+        .route(
+            "/api/court-calendars/closed-days",
+            get(calendar_handlers::list_closed_days),
+        )
+        .route(
+            "/api/court-calendars/business-days",
+            get(calendar_handlers::add_business_days),
+        )
+        .route(
+            "/api/calendar-events",
+            get(calendar_handlers::list_calendar_events),
+        )
+        .route(
+            "/api/court-calendars/:id",
+            get(calendar_handlers::get_court_calendar),
+        )
+        .route(
+            "/api/court-calendars/:id",
+            put(calendar_handlers::update_court_calendar),
+        )
+        .route(
+            "/api/court-calendars/:id",
+            delete(calendar_handlers::delete_court_calendar),
+        )
+        .route(
+            "/api/court-calendars/:id/closures",
+            get(calendar_handlers::list_court_closures),
+        )
+        .route(
+            "/api/court-calendars/:id/closures",
+            post(calendar_handlers::create_court_closure),
+        )
+        .route(
+            "/api/court-calendars/:id/closures/:closure_id",
+            delete(calendar_handlers::delete_court_closure),
+        )
+        .route(
+            "/api/court-calendars/:id/holidays/import",
+            post(calendar_handlers::import_court_holidays),
+        )
+        .with_state(calendar_service)
+        .route_layer(middleware::from_fn_with_state(
+            auth_service.clone(),
+            auth_middleware,
+        ));
+
+    // Build analytics protected routes
+    let analytics_protected_routes = Router::new()
+        .route(
+            "/api/analytics/motion-outcomes",
+            get(analytics_handlers::get_motion_outcomes),
+        )
+        .route(
+            "/api/analytics/motion-outcomes/export",
+            get(analytics_handlers::export_motion_outcomes),
+        )
+        .with_state(analytics_service)
+        .route_layer(middleware::from_fn_with_state(
+            auth_service.clone(),
+            auth_middleware,
+        ));
+
+    // Build task protected routes
+    let task_protected_routes = Router::new()
+        .route(
+            "/api/tasks",
+            get(task_handlers::list_tasks).post(task_handlers::create_task),
+        )
+        .route("/api/tasks/bulk", post(task_handlers::bulk_update_tasks))
+        .route(
+            "/api/tasks/:id",
+            get(task_handlers::get_task)
+                .put(task_handlers::update_task)
+                .delete(task_handlers::delete_task),
+        )
+        .route(
+            "/api/tasks/:id/complete",
+            post(task_handlers::complete_task),
+        )
+        .route(
+            "/api/tasks/:id/dependencies",
+            put(task_handlers::set_task_dependencies),
+        )
+        .route("/api/tasks/:id/move", post(task_handlers::move_task))
+        .route("/api/boards", get(task_handlers::get_board))
+        .route("/api/boards/wip-limits", put(task_handlers::set_wip_limit))
+        .route(
+            "/api/tasks/:id/recurrence",
+            put(task_handlers::set_task_recurrence),
+        )
+        .route(
+            "/api/tasks/:id/escalations",
+            get(task_handlers::list_task_escalations),
+        )
+        .route(
+            "/api/escalation-rules",
+            get(task_handlers::list_escalation_rules).post(task_handlers::create_escalation_rule),
+        )
+        .route(
+            "/api/escalation-rules/:id",
+            put(task_handlers::update_escalation_rule)
+                .delete(task_handlers::delete_escalation_rule),
+        )
+        .with_state(task_service)
+        .route_layer(middleware::from_fn_with_state(
+            auth_service.clone(),
+            auth_middleware,
+        ));
+
+    // Build workflow protected routes
+    let workflow_protected_routes = Router::new()
+        .route(
+            "/api/workflow-templates",
+            get(workflow_handlers::list_templates).post(workflow_handlers::create_template),
+        )
+        .route(
+            "/api/workflow-templates/:id",
+            get(workflow_handlers::get_template)
+                .put(workflow_handlers::update_template)
+                .delete(workflow_handlers::delete_template),
+        )
+        .route(
+            "/api/workflow-templates/:id/apply",
+            post(workflow_handlers::apply_template),
+        )
+        .route("/api/projects", get(workflow_handlers::list_projects))
+        .route("/api/projects/:id", get(workflow_handlers::get_project))
+        .route(
+            "/api/projects/:id/gantt",
+            get(workflow_handlers::get_project_gantt),
+        )
+        .with_state(workflow_service)
+        .route_layer(middleware::from_fn_with_state(
+            auth_service.clone(),
+            auth_middleware,
+        ));
+
+    // Build discovery protected routes
+    let discovery_protected_routes = Router::new()
+        .route(
+            "/api/discovery",
+            get(discovery_handlers::list_discovery_requests)
+                .post(discovery_handlers::create_discovery_request),
+        )
+        .route(
+            "/api/discovery/:id",
+            get(discovery_handlers::get_discovery_request)
+                .put(discovery_handlers::update_discovery_request)
+                .delete(discovery_handlers::delete_discovery_request),
+        )
+        .route(
+            "/api/discovery/:id/items",
+            post(discovery_handlers::add_discovery_item),
+        )
+        .route(
+            "/api/discovery/:id/items/:item_id",
+            put(discovery_handlers::update_discovery_item)
+                .delete(discovery_handlers::delete_discovery_item),
+        )
+        .with_state(discovery_service)
+        .route_layer(middleware::from_fn_with_state(
+            auth_service.clone(),
+            auth_middleware,
+        ));
+
     // Combine all routes
     let app = Router::new()
         .merge(public_routes)
@@ -311,6 +1237,19 @@ async fn main() -> anyhow::Result<()> {
         .merge(docket_protected_routes)
         .merge(evidence_protected_routes)
         .merge(motion_protected_routes)
+        .merge(production_protected_routes)
+        .merge(privilege_protected_routes)
+        .merge(redaction_protected_routes)
+        .merge(hold_protected_routes)
+        .merge(retention_protected_routes)
+        .merge(exhibit_protected_routes)
+        .merge(pacer_protected_routes)
+        .merge(deadline_protected_routes)
+        .merge(calendar_protected_routes)
+        .merge(analytics_protected_routes)
+        .merge(task_protected_routes)
+        .merge(workflow_protected_routes)
+        .merge(discovery_protected_routes)
         .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()))
         .layer(CompressionLayer::new())
         .layer(cors)
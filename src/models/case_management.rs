@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use utoipa::ToSchema;
@@ -175,3 +175,83 @@ pub struct CreatePartyRequest {
     pub phone: Option<String>,
     pub email: Option<String>,
 }
+
+/// Kind of court closure
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "court_closure_kind", rename_all = "PascalCase")]
+pub enum CourtClosureKind {
+    /// State or local holiday
+    Holiday,
+    /// Ad-hoc closure, e.g. for weather or an emergency
+    Closure,
+}
+
+/// Holiday and closure calendar for a court, a jurisdiction, or a court
+/// within a jurisdiction
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct CourtCalendar {
+    pub id: Uuid,
+    pub name: String,
+    /// Matches `cases.court`
+    pub court: Option<String>,
+    /// Matches `cases.jurisdiction`
+    pub jurisdiction: Option<String>,
+    pub observes_federal_holidays: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Day a calendar's courts are closed
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct CourtClosure {
+    pub id: Uuid,
+    pub calendar_id: Uuid,
+    pub date: NaiveDate,
+    pub name: String,
+    pub kind: CourtClosureKind,
+    /// Imported file the holiday came from
+    pub source: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Day a court is closed and why
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ClosedDay {
+    pub date: NaiveDate,
+    /// "Saturday", "Sunday", or the holiday or closure name
+    pub reason: String,
+}
+
+/// Result of business-day arithmetic on a court calendar
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BusinessDayResult {
+    pub date: NaiveDate,
+    pub days: i32,
+    pub result: NaiveDate,
+    /// Closed days skipped along the way
+    pub skipped: Vec<ClosedDay>,
+}
+
+/// Outcome of importing holidays into a court calendar
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct HolidayImportResult {
+    pub added: usize,
+    pub updated: usize,
+    pub closures: Vec<CourtClosure>,
+}
+
+/// Dated event on a case's calendar
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct CalendarEvent {
+    pub id: Uuid,
+    pub case_id: Uuid,
+    pub title: String,
+    pub event_date: NaiveDate,
+    pub description: Option<String>,
+    /// Module of the record the event was generated from, e.g. "Motions"
+    pub related_module: Option<String>,
+    pub related_item_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
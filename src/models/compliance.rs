@@ -1,3 +1,4 @@
+use super::MatterType;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
@@ -80,3 +81,82 @@ pub struct AuditLog {
     pub prev_hash: Option<String>,
     pub created_at: DateTime<Utc>,
 }
+
+/// Legal hold status enum
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "legal_hold_status", rename_all = "PascalCase")]
+pub enum LegalHoldStatus {
+    Active,
+    Released,
+}
+
+/// Legal hold model
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct LegalHold {
+    pub id: Uuid,
+    pub case_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub notice_text: String,
+    pub status: LegalHoldStatus,
+    /// Whether every document in the case is preserved, not just the document set
+    pub covers_entire_case: bool,
+    pub reminder_interval_days: i32,
+    pub issued_at: DateTime<Utc>,
+    pub released_at: Option<DateTime<Utc>>,
+    pub released_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Custodian under a legal hold, with notice and acknowledgement tracking
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct LegalHoldCustodian {
+    pub id: Uuid,
+    pub legal_hold_id: Uuid,
+    /// Custodian name as recorded on the case's ESI sources
+    pub custodian: String,
+    pub user_id: Option<Uuid>,
+    pub email: Option<String>,
+    pub notice_sent_at: Option<DateTime<Utc>>,
+    pub acknowledged_at: Option<DateTime<Utc>>,
+    pub last_reminder_at: Option<DateTime<Utc>>,
+    pub reminder_count: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Legal hold with its custodians and document set
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LegalHoldResponse {
+    #[serde(flatten)]
+    pub hold: LegalHold,
+    pub custodians: Vec<LegalHoldCustodian>,
+    pub document_ids: Vec<Uuid>,
+}
+
+/// Retention policy model.
+///
+/// A policy applies to documents matching its `doc_type` and case matter
+/// type (either may be left open) once they have not been modified for
+/// `retention_days`.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct RetentionPolicy {
+    pub id: Uuid,
+    pub name: String,
+    pub doc_type: Option<String>,
+    pub matter_type: Option<MatterType>,
+    pub retention_days: i32,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Outcome of applying a retention policy
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RetentionPurgeResult {
+    /// Documents soft-deleted by the policy
+    pub purged_document_ids: Vec<Uuid>,
+    /// Expired documents kept because they are under an active legal hold
+    pub held_document_ids: Vec<Uuid>,
+}
@@ -1,3 +1,4 @@
+use crate::models::ServiceMethod;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
@@ -5,7 +6,7 @@ use utoipa::ToSchema;
 use uuid::Uuid;
 
 /// Discovery type enum
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "discovery_type", rename_all = "PascalCase")]
 pub enum DiscoveryType {
     Production,
@@ -15,7 +16,7 @@ pub enum DiscoveryType {
 }
 
 /// Discovery status enum
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "discovery_status", rename_all = "PascalCase")]
 pub enum DiscoveryStatus {
     Draft,
@@ -28,6 +29,14 @@ pub enum DiscoveryStatus {
     MotionFiled,
 }
 
+/// Whether a set of discovery requests was served by our side or on it
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "discovery_direction", rename_all = "PascalCase")]
+pub enum DiscoveryDirection {
+    Propounded,
+    Received,
+}
+
 /// Discovery request model
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct DiscoveryRequest {
@@ -45,6 +54,42 @@ pub struct DiscoveryRequest {
     pub description: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub direction: DiscoveryDirection,
+    /// First, second, ... set of this type served in the case
+    pub set_number: i32,
+    pub service_method: ServiceMethod,
+    /// Days allowed to respond, when extended by stipulation or order
+    pub response_days: Option<i32>,
+    /// How the due date was counted
+    pub computation: Option<String>,
+    pub responded_at: Option<DateTime<Utc>>,
+    /// Task opened when responses became overdue
+    pub meet_and_confer_task_id: Option<Uuid>,
+}
+
+/// A numbered request within a discovery set
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct DiscoveryRequestItem {
+    pub id: Uuid,
+    pub discovery_request_id: Uuid,
+    pub number: i32,
+    /// e.g. "Interrogatory No. 3"
+    #[sqlx(skip)]
+    pub label: String,
+    pub text: String,
+    pub response: Option<String>,
+    pub objections: Option<String>,
+    pub responded_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// A discovery set with its numbered requests
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DiscoveryRequestDetail {
+    #[serde(flatten)]
+    pub request: DiscoveryRequest,
+    pub items: Vec<DiscoveryRequestItem>,
 }
 
 /// Deposition model
@@ -78,3 +123,161 @@ pub struct ESISource {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+/// Production set model
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ProductionSet {
+    pub id: Uuid,
+    pub case_id: Uuid,
+    pub name: String,
+    pub date: DateTime<Utc>,
+    pub bates_range: String,
+    pub doc_count: i32,
+    pub size: String,
+    pub format: String,
+    pub status: String,
+    pub bates_prefix: Option<String>,
+    pub bates_start: Option<i32>,
+    pub bates_end: Option<i32>,
+    pub bates_padding: Option<i32>,
+    pub confidentiality: Option<String>,
+    pub load_file_fields: Option<serde_json::Value>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Bates range assigned to a single document within a production
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ProductionDocument {
+    pub id: Uuid,
+    pub production_set_id: Uuid,
+    pub case_id: Uuid,
+    pub document_id: Uuid,
+    pub sort_order: i32,
+    pub bates_prefix: String,
+    pub bates_begin: i32,
+    pub bates_end: i32,
+    pub page_count: i32,
+    /// Redacted version produced in place of the original text
+    pub redacted_version_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Production set with its per-document Bates ranges
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProductionSetResponse {
+    #[serde(flatten)]
+    pub production: ProductionSet,
+    pub documents: Vec<ProductionDocument>,
+    /// Selected documents withheld from the production as privileged
+    pub withheld_document_ids: Vec<Uuid>,
+}
+
+/// Source of a value written to a DAT load file column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LoadFileSource {
+    BatesBegin,
+    BatesEnd,
+    PageCount,
+    DocumentId,
+    Title,
+    DocType,
+    Tags,
+    UploadDate,
+    LastModified,
+    Confidentiality,
+    ProductionName,
+    NativePath,
+    TextPath,
+}
+
+/// A single DAT load file column and where its value comes from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct LoadFileField {
+    /// Column header written to the DAT file (e.g., "BEGBATES")
+    pub name: String,
+    pub source: LoadFileSource,
+}
+
+impl LoadFileField {
+    pub fn new(name: &str, source: LoadFileSource) -> Self {
+        Self {
+            name: name.to_string(),
+            source,
+        }
+    }
+
+    /// Column mapping used when a production has no custom mapping
+    pub fn defaults() -> Vec<LoadFileField> {
+        vec![
+            LoadFileField::new("BEGBATES", LoadFileSource::BatesBegin),
+            LoadFileField::new("ENDBATES", LoadFileSource::BatesEnd),
+            LoadFileField::new("PAGECOUNT", LoadFileSource::PageCount),
+            LoadFileField::new("TITLE", LoadFileSource::Title),
+            LoadFileField::new("DOCTYPE", LoadFileSource::DocType),
+            LoadFileField::new("DATECREATED", LoadFileSource::UploadDate),
+            LoadFileField::new("DATEMODIFIED", LoadFileSource::LastModified),
+            LoadFileField::new("CONFIDENTIALITY", LoadFileSource::Confidentiality),
+            LoadFileField::new("NATIVELINK", LoadFileSource::NativePath),
+            LoadFileField::new("TEXTLINK", LoadFileSource::TextPath),
+        ]
+    }
+}
+
+/// Privilege basis enum
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "privilege_basis", rename_all = "PascalCase")]
+pub enum PrivilegeBasis {
+    #[serde(rename = "Attorney-Client")]
+    #[sqlx(rename = "Attorney-Client")]
+    AttorneyClient,
+    #[serde(rename = "Work Product")]
+    #[sqlx(rename = "Work Product")]
+    WorkProduct,
+}
+
+impl PrivilegeBasis {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PrivilegeBasis::AttorneyClient => "Attorney-Client",
+            PrivilegeBasis::WorkProduct => "Work Product",
+        }
+    }
+}
+
+/// Privilege designation on a document or communication
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct PrivilegeDesignation {
+    pub id: Uuid,
+    pub case_id: Uuid,
+    pub document_id: Option<Uuid>,
+    pub communication_id: Option<Uuid>,
+    pub basis: PrivilegeBasis,
+    pub author: String,
+    pub recipients: Vec<String>,
+    pub item_date: Option<DateTime<Utc>>,
+    pub description: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// A single row of a generated privilege log
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PrivilegeLogEntry {
+    /// Sequential log number (e.g., "PRIV-0001")
+    pub log_number: String,
+    pub designation_id: Uuid,
+    pub document_id: Option<Uuid>,
+    pub communication_id: Option<Uuid>,
+    pub item_date: Option<DateTime<Utc>>,
+    /// Document type or communication type
+    pub item_type: String,
+    pub author: String,
+    pub recipients: Vec<String>,
+    pub basis: PrivilegeBasis,
+    pub description: String,
+    /// Names of the productions this item was withheld from
+    pub withheld_from: Vec<String>,
+}
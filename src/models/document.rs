@@ -25,6 +25,8 @@ pub struct Document {
     pub updated_at: DateTime<Utc>,
     pub version: Option<i32>,
     pub deleted_at: Option<DateTime<Utc>>,
+    /// Current burned-in redacted version, if redactions have been applied
+    pub redacted_version_id: Option<Uuid>,
 }
 
 /// Document version model
@@ -40,6 +42,7 @@ pub struct DocumentVersion {
     pub author: Option<String>,
     pub author_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
+    pub is_redacted: bool,
 }
 
 /// Create document request
@@ -51,3 +54,52 @@ pub struct CreateDocumentRequest {
     pub content: Option<String>,
     pub tags: Option<Vec<String>>,
 }
+
+/// Redaction reason enum
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "redaction_reason", rename_all = "PascalCase")]
+pub enum RedactionReason {
+    #[serde(rename = "PII")]
+    #[sqlx(rename = "PII")]
+    Pii,
+    Privilege,
+    Confidential,
+}
+
+/// Redaction review status enum
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "redaction_status", rename_all = "PascalCase")]
+pub enum RedactionStatus {
+    Proposed,
+    Approved,
+    Rejected,
+}
+
+/// Redaction annotation on a document's text or on a rendered page.
+///
+/// Text redactions cover the character range `start_offset..end_offset` of
+/// the document content. Page redactions cover a rectangle (in PDF points,
+/// origin bottom-left) of a page of the rendered document.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Redaction {
+    pub id: Uuid,
+    pub document_id: Uuid,
+    pub case_id: Uuid,
+    pub start_offset: Option<i32>,
+    pub end_offset: Option<i32>,
+    pub page_number: Option<i32>,
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    pub width: Option<f64>,
+    pub height: Option<f64>,
+    pub reason: RedactionReason,
+    pub note: Option<String>,
+    pub status: RedactionStatus,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
@@ -1,11 +1,11 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
 /// Motion type enum
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "motion_type", rename_all = "PascalCase")]
 pub enum MotionType {
     Dismiss,
@@ -23,7 +23,7 @@ pub enum MotionType {
 }
 
 /// Motion status enum
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "motion_status", rename_all = "PascalCase")]
 pub enum MotionStatus {
     Draft,
@@ -43,7 +43,7 @@ pub enum MotionStatus {
 }
 
 /// Motion outcome enum
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "motion_outcome", rename_all = "PascalCase")]
 pub enum MotionOutcome {
     Granted,
@@ -53,7 +53,7 @@ pub enum MotionOutcome {
 }
 
 /// Docket entry type enum
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "docket_entry_type", rename_all = "PascalCase")]
 pub enum DocketEntryType {
     Filing,
@@ -78,7 +78,7 @@ pub enum EvidenceType {
 }
 
 /// Admissibility status enum
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "admissibility_status", rename_all = "PascalCase")]
 pub enum AdmissibilityStatus {
     Admissible,
@@ -100,11 +100,161 @@ pub struct Motion {
     pub outcome: Option<MotionOutcome>,
     pub filing_date: Option<DateTime<Utc>>,
     pub hearing_date: Option<DateTime<Utc>>,
+    /// Current due date of the opposition on the briefing schedule
+    pub opposition_due_date: Option<DateTime<Utc>>,
+    /// Current due date of the reply on the briefing schedule
+    pub reply_due_date: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub deleted_at: Option<DateTime<Utc>>,
 }
 
+/// Paper a docket entry records in a motion's briefing
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "motion_filing_role", rename_all = "PascalCase")]
+pub enum MotionFilingRole {
+    /// The motion itself
+    Filing,
+    Opposition,
+    Reply,
+    /// Order deciding the motion
+    Order,
+}
+
+/// Docket entry linked to a motion
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct MotionDocketEntry {
+    pub id: Uuid,
+    pub motion_id: Uuid,
+    pub docket_entry_id: Uuid,
+    pub role: MotionFilingRole,
+    /// Docket number of the linked entry
+    pub sequence_number: i32,
+    pub entry_date: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub created_by: Option<Uuid>,
+}
+
+/// Motion with the docket entries of its papers, in docket order
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MotionFilings {
+    pub motion: Motion,
+    pub entries: Vec<MotionDocketEntry>,
+}
+
+/// Change of a motion's status
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct MotionStatusTransition {
+    pub id: Uuid,
+    pub motion_id: Uuid,
+    /// Status before the change; absent for the status a motion was created in
+    pub from_status: Option<MotionStatus>,
+    pub to_status: MotionStatus,
+    /// Docket entry whose docketing moved the motion
+    pub docket_entry_id: Option<Uuid>,
+    pub transitioned_at: DateTime<Utc>,
+    pub transitioned_by: Option<Uuid>,
+}
+
+/// Status of a motion, where it may go next, and how it got there
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MotionStatusHistory {
+    pub motion_id: Uuid,
+    pub status: MotionStatus,
+    /// Statuses the motion may be moved to by hand
+    pub next_statuses: Vec<MotionStatus>,
+    /// Status changes, oldest first
+    pub transitions: Vec<MotionStatusTransition>,
+}
+
+/// Local rule setting when a brief is due, counted back from the hearing
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct BriefingRule {
+    pub id: Uuid,
+    /// Rule that prescribes the period
+    pub legal_rule_id: Uuid,
+    /// Motion type the rule is limited to; every type when absent
+    pub motion_type: Option<MotionType>,
+    /// Opposition or Reply
+    pub role: MotionFilingRole,
+    pub days_before_hearing: i32,
+    pub day_count: DayCount,
+    pub court: Option<String>,
+    pub jurisdiction: Option<String>,
+    pub priority: String,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Due date of an opposition or reply on a motion's briefing schedule
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct BriefingDeadline {
+    pub id: Uuid,
+    pub motion_id: Uuid,
+    /// Opposition or Reply
+    pub role: MotionFilingRole,
+    /// Rule the date was computed by, if one applies
+    pub briefing_rule_id: Option<Uuid>,
+    /// Date counted back from the hearing
+    pub computed_date: Option<NaiveDate>,
+    /// How the computed date was counted
+    pub computation: Option<String>,
+    /// Date set by hand in place of the computed date
+    pub override_date: Option<NaiveDate>,
+    pub override_reason: Option<String>,
+    /// Date the brief is due after overrides and extensions
+    pub due_date: NaiveDate,
+    pub status: DeadlineStatus,
+    /// Task tracking the due date
+    pub task_id: Option<Uuid>,
+    /// Calendar event marking the due date
+    pub calendar_event_id: Option<Uuid>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Stipulated extension of a briefing deadline
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct BriefingExtension {
+    pub id: Uuid,
+    pub briefing_deadline_id: Uuid,
+    pub previous_due_date: NaiveDate,
+    pub new_due_date: NaiveDate,
+    /// Date the parties stipulated to the extension
+    pub stipulated_on: NaiveDate,
+    /// Docket entry of the stipulation or the order approving it
+    pub docket_entry_id: Option<Uuid>,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub created_by: Option<Uuid>,
+}
+
+/// Briefing schedule of a motion
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BriefingSchedule {
+    pub motion_id: Uuid,
+    pub hearing_date: Option<DateTime<Utc>>,
+    pub deadlines: Vec<BriefingDeadline>,
+    /// Extensions of the deadlines, oldest first
+    pub extensions: Vec<BriefingExtension>,
+}
+
+/// Document filed with a docket entry
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct DocketEntryDocument {
+    pub id: Uuid,
+    pub docket_entry_id: Uuid,
+    pub document_id: Uuid,
+    pub document_title: String,
+    /// 0 for the main document, then 1, 2, ... for attachments
+    pub attachment_number: i32,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub created_by: Option<Uuid>,
+}
+
 /// Docket entry model
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct DocketEntry {
@@ -120,8 +270,97 @@ pub struct DocketEntry {
     pub description: Option<String>,
     pub filed_by: Option<String>,
     pub is_sealed: Option<bool>,
+    /// Court order under which the entry was sealed
+    pub seal_order: Option<String>,
+    pub sealed_at: Option<DateTime<Utc>>,
+    /// Court order under which the entry was last unsealed
+    pub unseal_order: Option<String>,
+    pub unsealed_at: Option<DateTime<Utc>>,
+    /// Event on this entry that starts deadline periods running
+    pub trigger_event: Option<DeadlineTrigger>,
+    /// How the filing was served, for added service days
+    pub service_method: Option<ServiceMethod>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// True when the entry is sealed and its contents are withheld from
+    /// the caller
+    #[sqlx(skip)]
+    #[serde(default)]
+    pub redacted: bool,
+}
+
+/// Kind of access to a sealed docket entry
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "sealed_access_action", rename_all = "PascalCase")]
+pub enum SealedAccessAction {
+    Sealed,
+    Unsealed,
+    Viewed,
+    Modified,
+    Deleted,
+    /// A user without a grant asked for the entry's contents
+    Denied,
+    Granted,
+    Revoked,
+}
+
+/// User allowed to see the contents of a sealed docket entry
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct SealedEntryGrant {
+    pub id: Uuid,
+    pub docket_entry_id: Uuid,
+    pub user_id: Uuid,
+    pub reason: Option<String>,
+    pub granted_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub revoked_by: Option<Uuid>,
+}
+
+/// Access to a sealed docket entry, recorded in the entry's audit trail
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct SealedEntryAccess {
+    pub id: Uuid,
+    pub docket_entry_id: Uuid,
+    pub user_id: Option<Uuid>,
+    pub action: SealedAccessAction,
+    /// Court order cited when sealing or unsealing
+    pub court_order: Option<String>,
+    pub details: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Inclusive range of docket numbers with no entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct SequenceGap {
+    pub from: i32,
+    pub to: i32,
+}
+
+/// PACER docket number carried by more than one entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PacerDuplicate {
+    pub pacer_sequence_number: i32,
+    pub entry_ids: Vec<Uuid>,
+}
+
+/// Consistency of a case's docket numbering, locally and against PACER
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DocketConsistencyReport {
+    pub case_id: Uuid,
+    /// Live (not deleted) entries on the docket
+    pub entry_count: usize,
+    pub last_sequence_number: i32,
+    /// Missing docket numbers; deleted entries keep theirs
+    pub sequence_gaps: Vec<SequenceGap>,
+    pub duplicate_sequence_numbers: Vec<i32>,
+    /// PACER numbers below the highest one that no live entry carries
+    pub pacer_gaps: Vec<SequenceGap>,
+    pub pacer_duplicates: Vec<PacerDuplicate>,
+    /// Live entries with no PACER number
+    pub entries_without_pacer_number: usize,
+    /// True when there are no gaps or duplicates in either numbering
+    pub consistent: bool,
 }
 
 /// Evidence item model
@@ -145,18 +384,387 @@ pub struct EvidenceItem {
     pub updated_at: DateTime<Utc>,
 }
 
+/// Kind of chain of custody event
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub enum CustodyAction {
+    Collection,
+    Transfer,
+    #[serde(rename = "Check-Out")]
+    CheckOut,
+    Analysis,
+    Return,
+    Acquisition,
+    Verification,
+    Scanned,
+}
+
+impl CustodyAction {
+    /// Value stored in `chain_of_custody_events.action`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CustodyAction::Collection => "Collection",
+            CustodyAction::Transfer => "Transfer",
+            CustodyAction::CheckOut => "Check-Out",
+            CustodyAction::Analysis => "Analysis",
+            CustodyAction::Return => "Return",
+            CustodyAction::Acquisition => "Acquisition",
+            CustodyAction::Verification => "Verification",
+            CustodyAction::Scanned => "Scanned",
+        }
+    }
+}
+
+/// Chain of custody event model.
+///
+/// Events are append-only; each one carries the hash of its predecessor so
+/// that any later alteration of the chain is detectable.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ChainOfCustodyEvent {
+    pub id: Uuid,
+    pub evidence_id: Uuid,
+    pub sequence: i32,
+    pub date: DateTime<Utc>,
+    pub action: String,
+    pub actor: String,
+    pub actor_id: Option<Uuid>,
+    pub from_custodian: Option<String>,
+    pub to_custodian: Option<String>,
+    pub location: Option<String>,
+    pub notes: Option<String>,
+    pub prev_hash: Option<String>,
+    pub hash: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// Full custody chain of an evidence item with its verification result
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CustodyChain {
+    pub evidence_id: Uuid,
+    pub tracking_uuid: Uuid,
+    pub events: Vec<ChainOfCustodyEvent>,
+    /// True when every event's hash and link to its predecessor check out
+    pub verified: bool,
+    /// Sequence of the first event that fails verification
+    pub broken_at_sequence: Option<i32>,
+}
+
+/// Result of the latest integrity check of an evidence file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "integrity_status", rename_all = "PascalCase")]
+pub enum IntegrityStatus {
+    Intact,
+    Mismatch,
+}
+
+/// File or forensic image acquired for a digital evidence item.
+///
+/// The digests are computed once at intake; the stored content is re-hashed
+/// against them on every verification.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct EvidenceFile {
+    pub id: Uuid,
+    pub evidence_id: Uuid,
+    pub tracking_uuid: Uuid,
+    pub file_name: String,
+    pub content_type: Option<String>,
+    pub size_bytes: i64,
+    pub sha256: String,
+    pub md5: String,
+    pub integrity_status: IntegrityStatus,
+    pub last_verified_at: DateTime<Utc>,
+    pub uploaded_by: Option<Uuid>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Outcome of re-hashing an evidence file
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EvidenceFileVerification {
+    pub file: EvidenceFile,
+    pub computed_sha256: String,
+    pub computed_md5: String,
+    pub intact: bool,
+    /// Custody event recording the verification
+    pub custody_event_id: Uuid,
+}
+
+/// Evidence item resolved from a scanned label
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EvidenceScanResponse {
+    pub item: EvidenceItem,
+    /// Custody event recording the scan
+    pub custody_event: ChainOfCustodyEvent,
+}
+
+/// Party offering a trial exhibit
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "exhibit_party", rename_all = "PascalCase")]
+pub enum ExhibitParty {
+    Plaintiff,
+    Defendant,
+    Joint,
+}
+
+impl ExhibitParty {
+    /// Prefix of the party's exhibit numbers, e.g. "P" for P-1
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            ExhibitParty::Plaintiff => "P",
+            ExhibitParty::Defendant => "D",
+            ExhibitParty::Joint => "J",
+        }
+    }
+}
+
+/// Exhibit status enum
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "exhibit_status", rename_all = "PascalCase")]
+pub enum ExhibitStatus {
+    /// Marked for identification, not yet offered
+    Marked,
+    Offered,
+    Admitted,
+    Excluded,
+}
+
 /// Trial exhibit model
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct TrialExhibit {
     pub id: Uuid,
     pub case_id: Uuid,
     pub exhibit_number: String,
+    pub exhibit_sequence: i32,
     pub title: String,
     pub date_marked: DateTime<Utc>,
-    pub party: String,
-    pub status: String,
+    pub party: ExhibitParty,
+    pub status: ExhibitStatus,
     pub file_type: String,
     pub description: Option<String>,
+    pub witness: Option<String>,
+    pub objection: Option<String>,
+    /// Evidence item the exhibit was promoted from
+    pub evidence_id: Option<Uuid>,
+    /// Document the exhibit was promoted from
+    pub document_id: Option<Uuid>,
+    pub offered_at: Option<DateTime<Utc>>,
+    /// When the exhibit was admitted or excluded
+    pub ruled_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Resolution of an admissibility challenge
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "challenge_status", rename_all = "PascalCase")]
+pub enum ChallengeStatus {
+    /// Awaiting a ruling
+    Pending,
+    /// Objection upheld; the evidence is excluded
+    Sustained,
+    /// Objection rejected; the evidence comes in
+    Overruled,
+    Withdrawn,
+}
+
+/// Objection to the admissibility of an evidence item.
+///
+/// The challenge cites a Federal Rule of Evidence and is resolved by the
+/// ruling on its linked motion in limine.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct AdmissibilityChallenge {
+    pub id: Uuid,
+    pub evidence_id: Uuid,
+    pub objecting_party: ExhibitParty,
+    /// Rule of evidence the objection rests on
+    pub legal_rule_id: Uuid,
+    /// Motion in limine raising the objection
+    pub motion_id: Option<Uuid>,
+    pub grounds: Option<String>,
+    pub status: ChallengeStatus,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// How a docket report entry compares with the docket on file
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub enum PacerEntryChangeKind {
+    /// Not on file yet
+    Added,
+    /// On file with a different date or docket text
+    Changed,
+}
+
+/// Docket entry added or changed by a PACER import
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PacerEntryChange {
+    pub kind: PacerEntryChangeKind,
+    pub pacer_sequence_number: Option<i32>,
+    pub date_filed: NaiveDate,
+    /// Fields that differ from the entry on file ("date", "text")
+    pub changed_fields: Vec<String>,
+    /// Docket text on file before the import
+    pub previous_text: Option<String>,
+    pub text: String,
+}
+
+/// Outcome of importing a CM/ECF docket report
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PacerImportResult {
+    /// Case the report was imported into; empty for a dry run that would create one
+    pub case_id: Option<Uuid>,
+    pub case_created: bool,
+    /// True when nothing was saved
+    pub dry_run: bool,
+    pub court: String,
+    pub case_number: String,
+    /// Case fields updated from the report
+    pub case_changes: Vec<String>,
+    pub parties_added: usize,
+    pub parties_updated: usize,
+    pub entries_added: usize,
+    pub entries_changed: usize,
+    pub entries_unchanged: usize,
+    pub entry_changes: Vec<PacerEntryChange>,
+    /// PACER numbers on file that the report no longer lists
+    pub missing_from_report: Vec<i32>,
+}
+
+/// Docket event that starts deadline periods running
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "deadline_trigger", rename_all = "PascalCase")]
+pub enum DeadlineTrigger {
+    ComplaintServed,
+    AmendedPleadingServed,
+    MotionFiled,
+    DiscoveryServed,
+    JudgmentEntered,
+    OrderEntered,
+}
+
+/// How a paper was served
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "service_method", rename_all = "PascalCase")]
+pub enum ServiceMethod {
+    Personal,
+    /// CM/ECF or email
+    Electronic,
+    Mail,
+    /// Left with the court clerk
+    LeftWithClerk,
+    /// Any other means the person consented to
+    OtherConsented,
+}
+
+/// Whether a period counts every day or only days the court is open
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "day_count", rename_all = "PascalCase")]
+pub enum DayCount {
+    CalendarDays,
+    CourtDays,
+}
+
+/// Deadline status enum
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "deadline_status", rename_all = "PascalCase")]
+pub enum DeadlineStatus {
+    Open,
+    Completed,
+    /// The triggering event was removed or no longer matches the rule
+    Vacated,
+}
+
+/// Deadline a legal rule attaches to a docket event.
+///
+/// Rules without a court or jurisdiction apply to every case.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct DeadlineRule {
+    pub id: Uuid,
+    /// Rule that prescribes the period
+    pub legal_rule_id: Uuid,
+    pub trigger_event: DeadlineTrigger,
+    /// Title given to computed deadlines, e.g. "Answer due"
+    pub title: String,
+    /// Length of the period; negative periods count back from the event
+    pub days: i32,
+    pub day_count: DayCount,
+    /// Whether days are added for service by mail and similar means
+    pub service_days_apply: bool,
+    pub court: Option<String>,
+    pub jurisdiction: Option<String>,
+    pub priority: String,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Deadline computed from a docket entry by a deadline rule
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Deadline {
+    pub id: Uuid,
+    pub case_id: Uuid,
+    /// Docket entry whose event triggered the deadline
+    pub docket_entry_id: Uuid,
+    pub deadline_rule_id: Uuid,
+    /// Task tracking the deadline
+    pub task_id: Option<Uuid>,
+    pub title: String,
+    pub trigger_date: NaiveDate,
+    pub due_date: NaiveDate,
+    /// How the due date was counted
+    pub computation: String,
+    pub status: DeadlineStatus,
+    pub completed_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+/// Due date counted for a period, without recording a deadline
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DeadlineCalculation {
+    pub trigger_date: NaiveDate,
+    pub due_date: NaiveDate,
+    pub computation: String,
+}
+
+/// Case or motion attribute motion outcomes are grouped by
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub enum OutcomeDimension {
+    Judge,
+    MotionType,
+    Court,
+    NatureOfSuit,
+}
+
+/// Outcomes of the decided motions in one group
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OutcomeStats {
+    /// Judge, motion type, court or nature of suit; absent when the cases
+    /// do not record it
+    pub key: Option<String>,
+    pub decided: i64,
+    pub granted: i64,
+    pub denied: i64,
+    /// Withdrawn or moot
+    pub other: i64,
+    /// Share of decided motions granted, from 0 to 1
+    pub grant_rate: f64,
+    /// Share of decided motions denied, from 0 to 1
+    pub deny_rate: f64,
+    pub median_days_filing_to_decision: Option<f64>,
+    pub median_days_hearing_to_decision: Option<f64>,
+}
+
+/// Grant and deny rates and decision times of decided motions
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MotionOutcomeReport {
+    pub group_by: OutcomeDimension,
+    /// Earliest decision date included
+    pub from: Option<NaiveDate>,
+    /// Latest decision date included
+    pub to: Option<NaiveDate>,
+    pub overall: OutcomeStats,
+    /// Groups with the most decided motions first
+    pub groups: Vec<OutcomeStats>,
+}
@@ -64,7 +64,9 @@ pub enum LegalRuleType {
     FRE,
     FRCP,
     FRAP,
+    #[sqlx(rename = "Local")]
     Local,
+    #[sqlx(rename = "State")]
     State,
 }
 
@@ -1,11 +1,11 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
 /// Task status enum
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "task_status", rename_all = "PascalCase")]
 pub enum TaskStatus {
     Pending,
@@ -17,6 +17,35 @@ pub enum TaskStatus {
     Completed,
 }
 
+/// Stage status enum
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "stage_status", rename_all = "PascalCase")]
+pub enum StageStatus {
+    Pending,
+    Active,
+    Completed,
+}
+
+/// When the next task of a recurring series is created
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "recurrence_trigger")]
+pub enum RecurrenceTrigger {
+    /// Once the current task is done
+    OnCompletion,
+    /// Once the current task falls due, done or not
+    OnSchedule,
+}
+
+/// What an escalation rule does with an overdue task
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "escalation_action")]
+pub enum EscalationAction {
+    /// Assign the task to the supervisor and notify them
+    Reassign,
+    /// Notify the supervisor only
+    Notify,
+}
+
 /// Workflow task model
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct WorkflowTask {
@@ -31,6 +60,21 @@ pub struct WorkflowTask {
     pub description: Option<String>,
     pub case_id: Option<Uuid>,
     pub project_id: Option<Uuid>,
+    /// Project stage the task belongs to
+    pub stage_id: Option<Uuid>,
+    /// Tasks that must be due before this one starts
+    pub dependencies: Option<Vec<Uuid>>,
+    /// RRULE the task repeats on, e.g. "FREQ=MONTHLY;BYMONTHDAY=5"
+    pub recurrence: Option<String>,
+    pub recurrence_trigger: Option<RecurrenceTrigger>,
+    /// First task of the recurring series this task belongs to
+    pub series_id: Option<Uuid>,
+    /// Number of the task within its series, from 1
+    pub occurrence: Option<i32>,
+    /// Position within its status column on the case's Kanban board
+    pub case_board_rank: Option<String>,
+    /// Position within its status column on the project's Kanban board
+    pub project_board_rank: Option<String>,
     pub completion: Option<i32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -48,19 +92,277 @@ pub struct Project {
     pub priority: String,
     pub lead: String,
     pub due_date: Option<DateTime<Utc>>,
+    /// Template the project was created from
+    pub template_id: Option<Uuid>,
+    /// Date the template's task offsets were counted from
+    pub anchor_date: Option<NaiveDate>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub deleted_at: Option<DateTime<Utc>>,
 }
 
+/// A stage of a project, rolled up from its tasks
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ProjectStage {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    /// Order of the stage in the project, from 1
+    pub position: i32,
+    pub name: String,
+    pub status: StageStatus,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A project stage and its tasks, soonest due first
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProjectStageTasks {
+    pub stage: ProjectStage,
+    pub tasks: Vec<WorkflowTask>,
+}
+
+/// A project with its stages in order
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProjectDetail {
+    pub project: Project,
+    pub stages: Vec<ProjectStageTasks>,
+}
+
+/// Escalates open tasks of at least a given priority once they are
+/// overdue by a number of hours
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct EscalationRule {
+    pub id: Uuid,
+    pub name: String,
+    /// High or Critical
+    pub min_priority: String,
+    pub overdue_hours: i32,
+    pub action: EscalationAction,
+    pub supervisor_id: Uuid,
+    /// Only tasks of this case; all cases when absent
+    pub case_id: Option<Uuid>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A task escalated by an escalation rule
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TaskEscalation {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub rule_id: Uuid,
+    pub action: EscalationAction,
+    pub previous_assignee_id: Option<Uuid>,
+    pub supervisor_id: Uuid,
+    pub escalated_at: DateTime<Utc>,
+}
+
+/// Work-in-progress limit of a board column
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct BoardWipLimit {
+    pub id: Uuid,
+    pub case_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+    pub status: TaskStatus,
+    pub wip_limit: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A status column of a Kanban board, tasks in board order
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BoardColumn {
+    pub status: TaskStatus,
+    pub wip_limit: Option<i32>,
+    pub tasks: Vec<WorkflowTask>,
+}
+
+/// Kanban board of a case or a project
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TaskBoard {
+    pub case_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+    pub columns: Vec<BoardColumn>,
+}
+
+/// A project task laid out for a Gantt chart
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GanttTask {
+    pub task_id: Uuid,
+    pub title: String,
+    pub stage_id: Option<Uuid>,
+    pub status: TaskStatus,
+    /// Start of the bar; the due date for tasks without a start date
+    pub start: DateTime<Utc>,
+    pub due: DateTime<Utc>,
+    pub completion: i32,
+    /// Tasks in the project this one depends on
+    pub dependencies: Vec<Uuid>,
+    /// Days the task could slip without delaying the project
+    pub slack_days: i64,
+    pub critical: bool,
+}
+
+/// Gantt data for a project, tasks in schedule order
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProjectGantt {
+    pub project_id: Uuid,
+    pub start: Option<DateTime<Utc>>,
+    pub finish: Option<DateTime<Utc>>,
+    pub tasks: Vec<GanttTask>,
+    /// Tasks with no slack, earliest first
+    pub critical_path: Vec<Uuid>,
+}
+
+/// Workflow template model
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct WorkflowTemplate {
+    pub id: Uuid,
+    pub title: String,
+    pub category: String,
+    /// Low, Medium or High
+    pub complexity: String,
+    /// Expected duration, e.g. "6 weeks"
+    pub duration: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub audit_ready: Option<bool>,
+    #[schema(value_type = Option<Vec<TemplateStage>>)]
+    pub stages: Option<serde_json::Value>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// A stage of a workflow template
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TemplateStage {
+    pub name: String,
+    pub tasks: Vec<TemplateTask>,
+}
+
+/// A task of a workflow template stage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TemplateTask {
+    pub title: String,
+    pub description: Option<String>,
+    /// Low, Medium (default), High or Critical
+    pub priority: Option<String>,
+    /// Calendar days from the anchor date to the due date; negative for
+    /// tasks due before it (e.g. before trial)
+    pub offset_days: i32,
+    /// Calendar days the task takes; its start date is this many days
+    /// before the due date
+    pub duration_days: Option<i32>,
+}
+
 /// Create task request
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateTaskRequest {
     pub title: String,
-    pub assignee: String,
+    /// Display name of the assignee; defaults to the assigned user's name
+    pub assignee: Option<String>,
     pub assignee_id: Option<Uuid>,
+    pub start_date: Option<DateTime<Utc>>,
     pub due_date: DateTime<Utc>,
+    /// Low, Medium, High or Critical
     pub priority: String,
     pub description: Option<String>,
     pub case_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+    /// RRULE the task repeats on, e.g. "FREQ=MONTHLY;BYMONTHDAY=5"
+    pub recurrence: Option<String>,
+    /// When the next task is created (defaults to OnCompletion)
+    pub recurrence_trigger: Option<RecurrenceTrigger>,
+}
+
+/// Update task request; omitted fields are unchanged
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateTaskRequest {
+    pub title: Option<String>,
+    /// Reassign the task to this user
+    pub assignee_id: Option<Uuid>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub priority: Option<String>,
+    pub description: Option<String>,
+    /// Percentage complete, 0 to 100
+    pub completion: Option<i32>,
+    /// Next status in the task workflow
+    pub status: Option<TaskStatus>,
+}
+
+/// Replace the tasks a task depends on
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetTaskDependenciesRequest {
+    /// Tasks that must be due before this one starts
+    pub depends_on: Vec<Uuid>,
+}
+
+/// Make a task recur, or stop it recurring
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetTaskRecurrenceRequest {
+    /// RRULE the task repeats on; omit to stop the series after this task
+    pub rule: Option<String>,
+    /// When the next task is created (defaults to OnCompletion)
+    pub trigger: Option<RecurrenceTrigger>,
+}
+
+/// Move a task on a board, changing its status and position together
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MoveTaskRequest {
+    /// Column to move the task to
+    pub status: TaskStatus,
+    /// Index in the column to drop the task at, from 0; past the end
+    /// appends
+    pub position: usize,
+    /// Board the position refers to: this project's, or the task's case
+    /// board when absent
+    pub project_id: Option<Uuid>,
+}
+
+/// Set or clear the WIP limit of a board column
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetWipLimitRequest {
+    /// Board of this case, or of `project_id`
+    pub case_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+    pub status: TaskStatus,
+    /// Maximum number of tasks in the column; omit to remove the limit
+    pub wip_limit: Option<i32>,
+}
+
+/// Create escalation rule request
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateEscalationRuleRequest {
+    pub name: String,
+    /// High or Critical
+    pub min_priority: String,
+    pub overdue_hours: i32,
+    pub action: EscalationAction,
+    pub supervisor_id: Uuid,
+    /// Only tasks of this case
+    pub case_id: Option<Uuid>,
+}
+
+/// Update escalation rule request; omitted fields are unchanged
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateEscalationRuleRequest {
+    pub name: Option<String>,
+    pub min_priority: Option<String>,
+    pub overdue_hours: Option<i32>,
+    pub action: Option<EscalationAction>,
+    pub supervisor_id: Option<Uuid>,
+    pub is_active: Option<bool>,
+}
+
+/// Changes applied to several tasks at once
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BulkUpdateTasksRequest {
+    pub task_ids: Vec<Uuid>,
+    pub assignee_id: Option<Uuid>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub priority: Option<String>,
+    pub status: Option<TaskStatus>,
 }
@@ -56,3 +56,1628 @@ fn test_config_default() {
     assert_eq!(config.server.port, 8080);
     assert_eq!(config.database.max_connections, 10);
 }
+
+#[test]
+fn test_bates_numbering_and_pagination() {
+    use rusty_saas::api::productions::bates::{format_bates, format_bates_range, paginate};
+
+    assert_eq!(format_bates("ACME", 42, 8), "ACME00000042");
+    assert_eq!(format_bates_range("ACME", 7, 7, 6), "ACME000007");
+    assert_eq!(format_bates_range("ACME", 1, 3, 4), "ACME0001 - ACME0003");
+
+    // Empty documents still consume one Bates number
+    assert_eq!(paginate(None).len(), 1);
+
+    let long_text = "line\n".repeat(120);
+    assert_eq!(paginate(Some(&long_text)).len(), 3);
+}
+
+#[test]
+fn test_pdf_rendering_produces_valid_structure() {
+    use rusty_saas::api::productions::bates::{paginate, render_stamped_pdf};
+
+    let pages = paginate(Some("Privileged (draft) memo"));
+    let pdf = render_stamped_pdf("Memo", &pages, "ACME", 1, 8, Some("CONFIDENTIAL"));
+    let text = String::from_utf8_lossy(&pdf);
+
+    assert!(text.starts_with("%PDF-1.4"));
+    assert!(text.contains("(ACME00000001) Tj"));
+    assert!(text.contains("(CONFIDENTIAL) Tj"));
+    assert!(text.contains("\\(draft\\)"));
+    assert!(text.trim_end().ends_with("%%EOF"));
+}
+
+#[test]
+fn test_load_file_dat_and_opt_generation() {
+    use chrono::Utc;
+    use rusty_saas::api::productions::load_file::{build_dat, build_opt, LoadFileDocument};
+    use rusty_saas::models::{
+        Document, LoadFileField, LoadFileSource, ProductionDocument, ProductionSet,
+    };
+    use uuid::Uuid;
+
+    let now = Utc::now();
+    let case_id = Uuid::new_v4();
+    let production = ProductionSet {
+        id: Uuid::new_v4(),
+        case_id,
+        name: "First Production".to_string(),
+        date: now,
+        bates_range: "ACME0001 - ACME0002".to_string(),
+        doc_count: 1,
+        size: "1 KB".to_string(),
+        format: "PDF".to_string(),
+        status: "Stamped".to_string(),
+        bates_prefix: Some("ACME".to_string()),
+        bates_start: Some(1),
+        bates_end: Some(2),
+        bates_padding: Some(4),
+        confidentiality: Some("CONFIDENTIAL".to_string()),
+        load_file_fields: None,
+        created_at: now,
+        updated_at: now,
+    };
+    let document = Document {
+        id: Uuid::new_v4(),
+        case_id,
+        title: "Contract.docx".to_string(),
+        doc_type: "Contract".to_string(),
+        content: Some("Line one\nLine two".to_string()),
+        upload_date: now,
+        last_modified: now,
+        tags: vec![],
+        file_size: None,
+        source_module: None,
+        status: None,
+        author_id: None,
+        created_at: now,
+        updated_at: now,
+        version: Some(1),
+        deleted_at: None,
+        redacted_version_id: None,
+    };
+    let produced = ProductionDocument {
+        id: Uuid::new_v4(),
+        production_set_id: production.id,
+        case_id,
+        document_id: document.id,
+        sort_order: 0,
+        bates_prefix: "ACME".to_string(),
+        bates_begin: 1,
+        bates_end: 2,
+        page_count: 2,
+        redacted_version_id: None,
+        created_at: now,
+    };
+    let entries = [LoadFileDocument {
+        document: &document,
+        produced: &produced,
+    }];
+
+    let fields = vec![
+        LoadFileField::new("BEGBATES", LoadFileSource::BatesBegin),
+        LoadFileField::new("ENDBATES", LoadFileSource::BatesEnd),
+        LoadFileField::new("NATIVELINK", LoadFileSource::NativePath),
+    ];
+    let dat = build_dat(&fields, &production, &entries, 4);
+    let rows: Vec<&str> = dat.trim_start_matches('\u{feff}').lines().collect();
+    assert_eq!(rows[0], "þBEGBATESþ\u{14}þENDBATESþ\u{14}þNATIVELINKþ");
+    assert_eq!(
+        rows[1],
+        "þACME0001þ\u{14}þACME0002þ\u{14}þ.\\VOL001\\NATIVES\\ACME0001.txtþ"
+    );
+
+    let opt = build_opt(&entries, 4);
+    let lines: Vec<&str> = opt.lines().collect();
+    assert_eq!(
+        lines[0],
+        "ACME0001,VOL001,.\\VOL001\\IMAGES\\ACME0001.pdf,Y,,,2"
+    );
+    assert_eq!(
+        lines[1],
+        "ACME0002,VOL001,.\\VOL001\\IMAGES\\ACME0001.pdf,,,,"
+    );
+}
+
+#[test]
+fn test_privilege_log_export_formats() {
+    use chrono::{TimeZone, Utc};
+    use rusty_saas::api::privilege::log::{log_number, log_rows, render_log, LogFormat};
+    use rusty_saas::models::{PrivilegeBasis, PrivilegeLogEntry};
+    use uuid::Uuid;
+
+    let entry = PrivilegeLogEntry {
+        log_number: log_number(0),
+        designation_id: Uuid::new_v4(),
+        document_id: Some(Uuid::new_v4()),
+        communication_id: None,
+        item_date: Some(Utc.with_ymd_and_hms(2024, 3, 5, 12, 0, 0).unwrap()),
+        item_type: "Memo".to_string(),
+        author: "J. Counsel".to_string(),
+        recipients: vec!["CEO".to_string(), "CFO".to_string()],
+        basis: PrivilegeBasis::AttorneyClient,
+        description: "Legal advice re \"merger\", draft terms".to_string(),
+        withheld_from: vec!["First Production".to_string()],
+    };
+
+    assert_eq!(entry.log_number, "PRIV-0001");
+    let entries = vec![entry];
+    let rows = log_rows(&entries);
+    assert_eq!(rows[0][1], "03/05/2024");
+    assert_eq!(rows[0][4], "CEO; CFO");
+    assert_eq!(rows[0][5], "Attorney-Client");
+
+    let csv =
+        String::from_utf8(render_log(LogFormat::Csv, "Acme v. Doe", &entries).unwrap()).unwrap();
+    assert!(csv.starts_with("Log No.,Date,Type,Author"));
+    assert!(csv.contains("\"Legal advice re \"\"merger\"\", draft terms\""));
+
+    let xlsx = render_log(LogFormat::Xlsx, "Acme v. Doe", &entries).unwrap();
+    assert!(xlsx.starts_with(b"PK"));
+
+    let pdf = render_log(LogFormat::Pdf, "Acme v. Doe", &entries).unwrap();
+    assert!(String::from_utf8_lossy(&pdf).contains("(Privilege Log) Tj"));
+
+    assert!(LogFormat::parse("docx").is_err());
+}
+
+#[test]
+fn test_redaction_burn_in_masks_text_and_page_areas() {
+    use rusty_saas::api::productions::bates::{paginate, render_stamped_pdf, REDACTION_MASK};
+    use rusty_saas::api::redactions::burn::{burn_in, PageArea};
+
+    let content = "Patient SSN 123-45-6789 is on file.\nSecond line stays.";
+    let redacted = burn_in(Some(content), &[(12, 23)], &[]);
+    assert_eq!(
+        redacted,
+        format!(
+            "Patient SSN {} is on file.\nSecond line stays.",
+            "█".repeat(11)
+        )
+    );
+    assert!(!redacted.contains("6789"));
+
+    // Page areas cover the second body line (baseline 727) from the left margin
+    let area = PageArea {
+        page_number: 1,
+        x: 36.0,
+        y: 720.0,
+        width: 36.0,
+        height: 12.0,
+    };
+    let redacted = burn_in(Some(content), &[], &[area]);
+    let lines: Vec<&str> = redacted.lines().collect();
+    assert_eq!(lines[0], "Patient SSN 123-45-6789 is on file.");
+    assert!(lines[1].starts_with(&REDACTION_MASK.to_string().repeat(6)));
+    assert!(lines[1].ends_with("ine stays."));
+
+    // Redacted text paginates exactly like the original
+    let long = "word ".repeat(2000);
+    let burned = burn_in(Some(&long), &[(0, long.len())], &[]);
+    assert_eq!(paginate(Some(&burned)).len(), paginate(Some(&long)).len());
+
+    // Masked characters are painted as boxes, not written as text
+    let pdf = render_stamped_pdf("Redacted", &paginate(Some(&burned)), "ACME", 1, 8, None);
+    let text = String::from_utf8_lossy(&pdf);
+    assert!(text.contains("re f"));
+    assert!(!text.contains("(word"));
+}
+
+#[test]
+fn test_legal_hold_reminder_schedule() {
+    use chrono::{Duration, Utc};
+    use rusty_saas::api::holds::service::reminder_due;
+    use rusty_saas::models::LegalHoldCustodian;
+    use uuid::Uuid;
+
+    let now = Utc::now();
+    let mut custodian = LegalHoldCustodian {
+        id: Uuid::new_v4(),
+        legal_hold_id: Uuid::new_v4(),
+        custodian: "J. Smith".to_string(),
+        user_id: None,
+        email: None,
+        notice_sent_at: None,
+        acknowledged_at: None,
+        last_reminder_at: None,
+        reminder_count: 0,
+        created_at: now,
+        updated_at: now,
+    };
+
+    // No reminders before the notice goes out
+    assert!(!reminder_due(&custodian, 7, now));
+
+    custodian.notice_sent_at = Some(now - Duration::days(6));
+    assert!(!reminder_due(&custodian, 7, now));
+    custodian.notice_sent_at = Some(now - Duration::days(7));
+    assert!(reminder_due(&custodian, 7, now));
+
+    // The interval restarts from the last reminder
+    custodian.last_reminder_at = Some(now - Duration::days(1));
+    assert!(!reminder_due(&custodian, 7, now));
+
+    custodian.last_reminder_at = Some(now - Duration::days(30));
+    custodian.acknowledged_at = Some(now - Duration::days(2));
+    assert!(!reminder_due(&custodian, 7, now));
+}
+
+#[test]
+fn test_custody_chain_hash_linking_and_verification() {
+    use rusty_saas::api::evidence::custody::{custody_timestamp, event_hash, verify_chain};
+    use rusty_saas::models::{ChainOfCustodyEvent, CustodyAction};
+    use uuid::Uuid;
+
+    let evidence_id = Uuid::new_v4();
+    let actions = [
+        CustodyAction::Collection,
+        CustodyAction::CheckOut,
+        CustodyAction::Analysis,
+        CustodyAction::Return,
+    ];
+    let mut events: Vec<ChainOfCustodyEvent> = Vec::new();
+    for (index, action) in actions.iter().enumerate() {
+        let mut event = ChainOfCustodyEvent {
+            id: Uuid::new_v4(),
+            evidence_id,
+            sequence: index as i32 + 1,
+            date: custody_timestamp(),
+            action: action.as_str().to_string(),
+            actor: "clerk@example.com".to_string(),
+            actor_id: None,
+            from_custodian: None,
+            to_custodian: Some("Evidence Room".to_string()),
+            location: Some("Locker 12".to_string()),
+            notes: None,
+            prev_hash: events.last().and_then(|e| e.hash.clone()),
+            hash: None,
+            created_at: None,
+        };
+        event.hash = Some(event_hash(&event));
+        events.push(event);
+    }
+
+    assert_eq!(verify_chain(&events), None);
+    assert_eq!(events[0].hash.as_ref().unwrap().len(), 64);
+
+    // Editing an event's content breaks that event
+    let mut tampered = events.clone();
+    tampered[2].notes = Some("edited".to_string());
+    assert_eq!(verify_chain(&tampered), Some(3));
+
+    // Re-hashing an edited event still breaks the link of its successor
+    tampered[2].hash = Some(event_hash(&tampered[2]));
+    assert_eq!(verify_chain(&tampered), Some(4));
+
+    // Removing an event leaves a gap in the sequence
+    let mut gapped = events.clone();
+    gapped.remove(1);
+    assert_eq!(verify_chain(&gapped), Some(3));
+}
+
+#[test]
+fn test_evidence_file_digests() {
+    use chrono::Utc;
+    use rusty_saas::api::evidence::integrity::FileDigests;
+    use rusty_saas::models::{EvidenceFile, IntegrityStatus};
+    use uuid::Uuid;
+
+    let digests = FileDigests::compute(b"abc");
+    assert_eq!(
+        digests.sha256,
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    assert_eq!(digests.md5, "900150983cd24fb0d6963f7d28e17f72");
+    assert_eq!(digests.size_bytes, 3);
+
+    let file = EvidenceFile {
+        id: Uuid::new_v4(),
+        evidence_id: Uuid::new_v4(),
+        tracking_uuid: Uuid::new_v4(),
+        file_name: "disk.img".to_string(),
+        content_type: None,
+        size_bytes: digests.size_bytes,
+        sha256: digests.sha256.clone(),
+        md5: digests.md5.clone(),
+        integrity_status: IntegrityStatus::Intact,
+        last_verified_at: Utc::now(),
+        uploaded_by: None,
+        created_at: None,
+        updated_at: None,
+    };
+
+    assert!(FileDigests::compute(b"abc").matches(&file));
+    assert!(!FileDigests::compute(b"abd").matches(&file));
+}
+
+#[test]
+fn test_evidence_label_sheets() {
+    use chrono::Utc;
+    use rusty_saas::api::evidence::labels::{
+        qr_modules, render_labels_pdf, render_labels_svg, LabelData,
+    };
+    use rusty_saas::models::EvidenceType;
+    use uuid::Uuid;
+
+    let tracking_uuid = Uuid::new_v4();
+    let (width, modules) = qr_modules(tracking_uuid).unwrap();
+    assert_eq!(modules.len(), width * width);
+    // Finder pattern in the top-left corner
+    assert!(modules[0] && modules[6] && !modules[width + 1]);
+
+    let labels: Vec<LabelData> = (0..11)
+        .map(|i| LabelData {
+            tracking_uuid: Uuid::new_v4(),
+            case_caption: "Smith v. Jones & Co.".to_string(),
+            title: format!("Laptop #{}", i),
+            collection_date: Utc::now(),
+            evidence_type: EvidenceType::Digital,
+        })
+        .collect();
+
+    // Ten labels per Letter sheet
+    let pdf = String::from_utf8_lossy(&render_labels_pdf(&labels).unwrap()).to_string();
+    assert!(pdf.contains("/Count 2"));
+    assert!(pdf.contains(&labels[10].tracking_uuid.to_string()));
+
+    let svg = render_labels_svg(&labels).unwrap();
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.contains("Smith v. Jones &amp; Co."));
+    assert!(svg.contains(&labels[0].tracking_uuid.to_string()));
+}
+
+#[test]
+fn test_exhibit_numbering_status_rules_and_list_export() {
+    use chrono::Utc;
+    use rusty_saas::api::exhibits::list::{
+        exhibit_list_rows, exhibit_number, render_exhibit_list, status_transition_allowed,
+        ExhibitListFormat,
+    };
+    use rusty_saas::models::{ExhibitParty, ExhibitStatus, TrialExhibit};
+    use uuid::Uuid;
+
+    assert_eq!(exhibit_number(ExhibitParty::Plaintiff, 1), "P-1");
+    assert_eq!(exhibit_number(ExhibitParty::Defendant, 12), "D-12");
+
+    assert!(status_transition_allowed(
+        ExhibitStatus::Marked,
+        ExhibitStatus::Offered
+    ));
+    assert!(status_transition_allowed(
+        ExhibitStatus::Offered,
+        ExhibitStatus::Excluded
+    ));
+    assert!(!status_transition_allowed(
+        ExhibitStatus::Marked,
+        ExhibitStatus::Excluded
+    ));
+    assert!(!status_transition_allowed(
+        ExhibitStatus::Admitted,
+        ExhibitStatus::Excluded
+    ));
+
+    let now = Utc::now();
+    let exhibit = |party: ExhibitParty, sequence: i32, status: ExhibitStatus| TrialExhibit {
+        id: Uuid::new_v4(),
+        case_id: Uuid::new_v4(),
+        exhibit_number: exhibit_number(party, sequence),
+        exhibit_sequence: sequence,
+        title: format!("Exhibit {}", exhibit_number(party, sequence)),
+        date_marked: now,
+        party,
+        status,
+        file_type: "Document".to_string(),
+        description: None,
+        witness: None,
+        objection: None,
+        evidence_id: None,
+        document_id: None,
+        offered_at: None,
+        ruled_at: Some(now),
+        created_at: now,
+        updated_at: now,
+    };
+    let exhibits = vec![
+        exhibit(ExhibitParty::Defendant, 1, ExhibitStatus::Excluded),
+        exhibit(ExhibitParty::Plaintiff, 2, ExhibitStatus::Admitted),
+        exhibit(ExhibitParty::Plaintiff, 1, ExhibitStatus::Marked),
+    ];
+
+    // Plaintiff exhibits first, numbers in their party's column
+    let rows = exhibit_list_rows(&exhibits);
+    assert_eq!(rows[0][0], "P-1");
+    assert_eq!(rows[1][0], "P-2");
+    assert_eq!(rows[2][0], "");
+    assert_eq!(rows[2][1], "D-1");
+    assert!(rows[2][4].starts_with("Excluded"));
+
+    let heading = vec!["Smith v. Jones".to_string()];
+    let pdf = render_exhibit_list(ExhibitListFormat::Pdf, &heading, &exhibits).unwrap();
+    assert!(pdf.starts_with(b"%PDF"));
+    let docx = render_exhibit_list(ExhibitListFormat::Docx, &heading, &exhibits).unwrap();
+    assert!(docx.starts_with(b"PK"));
+}
+
+#[test]
+fn test_admissibility_follows_motion_in_limine_rulings() {
+    use rusty_saas::api::evidence::challenges::{
+        admissibility_after_challenges, challenge_status_for_outcome,
+    };
+    use rusty_saas::models::{AdmissibilityStatus, ChallengeStatus, MotionOutcome};
+
+    assert_eq!(
+        challenge_status_for_outcome(MotionOutcome::Granted),
+        ChallengeStatus::Sustained
+    );
+    assert_eq!(
+        challenge_status_for_outcome(MotionOutcome::Denied),
+        ChallengeStatus::Overruled
+    );
+    assert_eq!(
+        challenge_status_for_outcome(MotionOutcome::Moot),
+        ChallengeStatus::Withdrawn
+    );
+
+    // Never challenged: admissibility is left alone
+    assert_eq!(admissibility_after_challenges(&[]), None);
+    // An open challenge outweighs any ruling
+    assert_eq!(
+        admissibility_after_challenges(&[ChallengeStatus::Sustained, ChallengeStatus::Pending]),
+        Some(AdmissibilityStatus::Challenged)
+    );
+    assert_eq!(
+        admissibility_after_challenges(&[ChallengeStatus::Overruled, ChallengeStatus::Sustained]),
+        Some(AdmissibilityStatus::Inadmissible)
+    );
+    assert_eq!(
+        admissibility_after_challenges(&[ChallengeStatus::Overruled, ChallengeStatus::Withdrawn]),
+        Some(AdmissibilityStatus::Admissible)
+    );
+    assert_eq!(
+        admissibility_after_challenges(&[ChallengeStatus::Withdrawn]),
+        Some(AdmissibilityStatus::Pending)
+    );
+}
+
+#[test]
+fn test_docket_numbering_consistency_report() {
+    use rusty_saas::api::docket::sequence::{consistency_report, missing_ranges, DocketNumbering};
+    use rusty_saas::models::SequenceGap;
+    use uuid::Uuid;
+
+    assert!(missing_ranges(&[]).is_empty());
+    assert_eq!(
+        missing_ranges(&[7, 1, 2, 4]),
+        vec![
+            SequenceGap { from: 3, to: 3 },
+            SequenceGap { from: 5, to: 6 }
+        ]
+    );
+
+    let entry = |sequence_number: i32, pacer: Option<i32>, deleted: bool| DocketNumbering {
+        id: Uuid::new_v4(),
+        sequence_number,
+        pacer_sequence_number: pacer,
+        deleted,
+    };
+    let case_id = Uuid::new_v4();
+
+    let clean = vec![
+        entry(1, Some(1), false),
+        entry(2, None, true),
+        entry(3, Some(2), false),
+    ];
+    let report = consistency_report(case_id, &clean);
+    assert!(report.consistent);
+    assert_eq!(report.entry_count, 2);
+    assert_eq!(report.last_sequence_number, 3);
+
+    // PACER entry 3 missing, 4 imported twice, and the deleted entry's
+    // PACER number does not count
+    let entries = vec![
+        entry(1, Some(1), false),
+        entry(2, Some(2), false),
+        entry(3, Some(4), false),
+        entry(4, Some(4), false),
+        entry(5, Some(5), true),
+        entry(6, None, false),
+    ];
+    let report = consistency_report(case_id, &entries);
+    assert!(!report.consistent);
+    assert!(report.sequence_gaps.is_empty());
+    assert_eq!(report.pacer_gaps, vec![SequenceGap { from: 3, to: 3 }]);
+    assert_eq!(report.pacer_duplicates.len(), 1);
+    assert_eq!(report.pacer_duplicates[0].pacer_sequence_number, 4);
+    assert_eq!(report.pacer_duplicates[0].entry_ids.len(), 2);
+    assert_eq!(report.entries_without_pacer_number, 1);
+}
+
+#[test]
+fn test_pacer_docket_report_import_parsing_and_diff() {
+    use chrono::NaiveDate;
+    use rusty_saas::api::pacer::diff::{
+        diff_entries, entry_type, report_timestamp, EntryMatch, ExistingEntry,
+    };
+    use rusty_saas::api::pacer::parser::parse_docket_report;
+    use rusty_saas::api::pacer::party_type;
+    use rusty_saas::models::DocketEntryType;
+    use uuid::Uuid;
+
+    let original = parse_docket_report(include_str!("fixtures/pacer/docket_report.html")).unwrap();
+    assert_eq!(original.court, "Southern District of New York");
+    assert_eq!(original.case_number, "1:23-cv-04567-JPO");
+    assert_eq!(
+        original.case_title,
+        "Acme Widgets, Inc. v. Globex Corporation et al"
+    );
+    assert_eq!(
+        original.assigned_to.as_deref(),
+        Some("Judge J. Paul Oetken")
+    );
+    assert_eq!(
+        original.referred_to.as_deref(),
+        Some("Magistrate Judge Sarah Netburn")
+    );
+    assert_eq!(
+        original.nature_of_suit.as_deref(),
+        Some("190 Contract: Other")
+    );
+    assert_eq!(original.date_filed, NaiveDate::from_ymd_opt(2023, 5, 30));
+    assert_eq!(original.date_terminated, None);
+
+    // Parties with their attorneys; the pro se defendant has none
+    assert_eq!(original.parties.len(), 3);
+    let plaintiff = &original.parties[0];
+    assert_eq!(
+        (plaintiff.name.as_str(), plaintiff.role.as_str()),
+        ("Acme Widgets, Inc.", "Plaintiff")
+    );
+    assert_eq!(plaintiff.attorneys.len(), 2);
+    let lead = &plaintiff.attorneys[0];
+    assert_eq!(lead.name, "Dana R. Whitfield");
+    assert_eq!(lead.address_lines[0], "Whitfield & Park LLP");
+    assert_eq!(lead.phone.as_deref(), Some("212-555-0142"));
+    assert_eq!(lead.fax.as_deref(), Some("212-555-0143"));
+    assert_eq!(lead.email.as_deref(), Some("dwhitfield@whitfieldpark.com"));
+    assert_eq!(
+        lead.designations,
+        vec!["LEAD ATTORNEY", "ATTORNEY TO BE NOTICED"]
+    );
+    assert_eq!(
+        original.parties[1].attorneys[0].phone.as_deref(),
+        Some("(646) 555-0199")
+    );
+    assert_eq!(original.parties[2].role, "Defendant");
+    assert!(original.parties[2].attorneys.is_empty());
+    assert_eq!(party_type("Acme Widgets, Inc."), "Corporation");
+    assert_eq!(party_type("United States of America"), "Government");
+    assert_eq!(party_type("Hank Scorpio"), "Individual");
+
+    // Docket entries, including an unnumbered minute entry
+    assert_eq!(original.entries.len(), 5);
+    assert_eq!(original.entries[0].number, Some(1));
+    assert_eq!(
+        original.entries[0].filed_by().as_deref(),
+        Some("Acme Widgets, Inc.")
+    );
+    assert_eq!(
+        original.entries[3].filed_by().as_deref(),
+        Some("Globex Corporation")
+    );
+    assert_eq!(original.entries[2].number, None);
+    assert_eq!(
+        entry_type(&original.entries[2].text),
+        DocketEntryType::MinuteEntry
+    );
+    assert_eq!(
+        entry_type(&original.entries[3].text),
+        DocketEntryType::Filing
+    );
+    assert_eq!(
+        entry_type(&original.entries[4].text),
+        DocketEntryType::Order
+    );
+
+    // A first import adds everything
+    let first = diff_entries(&[], &original.entries);
+    assert!(first
+        .matches
+        .iter()
+        .all(|m| matches!(m, EntryMatch::New(_))));
+
+    // Re-importing the same report changes nothing
+    let on_file: Vec<ExistingEntry> = original
+        .entries
+        .iter()
+        .map(|e| ExistingEntry {
+            id: Uuid::new_v4(),
+            pacer_sequence_number: e.number,
+            date: report_timestamp(e.date_filed),
+            description: Some(e.text.clone()),
+            is_sealed: false,
+        })
+        .collect();
+    let again = diff_entries(&on_file, &original.entries);
+    assert!(again
+        .matches
+        .iter()
+        .all(|m| matches!(m, EntryMatch::Unchanged { .. })));
+    assert!(again.missing_from_report.is_empty());
+
+    // The updated report corrects entry 2 and adds entries 5 and 6
+    let updated =
+        parse_docket_report(include_str!("fixtures/pacer/docket_report_updated.html")).unwrap();
+    assert_eq!(
+        updated.date_terminated,
+        NaiveDate::from_ymd_opt(2023, 9, 15)
+    );
+    let diff = diff_entries(&on_file, &updated.entries);
+    let changed: Vec<_> = diff
+        .matches
+        .iter()
+        .filter_map(|m| match m {
+            EntryMatch::Changed {
+                entry,
+                changed_fields,
+                ..
+            } => Some((entry.number, changed_fields.clone())),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(changed, vec![(Some(2), vec!["text"])]);
+    let added: Vec<Option<i32>> = diff
+        .matches
+        .iter()
+        .filter_map(|m| match m {
+            EntryMatch::New(entry) => Some(entry.number),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(added, vec![Some(5), Some(6)]);
+
+    // Entries on file that the report dropped are flagged
+    let diff = diff_entries(&on_file, &updated.entries[..2]);
+    assert_eq!(diff.missing_from_report, vec![3, 4]);
+
+    // Re-importing over a sealed entry flags it and withholds its text
+    let mut sealed_on_file = on_file.clone();
+    sealed_on_file[1].is_sealed = true;
+    let diff = diff_entries(&sealed_on_file, &updated.entries);
+    let sealed: Vec<_> = diff
+        .matches
+        .iter()
+        .filter_map(|m| match m {
+            EntryMatch::Changed {
+                entry,
+                previous_text,
+                sealed,
+                ..
+            } => Some((entry.number, previous_text.clone(), *sealed)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(sealed, vec![(Some(2), None, true)]);
+
+    assert!(parse_docket_report("<html><body>Login</body></html>").is_err());
+}
+
+#[test]
+fn test_deadline_counting() {
+    use chrono::NaiveDate;
+    use rusty_saas::api::calendars::business_days::BusinessCalendar;
+    use rusty_saas::api::calendars::holidays::federal_holidays;
+    use rusty_saas::api::deadlines::counting::{
+        added_service_days, check_period, compute_due_date,
+    };
+    use rusty_saas::models::{DayCount, ServiceMethod};
+
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let federal = BusinessCalendar::federal();
+    let court_closed = |d| federal.is_closed(d);
+
+    // Observed dates move off weekends, including into the previous year
+    assert_eq!(federal_holidays(2025).len(), 11);
+    assert!(federal_holidays(2021).contains(&(date(2021, 12, 31), "New Year's Day")));
+    assert!(!federal_holidays(2022)
+        .iter()
+        .any(|(_, name)| *name == "New Year's Day"));
+    assert!(court_closed(date(2022, 6, 20)));
+    assert!(!court_closed(date(2022, 6, 21)));
+
+    // 21 days after June 13, 2025 is Independence Day; the period runs to Monday
+    let answer = compute_due_date(
+        date(2025, 6, 13),
+        21,
+        DayCount::CalendarDays,
+        None,
+        false,
+        court_closed,
+    )
+    .unwrap();
+    assert_eq!(answer.due_date, date(2025, 7, 7));
+    assert!(answer.computation.contains("rolled to 2025-07-07"));
+
+    // Mail service adds three days after the Sunday rollover
+    let responses = compute_due_date(
+        date(2025, 10, 10),
+        30,
+        DayCount::CalendarDays,
+        Some(ServiceMethod::Mail),
+        true,
+        court_closed,
+    )
+    .unwrap();
+    assert_eq!(responses.due_date, date(2025, 11, 13));
+    assert_eq!(added_service_days(ServiceMethod::Electronic), 0);
+    let electronic = compute_due_date(
+        date(2025, 10, 10),
+        30,
+        DayCount::CalendarDays,
+        Some(ServiceMethod::Electronic),
+        true,
+        court_closed,
+    )
+    .unwrap();
+    assert_eq!(electronic.due_date, date(2025, 11, 10));
+
+    // Rules and the calculator share one limit, halved for court days
+    assert!(check_period(-1098, DayCount::CalendarDays).is_ok());
+    assert!(check_period(1099, DayCount::CalendarDays).is_err());
+    assert!(check_period(550, DayCount::CourtDays).is_err());
+
+    // Longer periods are refused rather than counted
+    assert!(compute_due_date(
+        date(2025, 10, 10),
+        i32::MAX,
+        DayCount::CourtDays,
+        None,
+        false,
+        court_closed,
+    )
+    .is_err());
+
+    // Court days skip Thanksgiving and the weekend
+    let court_days = compute_due_date(
+        date(2025, 11, 24),
+        7,
+        DayCount::CourtDays,
+        None,
+        false,
+        court_closed,
+    )
+    .unwrap();
+    assert_eq!(court_days.due_date, date(2025, 12, 4));
+
+    // Backward periods roll back past Labor Day weekend and ignore service days
+    let before_hearing = compute_due_date(
+        date(2025, 9, 15),
+        -14,
+        DayCount::CalendarDays,
+        Some(ServiceMethod::Mail),
+        true,
+        court_closed,
+    )
+    .unwrap();
+    assert_eq!(before_hearing.due_date, date(2025, 8, 29));
+
+    // Trigger dates outside the supported years are refused
+    assert!(compute_due_date(
+        date(262142, 12, 20),
+        100,
+        DayCount::CourtDays,
+        None,
+        false,
+        court_closed,
+    )
+    .is_err());
+}
+
+#[test]
+fn test_court_calendar_business_days() {
+    use chrono::NaiveDate;
+    use rusty_saas::api::calendars::business_days::{check_calendar_date, BusinessCalendar};
+    use rusty_saas::api::calendars::holidays::federal_holidays;
+    use rusty_saas::api::calendars::import::parse_holidays;
+
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    // A state court that keeps Lincoln's Birthday but not Columbus Day
+    let mut state = BusinessCalendar::new(false);
+    let ics = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nBEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20260212\r\nSUMMARY:Lincoln\\, Birthday\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20261225\r\nDTEND;VALUE=DATE:20261227\r\nSUMMARY:Christmas\r\n  Recess\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+    let holidays = parse_holidays(ics).unwrap();
+    assert_eq!(holidays.len(), 3);
+    assert_eq!(holidays[0].name, "Lincoln, Birthday");
+    assert_eq!(holidays[2].date, date(2026, 12, 26));
+    assert_eq!(holidays[2].name, "Christmas Recess");
+    for holiday in holidays {
+        state.add_closure(holiday.date, holiday.name);
+    }
+    assert!(state.is_closed(date(2026, 2, 12)));
+    assert!(state.is_business_day(date(2026, 10, 12)));
+    assert!(BusinessCalendar::federal().is_closed(date(2026, 10, 12)));
+
+    // Ad-hoc closures count like holidays
+    state.add_closure(date(2026, 2, 13), "Snow closure");
+    assert_eq!(
+        state.add_business_days(date(2026, 2, 11), 1),
+        Some(date(2026, 2, 16))
+    );
+    assert_eq!(
+        state.add_business_days(date(2026, 2, 16), -1),
+        Some(date(2026, 2, 11))
+    );
+    assert_eq!(
+        state.business_days_between(date(2026, 2, 9), date(2026, 2, 16)),
+        3
+    );
+    assert_eq!(
+        state.next_business_day(date(2026, 2, 13)),
+        Some(date(2026, 2, 16))
+    );
+    assert_eq!(
+        state.previous_business_day(date(2026, 2, 15)),
+        Some(date(2026, 2, 11))
+    );
+    let reasons: Vec<String> = state
+        .closed_days(date(2026, 2, 12), date(2026, 2, 15))
+        .into_iter()
+        .map(|d| d.reason)
+        .collect();
+    assert_eq!(
+        reasons,
+        vec!["Lincoln, Birthday", "Snow closure", "Saturday", "Sunday"]
+    );
+
+    // CSV lists with a header row and US-style dates
+    let csv = "date,name\n07/03/2026,\"Independence Day (observed)\"\n2026-11-27,Day after Thanksgiving\n";
+    let holidays = parse_holidays(csv).unwrap();
+    assert_eq!(holidays.len(), 2);
+    assert_eq!(holidays[0].date, date(2026, 7, 3));
+    assert_eq!(holidays[0].name, "Independence Day (observed)");
+    assert!(parse_holidays("date,name\nnot a date,Holiday\n").is_err());
+    assert!(parse_holidays(
+        "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART:20260101\nRRULE:FREQ=YEARLY\nEND:VEVENT\nEND:VCALENDAR\n"
+    )
+    .is_err());
+
+    // Dates far outside the supported years are refused, not counted
+    assert!(parse_holidays(
+        "date,name
+1850-01-01,Holiday
+"
+    )
+    .is_err());
+    assert!(check_calendar_date(date(262142, 12, 20)).is_err());
+    assert!(check_calendar_date(date(9999, 12, 31)).is_ok());
+    assert_eq!(
+        BusinessCalendar::federal().add_business_days(NaiveDate::MAX, 1),
+        None
+    );
+    assert!(!federal_holidays(262142).is_empty());
+}
+
+#[test]
+fn test_sealed_docket_entries_redaction_and_export() {
+    use chrono::{TimeZone, Utc};
+    use rusty_saas::api::docket::sealing::{redact_entry, validate_court_order, SEALED_TITLE};
+    use rusty_saas::api::docket::sheet::{
+        docket_sheet_rows, render_docket_sheet, DocketSheetFormat,
+    };
+    use rusty_saas::models::{DocketEntry, DocketEntryType, ServiceMethod};
+    use uuid::Uuid;
+
+    let date = Utc.with_ymd_and_hms(2026, 3, 2, 15, 0, 0).unwrap();
+    let entry = |sequence: i32, title: &str, sealed: bool| DocketEntry {
+        id: Uuid::new_v4(),
+        sequence_number: sequence,
+        pacer_sequence_number: Some(sequence + 10),
+        case_id: Uuid::new_v4(),
+        date,
+        entry_type: DocketEntryType::Filing,
+        title: title.to_string(),
+        description: Some("Exhibit A attached".to_string()),
+        filed_by: Some("Plaintiff".to_string()),
+        is_sealed: Some(sealed),
+        seal_order: sealed.then(|| "ECF No. 41".to_string()),
+        sealed_at: sealed.then_some(date),
+        unseal_order: None,
+        unsealed_at: None,
+        trigger_event: None,
+        service_method: Some(ServiceMethod::Electronic),
+        created_at: date,
+        updated_at: date,
+        redacted: false,
+    };
+
+    // Sealed entries keep their numbers, date and order but lose their contents
+    let sealed = redact_entry(entry(2, "Motion to Seal Medical Records", true));
+    assert!(sealed.redacted);
+    assert_eq!(sealed.title, SEALED_TITLE);
+    assert_eq!(sealed.description, None);
+    assert_eq!(sealed.filed_by, None);
+    assert_eq!(sealed.service_method, None);
+    assert_eq!(sealed.sequence_number, 2);
+    assert_eq!(sealed.seal_order.as_deref(), Some("ECF No. 41"));
+    let open = redact_entry(entry(1, "Complaint", false));
+    assert!(!open.redacted);
+    assert_eq!(open.title, "Complaint");
+
+    assert_eq!(validate_court_order("  ECF No. 41 ").unwrap(), "ECF No. 41");
+    assert!(validate_court_order("   ").is_err());
+    assert!(validate_court_order(&"x".repeat(256)).is_err());
+
+    // Sealed entries are left out of the docket sheet entirely
+    let entries = vec![
+        entry(3, "Order", false),
+        entry(2, "Motion to Seal Medical Records", true),
+        entry(1, "Complaint", false),
+    ];
+    let rows = docket_sheet_rows(&entries);
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0][1], "1");
+    assert_eq!(rows[0][2], "11");
+    assert_eq!(rows[0][3], "Filing: Complaint. Exhibit A attached");
+    assert_eq!(rows[1][1], "3");
+
+    let csv = render_docket_sheet(DocketSheetFormat::Csv, &[], &entries);
+    let csv = String::from_utf8(csv).unwrap();
+    assert!(csv.starts_with("Date Filed,#,PACER #,Docket Text,Filed By"));
+    assert!(!csv.contains("Medical Records"));
+    let heading = vec!["Smith v. Jones".to_string()];
+    let pdf = render_docket_sheet(DocketSheetFormat::Pdf, &heading, &entries);
+    assert!(pdf.starts_with(b"%PDF"));
+    assert!(DocketSheetFormat::parse("xlsx").is_err());
+}
+
+#[test]
+fn test_motion_status_advances_with_docketed_papers() {
+    use rusty_saas::api::motions::filings::status_after_docketing;
+    use rusty_saas::models::{MotionFilingRole, MotionStatus};
+
+    // Each paper moves the motion forward to its stage
+    assert_eq!(
+        status_after_docketing(MotionStatus::Draft, MotionFilingRole::Filing),
+        Some(MotionStatus::Filed)
+    );
+    assert_eq!(
+        status_after_docketing(MotionStatus::Filed, MotionFilingRole::Opposition),
+        Some(MotionStatus::OppositionServed)
+    );
+    assert_eq!(
+        status_after_docketing(MotionStatus::OppositionServed, MotionFilingRole::Reply),
+        Some(MotionStatus::ReplyServed)
+    );
+    assert_eq!(
+        status_after_docketing(MotionStatus::Submitted, MotionFilingRole::Order),
+        Some(MotionStatus::Decided)
+    );
+
+    // A reply docketed before the opposition still advances a filed motion
+    assert_eq!(
+        status_after_docketing(MotionStatus::Filed, MotionFilingRole::Reply),
+        Some(MotionStatus::ReplyServed)
+    );
+
+    // Statuses never move back, and withdrawn or decided motions stay put
+    assert_eq!(
+        status_after_docketing(MotionStatus::HearingSet, MotionFilingRole::Opposition),
+        None
+    );
+    assert_eq!(
+        status_after_docketing(MotionStatus::ReplyServed, MotionFilingRole::Filing),
+        None
+    );
+    assert_eq!(
+        status_after_docketing(MotionStatus::Withdrawn, MotionFilingRole::Order),
+        None
+    );
+    assert_eq!(
+        status_after_docketing(MotionStatus::Decided, MotionFilingRole::Order),
+        None
+    );
+}
+
+#[test]
+fn test_motion_briefing_schedule() {
+    use chrono::{NaiveDate, Utc};
+    use rusty_saas::api::calendars::business_days::BusinessCalendar;
+    use rusty_saas::api::motions::briefing::{
+        applicable_rule, briefing_due_date, effective_due_date, is_briefed,
+    };
+    use rusty_saas::models::{BriefingRule, DayCount, MotionFilingRole, MotionType};
+    use uuid::Uuid;
+
+    let date = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap();
+    let calendar = BusinessCalendar::federal();
+    let closed = |d| calendar.is_closed(d);
+
+    // Opposition 14 days before a Monday hearing lands on a Monday
+    let hearing = date(12, 15);
+    let opposition = briefing_due_date(hearing, 14, DayCount::CalendarDays, closed).unwrap();
+    assert_eq!(opposition.due_date, date(12, 1));
+
+    // A due date on a weekend moves back to the Friday before
+    let reply = briefing_due_date(hearing, 9, DayCount::CalendarDays, closed).unwrap();
+    assert_eq!(reply.due_date, date(12, 5));
+    assert!(reply.computation.contains("rolled to 2025-12-05"));
+
+    // Court days skip Thanksgiving and the weekend
+    let court_days = briefing_due_date(date(12, 1), 3, DayCount::CourtDays, closed).unwrap();
+    assert_eq!(court_days.due_date, date(11, 25));
+
+    // Extensions beat overrides, which beat the computed date
+    let computed = Some(date(12, 1));
+    assert_eq!(effective_due_date(computed, None, None), computed);
+    assert_eq!(
+        effective_due_date(computed, Some(date(12, 3)), None),
+        Some(date(12, 3))
+    );
+    assert_eq!(
+        effective_due_date(computed, Some(date(12, 3)), Some(date(12, 8))),
+        Some(date(12, 8))
+    );
+    assert_eq!(effective_due_date(None, None, None), None);
+
+    // The most specific matching rule sets each paper's due date
+    let rule = |role, motion_type, court: Option<&str>, days| BriefingRule {
+        id: Uuid::new_v4(),
+        legal_rule_id: Uuid::new_v4(),
+        motion_type,
+        role,
+        days_before_hearing: days,
+        day_count: DayCount::CalendarDays,
+        court: court.map(str::to_string),
+        jurisdiction: None,
+        priority: "High".to_string(),
+        created_by: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    };
+    let rules = vec![
+        rule(MotionFilingRole::Opposition, None, None, 21),
+        rule(MotionFilingRole::Opposition, None, Some("N.D. Cal."), 14),
+        rule(
+            MotionFilingRole::Opposition,
+            Some(MotionType::SummaryJudgment),
+            None,
+            28,
+        ),
+        rule(MotionFilingRole::Reply, None, None, 7),
+    ];
+    assert_eq!(
+        applicable_rule(&rules, MotionFilingRole::Opposition).map(|r| r.days_before_hearing),
+        Some(28)
+    );
+    assert_eq!(
+        applicable_rule(&rules[..2], MotionFilingRole::Opposition).map(|r| r.days_before_hearing),
+        Some(14)
+    );
+    assert_eq!(
+        applicable_rule(&rules, MotionFilingRole::Reply).map(|r| r.days_before_hearing),
+        Some(7)
+    );
+    assert!(applicable_rule(&rules, MotionFilingRole::Order).is_none());
+
+    assert!(is_briefed(MotionFilingRole::Opposition));
+    assert!(!is_briefed(MotionFilingRole::Filing));
+}
+
+#[test]
+fn test_motion_status_workflow() {
+    use rusty_saas::api::motions::workflow::{can_transition, is_pending, next_statuses};
+    use rusty_saas::models::MotionStatus;
+
+    // Motions move one step at a time along the flow
+    assert_eq!(
+        next_statuses(MotionStatus::Draft),
+        vec![MotionStatus::Filed, MotionStatus::Withdrawn]
+    );
+    assert_eq!(
+        next_statuses(MotionStatus::Submitted),
+        vec![MotionStatus::Decided, MotionStatus::Withdrawn]
+    );
+    assert!(can_transition(
+        MotionStatus::ReplyServed,
+        MotionStatus::HearingSet
+    ));
+    assert!(!can_transition(MotionStatus::Filed, MotionStatus::Decided));
+    assert!(!can_transition(
+        MotionStatus::HearingSet,
+        MotionStatus::Filed
+    ));
+
+    // Withdrawal is possible until the motion is decided
+    assert!(can_transition(MotionStatus::Draft, MotionStatus::Withdrawn));
+    assert!(can_transition(
+        MotionStatus::HearingSet,
+        MotionStatus::Withdrawn
+    ));
+    assert!(next_statuses(MotionStatus::Decided).is_empty());
+    assert!(next_statuses(MotionStatus::Withdrawn).is_empty());
+
+    // Pending means not yet decided or withdrawn
+    assert!(is_pending(MotionStatus::Draft));
+    assert!(is_pending(MotionStatus::Submitted));
+    assert!(!is_pending(MotionStatus::Decided));
+    assert!(!is_pending(MotionStatus::Withdrawn));
+}
+
+#[test]
+fn test_motion_outcome_analytics() {
+    use chrono::{DateTime, TimeZone, Utc};
+    use rusty_saas::api::analytics::outcomes::{
+        median, outcome_report, outcome_report_csv, DecidedMotion,
+    };
+    use rusty_saas::models::{MotionOutcome, MotionType, OutcomeDimension};
+
+    let day =
+        |d| -> Option<DateTime<Utc>> { Some(Utc.with_ymd_and_hms(2025, 3, d, 12, 0, 0).unwrap()) };
+    let motion = |judge: Option<&str>,
+                  motion_type,
+                  outcome,
+                  filed: u32,
+                  heard: Option<u32>,
+                  decided: u32| {
+        DecidedMotion {
+            judge: judge.map(str::to_string),
+            court: Some("S.D.N.Y.".to_string()),
+            nature_of_suit: None,
+            motion_type,
+            outcome,
+            filing_date: day(filed),
+            hearing_date: heard.and_then(day),
+            decided_at: day(decided),
+        }
+    };
+    let motions = vec![
+        motion(
+            Some("Hon. A. Smith"),
+            MotionType::Dismiss,
+            MotionOutcome::Granted,
+            1,
+            Some(10),
+            11,
+        ),
+        motion(
+            Some("Hon. A. Smith"),
+            MotionType::Dismiss,
+            MotionOutcome::Denied,
+            1,
+            Some(10),
+            21,
+        ),
+        motion(
+            Some("Hon. A. Smith"),
+            MotionType::SummaryJudgment,
+            MotionOutcome::Granted,
+            2,
+            None,
+            12,
+        ),
+        motion(
+            Some("Hon. B. Jones"),
+            MotionType::Dismiss,
+            MotionOutcome::Moot,
+            5,
+            None,
+            6,
+        ),
+        motion(
+            None,
+            MotionType::Sanctions,
+            MotionOutcome::Denied,
+            1,
+            None,
+            2,
+        ),
+    ];
+
+    assert_eq!(median(vec![]), None);
+    assert_eq!(median(vec![7, 1, 3]), Some(3.0));
+    assert_eq!(median(vec![4, 1, 3, 10]), Some(3.5));
+
+    let report = outcome_report(&motions, OutcomeDimension::Judge, None, None);
+    assert_eq!(report.overall.decided, 5);
+    assert_eq!(report.overall.granted, 2);
+    assert_eq!(report.overall.denied, 2);
+    assert_eq!(report.overall.other, 1);
+
+    // Busiest judge first; cases without a judge are grouped together
+    let smith = &report.groups[0];
+    assert_eq!(smith.key.as_deref(), Some("Hon. A. Smith"));
+    assert_eq!(smith.decided, 3);
+    assert!((smith.grant_rate - 2.0 / 3.0).abs() < 1e-9);
+    assert_eq!(smith.median_days_filing_to_decision, Some(10.0));
+    assert_eq!(smith.median_days_hearing_to_decision, Some(6.0));
+    assert_eq!(report.groups.len(), 3);
+    assert!(report.groups.iter().any(|g| g.key.is_none()));
+
+    let by_type = outcome_report(&motions, OutcomeDimension::MotionType, None, None);
+    assert_eq!(by_type.groups[0].key.as_deref(), Some("Dismiss"));
+    assert_eq!(by_type.groups[0].decided, 3);
+    assert_eq!(by_type.groups[0].median_days_hearing_to_decision, Some(6.0));
+
+    let csv = outcome_report_csv(&report);
+    let lines: Vec<&str> = csv.lines().collect();
+    assert!(lines[0].starts_with("Judge,Decided,Granted,Denied,Other,Grant Rate"));
+    assert!(lines[1].starts_with("Hon. A. Smith,3,2,1,0,66.7%,33.3%,10.0,6.0"));
+    assert!(lines.last().unwrap().starts_with("All,5,2,2,1,40.0%,40.0%"));
+}
+
+#[test]
+fn test_task_status_workflow() {
+    use rusty_saas::api::tasks::workflow::{can_transition, completion_for, next_statuses};
+    use rusty_saas::models::TaskStatus;
+
+    // Work starts, is reviewed and finished; review can send it back
+    assert!(can_transition(TaskStatus::Pending, TaskStatus::InProgress));
+    assert!(can_transition(TaskStatus::InProgress, TaskStatus::Review));
+    assert!(can_transition(TaskStatus::Review, TaskStatus::InProgress));
+    assert!(can_transition(TaskStatus::Review, TaskStatus::Done));
+    assert!(can_transition(TaskStatus::Done, TaskStatus::Completed));
+    assert!(!can_transition(TaskStatus::Pending, TaskStatus::Review));
+    assert!(!can_transition(TaskStatus::Pending, TaskStatus::Done));
+
+    // Open tasks can be completed directly; completed ones only reopened
+    assert!(can_transition(TaskStatus::Pending, TaskStatus::Completed));
+    assert_eq!(
+        next_statuses(TaskStatus::Completed),
+        &[TaskStatus::InProgress]
+    );
+
+    // Finished tasks are fully complete, reopened ones are not
+    assert_eq!(completion_for(TaskStatus::Done, 40), 100);
+    assert_eq!(completion_for(TaskStatus::Completed, 0), 100);
+    assert_eq!(completion_for(TaskStatus::InProgress, 100), 99);
+    assert_eq!(completion_for(TaskStatus::InProgress, 60), 60);
+}
+
+#[test]
+fn test_workflow_template_scheduling_and_rollup() {
+    use chrono::NaiveDate;
+    use rusty_saas::api::workflows::templates::{
+        project_status, schedule_task, stage_status, validate_stages,
+    };
+    use rusty_saas::models::{StageStatus, TaskStatus, TemplateStage, TemplateTask};
+
+    let task = |title: &str, offset_days: i32, duration_days: Option<i32>| TemplateTask {
+        title: title.to_string(),
+        description: None,
+        priority: None,
+        offset_days,
+        duration_days,
+    };
+    let stages = vec![
+        TemplateStage {
+            name: "Expert Discovery".to_string(),
+            tasks: vec![task("Serve expert reports", -90, Some(30))],
+        },
+        TemplateStage {
+            name: "Pretrial".to_string(),
+            tasks: vec![task("File motions in limine", -14, None)],
+        },
+    ];
+    assert!(validate_stages(&stages).is_ok());
+
+    // Stages need distinct names and at least one task
+    let mut duplicate = stages.clone();
+    duplicate[1].name = "expert discovery".to_string();
+    assert!(validate_stages(&duplicate).is_err());
+    let mut empty = stages.clone();
+    empty[0].tasks.clear();
+    assert!(validate_stages(&empty).is_err());
+    assert!(validate_stages(&[]).is_err());
+    let mut far = stages.clone();
+    far[0].tasks[0].offset_days = i32::MIN;
+    assert!(validate_stages(&far).is_err());
+    assert!(schedule_task(NaiveDate::MIN, &stages[0].tasks[0]).is_err());
+
+    // Due dates count from the anchor; start dates back off the duration
+    let trial = NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
+    assert_eq!(
+        schedule_task(trial, &stages[0].tasks[0]).unwrap(),
+        (
+            NaiveDate::from_ymd_opt(2026, 2, 1),
+            NaiveDate::from_ymd_opt(2026, 3, 3).unwrap()
+        )
+    );
+    assert_eq!(
+        schedule_task(trial, &stages[1].tasks[0]).unwrap(),
+        (None, NaiveDate::from_ymd_opt(2026, 5, 18).unwrap())
+    );
+
+    // Stage status rolls up from its tasks
+    assert_eq!(
+        stage_status(&[TaskStatus::Pending, TaskStatus::Pending]),
+        StageStatus::Pending
+    );
+    assert_eq!(
+        stage_status(&[TaskStatus::Done, TaskStatus::Pending]),
+        StageStatus::Active
+    );
+    assert_eq!(
+        stage_status(&[TaskStatus::Done, TaskStatus::Completed]),
+        StageStatus::Completed
+    );
+
+    // And project status from its stages
+    assert_eq!(
+        project_status(&[StageStatus::Pending, StageStatus::Pending]),
+        "Planning"
+    );
+    assert_eq!(
+        project_status(&[StageStatus::Completed, StageStatus::Pending]),
+        "Active"
+    );
+    assert_eq!(
+        project_status(&[StageStatus::Completed, StageStatus::Completed]),
+        "Completed"
+    );
+}
+
+#[test]
+fn test_task_dependencies_and_critical_path() {
+    use chrono::{TimeZone, Utc};
+    use rusty_saas::api::tasks::dependencies::{
+        creates_cycle, push_dependents, total_float, TaskDates,
+    };
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    let day = |d: u32| Utc.with_ymd_and_hms(2026, 3, d, 17, 0, 0).unwrap();
+    let task = |start: Option<u32>, due: u32, dependencies: Vec<Uuid>| TaskDates {
+        start: start.map(day),
+        due: day(due),
+        dependencies,
+        closed: false,
+    };
+
+    let reports = Uuid::new_v4();
+    let daubert = Uuid::new_v4();
+    let exhibits = Uuid::new_v4();
+    let pretrial = Uuid::new_v4();
+
+    // Expert reports before Daubert motions, both before the pretrial order
+    let mut tasks = HashMap::new();
+    tasks.insert(reports, task(Some(1), 10, vec![]));
+    tasks.insert(daubert, task(Some(8), 15, vec![reports]));
+    tasks.insert(exhibits, task(Some(2), 5, vec![]));
+    tasks.insert(pretrial, task(Some(16), 20, vec![daubert, exhibits]));
+
+    // Depending on a task that already depends on you closes a loop
+    let predecessors: HashMap<Uuid, Vec<Uuid>> = tasks
+        .iter()
+        .map(|(id, dates)| (*id, dates.dependencies.clone()))
+        .collect();
+    assert!(creates_cycle(reports, &[pretrial], &predecessors));
+    assert!(creates_cycle(reports, &[reports], &predecessors));
+    assert!(!creates_cycle(exhibits, &[reports], &predecessors));
+
+    // Daubert motions started before the reports were due, so they move two
+    // days later, which pushes the pretrial order back a day
+    let moved: HashMap<Uuid, TaskDates> = push_dependents(&tasks).into_iter().collect();
+    assert_eq!(moved.len(), 2);
+    assert_eq!(moved[&daubert].start, Some(day(10)));
+    assert_eq!(moved[&daubert].due, day(17));
+    assert_eq!(moved[&pretrial].start, Some(day(17)));
+    assert_eq!(moved[&pretrial].due, day(21));
+
+    // Finished tasks keep their dates
+    let mut finished = tasks.clone();
+    finished.get_mut(&daubert).unwrap().closed = true;
+    let moved_after_close = push_dependents(&finished);
+    assert!(moved_after_close.is_empty());
+
+    // After rescheduling, the reports chain is critical and exhibits have slack
+    let mut scheduled = tasks.clone();
+    scheduled.extend(moved);
+    let float = total_float(&scheduled);
+    assert_eq!(float[&reports].num_days(), 0);
+    assert_eq!(float[&daubert].num_days(), 0);
+    assert_eq!(float[&pretrial].num_days(), 0);
+    assert_eq!(float[&exhibits].num_days(), 12);
+}
+
+#[test]
+fn test_task_recurrence_rules_and_escalation_priorities() {
+    use chrono::NaiveDate;
+    use rusty_saas::api::tasks::escalation::priorities_at_least;
+    use rusty_saas::api::tasks::recurrence::{Frequency, Recurrence};
+
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    // Monthly status reports pinned to the day of the first report
+    let monthly: Recurrence = "FREQ=MONTHLY".parse().unwrap();
+    let monthly = monthly.anchored(date(2026, 1, 31));
+    assert_eq!(monthly.to_string(), "FREQ=MONTHLY;BYMONTHDAY=31");
+    assert_eq!(
+        monthly.next_after(date(2026, 1, 31)),
+        Some(date(2026, 2, 28))
+    );
+    assert_eq!(
+        monthly.next_after(date(2026, 2, 28)),
+        Some(date(2026, 3, 31))
+    );
+
+    // Quarterly trust reconciliations, four of them
+    let quarterly: Recurrence = "RRULE:FREQ=MONTHLY;INTERVAL=3;BYMONTHDAY=15;COUNT=4"
+        .parse()
+        .unwrap();
+    assert_eq!(quarterly.frequency, Frequency::Monthly);
+    assert_eq!(
+        quarterly.next_after(date(2026, 11, 15)),
+        Some(date(2027, 2, 15))
+    );
+    assert!(quarterly.allows(4, date(2027, 8, 15)));
+    assert!(!quarterly.allows(5, date(2027, 11, 15)));
+
+    // Weekly until a date
+    let weekly: Recurrence = "FREQ=WEEKLY;INTERVAL=2;UNTIL=20260301".parse().unwrap();
+    assert_eq!(
+        weekly.next_after(date(2026, 2, 10)),
+        Some(date(2026, 2, 24))
+    );
+    assert!(weekly.allows(10, date(2026, 3, 1)));
+    assert!(!weekly.allows(10, date(2026, 3, 2)));
+
+    // Unsupported or contradictory rules are rejected
+    assert!("INTERVAL=2".parse::<Recurrence>().is_err());
+    assert!("FREQ=HOURLY".parse::<Recurrence>().is_err());
+    assert!("FREQ=WEEKLY;BYDAY=MO".parse::<Recurrence>().is_err());
+    assert!("FREQ=DAILY;BYMONTHDAY=3".parse::<Recurrence>().is_err());
+    assert!("FREQ=DAILY;COUNT=3;UNTIL=20260101"
+        .parse::<Recurrence>()
+        .is_err());
+    assert!("FREQ=DAILY;INTERVAL=0".parse::<Recurrence>().is_err());
+    assert!("FREQ=DAILY;INTERVAL=4000000000"
+        .parse::<Recurrence>()
+        .is_err());
+
+    // Occurrences past the last representable date end the series
+    let yearly: Recurrence = "FREQ=YEARLY;INTERVAL=1000".parse().unwrap();
+    assert_eq!(yearly.next_after(NaiveDate::MAX), None);
+    assert_eq!(
+        yearly.next_after(date(2026, 2, 28)),
+        Some(date(3026, 2, 28))
+    );
+
+    // Escalation rules cover their priority and anything higher
+    assert_eq!(priorities_at_least("High"), &["High", "Critical"]);
+    assert_eq!(priorities_at_least("Critical"), &["Critical"]);
+    assert!(priorities_at_least("Urgent").is_empty());
+}
+
+#[test]
+fn test_board_ranks_keep_drag_order() {
+    use rusty_saas::api::tasks::board::{rank_between, spread_ranks};
+
+    // Ranks between neighbours sort between them
+    let rank = |before: Option<&str>, after: Option<&str>| rank_between(before, after).unwrap();
+    assert_eq!(rank(None, None), "i");
+    assert_eq!(rank(Some("i"), None), "r");
+    assert_eq!(rank(None, Some("i")), "9");
+    assert_eq!(rank(Some("a"), Some("b")), "ai");
+    assert_eq!(rank(Some("az"), Some("b")), "azi");
+    assert_eq!(rank(None, Some("001")), "000i");
+
+    // Neighbours out of order or sharing a rank leave no room between them
+    assert_eq!(rank_between(Some("i"), Some("i")), None);
+    assert_eq!(rank_between(Some("r"), Some("i")), None);
+    assert_eq!(rank_between(Some("i"), Some("i0")), None);
+    assert_eq!(rank_between(None, Some("0")), None);
+
+    // Dropping tasks at the front, the back and in the middle of a column
+    // keeps every rank in order without renumbering the others
+    let mut column: Vec<String> = Vec::new();
+    for i in 0..200usize {
+        let position = match i % 3 {
+            0 => 0,
+            1 => column.len(),
+            _ => column.len() / 2,
+        };
+        let before = position.checked_sub(1).map(|p| column[p].as_str());
+        let after = column.get(position).map(String::as_str);
+        let rank = rank(before, after);
+        assert!(before.is_none_or(|b| b < rank.as_str()));
+        assert!(after.is_none_or(|a| rank.as_str() < a));
+        assert!(!rank.ends_with('0'));
+        column.insert(position, rank);
+    }
+    assert!(column.windows(2).all(|pair| pair[0] < pair[1]));
+
+    // Respreading a column gives short, evenly spaced, ascending ranks
+    let spread = spread_ranks(1000);
+    assert_eq!(spread.len(), 1000);
+    assert!(spread.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(spread
+        .iter()
+        .all(|rank| rank.len() <= 3 && !rank.ends_with('0')));
+}
+
+#[test]
+fn test_discovery_response_deadlines() {
+    use chrono::NaiveDate;
+    use rusty_saas::api::calendars::business_days::BusinessCalendar;
+    use rusty_saas::api::discovery_requests::responses::{
+        can_transition, is_overdue, item_label, response_due_date, response_period,
+    };
+    use rusty_saas::models::{DiscoveryStatus, DiscoveryType, ServiceMethod};
+
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let federal = BusinessCalendar::federal();
+    let court_closed = |d| federal.is_closed(d);
+
+    // Written discovery gets 30 days; depositions go ahead on the noticed date
+    assert_eq!(response_period(DiscoveryType::Interrogatory), Some(30));
+    assert_eq!(response_period(DiscoveryType::Admission), Some(30));
+    assert_eq!(response_period(DiscoveryType::Deposition), None);
+
+    // Electronic service earns no extra days
+    let electronic = response_due_date(
+        date(2025, 9, 2),
+        30,
+        ServiceMethod::Electronic,
+        court_closed,
+    )
+    .unwrap();
+    assert_eq!(electronic.due_date, date(2025, 10, 2));
+
+    // Mail service adds three days, landing on a Sunday that rolls to Monday
+    let mail = response_due_date(date(2025, 9, 2), 30, ServiceMethod::Mail, court_closed).unwrap();
+    assert_eq!(mail.due_date, date(2025, 10, 6));
+
+    // A period ending on Veterans Day runs to the next day
+    let holiday = response_due_date(
+        date(2025, 10, 12),
+        30,
+        ServiceMethod::Personal,
+        court_closed,
+    )
+    .unwrap();
+    assert_eq!(holiday.due_date, date(2025, 11, 12));
+
+    // Numbered requests are cited by type
+    assert_eq!(
+        item_label(DiscoveryType::Interrogatory, 1),
+        "Interrogatory No. 1"
+    );
+    assert_eq!(
+        item_label(DiscoveryType::Production, 12),
+        "Request for Production No. 12"
+    );
+
+    // Sets are served before they can be answered, and closed sets stay closed
+    assert!(can_transition(
+        DiscoveryStatus::Draft,
+        DiscoveryStatus::Served
+    ));
+    assert!(!can_transition(
+        DiscoveryStatus::Draft,
+        DiscoveryStatus::Responded
+    ));
+    assert!(can_transition(
+        DiscoveryStatus::Overdue,
+        DiscoveryStatus::MotionFiled
+    ));
+    assert!(!can_transition(
+        DiscoveryStatus::Closed,
+        DiscoveryStatus::Served
+    ));
+
+    // Only served sets past their due date are overdue
+    assert!(is_overdue(
+        DiscoveryStatus::Served,
+        date(2025, 10, 2),
+        date(2025, 10, 3)
+    ));
+    assert!(!is_overdue(
+        DiscoveryStatus::Served,
+        date(2025, 10, 2),
+        date(2025, 10, 2)
+    ));
+    assert!(!is_overdue(
+        DiscoveryStatus::Responded,
+        date(2025, 10, 2),
+        date(2025, 10, 3)
+    ));
+}